@@ -0,0 +1,14 @@
+//! Detects targets where `r7` is reserved as the frame pointer and can't be
+//! bound directly as the system call number on `arm-linux`.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(thumb_mode)");
+    let target = env::var("TARGET").unwrap_or_default();
+    let features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    if target.starts_with("thumb") || features.split(',').any(|feature| feature == "thumb-mode") {
+        println!("cargo:rustc-cfg=thumb_mode");
+    }
+}
@@ -0,0 +1,52 @@
+#!/bin/sh
+# Builds ci/static-pie as a static position independent executable, in debug
+# and release, with and without packed relative relocations, and runs it with
+# and without its PT_PHDR program header.
+#
+# TARGET selects the target, and RUNNER a command to run it with, such as
+# qemu-arm. Packed relocations need binutils 2.38 or later, and are skipped if
+# NO_RELR is set.
+set -eu
+cd "$(dirname "$0")"
+target=${TARGET:-x86_64-unknown-linux-gnu}
+flags="-C link-arg=-nostartfiles -C target-feature=+crt-static -C relocation-model=pie"
+
+# Replaces the PT_PHDR program header with PT_NULL, so that the load offset is
+# found through _DYNAMIC.
+strip_phdr() {
+    python3 - "$1" "$2" <<'PY'
+import struct, sys
+image = bytearray(open(sys.argv[1], "rb").read())
+wide = image[4] == 2
+phoff, = struct.unpack_from("<Q" if wide else "<I", image, 0x20 if wide else 0x1c)
+phentsize, phnum = struct.unpack_from("<HH", image, 0x36 if wide else 0x2a)
+for i in range(phnum):
+    if struct.unpack_from("<I", image, phoff + i * phentsize)[0] == 6:
+        struct.pack_into("<I", image, phoff + i * phentsize, 0)
+open(sys.argv[2], "wb").write(image)
+PY
+    chmod +x "$2"
+}
+
+check() {
+    readelf -h "$1" | grep -q "DYN (" || { echo "$1: not position independent"; exit 1; }
+    if readelf -l "$1" | grep -q INTERP; then
+        echo "$1: has an interpreter"
+        exit 1
+    fi
+    ${RUNNER:-} "$1" one two
+}
+
+for relr in "" "-C link-arg=-Wl,-z,pack-relative-relocs"; do
+    if [ -n "$relr" ] && [ -n "${NO_RELR:-}" ]; then
+        continue
+    fi
+    for profile in debug release; do
+        release=$([ $profile = release ] && echo --release || true)
+        RUSTFLAGS="$flags $relr" cargo build --target "$target" $release
+        binary=target/$target/$profile/static-pie
+        check "$binary"
+        strip_phdr "$binary" "$binary-no-phdr"
+        check "$binary-no-phdr"
+    done
+done
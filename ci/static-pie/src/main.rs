@@ -0,0 +1,95 @@
+//! A static PIE using the `_start` entry point of `raw-syscall-base`, which
+//! exits with status 0 if its relocations were applied.
+//!
+//! The data below holds addresses, so reading it before relocation gives the
+//! link-time addresses rather than the run-time ones. Built and run by
+//! `run.sh`.
+//!
+
+#![no_std]
+#![no_main]
+
+use raw_syscall_base::{
+    entry,
+    nr,
+    syscall,
+    StartupInfo,
+};
+
+entry!(main);
+
+static MESSAGE: &[u8] = b"relocated\n";
+static FUNCTIONS: [fn() -> usize; 2] = [one, two];
+
+fn one() -> usize {
+    1
+}
+
+fn two() -> usize {
+    2
+}
+
+fn main(info: StartupInfo) -> i32 {
+    if info.args().count() != info.argc() {
+        return 1;
+    }
+    if FUNCTIONS[0]() + FUNCTIONS[1]() != 3 {
+        return 2;
+    }
+    match unsafe { syscall!(nr::WRITE, 1, MESSAGE.as_ptr(), MESSAGE.len()) } {
+        Ok(len) if len == MESSAGE.len() => 0,
+        _ => 3,
+    }
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
+
+// The C library's versions may be chosen through `IRELATIVE` relocations,
+// which aren't applied, so simple ones are provided.
+
+#[no_mangle]
+unsafe extern "C" fn memcpy(dst: *mut u8, src: *const u8, n: usize) -> *mut u8 {
+    for i in 0..n {
+        core::ptr::write_volatile(dst.add(i), *src.add(i));
+    }
+    dst
+}
+
+#[no_mangle]
+unsafe extern "C" fn memset(dst: *mut u8, c: i32, n: usize) -> *mut u8 {
+    for i in 0..n {
+        core::ptr::write_volatile(dst.add(i), c as u8);
+    }
+    dst
+}
+
+#[no_mangle]
+unsafe extern "C" fn memcmp(a: *const u8, b: *const u8, n: usize) -> i32 {
+    for i in 0..n {
+        let (x, y) = (core::ptr::read_volatile(a.add(i)), *b.add(i));
+        if x != y {
+            return i32::from(x) - i32::from(y);
+        }
+    }
+    0
+}
+
+#[no_mangle]
+unsafe extern "C" fn bcmp(a: *const u8, b: *const u8, n: usize) -> i32 {
+    memcmp(a, b, n)
+}
+
+#[no_mangle]
+unsafe extern "C" fn strlen(s: *const u8) -> usize {
+    let mut len = 0;
+    while core::ptr::read_volatile(s.add(len)) != 0 {
+        len += 1;
+    }
+    len
+}
@@ -1,4 +1,6 @@
+use crate::Errno;
 use ::core::{
+    arch::asm,
     hint::unreachable_unchecked,
     result::Result::{
         self,
@@ -12,8 +14,11 @@ use ::core::{
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
 #[inline(always)]
-pub unsafe fn syscall(x8: usize, a: &[usize]) -> Result<usize, usize> {
+pub unsafe fn syscall(x8: usize, a: &[usize]) -> Result<usize, Errno> {
     match a.len() {
         0 => syscall_0(x8),
         1 => syscall_1(x8, a[0]),
@@ -31,6 +36,9 @@ pub unsafe fn syscall(x8: usize, a: &[usize]) -> Result<usize, usize> {
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
 /// This should only be used for calls like `exit` or `exit_group` which are
 /// guaranteed to never return.
 ///
@@ -53,19 +61,18 @@ pub unsafe fn syscall_nr(x8: usize, a: &[usize]) -> ! {
 /// The argument specifies the system call.
 ///
 #[inline(always)]
-pub unsafe fn syscall_0(x8: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_0(x8: usize) -> Result<usize, Errno> {
     let x0: usize;
     asm!(
-        "svc $$0"
-        : "={x0}"(x0)
-        : "{x8}"(x8)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        lateout("x0") x0,
+        in("x8") x8,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -78,13 +85,10 @@ pub unsafe fn syscall_0(x8: usize) -> Result<usize, usize> {
 #[inline(always)]
 pub unsafe fn syscall_0_nr(x8: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with one argument and returns the result.
@@ -93,18 +97,17 @@ pub unsafe fn syscall_0_nr(x8: usize) -> ! {
 /// argument to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_1(x8: usize, mut x0: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_1(x8: usize, mut x0: usize) -> Result<usize, Errno> {
     asm!(
-        "svc $$0"
-        : "+{x0}"(x0)
-        : "{x8}"(x8)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        inlateout("x0") x0,
+        in("x8") x8,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -118,13 +121,11 @@ pub unsafe fn syscall_1(x8: usize, mut x0: usize) -> Result<usize, usize> {
 #[inline(always)]
 pub unsafe fn syscall_1_nr(x8: usize, x0: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8), "{x0}"(x0)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        in("x0") x0,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with two arguments and returns the result.
@@ -133,18 +134,18 @@ pub unsafe fn syscall_1_nr(x8: usize, x0: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_2(x8: usize, mut x0: usize, x1: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_2(x8: usize, mut x0: usize, x1: usize) -> Result<usize, Errno> {
     asm!(
-        "svc $$0"
-        : "+{x0}"(x0)
-        : "{x8}"(x8), "{x1}"(x1)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        inlateout("x0") x0,
+        in("x8") x8,
+        in("x1") x1,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -158,13 +159,12 @@ pub unsafe fn syscall_2(x8: usize, mut x0: usize, x1: usize) -> Result<usize, us
 #[inline(always)]
 pub unsafe fn syscall_2_nr(x8: usize, x0: usize, x1: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8), "{x0}"(x0), "{x1}"(x1)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        in("x0") x0,
+        in("x1") x1,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with three arguments and returns the result.
@@ -173,18 +173,19 @@ pub unsafe fn syscall_2_nr(x8: usize, x0: usize, x1: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_3(x8: usize, mut x0: usize, x1: usize, x2: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_3(x8: usize, mut x0: usize, x1: usize, x2: usize) -> Result<usize, Errno> {
     asm!(
-        "svc $$0"
-        : "+{x0}"(x0)
-        : "{x8}"(x8), "{x1}"(x1), "{x2}"(x2)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        inlateout("x0") x0,
+        in("x8") x8,
+        in("x1") x1,
+        in("x2") x2,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -198,13 +199,13 @@ pub unsafe fn syscall_3(x8: usize, mut x0: usize, x1: usize, x2: usize) -> Resul
 #[inline(always)]
 pub unsafe fn syscall_3_nr(x8: usize, x0: usize, x1: usize, x2: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8), "{x0}"(x0), "{x1}"(x1), "{x2}"(x2)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        in("x0") x0,
+        in("x1") x1,
+        in("x2") x2,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with four arguments and returns the result.
@@ -213,18 +214,20 @@ pub unsafe fn syscall_3_nr(x8: usize, x0: usize, x1: usize, x2: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_4(x8: usize, mut x0: usize, x1: usize, x2: usize, x3: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_4(x8: usize, mut x0: usize, x1: usize, x2: usize, x3: usize) -> Result<usize, Errno> {
     asm!(
-        "svc $$0"
-        : "+{x0}"(x0)
-        : "{x8}"(x8), "{x1}"(x1), "{x2}"(x2), "{x3}"(x3)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        inlateout("x0") x0,
+        in("x8") x8,
+        in("x1") x1,
+        in("x2") x2,
+        in("x3") x3,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -238,13 +241,14 @@ pub unsafe fn syscall_4(x8: usize, mut x0: usize, x1: usize, x2: usize, x3: usiz
 #[inline(always)]
 pub unsafe fn syscall_4_nr(x8: usize, x0: usize, x1: usize, x2: usize, x3: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8), "{x0}"(x0), "{x1}"(x1), "{x2}"(x2), "{x3}"(x3)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        in("x0") x0,
+        in("x1") x1,
+        in("x2") x2,
+        in("x3") x3,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with five arguments and returns the result.
@@ -253,18 +257,21 @@ pub unsafe fn syscall_4_nr(x8: usize, x0: usize, x1: usize, x2: usize, x3: usize
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_5(x8: usize, mut x0: usize, x1: usize, x2: usize, x3: usize, x4: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_5(x8: usize, mut x0: usize, x1: usize, x2: usize, x3: usize, x4: usize) -> Result<usize, Errno> {
     asm!(
-        "svc $$0"
-        : "+{x0}"(x0)
-        : "{x8}"(x8), "{x1}"(x1), "{x2}"(x2), "{x3}"(x3), "{x4}"(x4)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        inlateout("x0") x0,
+        in("x8") x8,
+        in("x1") x1,
+        in("x2") x2,
+        in("x3") x3,
+        in("x4") x4,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -278,13 +285,15 @@ pub unsafe fn syscall_5(x8: usize, mut x0: usize, x1: usize, x2: usize, x3: usiz
 #[inline(always)]
 pub unsafe fn syscall_5_nr(x8: usize, x0: usize, x1: usize, x2: usize, x3: usize, x4: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8), "{x0}"(x0), "{x1}"(x1), "{x2}"(x2), "{x3}"(x3), "{x4}"(x4)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        in("x0") x0,
+        in("x1") x1,
+        in("x2") x2,
+        in("x3") x3,
+        in("x4") x4,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with six arguments and returns the result.
@@ -301,19 +310,23 @@ pub unsafe fn syscall_6(
     x3: usize,
     x4: usize,
     x5: usize,
-) -> Result<usize, usize>
+) -> Result<usize, Errno>
 {
     asm!(
-        "svc $$0"
-        : "+{x0}"(x0)
-        : "{x8}"(x8), "{x1}"(x1), "{x2}"(x2), "{x3}"(x3), "{x4}"(x4), "{x5}"(x5)
-        : "cc", "memory"
-        : "volatile"
+        "svc 0",
+        inlateout("x0") x0,
+        in("x8") x8,
+        in("x1") x1,
+        in("x2") x2,
+        in("x3") x3,
+        in("x4") x4,
+        in("x5") x5,
+        options(nostack, preserves_flags),
     );
     if x0 < 0xffff_ffff_ffff_f000 {
         Ok(x0)
     } else {
-        Err(x0)
+        Err(Errno::new(x0.wrapping_neg()))
     }
 }
 
@@ -327,11 +340,24 @@ pub unsafe fn syscall_6(
 #[inline(always)]
 pub unsafe fn syscall_6_nr(x8: usize, x0: usize, x1: usize, x2: usize, x3: usize, x4: usize, x5: usize) -> ! {
     asm!(
-        "svc $$0"
-        :
-        : "{x8}"(x8), "{x0}"(x0), "{x1}"(x1), "{x2}"(x2), "{x3}"(x3), "{x4}"(x4), "{x5}"(x5)
-        : "cc"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "svc 0",
+        in("x8") x8,
+        in("x0") x0,
+        in("x1") x1,
+        in("x2") x2,
+        in("x3") x3,
+        in("x4") x4,
+        in("x5") x5,
+        options(noreturn, nostack),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(x8);
+    syscall_1_as = syscall_1(x8, x0);
+    syscall_2_as = syscall_2(x8, x0, x1);
+    syscall_3_as = syscall_3(x8, x0, x1, x2);
+    syscall_4_as = syscall_4(x8, x0, x1, x2, x3);
+    syscall_5_as = syscall_5(x8, x0, x1, x2, x3, x4);
+    syscall_6_as = syscall_6(x8, x0, x1, x2, x3, x4, x5);
 }
@@ -0,0 +1,252 @@
+use crate::{
+    errno::E2BIG,
+    syscall,
+    syscall_nr,
+    Errno,
+    SyscallArg,
+    MAX_ARGS,
+};
+use ::core::{
+    clone::Clone,
+    cmp::Ord,
+    fmt::Debug,
+    marker::Copy,
+    result::Result::{
+        self,
+        Err,
+    },
+};
+
+/// Builds the register layout of system call arguments.
+///
+/// Arguments are added in order as they appear in the kernel's prototype for
+/// the system call. 64-bit values are split and aligned as the platform
+/// expects, so on 32-bit platforms they may take two registers and a padding
+/// register before them.
+///
+/// Adding more arguments than registers are available causes the system call
+/// to fail with `E2BIG` without being performed.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Args {
+    regs: [SyscallArg; MAX_ARGS],
+    len: usize,
+}
+
+impl Args {
+    /// Creates an empty argument list.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            regs: [0; MAX_ARGS],
+            len: 0,
+        }
+    }
+
+    /// Adds an argument occupying one register.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn usize(self, value: usize) -> Self {
+        self.reg(value as SyscallArg)
+    }
+
+    /// Adds a 32-bit argument, which is zero-extended on 64-bit platforms.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn u32(self, value: u32) -> Self {
+        self.reg(value as SyscallArg)
+    }
+
+    /// Adds a 64-bit argument, which occupies one register on 64-bit
+    /// platforms and on `x32-linux`.
+    ///
+    #[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+    #[inline(always)]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn u64(self, value: u64) -> Self {
+        self.reg(value as SyscallArg)
+    }
+
+    /// Adds a 64-bit argument, which occupies an aligned pair of registers on
+    /// EABI `arm` and o32 `mips`.
+    ///
+    /// If the next register is odd numbered it is skipped as padding, and the
+    /// pair holds the halves in memory order for the target's endianness.
+    ///
+    #[cfg(any(target_arch = "arm", target_arch = "mips"))]
+    #[inline(always)]
+    #[must_use]
+    pub fn u64(mut self, value: u64) -> Self {
+        if self.len % 2 == 1 {
+            self = self.usize(0);
+        }
+        self.pair(value)
+    }
+
+    /// Adds a 64-bit argument, which occupies the next two registers on `x86`.
+    ///
+    #[cfg(target_arch = "x86")]
+    #[inline(always)]
+    #[must_use]
+    pub fn u64(self, value: u64) -> Self {
+        self.pair(value)
+    }
+
+    /// Adds both halves of a 64-bit argument in memory order.
+    ///
+    #[cfg(any(target_arch = "arm", target_arch = "mips", target_arch = "x86"))]
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn pair(self, value: u64) -> Self {
+        let low = value as usize;
+        let high = (value >> 32) as usize;
+        if ::core::cfg!(target_endian = "little") {
+            self.usize(low).usize(high)
+        } else {
+            self.usize(high).usize(low)
+        }
+    }
+
+    /// Adds a register as the next argument.
+    ///
+    #[inline(always)]
+    fn reg(mut self, value: SyscallArg) -> Self {
+        if self.len < MAX_ARGS {
+            self.regs[self.len] = value;
+        }
+        self.len += 1;
+        self
+    }
+
+    /// Returns the registers filled so far.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice(&self) -> &[SyscallArg] {
+        &self.regs[..self.len.min(MAX_ARGS)]
+    }
+
+    /// Performs a system call with these arguments and returns the result.
+    ///
+    /// The argument specifies the system call.
+    ///
+    #[inline(always)]
+    pub unsafe fn syscall(&self, number: usize) -> Result<usize, Errno> {
+        if self.len > MAX_ARGS {
+            Err(E2BIG)
+        } else {
+            syscall(number, &self.regs[..self.len])
+        }
+    }
+
+    /// Performs a system call with these arguments which never returns.
+    ///
+    /// The argument specifies the system call. If too many arguments were
+    /// added, `E2BIG` is returned without performing the system call.
+    ///
+    /// This function should only be used for calls guaranteed to never return.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn syscall_nr(&self, number: usize) -> Errno {
+        if self.len > MAX_ARGS {
+            E2BIG
+        } else {
+            syscall_nr(number, &self.regs[..self.len])
+        }
+    }
+}
+
+impl ::core::default::Default for Args {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Args;
+    use crate::{
+        errno::E2BIG,
+        MAX_ARGS,
+    };
+    use ::core::{
+        assert_eq,
+        result::Result::Err,
+    };
+
+    #[test]
+    fn registers() {
+        let args = Args::new().usize(1).u32(2).usize(3);
+        assert_eq!(args.as_slice(), &[1, 2, 3]);
+    }
+
+    #[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+    #[test]
+    fn u64() {
+        let args = Args::new().usize(1).u64(0x1122_3344_5566_7788).usize(2);
+        assert_eq!(args.as_slice(), &[1, 0x1122_3344_5566_7788, 2]);
+    }
+
+    #[cfg(all(any(target_arch = "arm", target_arch = "mips"), target_endian = "little"))]
+    #[test]
+    fn u64() {
+        let args = Args::new().usize(1).u64(0x1122_3344_5566_7788).usize(2);
+        assert_eq!(args.as_slice(), &[1, 0, 0x5566_7788, 0x1122_3344, 2]);
+        let args = Args::new().u64(0x1122_3344_5566_7788).usize(2);
+        assert_eq!(args.as_slice(), &[0x5566_7788, 0x1122_3344, 2]);
+    }
+
+    #[cfg(all(any(target_arch = "arm", target_arch = "mips"), target_endian = "big"))]
+    #[test]
+    fn u64() {
+        let args = Args::new().usize(1).u64(0x1122_3344_5566_7788).usize(2);
+        assert_eq!(args.as_slice(), &[1, 0, 0x1122_3344, 0x5566_7788, 2]);
+        let args = Args::new().u64(0x1122_3344_5566_7788).usize(2);
+        assert_eq!(args.as_slice(), &[0x1122_3344, 0x5566_7788, 2]);
+    }
+
+    #[cfg(target_arch = "x86")]
+    #[test]
+    fn u64() {
+        let args = Args::new().usize(1).u64(0x1122_3344_5566_7788).usize(2);
+        assert_eq!(args.as_slice(), &[1, 0x5566_7788, 0x1122_3344, 2]);
+    }
+
+    #[test]
+    fn too_many() {
+        let mut args = Args::new();
+        for i in 0..=MAX_ARGS {
+            args = args.usize(i);
+        }
+        assert_eq!(args.as_slice().len(), MAX_ARGS);
+        assert_eq!(unsafe { args.syscall(usize::MAX) }, Err(E2BIG));
+    }
+
+    /// Reads with an offset whose high half is set, which only reads past the
+    /// end of the file if the whole offset reaches the kernel, such as through
+    /// the stack on o32 `mips`.
+    ///
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn pread64() {
+        use crate::nr;
+        use ::core::result::Result::Ok;
+
+        let mut buf = [0_u8; 3];
+        unsafe {
+            let fd = syscall!(nr::OPENAT, -100_i32, b"/proc/self/exe\0", 0_u32, ()).unwrap();
+            let args = Args::new().usize(fd).usize(buf.as_mut_ptr() as usize).usize(buf.len());
+            assert_eq!(args.u64(1).syscall(nr::PREAD64), Ok(3));
+            assert_eq!(&buf, b"ELF");
+            assert_eq!(args.u64(1 << 32 | 1).syscall(nr::PREAD64), Ok(0));
+            assert_eq!(syscall!(nr::CLOSE, fd), Ok(0));
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use crate::Errno;
 use ::core::{
+    arch::asm,
     hint::unreachable_unchecked,
     result::Result::{
         self,
@@ -12,8 +14,11 @@ use ::core::{
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
 #[inline(always)]
-pub unsafe fn syscall(r7: usize, a: &[usize]) -> Result<usize, usize> {
+pub unsafe fn syscall(r7: usize, a: &[usize]) -> Result<usize, Errno> {
     match a.len() {
         0 => syscall_0(r7),
         1 => syscall_1(r7, a[0]),
@@ -31,6 +36,9 @@ pub unsafe fn syscall(r7: usize, a: &[usize]) -> Result<usize, usize> {
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
 /// This should only be used for calls like `exit` or `exit_group` which are
 /// guaranteed to never return.
 ///
@@ -53,19 +61,30 @@ pub unsafe fn syscall_nr(r7: usize, a: &[usize]) -> ! {
 /// The argument specifies the system call.
 ///
 #[inline(always)]
-pub unsafe fn syscall_0(r7: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_0(r7: usize) -> Result<usize, Errno> {
     let r0: usize;
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        lateout("r0") r0,
+        in("r7") r7,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "={r0}"(r0)
-        : "{r7}"(r7)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        lateout("r0") r0,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -77,14 +96,19 @@ pub unsafe fn syscall_0(r7: usize) -> Result<usize, usize> {
 ///
 #[inline(always)]
 pub unsafe fn syscall_0_nr(r7: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        options(noreturn, nostack),
+    );
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
 }
 
 /// Performs a system call with one argument and returns the result.
@@ -93,18 +117,29 @@ pub unsafe fn syscall_0_nr(r7: usize) -> ! {
 /// argument to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_1(r7: usize, mut r0: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_1(r7: usize, mut r0: usize) -> Result<usize, Errno> {
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        inlateout("r0") r0,
+        in("r7") r7,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "+{r0}"(r0)
-        : "{r7}"(r7)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        inlateout("r0") r0,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -117,14 +152,21 @@ pub unsafe fn syscall_1(r7: usize, mut r0: usize) -> Result<usize, usize> {
 ///
 #[inline(always)]
 pub unsafe fn syscall_1_nr(r7: usize, r0: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7), "{r0}"(r0)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        in("r0") r0,
+        options(noreturn, nostack),
+    );
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        in("r0") r0,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
 }
 
 /// Performs a system call with two arguments and returns the result.
@@ -133,18 +175,31 @@ pub unsafe fn syscall_1_nr(r7: usize, r0: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_2(r7: usize, mut r0: usize, r1: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_2(r7: usize, mut r0: usize, r1: usize) -> Result<usize, Errno> {
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        inlateout("r0") r0,
+        in("r7") r7,
+        in("r1") r1,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "+{r0}"(r0)
-        : "{r7}"(r7), "{r1}"(r1)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        inlateout("r0") r0,
+        in("r1") r1,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -157,14 +212,23 @@ pub unsafe fn syscall_2(r7: usize, mut r0: usize, r1: usize) -> Result<usize, us
 ///
 #[inline(always)]
 pub unsafe fn syscall_2_nr(r7: usize, r0: usize, r1: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7), "{r0}"(r0), "{r1}"(r1)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        in("r0") r0,
+        in("r1") r1,
+        options(noreturn, nostack),
+    );
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        in("r0") r0,
+        in("r1") r1,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
 }
 
 /// Performs a system call with three arguments and returns the result.
@@ -173,18 +237,33 @@ pub unsafe fn syscall_2_nr(r7: usize, r0: usize, r1: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_3(r7: usize, mut r0: usize, r1: usize, r2: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_3(r7: usize, mut r0: usize, r1: usize, r2: usize) -> Result<usize, Errno> {
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        inlateout("r0") r0,
+        in("r7") r7,
+        in("r1") r1,
+        in("r2") r2,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "+{r0}"(r0)
-        : "{r7}"(r7), "{r1}"(r1), "{r2}"(r2)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        inlateout("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -197,14 +276,25 @@ pub unsafe fn syscall_3(r7: usize, mut r0: usize, r1: usize, r2: usize) -> Resul
 ///
 #[inline(always)]
 pub unsafe fn syscall_3_nr(r7: usize, r0: usize, r1: usize, r2: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7), "{r0}"(r0), "{r1}"(r1), "{r2}"(r2)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        options(noreturn, nostack),
+    );
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
 }
 
 /// Performs a system call with four arguments and returns the result.
@@ -213,18 +303,35 @@ pub unsafe fn syscall_3_nr(r7: usize, r0: usize, r1: usize, r2: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_4(r7: usize, mut r0: usize, r1: usize, r2: usize, r3: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_4(r7: usize, mut r0: usize, r1: usize, r2: usize, r3: usize) -> Result<usize, Errno> {
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        inlateout("r0") r0,
+        in("r7") r7,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "+{r0}"(r0)
-        : "{r7}"(r7), "{r1}"(r1), "{r2}"(r2), "{r3}"(r3)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        inlateout("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -237,14 +344,27 @@ pub unsafe fn syscall_4(r7: usize, mut r0: usize, r1: usize, r2: usize, r3: usiz
 ///
 #[inline(always)]
 pub unsafe fn syscall_4_nr(r7: usize, r0: usize, r1: usize, r2: usize, r3: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7), "{r0}"(r0), "{r1}"(r1), "{r2}"(r2), "{r3}"(r3)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        options(noreturn, nostack),
+    );
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
 }
 
 /// Performs a system call with five arguments and returns the result.
@@ -253,18 +373,37 @@ pub unsafe fn syscall_4_nr(r7: usize, r0: usize, r1: usize, r2: usize, r3: usize
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_5(r7: usize, mut r0: usize, r1: usize, r2: usize, r3: usize, r4: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_5(r7: usize, mut r0: usize, r1: usize, r2: usize, r3: usize, r4: usize) -> Result<usize, Errno> {
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        inlateout("r0") r0,
+        in("r7") r7,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "+{r0}"(r0)
-        : "{r7}"(r7), "{r1}"(r1), "{r2}"(r2), "{r3}"(r3), "{r4}"(r4)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        inlateout("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -277,14 +416,29 @@ pub unsafe fn syscall_5(r7: usize, mut r0: usize, r1: usize, r2: usize, r3: usiz
 ///
 #[inline(always)]
 pub unsafe fn syscall_5_nr(r7: usize, r0: usize, r1: usize, r2: usize, r3: usize, r4: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7), "{r0}"(r0), "{r1}"(r1), "{r2}"(r2), "{r3}"(r3), "{r4}"(r4)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        options(noreturn, nostack),
+    );
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
 }
 
 /// Performs a system call with six arguments and returns the result.
@@ -301,19 +455,40 @@ pub unsafe fn syscall_6(
     r3: usize,
     r4: usize,
     r5: usize,
-) -> Result<usize, usize>
+) -> Result<usize, Errno>
 {
+    #[cfg(not(thumb_mode))]
+    asm!(
+        "svc 0",
+        inlateout("r0") r0,
+        in("r7") r7,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(thumb_mode)]
     asm!(
-        "svc $$0"
-        : "+{r0}"(r0)
-        : "{r7}"(r7), "{r1}"(r1), "{r2}"(r2), "{r3}"(r3), "{r4}"(r4), "{r5}"(r5)
-        : "cc", "memory"
-        : "volatile"
+        "mov {tmp}, r7",
+        "mov r7, {r7}",
+        "svc 0",
+        "mov r7, {tmp}",
+        r7 = in(reg) r7,
+        tmp = out(reg) _,
+        inlateout("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        options(nostack, preserves_flags),
     );
     if r0 < 0xffff_f000 {
         Ok(r0)
     } else {
-        Err(r0)
+        Err(Errno::new(r0.wrapping_neg()))
     }
 }
 
@@ -326,12 +501,39 @@ pub unsafe fn syscall_6(
 ///
 #[inline(always)]
 pub unsafe fn syscall_6_nr(r7: usize, r0: usize, r1: usize, r2: usize, r3: usize, r4: usize, r5: usize) -> ! {
+    #[cfg(not(thumb_mode))]
     asm!(
-        "svc $$0"
-        :
-        : "{r7}"(r7), "{r0}"(r0), "{r1}"(r1), "{r2}"(r2), "{r3}"(r3), "{r4}"(r4), "{r5}"(r5)
-        : "cc"
-        : "volatile"
+        "svc 0",
+        in("r7") r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        options(noreturn, nostack),
     );
-    unreachable_unchecked()
+    #[cfg(thumb_mode)]
+    asm!(
+        "mov r7, {r7}",
+        "svc 0",
+        r7 = in(reg) r7,
+        in("r0") r0,
+        in("r1") r1,
+        in("r2") r2,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        options(noreturn, nostack),
+    );
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(r7);
+    syscall_1_as = syscall_1(r7, r0);
+    syscall_2_as = syscall_2(r7, r0, r1);
+    syscall_3_as = syscall_3(r7, r0, r1, r2);
+    syscall_4_as = syscall_4(r7, r0, r1, r2, r3);
+    syscall_5_as = syscall_5(r7, r0, r1, r2, r3, r4);
+    syscall_6_as = syscall_6(r7, r0, r1, r2, r3, r4, r5);
 }
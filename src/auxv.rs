@@ -0,0 +1,438 @@
+//! The auxiliary vector, through which the kernel passes information about the
+//! process and the system to a new program.
+//!
+//! The vector is a sequence of key/value pairs terminated by an `AT_NULL` key.
+//! It is found on the initial process stack past the environment pointers, so
+//! a program providing its own entry point can use `Auxv::from_stack`, while
+//! any other program can copy it from `/proc/self/auxv` with `read_proc`.
+//!
+
+use crate::{
+    errno,
+    nr,
+    Errno,
+};
+use ::core::{
+    clone::Clone,
+    cmp::{
+        Eq,
+        PartialEq,
+    },
+    fmt::Debug,
+    iter::{
+        IntoIterator,
+        Iterator,
+    },
+    marker::{
+        Copy,
+        PhantomData,
+    },
+    mem,
+    option::Option::{
+        self,
+        None,
+        Some,
+    },
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+};
+
+/// End of the vector.
+///
+pub const AT_NULL: usize = 0;
+
+/// Entry to be ignored.
+///
+pub const AT_IGNORE: usize = 1;
+
+/// File descriptor of the program.
+///
+pub const AT_EXECFD: usize = 2;
+
+/// Address of the program headers of the program.
+///
+pub const AT_PHDR: usize = 3;
+
+/// Size of a program header entry.
+///
+pub const AT_PHENT: usize = 4;
+
+/// Number of program headers.
+///
+pub const AT_PHNUM: usize = 5;
+
+/// System page size.
+///
+pub const AT_PAGESZ: usize = 6;
+
+/// Base address of the interpreter.
+///
+pub const AT_BASE: usize = 7;
+
+/// Flags.
+///
+pub const AT_FLAGS: usize = 8;
+
+/// Entry point of the program.
+///
+pub const AT_ENTRY: usize = 9;
+
+/// Non-zero if the program is not ELF.
+///
+pub const AT_NOTELF: usize = 10;
+
+/// Real user ID.
+///
+pub const AT_UID: usize = 11;
+
+/// Effective user ID.
+///
+pub const AT_EUID: usize = 12;
+
+/// Real group ID.
+///
+pub const AT_GID: usize = 13;
+
+/// Effective group ID.
+///
+pub const AT_EGID: usize = 14;
+
+/// Address of a string identifying the CPU for optimizations.
+///
+pub const AT_PLATFORM: usize = 15;
+
+/// Platform dependent bits describing the capabilities of the CPU.
+///
+pub const AT_HWCAP: usize = 16;
+
+/// Frequency at which `times` increments.
+///
+pub const AT_CLKTCK: usize = 17;
+
+/// Non-zero if the program runs with elevated privileges, such as setuid.
+///
+pub const AT_SECURE: usize = 23;
+
+/// Address of a string identifying the real platform.
+///
+pub const AT_BASE_PLATFORM: usize = 24;
+
+/// Address of 16 random bytes.
+///
+pub const AT_RANDOM: usize = 25;
+
+/// Extension of `AT_HWCAP`.
+///
+pub const AT_HWCAP2: usize = 26;
+
+/// Address of the file name of the program.
+///
+pub const AT_EXECFN: usize = 31;
+
+/// Address of the ELF header of the vDSO.
+///
+pub const AT_SYSINFO_EHDR: usize = 33;
+
+/// Minimal stack size for signal delivery.
+///
+pub const AT_MINSIGSTKSZ: usize = 51;
+
+/// Address of `__kernel_vsyscall` in the vDSO.
+///
+#[cfg(target_arch = "x86")]
+pub const AT_SYSINFO: usize = 32;
+
+/// Data cache block size.
+///
+#[cfg(target_arch = "powerpc64")]
+pub const AT_DCACHEBSIZE: usize = 19;
+
+/// Instruction cache block size.
+///
+#[cfg(target_arch = "powerpc64")]
+pub const AT_ICACHEBSIZE: usize = 20;
+
+/// Unified cache block size.
+///
+#[cfg(target_arch = "powerpc64")]
+pub const AT_UCACHEBSIZE: usize = 21;
+
+/// Entry to be ignored, used by old kernels to align the vector.
+///
+#[cfg(target_arch = "powerpc64")]
+pub const AT_IGNOREPPC: usize = 22;
+
+/// Size of the L1 instruction cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L1I_CACHESIZE: usize = 40;
+
+/// Geometry of the L1 instruction cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L1I_CACHEGEOMETRY: usize = 41;
+
+/// Size of the L1 data cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L1D_CACHESIZE: usize = 42;
+
+/// Geometry of the L1 data cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L1D_CACHEGEOMETRY: usize = 43;
+
+/// Size of the L2 cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L2_CACHESIZE: usize = 44;
+
+/// Geometry of the L2 cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L2_CACHEGEOMETRY: usize = 45;
+
+/// Size of the L3 cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L3_CACHESIZE: usize = 46;
+
+/// Geometry of the L3 cache.
+///
+#[cfg(any(target_arch = "powerpc64", target_arch = "riscv64"))]
+pub const AT_L3_CACHEGEOMETRY: usize = 47;
+
+const AT_FDCWD: i32 = -100;
+const O_RDONLY: usize = 0;
+const O_CLOEXEC: usize = 0o2_000_000;
+const PROC_SELF_AUXV: &[u8] = b"/proc/self/auxv\0";
+
+/// An entry of the auxiliary vector.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// One of the `AT_*` constants.
+    ///
+    pub key: usize,
+    /// The value, which is an address for some keys.
+    ///
+    pub value: usize,
+}
+
+/// An auxiliary vector in memory, borrowed for `'a`.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Auxv<'a> {
+    start: *const usize,
+    marker: PhantomData<&'a [usize]>,
+}
+
+impl Auxv<'static> {
+    /// Uses the auxiliary vector at the given address, which must remain valid
+    /// and unmodified, such as the one on the initial process stack.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn from_ptr(start: *const usize) -> Self {
+        Self {
+            start,
+            marker: PhantomData,
+        }
+    }
+
+    /// Finds the auxiliary vector on the initial process stack.
+    ///
+    /// The argument is the stack pointer on entry to the program, which points
+    /// to `argc`, followed by the argument pointers, the environment pointers
+    /// and the auxiliary vector, each of the pointer arrays terminated by null.
+    ///
+    #[must_use]
+    pub unsafe fn from_stack(sp: *const usize) -> Self {
+        let mut envp = sp.add(*sp + 2);
+        while *envp != 0 {
+            envp = envp.add(1);
+        }
+        Self::from_ptr(envp.add(1))
+    }
+}
+
+impl<'a> Auxv<'a> {
+    /// Returns an iterator over the entries, excluding the terminating
+    /// `AT_NULL`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn iter(self) -> Iter<'a> {
+        Iter {
+            next: self.start,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the value of the first entry with the given key, or `None` if
+    /// there is none.
+    ///
+    #[must_use]
+    pub fn get(self, key: usize) -> Option<usize> {
+        self.iter().find(|entry| entry.key == key).map(|entry| entry.value)
+    }
+}
+
+impl<'a> IntoIterator for Auxv<'a> {
+    type IntoIter = Iter<'a>;
+    type Item = Entry;
+
+    #[inline(always)]
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an auxiliary vector.
+///
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    next: *const usize,
+    marker: PhantomData<&'a [usize]>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Entry;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Entry> {
+        // The vector was valid when the `Auxv` was created and is borrowed for
+        // `'a`, and the terminating entry is never passed.
+        unsafe {
+            let key = *self.next;
+            if key == AT_NULL {
+                return None;
+            }
+            let value = *self.next.add(1);
+            self.next = self.next.add(2);
+            Some(Entry { key, value })
+        }
+    }
+}
+
+/// Copies the auxiliary vector of the process from `/proc/self/auxv` into the
+/// buffer, and returns it.
+///
+/// This works regardless of how the program was started, but requires `/proc`
+/// to be mounted. Fails with `ERANGE` if the vector doesn't fit in the buffer.
+///
+pub unsafe fn read_proc(buf: &mut [usize]) -> Result<Auxv<'_>, Errno> {
+    let fd = syscall!(nr::OPENAT, AT_FDCWD, PROC_SELF_AUXV.as_ptr(), O_RDONLY | O_CLOEXEC)?;
+    let read = read_all(fd, buf);
+    // A read-only file descriptor has nothing to flush, so errors are ignored.
+    let _ = syscall!(nr::CLOSE, fd);
+    let words = read? / mem::size_of::<usize>();
+    if buf[..words].chunks_exact(2).any(|entry| entry[0] == AT_NULL) {
+        Ok(Auxv {
+            start: buf.as_ptr(),
+            marker: PhantomData,
+        })
+    } else {
+        Err(errno::ERANGE)
+    }
+}
+
+/// Reads from the file descriptor until the end of the file or the buffer,
+/// and returns the number of bytes read.
+///
+unsafe fn read_all(fd: usize, buf: &mut [usize]) -> Result<usize, Errno> {
+    let size = mem::size_of_val(buf);
+    let start = buf.as_mut_ptr().cast::<u8>();
+    let mut len = 0;
+    while len < size {
+        match syscall!(nr::READ, fd, start.add(len), size - len) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(errno::EINTR) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_proc,
+        Auxv,
+        Entry,
+        AT_NULL,
+        AT_PAGESZ,
+        AT_PHDR,
+        AT_PHNUM,
+        AT_UID,
+    };
+    use crate::errno;
+    use ::core::{
+        assert,
+        assert_eq,
+        iter::{
+            IntoIterator,
+            Iterator,
+        },
+        option::Option::{
+            None,
+            Some,
+        },
+        result::Result::{
+            Err,
+            Ok,
+        },
+    };
+
+    /// An initial stack with two arguments and one environment variable.
+    ///
+    const STACK: [usize; 14] = [
+        2, 0x100, 0x200, 0, 0x300, 0, AT_PAGESZ, 4096, AT_UID, 1000, AT_UID, 0, AT_NULL, 0,
+    ];
+
+    #[test]
+    fn iterate() {
+        let auxv = unsafe { Auxv::from_ptr(STACK[6..].as_ptr()) };
+        let mut iter = auxv.iter();
+        assert_eq!(iter.next(), Some(Entry { key: AT_PAGESZ, value: 4096 }));
+        assert_eq!(iter.next(), Some(Entry { key: AT_UID, value: 1000 }));
+        assert_eq!(iter.next(), Some(Entry { key: AT_UID, value: 0 }));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(auxv.into_iter().count(), 3);
+    }
+
+    #[test]
+    fn get() {
+        let auxv = unsafe { Auxv::from_ptr(STACK[6..].as_ptr()) };
+        assert_eq!(auxv.get(AT_PAGESZ), Some(4096));
+        assert_eq!(auxv.get(AT_UID), Some(1000));
+        assert_eq!(auxv.get(AT_PHDR), None);
+        assert_eq!(auxv.get(AT_NULL), None);
+    }
+
+    #[test]
+    fn from_stack() {
+        let auxv = unsafe { Auxv::from_stack(STACK.as_ptr()) };
+        assert_eq!(auxv.iter().next(), Some(Entry { key: AT_PAGESZ, value: 4096 }));
+        let empty = [0, 0, 0, AT_NULL, 0];
+        assert_eq!(unsafe { Auxv::from_stack(empty.as_ptr()) }.iter().count(), 0);
+    }
+
+    #[test]
+    fn proc() {
+        let mut buf = [0; 128];
+        let auxv = match unsafe { read_proc(&mut buf) } {
+            Ok(auxv) => auxv,
+            Err(_) => return,
+        };
+        assert!(auxv.get(AT_PAGESZ).map_or(false, usize::is_power_of_two));
+        assert!(auxv.get(AT_PHNUM).map_or(false, |phnum| phnum > 0));
+        let mut small = [0; 2];
+        assert_eq!(unsafe { read_proc(&mut small) }.err(), Some(errno::ERANGE));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    errno::E2BIG,
+    syscall,
+    syscall_nr,
+    Errno,
+    SyscallArg,
+};
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+use crate::syscall_indirect;
+use ::core::result::Result::{
+    self,
+    Err,
+};
+
+/// The largest number of arguments any system call accepts.
+///
+pub const MAX_ARGS: usize = 6;
+
+/// The largest number of arguments `syscall_indirect` accepts besides the
+/// number of the call.
+///
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+pub const MAX_INDIRECT_ARGS: usize = 7;
+
+/// Rejects arrays of more than `MAX_ARGS` arguments at compile time.
+///
+struct Arity<const N: usize>;
+
+impl<const N: usize> Arity<N> {
+    const CHECK: () = ::core::assert!(N <= MAX_ARGS, "system calls take at most six arguments");
+}
+
+/// Rejects arrays of more than `MAX_INDIRECT_ARGS` arguments at compile time.
+///
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+struct IndirectArity<const N: usize>;
+
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+impl<const N: usize> IndirectArity<N> {
+    const CHECK: () = ::core::assert!(
+        N <= MAX_INDIRECT_ARGS,
+        "indirect system calls take at most seven arguments"
+    );
+}
+
+/// Performs a system call and returns the result, checking the number of
+/// arguments.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// Unlike `syscall`, a slice of more than six arguments is rejected with
+/// `E2BIG` without performing the system call.
+///
+#[inline(always)]
+pub unsafe fn try_syscall(number: usize, a: &[SyscallArg]) -> Result<usize, Errno> {
+    if a.len() > MAX_ARGS {
+        Err(E2BIG)
+    } else {
+        syscall(number, a)
+    }
+}
+
+/// Performs a system call which never returns, checking the number of
+/// arguments.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// Unlike `syscall_nr`, a slice of more than six arguments is rejected by
+/// returning `E2BIG` without performing the system call.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+#[must_use]
+pub unsafe fn try_syscall_nr(number: usize, a: &[SyscallArg]) -> Errno {
+    if a.len() > MAX_ARGS {
+        E2BIG
+    } else {
+        syscall_nr(number, a)
+    }
+}
+
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the second is an array of
+/// arguments to pass it. Arrays of more than six arguments fail to compile.
+///
+/// ```compile_fail
+/// let _ = unsafe { raw_syscall_base::syscall_array(0, [0; 7]) };
+/// ```
+///
+#[inline(always)]
+pub unsafe fn syscall_array<const N: usize>(number: usize, a: [SyscallArg; N]) -> Result<usize, Errno> {
+    #[allow(clippy::let_unit_value)]
+    let () = Arity::<N>::CHECK;
+    syscall(number, &a)
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the second is an array of
+/// arguments to pass it. Arrays of more than six arguments fail to compile.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_array_nr<const N: usize>(number: usize, a: [SyscallArg; N]) -> ! {
+    #[allow(clippy::let_unit_value)]
+    let () = Arity::<N>::CHECK;
+    syscall_nr(number, &a)
+}
+
+/// Performs a system call through the indirect `__syscall` entry and returns
+/// the result.
+///
+/// The first argument specifies the system call, and the second is an array of
+/// arguments to pass it. Arrays of more than seven arguments fail to compile.
+///
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+#[inline(always)]
+pub unsafe fn syscall_indirect_array<const N: usize>(number: usize, a: [usize; N]) -> Result<usize, Errno> {
+    #[allow(clippy::let_unit_value)]
+    let () = IndirectArity::<N>::CHECK;
+    syscall_indirect(number, &a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        syscall_array,
+        try_syscall,
+        try_syscall_nr,
+        MAX_ARGS,
+    };
+    use crate::{
+        errno::E2BIG,
+        nr,
+        syscall,
+    };
+    use ::core::{
+        assert_eq,
+        result::Result::Err,
+    };
+
+    #[test]
+    fn too_many() {
+        unsafe {
+            assert_eq!(try_syscall(nr::GETPID, &[0; MAX_ARGS + 1]), Err(E2BIG));
+            assert_eq!(try_syscall_nr(nr::EXIT, &[0; MAX_ARGS + 1]), E2BIG);
+        }
+    }
+
+    #[test]
+    fn getpid() {
+        unsafe {
+            let pid = syscall(nr::GETPID, &[]);
+            assert_eq!(try_syscall(nr::GETPID, &[]), pid);
+            assert_eq!(try_syscall(nr::GETPID, &[0; MAX_ARGS]), pid);
+            assert_eq!(syscall_array(nr::GETPID, []), pid);
+            assert_eq!(syscall_array(nr::GETPID, [0; MAX_ARGS]), pid);
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{
+    Fd,
+    SyscallArg,
+};
+use ::core::{
+    convert::{
+        From,
+        TryFrom,
+    },
+    debug_assert,
+    debug_assert_ne,
+    marker::Sized,
+    option::Option::{
+        self,
+        None,
+        Some,
+    },
+    ptr::{
+        self,
+        NonNull,
+    },
+};
+
+/// Conversion of a value into a system call argument register.
+///
+/// This is used by the `syscall!` and `syscall_nr!` macros and the
+/// `syscall_N_typed` functions to convert each argument.
+///
+/// Signed integers are sign-extended and unsigned integers are zero-extended
+/// to the width of a register, so an `i32` like `AT_FDCWD` keeps its value
+/// when the kernel reads the argument as an `int` or a `long`. 64-bit integers
+/// are only accepted on platforms where they fit in one register; on 32-bit
+/// platforms other than `x32-linux` use `Args` to lay them out.
+///
+/// Pointers and references are passed as their address, and `None` is passed
+/// as a null pointer. Slices and other unsized values are passed as the
+/// address of their data, without their length.
+///
+pub trait IntoSyscallArg {
+    /// Returns the value to place in the argument register.
+    ///
+    fn into_syscall_arg(self) -> SyscallArg;
+}
+
+/// Implements `IntoSyscallArg` for integers which are converted with `as`,
+/// which zero-extends unsigned and sign-extends signed types.
+///
+macro_rules! into_syscall_arg_int {
+    ($($via:ty: $($t:ty),*;)*) => {
+        $($(
+            impl IntoSyscallArg for $t {
+                #[inline(always)]
+                #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+                fn into_syscall_arg(self) -> SyscallArg {
+                    self as $via as SyscallArg
+                }
+            }
+        )*)*
+    };
+}
+
+into_syscall_arg_int! {
+    SyscallArg: usize, u8, u16, u32;
+}
+
+#[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+into_syscall_arg_int! {
+    isize: isize, i8, i16, i32;
+}
+
+#[cfg(target_pointer_width = "64")]
+into_syscall_arg_int! {
+    usize: u64;
+    isize: i64;
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+into_syscall_arg_int! {
+    u64: u64;
+    i64: isize, i8, i16, i32, i64;
+}
+
+impl IntoSyscallArg for bool {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        SyscallArg::from(self)
+    }
+}
+
+/// Passes zero, as a placeholder for unused arguments.
+///
+impl IntoSyscallArg for () {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        0
+    }
+}
+
+impl<T: ?Sized> IntoSyscallArg for *const T {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        (self.cast::<u8>() as usize).into_syscall_arg()
+    }
+}
+
+impl<T: ?Sized> IntoSyscallArg for *mut T {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        (self.cast::<u8>() as usize).into_syscall_arg()
+    }
+}
+
+impl<T: ?Sized> IntoSyscallArg for &T {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        (self as *const T).into_syscall_arg()
+    }
+}
+
+impl<T: ?Sized> IntoSyscallArg for &mut T {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        (self as *mut T).into_syscall_arg()
+    }
+}
+
+impl<T: ?Sized> IntoSyscallArg for Option<&T> {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        match self {
+            Some(r) => r.into_syscall_arg(),
+            None => ptr::null::<u8>().into_syscall_arg(),
+        }
+    }
+}
+
+impl<T: ?Sized> IntoSyscallArg for Option<&mut T> {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        match self {
+            Some(r) => r.into_syscall_arg(),
+            None => ptr::null_mut::<u8>().into_syscall_arg(),
+        }
+    }
+}
+
+/// Conversion of the successful result of a system call into a typed value.
+///
+/// This is used by the `syscall_N_as` functions to decode results. In debug
+/// builds the result is checked to be valid for the type, such as a file
+/// descriptor fitting in an `i32` or a pointer being non-null.
+///
+pub trait FromSyscallRet: Sized {
+    /// Decodes the successful result of a system call.
+    ///
+    /// # Safety
+    ///
+    /// The result must be valid for the type, which is only checked in debug
+    /// builds.
+    ///
+    unsafe fn from_syscall_ret(ret: usize) -> Self;
+}
+
+impl FromSyscallRet for usize {
+    #[inline(always)]
+    unsafe fn from_syscall_ret(ret: usize) -> Self {
+        ret
+    }
+}
+
+impl FromSyscallRet for u32 {
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    unsafe fn from_syscall_ret(ret: usize) -> Self {
+        debug_assert!(u32::try_from(ret).is_ok(), "system call result does not fit in u32");
+        ret as u32
+    }
+}
+
+impl FromSyscallRet for i32 {
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    unsafe fn from_syscall_ret(ret: usize) -> Self {
+        debug_assert!(i32::try_from(ret).is_ok(), "system call result does not fit in i32");
+        ret as i32
+    }
+}
+
+impl FromSyscallRet for Fd {
+    #[inline(always)]
+    unsafe fn from_syscall_ret(ret: usize) -> Self {
+        Fd::new(i32::from_syscall_ret(ret))
+    }
+}
+
+impl<T> FromSyscallRet for *mut T {
+    #[inline(always)]
+    unsafe fn from_syscall_ret(ret: usize) -> Self {
+        ret as *mut T
+    }
+}
+
+impl<T> FromSyscallRet for NonNull<T> {
+    #[inline(always)]
+    unsafe fn from_syscall_ret(ret: usize) -> Self {
+        debug_assert_ne!(ret, 0, "system call result is a null pointer");
+        NonNull::new_unchecked(ret as *mut T)
+    }
+}
+
+/// Defines the `syscall_N_as` functions for a platform, given the name of each
+/// `syscall_N` function and its parameters.
+///
+macro_rules! syscall_as {
+    ($($name:ident = $call:ident($number:ident $(, $reg:ident)*);)*) => {
+        $(
+            /// Performs a system call and decodes the result with
+            /// `FromSyscallRet`.
+            ///
+            /// The first argument specifies the system call, and the remaining
+            /// arguments are the arguments to pass it.
+            ///
+            #[inline(always)]
+            pub unsafe fn $name<T: $crate::FromSyscallRet>(
+                $number: usize,
+                $($reg: $crate::SyscallArg),*
+            ) -> ::core::result::Result<T, $crate::Errno> {
+                match $call($number $(, $reg)*) {
+                    ::core::result::Result::Ok(ret) => ::core::result::Result::Ok(T::from_syscall_ret(ret)),
+                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntoSyscallArg;
+    use crate::{
+        nr,
+        syscall_0,
+        syscall_0_as,
+        syscall_1_as,
+        syscall_4_as,
+        Fd,
+        SyscallArg,
+    };
+    use ::core::{
+        assert,
+        assert_eq,
+        convert::TryFrom,
+        option::Option::{
+            self,
+            None,
+        },
+        ptr,
+        result::Result::Ok,
+    };
+
+    const AT_FDCWD: i32 = -100;
+
+    #[test]
+    fn integers() {
+        assert_eq!(0_u8.into_syscall_arg(), 0);
+        assert_eq!(u16::MAX.into_syscall_arg(), 0xffff);
+        assert_eq!(u32::MAX.into_syscall_arg(), 0xffff_ffff);
+        assert_eq!(usize::MAX.into_syscall_arg(), usize::MAX as SyscallArg);
+        assert_eq!((-1_i8).into_syscall_arg(), SyscallArg::MAX);
+        assert_eq!((-1_i16).into_syscall_arg(), SyscallArg::MAX);
+        assert_eq!(AT_FDCWD.into_syscall_arg(), SyscallArg::MAX - 99);
+        assert_eq!(i32::MIN.into_syscall_arg(), SyscallArg::MAX - 0x7fff_ffff);
+        assert_eq!((-100_isize).into_syscall_arg(), SyscallArg::MAX - 99);
+    }
+
+    #[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+    #[test]
+    fn integers_64() {
+        assert_eq!(0x1122_3344_5566_7788_u64.into_syscall_arg(), 0x1122_3344_5566_7788);
+        assert_eq!((-100_i64).into_syscall_arg(), SyscallArg::MAX - 99);
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(false.into_syscall_arg(), 0);
+        assert_eq!(true.into_syscall_arg(), 1);
+        assert_eq!(().into_syscall_arg(), 0);
+        assert_eq!(None::<&u8>.into_syscall_arg(), 0);
+        assert_eq!(None::<&mut [u8]>.into_syscall_arg(), 0);
+        assert_eq!(ptr::null::<u8>().into_syscall_arg(), 0);
+    }
+
+    #[test]
+    fn references() {
+        let mut value = 0_u32;
+        let mut buf = [0_u8; 4];
+        let address = ptr::addr_of!(value) as usize as SyscallArg;
+        let data = buf.as_ptr() as usize as SyscallArg;
+        assert_eq!((&value).into_syscall_arg(), address);
+        assert_eq!((&mut value).into_syscall_arg(), address);
+        assert_eq!(Option::Some(&value).into_syscall_arg(), address);
+        assert_eq!((&buf[..]).into_syscall_arg(), data);
+        assert_eq!((&mut buf[..]).into_syscall_arg(), data);
+        let text = "text";
+        assert_eq!(text.into_syscall_arg(), text.as_ptr() as usize as SyscallArg);
+    }
+
+    #[test]
+    fn results() {
+        unsafe {
+            let pid = syscall_0(nr::GETPID).unwrap();
+            assert_eq!(syscall_0_as::<usize>(nr::GETPID), Ok(pid));
+            assert_eq!(syscall_0_as::<i32>(nr::GETPID), Ok(i32::try_from(pid).unwrap()));
+            let uid = syscall_0(nr::GETUID).unwrap();
+            assert_eq!(syscall_0_as::<u32>(nr::GETUID), Ok(u32::try_from(uid).unwrap()));
+        }
+    }
+
+    #[test]
+    fn fd() {
+        unsafe {
+            let path = b"/\0";
+            let fd = syscall_4_as::<Fd>(nr::OPENAT, AT_FDCWD.into_syscall_arg(), path.into_syscall_arg(), 0, 0);
+            let fd = fd.unwrap();
+            assert!(fd.raw() >= 0);
+            assert_eq!(syscall_1_as::<usize>(nr::CLOSE, fd.into_syscall_arg()), Ok(0));
+        }
+    }
+
+    #[cfg(all(
+        target_os = "linux",
+        target_pointer_width = "64",
+        not(target_arch = "mips64")
+    ))]
+    #[test]
+    fn non_null() {
+        use crate::{
+            syscall_2,
+            syscall_6_as,
+        };
+        use ::core::ptr::NonNull;
+
+        const PROT_READ: SyscallArg = 1;
+        const MAP_PRIVATE_ANONYMOUS: SyscallArg = 0x22;
+        const NO_FD: SyscallArg = SyscallArg::MAX;
+
+        unsafe {
+            let map = syscall_6_as::<NonNull<u8>>(nr::MMAP, 0, 4096, PROT_READ, MAP_PRIVATE_ANONYMOUS, NO_FD, 0);
+            let map = map.unwrap();
+            assert_eq!(map.as_ptr().read(), 0);
+            assert_eq!(syscall_2(nr::MUNMAP, map.as_ptr().into_syscall_arg(), 4096), Ok(0));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! ELF structures of the target's native class, as found in memory.
+//!
+//! Field names follow the ELF specification, and fields which are only needed
+//! for layout aren't read.
+//!
+
+/// The four bytes every ELF image starts with.
+///
+pub const MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+
+/// The value of `e_ident[EI_CLASS]` for the target.
+///
+#[cfg(target_pointer_width = "32")]
+pub const CLASS: u8 = 1;
+
+/// The value of `e_ident[EI_CLASS]` for the target.
+///
+#[cfg(target_pointer_width = "64")]
+pub const CLASS: u8 = 2;
+
+/// Index of the class in `e_ident`.
+///
+pub const EI_CLASS: usize = 4;
+
+pub const PT_LOAD: u32 = 1;
+pub const PT_DYNAMIC: u32 = 2;
+
+pub const DT_NULL: isize = 0;
+pub const DT_HASH: isize = 4;
+pub const DT_STRTAB: isize = 5;
+pub const DT_SYMTAB: isize = 6;
+pub const DT_GNU_HASH: isize = 0x6fff_fef5;
+pub const DT_VERSYM: isize = 0x6fff_fff0;
+pub const DT_VERDEF: isize = 0x6fff_fffc;
+
+pub const STT_FUNC: u8 = 2;
+pub const STB_GLOBAL: u8 = 1;
+pub const STB_WEAK: u8 = 2;
+pub const SHN_UNDEF: u16 = 0;
+
+pub const VER_FLG_BASE: u16 = 1;
+
+/// The file header, whose address-sized fields match the native class.
+///
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+pub struct Ehdr {
+    pub e_ident: [u8; 16],
+    pub e_type: u16,
+    pub e_machine: u16,
+    pub e_version: u32,
+    pub e_entry: usize,
+    pub e_phoff: usize,
+    pub e_shoff: usize,
+    pub e_flags: u32,
+    pub e_ehsize: u16,
+    pub e_phentsize: u16,
+    pub e_phnum: u16,
+    pub e_shentsize: u16,
+    pub e_shnum: u16,
+    pub e_shstrndx: u16,
+}
+
+/// A program header.
+///
+#[cfg(target_pointer_width = "32")]
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+pub struct Phdr {
+    pub p_type: u32,
+    pub p_offset: usize,
+    pub p_vaddr: usize,
+    pub p_paddr: usize,
+    pub p_filesz: usize,
+    pub p_memsz: usize,
+    pub p_flags: u32,
+    pub p_align: usize,
+}
+
+/// A program header.
+///
+#[cfg(target_pointer_width = "64")]
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+pub struct Phdr {
+    pub p_type: u32,
+    pub p_flags: u32,
+    pub p_offset: usize,
+    pub p_vaddr: usize,
+    pub p_paddr: usize,
+    pub p_filesz: usize,
+    pub p_memsz: usize,
+    pub p_align: usize,
+}
+
+/// An entry of the dynamic section.
+///
+#[repr(C)]
+pub struct Dyn {
+    pub d_tag: isize,
+    pub d_val: usize,
+}
+
+/// A symbol table entry.
+///
+#[cfg(target_pointer_width = "32")]
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+pub struct Sym {
+    pub st_name: u32,
+    pub st_value: usize,
+    pub st_size: usize,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+}
+
+/// A symbol table entry.
+///
+#[cfg(target_pointer_width = "64")]
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+pub struct Sym {
+    pub st_name: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+    pub st_value: usize,
+    pub st_size: usize,
+}
+
+/// A version definition.
+///
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+pub struct Verdef {
+    pub vd_version: u16,
+    pub vd_flags: u16,
+    pub vd_ndx: u16,
+    pub vd_cnt: u16,
+    pub vd_hash: u32,
+    pub vd_aux: u32,
+    pub vd_next: u32,
+}
+
+/// An auxiliary entry of a version definition holding its name.
+///
+#[repr(C)]
+pub struct Verdaux {
+    pub vda_name: u32,
+    pub vda_next: u32,
+}
+
+/// The entry size of `DT_HASH` tables, which is 64 bits on `s390x`.
+///
+#[cfg(target_arch = "s390x")]
+pub type HashWord = u64;
+
+/// The entry size of `DT_HASH` tables.
+///
+#[cfg(not(target_arch = "s390x"))]
+pub type HashWord = u32;
@@ -0,0 +1,150 @@
+//! Error numbers for Linux on MIPS.
+//!
+//! Every constant is documented with its description, and the `name` and
+//! `description` lookups return static strings so they work without
+//! allocation.
+//!
+//! Generated by `tools/gen-errno.py` from `syscalls/mips/errno.h`, do not edit.
+
+use crate::Errno;
+
+errno_table! {
+    EPERM = 1 => "Operation not permitted",
+    ENOENT = 2 => "No such file or directory",
+    ESRCH = 3 => "No such process",
+    EINTR = 4 => "Interrupted system call",
+    EIO = 5 => "I/O error",
+    ENXIO = 6 => "No such device or address",
+    E2BIG = 7 => "Argument list too long",
+    ENOEXEC = 8 => "Exec format error",
+    EBADF = 9 => "Bad file number",
+    ECHILD = 10 => "No child processes",
+    EAGAIN = 11 => "Try again",
+    ENOMEM = 12 => "Out of memory",
+    EACCES = 13 => "Permission denied",
+    EFAULT = 14 => "Bad address",
+    ENOTBLK = 15 => "Block device required",
+    EBUSY = 16 => "Device or resource busy",
+    EEXIST = 17 => "File exists",
+    EXDEV = 18 => "Cross-device link",
+    ENODEV = 19 => "No such device",
+    ENOTDIR = 20 => "Not a directory",
+    EISDIR = 21 => "Is a directory",
+    EINVAL = 22 => "Invalid argument",
+    ENFILE = 23 => "File table overflow",
+    EMFILE = 24 => "Too many open files",
+    ENOTTY = 25 => "Not a typewriter",
+    ETXTBSY = 26 => "Text file busy",
+    EFBIG = 27 => "File too large",
+    ENOSPC = 28 => "No space left on device",
+    ESPIPE = 29 => "Illegal seek",
+    EROFS = 30 => "Read-only file system",
+    EMLINK = 31 => "Too many links",
+    EPIPE = 32 => "Broken pipe",
+    EDOM = 33 => "Math argument out of domain of func",
+    ERANGE = 34 => "Math result not representable",
+    ENOMSG = 35 => "No message of desired type",
+    EIDRM = 36 => "Identifier removed",
+    ECHRNG = 37 => "Channel number out of range",
+    EL2NSYNC = 38 => "Level 2 not synchronized",
+    EL3HLT = 39 => "Level 3 halted",
+    EL3RST = 40 => "Level 3 reset",
+    ELNRNG = 41 => "Link number out of range",
+    EUNATCH = 42 => "Protocol driver not attached",
+    ENOCSI = 43 => "No CSI structure available",
+    EL2HLT = 44 => "Level 2 halted",
+    EDEADLK = 45 => "Resource deadlock would occur",
+    ENOLCK = 46 => "No record locks available",
+    EBADE = 50 => "Invalid exchange",
+    EBADR = 51 => "Invalid request descriptor",
+    EXFULL = 52 => "Exchange full",
+    ENOANO = 53 => "No anode",
+    EBADRQC = 54 => "Invalid request code",
+    EBADSLT = 55 => "Invalid slot",
+    EDEADLOCK = 56 => "File locking deadlock error",
+    EBFONT = 59 => "Bad font file format",
+    ENOSTR = 60 => "Device not a stream",
+    ENODATA = 61 => "No data available",
+    ETIME = 62 => "Timer expired",
+    ENOSR = 63 => "Out of streams resources",
+    ENONET = 64 => "Machine is not on the network",
+    ENOPKG = 65 => "Package not installed",
+    EREMOTE = 66 => "Object is remote",
+    ENOLINK = 67 => "Link has been severed",
+    EADV = 68 => "Advertise error",
+    ESRMNT = 69 => "Srmount error",
+    ECOMM = 70 => "Communication error on send",
+    EPROTO = 71 => "Protocol error",
+    EDOTDOT = 73 => "RFS specific error",
+    EMULTIHOP = 74 => "Multihop attempted",
+    EBADMSG = 77 => "Not a data message",
+    ENAMETOOLONG = 78 => "File name too long",
+    EOVERFLOW = 79 => "Value too large for defined data type",
+    ENOTUNIQ = 80 => "Name not unique on network",
+    EBADFD = 81 => "File descriptor in bad state",
+    EREMCHG = 82 => "Remote address changed",
+    ELIBACC = 83 => "Can not access a needed shared library",
+    ELIBBAD = 84 => "Accessing a corrupted shared library",
+    ELIBSCN = 85 => ".lib section in a.out corrupted",
+    ELIBMAX = 86 => "Attempting to link in too many shared libraries",
+    ELIBEXEC = 87 => "Cannot exec a shared library directly",
+    EILSEQ = 88 => "Illegal byte sequence",
+    ENOSYS = 89 => "Invalid system call number",
+    ELOOP = 90 => "Too many symbolic links encountered",
+    ERESTART = 91 => "Interrupted system call should be restarted",
+    ESTRPIPE = 92 => "Streams pipe error",
+    ENOTEMPTY = 93 => "Directory not empty",
+    EUSERS = 94 => "Too many users",
+    ENOTSOCK = 95 => "Socket operation on non-socket",
+    EDESTADDRREQ = 96 => "Destination address required",
+    EMSGSIZE = 97 => "Message too long",
+    EPROTOTYPE = 98 => "Protocol wrong type for socket",
+    ENOPROTOOPT = 99 => "Protocol not available",
+    EPROTONOSUPPORT = 120 => "Protocol not supported",
+    ESOCKTNOSUPPORT = 121 => "Socket type not supported",
+    EOPNOTSUPP = 122 => "Operation not supported on transport endpoint",
+    EPFNOSUPPORT = 123 => "Protocol family not supported",
+    EAFNOSUPPORT = 124 => "Address family not supported by protocol",
+    EADDRINUSE = 125 => "Address already in use",
+    EADDRNOTAVAIL = 126 => "Cannot assign requested address",
+    ENETDOWN = 127 => "Network is down",
+    ENETUNREACH = 128 => "Network is unreachable",
+    ENETRESET = 129 => "Network dropped connection because of reset",
+    ECONNABORTED = 130 => "Software caused connection abort",
+    ECONNRESET = 131 => "Connection reset by peer",
+    ENOBUFS = 132 => "No buffer space available",
+    EISCONN = 133 => "Transport endpoint is already connected",
+    ENOTCONN = 134 => "Transport endpoint is not connected",
+    EUCLEAN = 135 => "Structure needs cleaning",
+    ENOTNAM = 137 => "Not a XENIX named type file",
+    ENAVAIL = 138 => "No XENIX semaphores available",
+    EISNAM = 139 => "Is a named type file",
+    EREMOTEIO = 140 => "Remote I/O error",
+    EINIT = 141 => "Reserved",
+    EREMDEV = 142 => "Device does not exist",
+    ESHUTDOWN = 143 => "Cannot send after transport endpoint shutdown",
+    ETOOMANYREFS = 144 => "Too many references: cannot splice",
+    ETIMEDOUT = 145 => "Connection timed out",
+    ECONNREFUSED = 146 => "Connection refused",
+    EHOSTDOWN = 147 => "Host is down",
+    EHOSTUNREACH = 148 => "No route to host",
+    EALREADY = 149 => "Operation already in progress",
+    EINPROGRESS = 150 => "Operation now in progress",
+    ESTALE = 151 => "Stale file handle",
+    ECANCELED = 158 => "Operation Canceled",
+    ENOMEDIUM = 159 => "No medium found",
+    EMEDIUMTYPE = 160 => "Wrong medium type",
+    ENOKEY = 161 => "Required key not available",
+    EKEYEXPIRED = 162 => "Key has expired",
+    EKEYREVOKED = 163 => "Key has been revoked",
+    EKEYREJECTED = 164 => "Key was rejected by service",
+    EOWNERDEAD = 165 => "Owner died",
+    ENOTRECOVERABLE = 166 => "State not recoverable",
+    ERFKILL = 167 => "Operation not possible due to RF-kill",
+    EHWPOISON = 168 => "Memory page has hardware error",
+    EDQUOT = 1133 => "Quota exceeded",
+}
+
+/// Alias of `EAGAIN`.
+///
+pub const EWOULDBLOCK: Errno = EAGAIN;
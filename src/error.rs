@@ -0,0 +1,236 @@
+use ::core::{
+    clone::Clone,
+    cmp::{
+        Eq,
+        Ord,
+        PartialEq,
+        PartialOrd,
+    },
+    fmt,
+    hash::Hash,
+    marker::Copy,
+    option::Option::{
+        self,
+        None,
+        Some,
+    },
+    write,
+};
+
+/// An error number returned by a failed system call.
+///
+/// This always holds the positive error number, regardless of how the
+/// platform reports failure, and can be compared against the constants in the
+/// `errno` module.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Errno(usize);
+
+impl Errno {
+    /// Creates an error from a positive error number.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(raw: usize) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the positive error number.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self.0
+    }
+
+    /// Returns the symbolic name of the error, such as `"ENOENT"`, or `None`
+    /// if it is not known on this platform.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn name(self) -> Option<&'static str> {
+        crate::errno::name(self)
+    }
+
+    /// Returns a human-readable description of the error, or `None` if it is
+    /// not known on this platform.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn description(self) -> Option<&'static str> {
+        crate::errno::description(self)
+    }
+}
+
+impl fmt::Debug for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "Errno({})", self.0),
+        }
+    }
+}
+
+impl fmt::Display for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.description() {
+            Some(description) => f.write_str(description),
+            None => write!(f, "Unknown error {}", self.0),
+        }
+    }
+}
+
+/// Defines the error constants for a platform along with `name` and
+/// `description` lookups.
+///
+macro_rules! errno_table {
+    ($($name:ident = $raw:literal => $description:literal,)*) => {
+        $(
+            #[doc = $description]
+            pub const $name: Errno = Errno::new($raw);
+        )*
+
+        /// Returns the symbolic name of an error, or `None` if unknown.
+        ///
+        #[must_use]
+        pub fn name(e: Errno) -> ::core::option::Option<&'static str> {
+            match e.raw() {
+                $($raw => ::core::option::Option::Some(::core::stringify!($name)),)*
+                _ => ::core::option::Option::None,
+            }
+        }
+
+        /// Returns a human-readable description of an error, or `None` if
+        /// unknown.
+        ///
+        #[must_use]
+        pub fn description(e: Errno) -> ::core::option::Option<&'static str> {
+            match e.raw() {
+                $($raw => ::core::option::Option::Some($description),)*
+                _ => ::core::option::Option::None,
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Errno;
+    use crate::errno::{
+        self,
+        EDEADLK,
+        EDQUOT,
+        ENOENT,
+    };
+    use ::core::{
+        assert_eq,
+        fmt::{
+            self,
+            Write,
+        },
+        option::Option::{
+            None,
+            Some,
+        },
+        result::Result::Ok,
+        str,
+        write,
+    };
+
+    /// An error number no platform defines.
+    ///
+    const UNKNOWN: Errno = Errno::new(4000);
+
+    /// Formats into a fixed buffer, as there is no allocator.
+    ///
+    struct Buffer {
+        data: [u8; 64],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Self { data: [0; 64], len: 0 }
+        }
+
+        fn as_str(&self) -> &str {
+            str::from_utf8(&self.data[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.data[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(ENOENT.name(), Some("ENOENT"));
+        assert_eq!(errno::name(ENOENT), Some("ENOENT"));
+        assert_eq!(UNKNOWN.name(), None);
+    }
+
+    #[test]
+    fn description() {
+        assert_eq!(ENOENT.description(), Some("No such file or directory"));
+        assert_eq!(errno::description(ENOENT), Some("No such file or directory"));
+        assert_eq!(UNKNOWN.description(), None);
+    }
+
+    #[test]
+    fn debug() {
+        let mut buf = Buffer::new();
+        write!(buf, "{ENOENT:?} {UNKNOWN:?}").unwrap();
+        assert_eq!(buf.as_str(), "ENOENT Errno(4000)");
+    }
+
+    #[test]
+    fn display() {
+        let mut buf = Buffer::new();
+        write!(buf, "{ENOENT}, {UNKNOWN}").unwrap();
+        assert_eq!(buf.as_str(), "No such file or directory, Unknown error 4000");
+    }
+
+    #[cfg(all(
+        target_os = "linux",
+        not(any(target_arch = "mips", target_arch = "mips64", target_arch = "powerpc64"))
+    ))]
+    #[test]
+    fn platform() {
+        assert_eq!(EDQUOT.raw(), 122);
+        assert_eq!(EDEADLK.raw(), 35);
+        assert_eq!(errno::EDEADLOCK, EDEADLK);
+        assert_eq!(errno::EDEADLOCK.name(), Some("EDEADLK"));
+    }
+
+    #[cfg(all(target_os = "linux", any(target_arch = "mips", target_arch = "mips64")))]
+    #[test]
+    fn platform() {
+        assert_eq!(EDQUOT.raw(), 1133);
+        assert_eq!(EDQUOT.name(), Some("EDQUOT"));
+        assert_eq!(EDEADLK.raw(), 45);
+        assert_eq!(errno::EDEADLOCK.raw(), 56);
+        assert_eq!(errno::EDEADLOCK.name(), Some("EDEADLOCK"));
+    }
+
+    #[cfg(all(target_os = "linux", target_arch = "powerpc64"))]
+    #[test]
+    fn platform() {
+        assert_eq!(EDQUOT.raw(), 122);
+        assert_eq!(EDEADLK.raw(), 35);
+        assert_eq!(errno::EDEADLOCK.raw(), 58);
+        assert_eq!(errno::EDEADLOCK.name(), Some("EDEADLOCK"));
+        assert_eq!(errno::EDEADLOCK.description(), Some("File locking deadlock error"));
+    }
+
+    #[cfg(target_os = "freebsd")]
+    #[test]
+    fn platform() {
+        assert_eq!(EDQUOT.raw(), 69);
+        assert_eq!(EDEADLK.raw(), 11);
+    }
+}
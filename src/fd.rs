@@ -0,0 +1,49 @@
+use crate::{
+    IntoSyscallArg,
+    SyscallArg,
+};
+use ::core::{
+    clone::Clone,
+    cmp::{
+        Eq,
+        Ord,
+        PartialEq,
+        PartialOrd,
+    },
+    fmt::Debug,
+    hash::Hash,
+    marker::Copy,
+};
+
+/// A file descriptor returned by or passed to a system call.
+///
+/// This does not own the descriptor, so it is never closed implicitly.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fd(i32);
+
+impl Fd {
+    /// Creates a file descriptor from its number.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the number of the file descriptor.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> i32 {
+        self.0
+    }
+}
+
+impl IntoSyscallArg for Fd {
+    #[inline(always)]
+    fn into_syscall_arg(self) -> SyscallArg {
+        self.0.into_syscall_arg()
+    }
+}
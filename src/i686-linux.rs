@@ -0,0 +1,579 @@
+use crate::{
+    auxv::{
+        Auxv,
+        AT_SYSINFO,
+    },
+    Errno,
+};
+use ::core::{
+    arch::asm,
+    hint::unreachable_unchecked,
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+/// Entry point used instead of `int 0x80` when non-zero.
+///
+static VSYSCALL: AtomicUsize = AtomicUsize::new(0);
+
+/// Enables dispatching system calls through the vDSO `__kernel_vsyscall`
+/// entry point.
+///
+/// The argument points to the auxiliary vector, a sequence of key/value pairs
+/// terminated by a zero key, as found on the initial process stack past the
+/// environment pointers.
+///
+/// Returns `true` if an entry point was found. If none is found, system calls
+/// continue to use `int 0x80`. Calls which never return always use `int 0x80`.
+///
+#[inline(always)]
+pub unsafe fn init_vsyscall(auxv: *const usize) -> bool {
+    let entry = Auxv::from_ptr(auxv).get(AT_SYSINFO).unwrap_or(0);
+    VSYSCALL.store(entry, Ordering::Relaxed);
+    entry != 0
+}
+
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
+#[inline(always)]
+pub unsafe fn syscall(eax: usize, a: &[usize]) -> Result<usize, Errno> {
+    match a.len() {
+        0 => syscall_0(eax),
+        1 => syscall_1(eax, a[0]),
+        2 => syscall_2(eax, a[0], a[1]),
+        3 => syscall_3(eax, a[0], a[1], a[2]),
+        4 => syscall_4(eax, a[0], a[1], a[2], a[3]),
+        5 => syscall_5(eax, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6(eax, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_nr(eax: usize, a: &[usize]) -> ! {
+    match a.len() {
+        0 => syscall_0_nr(eax),
+        1 => syscall_1_nr(eax, a[0]),
+        2 => syscall_2_nr(eax, a[0], a[1]),
+        3 => syscall_3_nr(eax, a[0], a[1], a[2]),
+        4 => syscall_4_nr(eax, a[0], a[1], a[2], a[3]),
+        5 => syscall_5_nr(eax, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6_nr(eax, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call with no arguments and returns the result.
+///
+/// The argument specifies the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_0(mut eax: usize) -> Result<usize, Errno> {
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "int 0x80",
+            inlateout("eax") eax,
+            options(nostack, preserves_flags),
+        );
+    } else {
+        asm!(
+            "call {entry}",
+            entry = in(reg) entry,
+            inlateout("eax") eax,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with no arguments which never returns.
+///
+/// The argument specifies the system call.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_nr(eax: usize) -> ! {
+    asm!(
+        "int 0x80",
+        in("eax") eax,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with one argument and returns the result.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_1(mut eax: usize, ebx: usize) -> Result<usize, Errno> {
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "int 0x80",
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            options(nostack, preserves_flags),
+        );
+    } else {
+        asm!(
+            "call {entry}",
+            entry = in(reg) entry,
+            inlateout("eax") eax,
+            in("ebx") ebx,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with one argument and never returns.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_nr(eax: usize, ebx: usize) -> ! {
+    asm!(
+        "int 0x80",
+        in("eax") eax,
+        in("ebx") ebx,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with two arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_2(mut eax: usize, ebx: usize, ecx: usize) -> Result<usize, Errno> {
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "int 0x80",
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            options(nostack, preserves_flags),
+        );
+    } else {
+        asm!(
+            "call {entry}",
+            entry = in(reg) entry,
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with two arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_nr(eax: usize, ebx: usize, ecx: usize) -> ! {
+    asm!(
+        "int 0x80",
+        in("eax") eax,
+        in("ebx") ebx,
+        in("ecx") ecx,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with three arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_3(mut eax: usize, ebx: usize, ecx: usize, edx: usize) -> Result<usize, Errno> {
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "int 0x80",
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+            options(nostack, preserves_flags),
+        );
+    } else {
+        asm!(
+            "call {entry}",
+            entry = in(reg) entry,
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with three arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_nr(eax: usize, ebx: usize, ecx: usize, edx: usize) -> ! {
+    asm!(
+        "int 0x80",
+        in("eax") eax,
+        in("ebx") ebx,
+        in("ecx") ecx,
+        in("edx") edx,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with four arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The compiler reserves `esi`, so the fourth argument is swapped into place
+/// around the call.
+///
+#[inline(always)]
+pub unsafe fn syscall_4(mut eax: usize, ebx: usize, ecx: usize, edx: usize, esi: usize) -> Result<usize, Errno> {
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "xchg esi, {esi}",
+            "int 0x80",
+            "xchg esi, {esi}",
+            esi = in(reg) esi,
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+            options(nostack, preserves_flags),
+        );
+    } else {
+        asm!(
+            "xchg esi, {esi}",
+            "call edi",
+            "xchg esi, {esi}",
+            esi = in(reg) esi,
+            in("edi") entry,
+            inlateout("eax") eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with four arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_nr(eax: usize, ebx: usize, ecx: usize, edx: usize, esi: usize) -> ! {
+    asm!(
+        "mov esi, {esi}",
+        "int 0x80",
+        esi = in(reg) esi,
+        in("eax") eax,
+        in("ebx") ebx,
+        in("ecx") ecx,
+        in("edx") edx,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with five arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The compiler reserves `esi` and no other register is free to swap it with,
+/// so the fourth argument and the system call are loaded from the stack.
+///
+#[inline(always)]
+pub unsafe fn syscall_5(
+    mut eax: usize,
+    ebx: usize,
+    ecx: usize,
+    edx: usize,
+    esi: usize,
+    edi: usize,
+) -> Result<usize, Errno>
+{
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "push esi",
+            "mov esi, DWORD PTR [eax]",
+            "mov eax, DWORD PTR [eax + 4]",
+            "int 0x80",
+            "pop esi",
+            inlateout("eax") &[esi, eax] => eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+            in("edi") edi,
+            options(preserves_flags),
+        );
+    } else {
+        asm!(
+            "push esi",
+            "push DWORD PTR [eax]",
+            "mov esi, DWORD PTR [eax + 4]",
+            "mov eax, DWORD PTR [eax + 8]",
+            "call DWORD PTR [esp]",
+            "pop esi",
+            "pop esi",
+            inlateout("eax") &[entry, esi, eax] => eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+            in("edi") edi,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with five arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_nr(eax: usize, ebx: usize, ecx: usize, edx: usize, esi: usize, edi: usize) -> ! {
+    asm!(
+        "mov esi, DWORD PTR [eax]",
+        "mov eax, DWORD PTR [eax + 4]",
+        "int 0x80",
+        in("eax") &[esi, eax],
+        in("ebx") ebx,
+        in("ecx") ecx,
+        in("edx") edx,
+        in("edi") edi,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with six arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The compiler reserves `esi` and `ebp`, the latter as the frame pointer, so
+/// the fourth and sixth arguments and the system call are loaded from the
+/// stack with both registers saved around the call.
+///
+#[inline(always)]
+pub unsafe fn syscall_6(
+    mut eax: usize,
+    ebx: usize,
+    ecx: usize,
+    edx: usize,
+    esi: usize,
+    edi: usize,
+    ebp: usize,
+) -> Result<usize, Errno>
+{
+    let entry = VSYSCALL.load(Ordering::Relaxed);
+    if entry == 0 {
+        asm!(
+            "push ebp",
+            "push esi",
+            "mov esi, DWORD PTR [eax]",
+            "mov ebp, DWORD PTR [eax + 4]",
+            "mov eax, DWORD PTR [eax + 8]",
+            "int 0x80",
+            "pop esi",
+            "pop ebp",
+            inlateout("eax") &[esi, ebp, eax] => eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+            in("edi") edi,
+            options(preserves_flags),
+        );
+    } else {
+        asm!(
+            "push ebp",
+            "push esi",
+            "push DWORD PTR [eax]",
+            "mov esi, DWORD PTR [eax + 4]",
+            "mov ebp, DWORD PTR [eax + 8]",
+            "mov eax, DWORD PTR [eax + 12]",
+            "call DWORD PTR [esp]",
+            "pop esi",
+            "pop esi",
+            "pop ebp",
+            inlateout("eax") &[entry, esi, ebp, eax] => eax,
+            in("ebx") ebx,
+            in("ecx") ecx,
+            in("edx") edx,
+            in("edi") edi,
+        );
+    }
+    if eax < 0xffff_f000 {
+        Ok(eax)
+    } else {
+        Err(Errno::new(eax.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with six arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_nr(eax: usize, ebx: usize, ecx: usize, edx: usize, esi: usize, edi: usize, ebp: usize) -> ! {
+    asm!(
+        "mov esi, DWORD PTR [eax]",
+        "mov ebp, DWORD PTR [eax + 4]",
+        "mov eax, DWORD PTR [eax + 8]",
+        "int 0x80",
+        in("eax") &[esi, ebp, eax],
+        in("ebx") ebx,
+        in("ecx") ecx,
+        in("edx") edx,
+        in("edi") edi,
+        options(noreturn, nostack),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(eax);
+    syscall_1_as = syscall_1(eax, ebx);
+    syscall_2_as = syscall_2(eax, ebx, ecx);
+    syscall_3_as = syscall_3(eax, ebx, ecx, edx);
+    syscall_4_as = syscall_4(eax, ebx, ecx, edx, esi);
+    syscall_5_as = syscall_5(eax, ebx, ecx, edx, esi, edi);
+    syscall_6_as = syscall_6(eax, ebx, ecx, edx, esi, edi, ebp);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        init_vsyscall,
+        syscall_0,
+        syscall_1,
+        syscall_2,
+        syscall_4,
+        syscall_5,
+        syscall_6,
+        VSYSCALL,
+    };
+    use crate::{
+        auxv,
+        nr,
+    };
+    use ::core::{
+        assert,
+        assert_eq,
+        result::Result::Ok,
+        sync::atomic::Ordering,
+    };
+
+    const AT_FDCWD: usize = usize::MAX - 99;
+    const PROT_READ: usize = 1;
+    const MAP_PRIVATE_ANONYMOUS: usize = 0x22;
+
+    /// Performs calls using every argument register, including `ebp`, which
+    /// the vDSO entry point and `int 0x80` receive differently.
+    ///
+    unsafe fn exercise() {
+        let pid = syscall_0(nr::GETPID).unwrap();
+        assert!(pid > 0);
+        let path = b"/proc/self/exe\0";
+        let fd = syscall_4(nr::OPENAT, AT_FDCWD, path.as_ptr() as usize, 0, 0).unwrap();
+        let mut buf = [0_u8; 3];
+        // The offset is passed as its low and high halves.
+        assert_eq!(syscall_5(nr::PREAD64, fd, buf.as_mut_ptr() as usize, 3, 1, 0), Ok(3));
+        assert_eq!(&buf, b"ELF");
+        assert_eq!(syscall_5(nr::PREAD64, fd, buf.as_mut_ptr() as usize, 3, 1, 1), Ok(0));
+        assert_eq!(syscall_1(nr::CLOSE, fd), Ok(0));
+        let map = syscall_6(nr::MMAP2, 0, 4096, PROT_READ, MAP_PRIVATE_ANONYMOUS, usize::MAX, 0).unwrap();
+        assert_eq!(*(map as *const u8), 0);
+        assert_eq!(syscall_2(nr::MUNMAP, map, 4096), Ok(0));
+    }
+
+    #[test]
+    fn int_0x80_and_vsyscall() {
+        let mut buf = [0; 128];
+        unsafe {
+            VSYSCALL.store(0, Ordering::Relaxed);
+            exercise();
+            auxv::read_proc(&mut buf).unwrap();
+            assert!(init_vsyscall(buf.as_ptr()));
+            exercise();
+            VSYSCALL.store(0, Ordering::Relaxed);
+        }
+    }
+}
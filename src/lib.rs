@@ -7,9 +7,13 @@
 //! return values.
 //!
 //! All arguments and return values use the most basic possible types, for
-//! example everything is `usize` on `x86_64-linux`. All arguments must be
-//! converted to this type, and it's up to the caller to determine whether the
-//! result represents a pointer or error code or whatever.
+//! example everything is `usize` on `x86_64-linux`, while arguments are full
+//! `u64` registers on `x32-linux`, as named by `SyscallArg`. All arguments
+//! must be converted to this type, and it's up to the caller to determine
+//! whether a successful result represents a pointer or file descriptor or
+//! whatever.
+//! Failures are returned as an `Errno` holding the positive error number on
+//! every platform. System call numbers are in the `nr` module.
 //!
 //! The intention is to provide a minimal stable base with no unnecessary
 //! overhead on which to build a higher-level library.
@@ -17,10 +21,71 @@
 #![no_std]
 #![no_implicit_prelude]
 #![allow(unsafe_code)]
-#![allow(unstable_features)]
-#![feature(asm)]
-#![cfg_attr(feature = "cargo-clippy", deny(clippy::all, clippy::pedantic, warnings))]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::inline_always, clippy::similar_names))]
+#![cfg_attr(any(target_arch = "mips", target_arch = "mips64"), feature(asm_experimental_arch))]
+#![deny(clippy::all, clippy::pedantic, warnings)]
+#![allow(
+    clippy::inline_always,
+    clippy::similar_names,
+    clippy::missing_errors_doc,
+    clippy::missing_safety_doc
+)]
+
+#[macro_use]
+mod error;
+#[macro_use]
+mod macros;
+#[macro_use]
+mod convert;
+
+mod args;
+mod checked;
+#[cfg(target_os = "linux")]
+mod elf;
+mod fd;
+#[cfg(all(
+    feature = "relocate",
+    any(feature = "start", test),
+    target_os = "linux",
+    any(target_arch = "aarch64", target_arch = "arm", target_arch = "x86_64")
+))]
+mod relocate;
+#[cfg(all(feature = "start", not(test)))]
+mod start;
+mod sysno;
+mod typed;
+
+#[cfg(target_os = "linux")]
+pub mod auxv;
+pub mod translate;
+#[cfg(target_os = "linux")]
+pub mod vdso;
+
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "loongarch64",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+#[path = "errno/linux.rs"]
+pub mod errno;
+
+#[cfg(all(target_os = "linux", any(target_arch = "mips", target_arch = "mips64")))]
+#[path = "errno/linux-mips.rs"]
+pub mod errno;
+
+#[cfg(all(target_arch = "powerpc64", target_os = "linux"))]
+#[path = "errno/linux-powerpc.rs"]
+pub mod errno;
+
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+#[path = "errno/freebsd.rs"]
+pub mod errno;
 
 #[cfg(all(target_arch = "aarch64", target_os = "linux"))]
 #[path = "aarch64-linux.rs"]
@@ -30,12 +95,127 @@ mod platform;
 #[path = "arm-linux.rs"]
 mod platform;
 
-#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+#[cfg(all(target_arch = "loongarch64", target_os = "linux"))]
+#[path = "loongarch64-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "mips", target_os = "linux"))]
+#[path = "mips-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "mips64", target_os = "linux"))]
+#[path = "mips64-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "powerpc64", target_os = "linux"))]
+#[path = "powerpc64-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "riscv64", target_os = "linux"))]
+#[path = "riscv64-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "s390x", target_os = "linux"))]
+#[path = "s390x-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "x86", target_os = "linux"))]
+#[path = "i686-linux.rs"]
+mod platform;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "64"))]
 #[path = "x86_64-linux.rs"]
 mod platform;
 
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "32"))]
+#[path = "x32-linux.rs"]
+mod platform;
+
 #[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
 #[path = "x86_64-freebsd.rs"]
 mod platform;
 
-pub use self::platform::*;
+/// A system call argument register.
+///
+/// This is `usize` on every platform except `x32-linux`.
+///
+#[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+pub type SyscallArg = usize;
+
+/// A system call argument register.
+///
+/// This is `u64` on `x32-linux`, where registers are wider than `usize` and
+/// the kernel reads arguments such as offsets in full.
+///
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+pub type SyscallArg = u64;
+
+#[cfg(all(target_arch = "aarch64", target_os = "linux"))]
+pub use self::translate::aarch64_linux as nr;
+
+#[cfg(all(target_arch = "arm", target_os = "linux"))]
+pub use self::translate::arm_linux as nr;
+
+#[cfg(all(target_arch = "loongarch64", target_os = "linux"))]
+pub use self::translate::loongarch64_linux as nr;
+
+#[cfg(all(target_arch = "mips", target_os = "linux"))]
+pub use self::translate::mips_linux as nr;
+
+#[cfg(all(target_arch = "mips64", target_os = "linux"))]
+pub use self::translate::mips64_linux as nr;
+
+#[cfg(all(target_arch = "powerpc64", target_os = "linux"))]
+pub use self::translate::powerpc64_linux as nr;
+
+#[cfg(all(target_arch = "riscv64", target_os = "linux"))]
+pub use self::translate::riscv64_linux as nr;
+
+#[cfg(all(target_arch = "s390x", target_os = "linux"))]
+pub use self::translate::s390x_linux as nr;
+
+#[cfg(all(target_arch = "x86", target_os = "linux"))]
+pub use self::translate::i686_linux as nr;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "64"))]
+pub use self::translate::x86_64_linux as nr;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "32"))]
+pub use self::translate::x32_linux as nr;
+
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+pub use self::translate::x86_64_freebsd as nr;
+
+pub use self::{
+    args::Args,
+    checked::{
+        syscall_array,
+        syscall_array_nr,
+        try_syscall,
+        try_syscall_nr,
+        MAX_ARGS,
+    },
+    convert::{
+        FromSyscallRet,
+        IntoSyscallArg,
+    },
+    error::Errno,
+    fd::Fd,
+    platform::*,
+    sysno::ParseSysnoError,
+    typed::*,
+};
+
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+pub use self::checked::{
+    syscall_indirect_array,
+    MAX_INDIRECT_ARGS,
+};
+
+#[cfg(all(feature = "start", not(test)))]
+pub use self::start::{
+    StartupInfo,
+    Strings,
+};
+
+pub use self::nr::Sysno;
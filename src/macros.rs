@@ -0,0 +1,195 @@
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it. Each argument is converted with
+/// `IntoSyscallArg`, and the call expands directly to the `syscall_N` function
+/// matching the number of arguments, so there is no runtime dispatch.
+///
+/// There is no const-generic `syscall::<N>`, as `syscall` already takes a
+/// slice and changing its signature would break existing callers. The macro
+/// selects the arity instead, and `syscall_array::<N>` takes the arguments as
+/// an array whose length is checked at compile time.
+///
+/// The expansion calls an unsafe function, so it must be used in an unsafe
+/// block.
+///
+#[macro_export]
+macro_rules! syscall {
+    ($n:expr $(,)?) => {
+        $crate::syscall_0($n)
+    };
+    ($n:expr, $a0:expr $(,)?) => {
+        $crate::syscall_1($n, $crate::IntoSyscallArg::into_syscall_arg($a0))
+    };
+    ($n:expr, $a0:expr, $a1:expr $(,)?) => {
+        $crate::syscall_2(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr $(,)?) => {
+        $crate::syscall_3(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {
+        $crate::syscall_4(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+            $crate::IntoSyscallArg::into_syscall_arg($a3),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {
+        $crate::syscall_5(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+            $crate::IntoSyscallArg::into_syscall_arg($a3),
+            $crate::IntoSyscallArg::into_syscall_arg($a4),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {
+        $crate::syscall_6(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+            $crate::IntoSyscallArg::into_syscall_arg($a3),
+            $crate::IntoSyscallArg::into_syscall_arg($a4),
+            $crate::IntoSyscallArg::into_syscall_arg($a5),
+        )
+    };
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it. Each argument is converted with
+/// `IntoSyscallArg`, and the call expands directly to the `syscall_N_nr`
+/// function matching the number of arguments, so there is no runtime dispatch.
+///
+/// The expansion calls an unsafe function, so it must be used in an unsafe
+/// block. This should only be used for calls like `exit` or `exit_group`
+/// which are guaranteed to never return.
+///
+#[macro_export]
+macro_rules! syscall_nr {
+    ($n:expr $(,)?) => {
+        $crate::syscall_0_nr($n)
+    };
+    ($n:expr, $a0:expr $(,)?) => {
+        $crate::syscall_1_nr($n, $crate::IntoSyscallArg::into_syscall_arg($a0))
+    };
+    ($n:expr, $a0:expr, $a1:expr $(,)?) => {
+        $crate::syscall_2_nr(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr $(,)?) => {
+        $crate::syscall_3_nr(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {
+        $crate::syscall_4_nr(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+            $crate::IntoSyscallArg::into_syscall_arg($a3),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {
+        $crate::syscall_5_nr(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+            $crate::IntoSyscallArg::into_syscall_arg($a3),
+            $crate::IntoSyscallArg::into_syscall_arg($a4),
+        )
+    };
+    ($n:expr, $a0:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {
+        $crate::syscall_6_nr(
+            $n,
+            $crate::IntoSyscallArg::into_syscall_arg($a0),
+            $crate::IntoSyscallArg::into_syscall_arg($a1),
+            $crate::IntoSyscallArg::into_syscall_arg($a2),
+            $crate::IntoSyscallArg::into_syscall_arg($a3),
+            $crate::IntoSyscallArg::into_syscall_arg($a4),
+            $crate::IntoSyscallArg::into_syscall_arg($a5),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errno::EBADF,
+        nr,
+        syscall_0,
+        Fd,
+    };
+    use ::core::{
+        assert_eq,
+        option::Option::None,
+        result::Result::{
+            Err,
+            Ok,
+        },
+    };
+
+    /// Passes every arity to `getpid`, which ignores its arguments, so each
+    /// expansion is checked against the same result.
+    ///
+    #[test]
+    fn arities() {
+        let buf = [0_u8; 4];
+        unsafe {
+            let pid = syscall_0(nr::GETPID);
+            assert_eq!(syscall!(nr::GETPID), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8, -1_i32), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8, -1_i32, true), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8, -1_i32, true, ()), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8, -1_i32, true, (), None::<&u8>), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8, -1_i32, true, (), None::<&u8>, &buf), pid);
+            assert_eq!(syscall!(nr::GETPID, 0_u8, -1_i32, true, (), None::<&u8>, &buf,), pid);
+        }
+    }
+
+    #[test]
+    fn mixed() {
+        let mut buf = [0_u8; 4];
+        unsafe {
+            let fd = syscall!(nr::OPENAT, -100_i32, b"/\0", 0_u32, ()).unwrap();
+            assert_eq!(syscall!(nr::READ, Fd::new(-1), &mut buf, buf.len()), Err(EBADF));
+            assert_eq!(syscall!(nr::CLOSE, fd), Ok(0));
+            assert_eq!(syscall!(nr::CLOSE, fd,), Err(EBADF));
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "64"))]
+    #[test]
+    fn literal_number() {
+        use crate::Sysno;
+        use ::core::convert::Into;
+
+        unsafe {
+            assert_eq!(syscall!(39), syscall!(nr::GETPID));
+            assert_eq!(syscall!(39,), syscall!(Sysno::Getpid.into()));
+        }
+    }
+}
@@ -0,0 +1,514 @@
+use crate::Errno;
+use ::core::{
+    arch::asm,
+    hint::unreachable_unchecked,
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+};
+
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
+#[inline(always)]
+pub unsafe fn syscall(v0: usize, a: &[usize]) -> Result<usize, Errno> {
+    match a.len() {
+        0 => syscall_0(v0),
+        1 => syscall_1(v0, a[0]),
+        2 => syscall_2(v0, a[0], a[1]),
+        3 => syscall_3(v0, a[0], a[1], a[2]),
+        4 => syscall_4(v0, a[0], a[1], a[2], a[3]),
+        5 => syscall_5(v0, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6(v0, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_nr(v0: usize, a: &[usize]) -> ! {
+    match a.len() {
+        0 => syscall_0_nr(v0),
+        1 => syscall_1_nr(v0, a[0]),
+        2 => syscall_2_nr(v0, a[0], a[1]),
+        3 => syscall_3_nr(v0, a[0], a[1], a[2]),
+        4 => syscall_4_nr(v0, a[0], a[1], a[2], a[3]),
+        5 => syscall_5_nr(v0, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6_nr(v0, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call with no arguments and returns the result.
+///
+/// The argument specifies the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_0(mut v0: usize) -> Result<usize, Errno> {
+    let a3: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") v0,
+        lateout("$7") a3,
+        lateout("$3") _,
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with no arguments which never returns.
+///
+/// The argument specifies the system call.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_nr(v0: usize) -> ! {
+    asm!(
+        "syscall",
+        in("$2") v0,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with one argument and returns the result.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_1(mut v0: usize, a0: usize) -> Result<usize, Errno> {
+    let a3: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") v0,
+        in("$4") a0,
+        lateout("$7") a3,
+        lateout("$3") _,
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with one argument and never returns.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_nr(v0: usize, a0: usize) -> ! {
+    asm!(
+        "syscall",
+        in("$2") v0,
+        in("$4") a0,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with two arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_2(mut v0: usize, a0: usize, a1: usize) -> Result<usize, Errno> {
+    let a3: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        lateout("$7") a3,
+        lateout("$3") _,
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with two arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_nr(v0: usize, a0: usize, a1: usize) -> ! {
+    asm!(
+        "syscall",
+        in("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with three arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_3(mut v0: usize, a0: usize, a1: usize, a2: usize) -> Result<usize, Errno> {
+    let a3: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        lateout("$7") a3,
+        lateout("$3") _,
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with three arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_nr(v0: usize, a0: usize, a1: usize, a2: usize) -> ! {
+    asm!(
+        "syscall",
+        in("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with four arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_4(mut v0: usize, a0: usize, a1: usize, a2: usize, mut a3: usize) -> Result<usize, Errno> {
+    asm!(
+        "syscall",
+        inlateout("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        inlateout("$7") a3,
+        lateout("$3") _,
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with four arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_nr(v0: usize, a0: usize, a1: usize, a2: usize, a3: usize) -> ! {
+    asm!(
+        "syscall",
+        in("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        in("$7") a3,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with five arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The fifth and later arguments are passed on the stack, above the 16 bytes
+/// the o32 calling convention reserves for the register arguments.
+///
+#[inline(always)]
+pub unsafe fn syscall_5(
+    mut v0: usize,
+    a0: usize,
+    a1: usize,
+    a2: usize,
+    mut a3: usize,
+    sp16: usize,
+) -> Result<usize, Errno>
+{
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw $8, 16($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        inlateout("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        inlateout("$7") a3,
+        inlateout("$8") sp16 => _,
+        lateout("$3") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with five arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The fifth and later arguments are passed on the stack, above the 16 bytes
+/// the o32 calling convention reserves for the register arguments.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_nr(v0: usize, a0: usize, a1: usize, a2: usize, a3: usize, sp16: usize) -> ! {
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw $8, 16($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        in("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        in("$7") a3,
+        in("$8") sp16,
+        options(noreturn),
+    )
+}
+
+/// Performs a system call with six arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The fifth and later arguments are passed on the stack, above the 16 bytes
+/// the o32 calling convention reserves for the register arguments.
+///
+#[inline(always)]
+pub unsafe fn syscall_6(
+    mut v0: usize,
+    a0: usize,
+    a1: usize,
+    a2: usize,
+    mut a3: usize,
+    sp16: usize,
+    sp20: usize,
+) -> Result<usize, Errno>
+{
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw $8, 16($sp)",
+        "sw $9, 20($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        inlateout("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        inlateout("$7") a3,
+        inlateout("$8") sp16 => _,
+        inlateout("$9") sp20 => _,
+        lateout("$3") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(preserves_flags),
+    );
+    if a3 == 0 {
+        Ok(v0)
+    } else {
+        Err(Errno::new(v0))
+    }
+}
+
+/// Performs a system call with six arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The fifth and later arguments are passed on the stack, above the 16 bytes
+/// the o32 calling convention reserves for the register arguments.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_nr(v0: usize, a0: usize, a1: usize, a2: usize, a3: usize, sp16: usize, sp20: usize) -> ! {
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw $8, 16($sp)",
+        "sw $9, 20($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        in("$2") v0,
+        in("$4") a0,
+        in("$5") a1,
+        in("$6") a2,
+        in("$7") a3,
+        in("$8") sp16,
+        in("$9") sp20,
+        options(noreturn),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(v0);
+    syscall_1_as = syscall_1(v0, a0);
+    syscall_2_as = syscall_2(v0, a0, a1);
+    syscall_3_as = syscall_3(v0, a0, a1, a2);
+    syscall_4_as = syscall_4(v0, a0, a1, a2, a3);
+    syscall_5_as = syscall_5(v0, a0, a1, a2, a3, sp16);
+    syscall_6_as = syscall_6(v0, a0, a1, a2, a3, sp16, sp20);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        syscall_1,
+        syscall_4,
+        syscall_6,
+    };
+    use crate::nr;
+    use ::core::{
+        assert_eq,
+        result::Result::Ok,
+    };
+
+    const AT_FDCWD: usize = usize::MAX - 99;
+
+    /// Reads at offsets passed in the fifth and sixth arguments, which go on
+    /// the stack. The second offset has its high half set, so the read is
+    /// past the end of the file only if both stack slots reach the kernel.
+    ///
+    #[test]
+    fn stack_arguments() {
+        let path = b"/proc/self/exe\0";
+        let mut buf = [0_u8; 3];
+        let (low, high) = if ::core::cfg!(target_endian = "little") { (4, 5) } else { (5, 4) };
+        unsafe {
+            let fd = syscall_4(nr::OPENAT, AT_FDCWD, path.as_ptr() as usize, 0, 0).unwrap();
+            let mut args = [fd, buf.as_mut_ptr() as usize, buf.len(), 0, 0, 0];
+            args[low] = 1;
+            assert_eq!(syscall_6(nr::PREAD64, args[0], args[1], args[2], 0, args[4], args[5]), Ok(3));
+            assert_eq!(&buf, b"ELF");
+            args[high] = 1;
+            assert_eq!(syscall_6(nr::PREAD64, args[0], args[1], args[2], 0, args[4], args[5]), Ok(0));
+            assert_eq!(syscall_1(nr::CLOSE, fd), Ok(0));
+        }
+    }
+}
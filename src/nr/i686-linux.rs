@@ -0,0 +1,2705 @@
+//! System call numbers for `i686-linux`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/x86/unistd_32.h`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const RESTART_SYSCALL: usize = 0;
+pub const EXIT: usize = 1;
+pub const FORK: usize = 2;
+pub const READ: usize = 3;
+pub const WRITE: usize = 4;
+pub const OPEN: usize = 5;
+pub const CLOSE: usize = 6;
+pub const WAITPID: usize = 7;
+pub const CREAT: usize = 8;
+pub const LINK: usize = 9;
+pub const UNLINK: usize = 10;
+pub const EXECVE: usize = 11;
+pub const CHDIR: usize = 12;
+pub const TIME: usize = 13;
+pub const MKNOD: usize = 14;
+pub const CHMOD: usize = 15;
+pub const LCHOWN: usize = 16;
+pub const BREAK: usize = 17;
+pub const OLDSTAT: usize = 18;
+pub const LSEEK: usize = 19;
+pub const GETPID: usize = 20;
+pub const MOUNT: usize = 21;
+pub const UMOUNT: usize = 22;
+pub const SETUID: usize = 23;
+pub const GETUID: usize = 24;
+pub const STIME: usize = 25;
+pub const PTRACE: usize = 26;
+pub const ALARM: usize = 27;
+pub const OLDFSTAT: usize = 28;
+pub const PAUSE: usize = 29;
+pub const UTIME: usize = 30;
+pub const STTY: usize = 31;
+pub const GTTY: usize = 32;
+pub const ACCESS: usize = 33;
+pub const NICE: usize = 34;
+pub const FTIME: usize = 35;
+pub const SYNC: usize = 36;
+pub const KILL: usize = 37;
+pub const RENAME: usize = 38;
+pub const MKDIR: usize = 39;
+pub const RMDIR: usize = 40;
+pub const DUP: usize = 41;
+pub const PIPE: usize = 42;
+pub const TIMES: usize = 43;
+pub const PROF: usize = 44;
+pub const BRK: usize = 45;
+pub const SETGID: usize = 46;
+pub const GETGID: usize = 47;
+pub const SIGNAL: usize = 48;
+pub const GETEUID: usize = 49;
+pub const GETEGID: usize = 50;
+pub const ACCT: usize = 51;
+pub const UMOUNT2: usize = 52;
+pub const LOCK: usize = 53;
+pub const IOCTL: usize = 54;
+pub const FCNTL: usize = 55;
+pub const MPX: usize = 56;
+pub const SETPGID: usize = 57;
+pub const ULIMIT: usize = 58;
+pub const OLDOLDUNAME: usize = 59;
+pub const UMASK: usize = 60;
+pub const CHROOT: usize = 61;
+pub const USTAT: usize = 62;
+pub const DUP2: usize = 63;
+pub const GETPPID: usize = 64;
+pub const GETPGRP: usize = 65;
+pub const SETSID: usize = 66;
+pub const SIGACTION: usize = 67;
+pub const SGETMASK: usize = 68;
+pub const SSETMASK: usize = 69;
+pub const SETREUID: usize = 70;
+pub const SETREGID: usize = 71;
+pub const SIGSUSPEND: usize = 72;
+pub const SIGPENDING: usize = 73;
+pub const SETHOSTNAME: usize = 74;
+pub const SETRLIMIT: usize = 75;
+pub const GETRLIMIT: usize = 76;
+pub const GETRUSAGE: usize = 77;
+pub const GETTIMEOFDAY: usize = 78;
+pub const SETTIMEOFDAY: usize = 79;
+pub const GETGROUPS: usize = 80;
+pub const SETGROUPS: usize = 81;
+pub const SELECT: usize = 82;
+pub const SYMLINK: usize = 83;
+pub const OLDLSTAT: usize = 84;
+pub const READLINK: usize = 85;
+pub const USELIB: usize = 86;
+pub const SWAPON: usize = 87;
+pub const REBOOT: usize = 88;
+pub const READDIR: usize = 89;
+pub const MMAP: usize = 90;
+pub const MUNMAP: usize = 91;
+pub const TRUNCATE: usize = 92;
+pub const FTRUNCATE: usize = 93;
+pub const FCHMOD: usize = 94;
+pub const FCHOWN: usize = 95;
+pub const GETPRIORITY: usize = 96;
+pub const SETPRIORITY: usize = 97;
+pub const PROFIL: usize = 98;
+pub const STATFS: usize = 99;
+pub const FSTATFS: usize = 100;
+pub const IOPERM: usize = 101;
+pub const SOCKETCALL: usize = 102;
+pub const SYSLOG: usize = 103;
+pub const SETITIMER: usize = 104;
+pub const GETITIMER: usize = 105;
+pub const STAT: usize = 106;
+pub const LSTAT: usize = 107;
+pub const FSTAT: usize = 108;
+pub const OLDUNAME: usize = 109;
+pub const IOPL: usize = 110;
+pub const VHANGUP: usize = 111;
+pub const IDLE: usize = 112;
+pub const VM86OLD: usize = 113;
+pub const WAIT4: usize = 114;
+pub const SWAPOFF: usize = 115;
+pub const SYSINFO: usize = 116;
+pub const IPC: usize = 117;
+pub const FSYNC: usize = 118;
+pub const SIGRETURN: usize = 119;
+pub const CLONE: usize = 120;
+pub const SETDOMAINNAME: usize = 121;
+pub const UNAME: usize = 122;
+pub const MODIFY_LDT: usize = 123;
+pub const ADJTIMEX: usize = 124;
+pub const MPROTECT: usize = 125;
+pub const SIGPROCMASK: usize = 126;
+pub const CREATE_MODULE: usize = 127;
+pub const INIT_MODULE: usize = 128;
+pub const DELETE_MODULE: usize = 129;
+pub const GET_KERNEL_SYMS: usize = 130;
+pub const QUOTACTL: usize = 131;
+pub const GETPGID: usize = 132;
+pub const FCHDIR: usize = 133;
+pub const BDFLUSH: usize = 134;
+pub const SYSFS: usize = 135;
+pub const PERSONALITY: usize = 136;
+pub const AFS_SYSCALL: usize = 137;
+pub const SETFSUID: usize = 138;
+pub const SETFSGID: usize = 139;
+pub const _LLSEEK: usize = 140;
+pub const GETDENTS: usize = 141;
+pub const _NEWSELECT: usize = 142;
+pub const FLOCK: usize = 143;
+pub const MSYNC: usize = 144;
+pub const READV: usize = 145;
+pub const WRITEV: usize = 146;
+pub const GETSID: usize = 147;
+pub const FDATASYNC: usize = 148;
+pub const _SYSCTL: usize = 149;
+pub const MLOCK: usize = 150;
+pub const MUNLOCK: usize = 151;
+pub const MLOCKALL: usize = 152;
+pub const MUNLOCKALL: usize = 153;
+pub const SCHED_SETPARAM: usize = 154;
+pub const SCHED_GETPARAM: usize = 155;
+pub const SCHED_SETSCHEDULER: usize = 156;
+pub const SCHED_GETSCHEDULER: usize = 157;
+pub const SCHED_YIELD: usize = 158;
+pub const SCHED_GET_PRIORITY_MAX: usize = 159;
+pub const SCHED_GET_PRIORITY_MIN: usize = 160;
+pub const SCHED_RR_GET_INTERVAL: usize = 161;
+pub const NANOSLEEP: usize = 162;
+pub const MREMAP: usize = 163;
+pub const SETRESUID: usize = 164;
+pub const GETRESUID: usize = 165;
+pub const VM86: usize = 166;
+pub const QUERY_MODULE: usize = 167;
+pub const POLL: usize = 168;
+pub const NFSSERVCTL: usize = 169;
+pub const SETRESGID: usize = 170;
+pub const GETRESGID: usize = 171;
+pub const PRCTL: usize = 172;
+pub const RT_SIGRETURN: usize = 173;
+pub const RT_SIGACTION: usize = 174;
+pub const RT_SIGPROCMASK: usize = 175;
+pub const RT_SIGPENDING: usize = 176;
+pub const RT_SIGTIMEDWAIT: usize = 177;
+pub const RT_SIGQUEUEINFO: usize = 178;
+pub const RT_SIGSUSPEND: usize = 179;
+pub const PREAD64: usize = 180;
+pub const PWRITE64: usize = 181;
+pub const CHOWN: usize = 182;
+pub const GETCWD: usize = 183;
+pub const CAPGET: usize = 184;
+pub const CAPSET: usize = 185;
+pub const SIGALTSTACK: usize = 186;
+pub const SENDFILE: usize = 187;
+pub const GETPMSG: usize = 188;
+pub const PUTPMSG: usize = 189;
+pub const VFORK: usize = 190;
+pub const UGETRLIMIT: usize = 191;
+pub const MMAP2: usize = 192;
+pub const TRUNCATE64: usize = 193;
+pub const FTRUNCATE64: usize = 194;
+pub const STAT64: usize = 195;
+pub const LSTAT64: usize = 196;
+pub const FSTAT64: usize = 197;
+pub const LCHOWN32: usize = 198;
+pub const GETUID32: usize = 199;
+pub const GETGID32: usize = 200;
+pub const GETEUID32: usize = 201;
+pub const GETEGID32: usize = 202;
+pub const SETREUID32: usize = 203;
+pub const SETREGID32: usize = 204;
+pub const GETGROUPS32: usize = 205;
+pub const SETGROUPS32: usize = 206;
+pub const FCHOWN32: usize = 207;
+pub const SETRESUID32: usize = 208;
+pub const GETRESUID32: usize = 209;
+pub const SETRESGID32: usize = 210;
+pub const GETRESGID32: usize = 211;
+pub const CHOWN32: usize = 212;
+pub const SETUID32: usize = 213;
+pub const SETGID32: usize = 214;
+pub const SETFSUID32: usize = 215;
+pub const SETFSGID32: usize = 216;
+pub const PIVOT_ROOT: usize = 217;
+pub const MINCORE: usize = 218;
+pub const MADVISE: usize = 219;
+pub const GETDENTS64: usize = 220;
+pub const FCNTL64: usize = 221;
+pub const GETTID: usize = 224;
+pub const READAHEAD: usize = 225;
+pub const SETXATTR: usize = 226;
+pub const LSETXATTR: usize = 227;
+pub const FSETXATTR: usize = 228;
+pub const GETXATTR: usize = 229;
+pub const LGETXATTR: usize = 230;
+pub const FGETXATTR: usize = 231;
+pub const LISTXATTR: usize = 232;
+pub const LLISTXATTR: usize = 233;
+pub const FLISTXATTR: usize = 234;
+pub const REMOVEXATTR: usize = 235;
+pub const LREMOVEXATTR: usize = 236;
+pub const FREMOVEXATTR: usize = 237;
+pub const TKILL: usize = 238;
+pub const SENDFILE64: usize = 239;
+pub const FUTEX: usize = 240;
+pub const SCHED_SETAFFINITY: usize = 241;
+pub const SCHED_GETAFFINITY: usize = 242;
+pub const SET_THREAD_AREA: usize = 243;
+pub const GET_THREAD_AREA: usize = 244;
+pub const IO_SETUP: usize = 245;
+pub const IO_DESTROY: usize = 246;
+pub const IO_GETEVENTS: usize = 247;
+pub const IO_SUBMIT: usize = 248;
+pub const IO_CANCEL: usize = 249;
+pub const FADVISE64: usize = 250;
+pub const EXIT_GROUP: usize = 252;
+pub const LOOKUP_DCOOKIE: usize = 253;
+pub const EPOLL_CREATE: usize = 254;
+pub const EPOLL_CTL: usize = 255;
+pub const EPOLL_WAIT: usize = 256;
+pub const REMAP_FILE_PAGES: usize = 257;
+pub const SET_TID_ADDRESS: usize = 258;
+pub const TIMER_CREATE: usize = 259;
+pub const TIMER_SETTIME: usize = 260;
+pub const TIMER_GETTIME: usize = 261;
+pub const TIMER_GETOVERRUN: usize = 262;
+pub const TIMER_DELETE: usize = 263;
+pub const CLOCK_SETTIME: usize = 264;
+pub const CLOCK_GETTIME: usize = 265;
+pub const CLOCK_GETRES: usize = 266;
+pub const CLOCK_NANOSLEEP: usize = 267;
+pub const STATFS64: usize = 268;
+pub const FSTATFS64: usize = 269;
+pub const TGKILL: usize = 270;
+pub const UTIMES: usize = 271;
+pub const FADVISE64_64: usize = 272;
+pub const VSERVER: usize = 273;
+pub const MBIND: usize = 274;
+pub const GET_MEMPOLICY: usize = 275;
+pub const SET_MEMPOLICY: usize = 276;
+pub const MQ_OPEN: usize = 277;
+pub const MQ_UNLINK: usize = 278;
+pub const MQ_TIMEDSEND: usize = 279;
+pub const MQ_TIMEDRECEIVE: usize = 280;
+pub const MQ_NOTIFY: usize = 281;
+pub const MQ_GETSETATTR: usize = 282;
+pub const KEXEC_LOAD: usize = 283;
+pub const WAITID: usize = 284;
+pub const ADD_KEY: usize = 286;
+pub const REQUEST_KEY: usize = 287;
+pub const KEYCTL: usize = 288;
+pub const IOPRIO_SET: usize = 289;
+pub const IOPRIO_GET: usize = 290;
+pub const INOTIFY_INIT: usize = 291;
+pub const INOTIFY_ADD_WATCH: usize = 292;
+pub const INOTIFY_RM_WATCH: usize = 293;
+pub const MIGRATE_PAGES: usize = 294;
+pub const OPENAT: usize = 295;
+pub const MKDIRAT: usize = 296;
+pub const MKNODAT: usize = 297;
+pub const FCHOWNAT: usize = 298;
+pub const FUTIMESAT: usize = 299;
+pub const FSTATAT64: usize = 300;
+pub const UNLINKAT: usize = 301;
+pub const RENAMEAT: usize = 302;
+pub const LINKAT: usize = 303;
+pub const SYMLINKAT: usize = 304;
+pub const READLINKAT: usize = 305;
+pub const FCHMODAT: usize = 306;
+pub const FACCESSAT: usize = 307;
+pub const PSELECT6: usize = 308;
+pub const PPOLL: usize = 309;
+pub const UNSHARE: usize = 310;
+pub const SET_ROBUST_LIST: usize = 311;
+pub const GET_ROBUST_LIST: usize = 312;
+pub const SPLICE: usize = 313;
+pub const SYNC_FILE_RANGE: usize = 314;
+pub const TEE: usize = 315;
+pub const VMSPLICE: usize = 316;
+pub const MOVE_PAGES: usize = 317;
+pub const GETCPU: usize = 318;
+pub const EPOLL_PWAIT: usize = 319;
+pub const UTIMENSAT: usize = 320;
+pub const SIGNALFD: usize = 321;
+pub const TIMERFD_CREATE: usize = 322;
+pub const EVENTFD: usize = 323;
+pub const FALLOCATE: usize = 324;
+pub const TIMERFD_SETTIME: usize = 325;
+pub const TIMERFD_GETTIME: usize = 326;
+pub const SIGNALFD4: usize = 327;
+pub const EVENTFD2: usize = 328;
+pub const EPOLL_CREATE1: usize = 329;
+pub const DUP3: usize = 330;
+pub const PIPE2: usize = 331;
+pub const INOTIFY_INIT1: usize = 332;
+pub const PREADV: usize = 333;
+pub const PWRITEV: usize = 334;
+pub const RT_TGSIGQUEUEINFO: usize = 335;
+pub const PERF_EVENT_OPEN: usize = 336;
+pub const RECVMMSG: usize = 337;
+pub const FANOTIFY_INIT: usize = 338;
+pub const FANOTIFY_MARK: usize = 339;
+pub const PRLIMIT64: usize = 340;
+pub const NAME_TO_HANDLE_AT: usize = 341;
+pub const OPEN_BY_HANDLE_AT: usize = 342;
+pub const CLOCK_ADJTIME: usize = 343;
+pub const SYNCFS: usize = 344;
+pub const SENDMMSG: usize = 345;
+pub const SETNS: usize = 346;
+pub const PROCESS_VM_READV: usize = 347;
+pub const PROCESS_VM_WRITEV: usize = 348;
+pub const KCMP: usize = 349;
+pub const FINIT_MODULE: usize = 350;
+pub const SCHED_SETATTR: usize = 351;
+pub const SCHED_GETATTR: usize = 352;
+pub const RENAMEAT2: usize = 353;
+pub const SECCOMP: usize = 354;
+pub const GETRANDOM: usize = 355;
+pub const MEMFD_CREATE: usize = 356;
+pub const BPF: usize = 357;
+pub const EXECVEAT: usize = 358;
+pub const SOCKET: usize = 359;
+pub const SOCKETPAIR: usize = 360;
+pub const BIND: usize = 361;
+pub const CONNECT: usize = 362;
+pub const LISTEN: usize = 363;
+pub const ACCEPT4: usize = 364;
+pub const GETSOCKOPT: usize = 365;
+pub const SETSOCKOPT: usize = 366;
+pub const GETSOCKNAME: usize = 367;
+pub const GETPEERNAME: usize = 368;
+pub const SENDTO: usize = 369;
+pub const SENDMSG: usize = 370;
+pub const RECVFROM: usize = 371;
+pub const RECVMSG: usize = 372;
+pub const SHUTDOWN: usize = 373;
+pub const USERFAULTFD: usize = 374;
+pub const MEMBARRIER: usize = 375;
+pub const MLOCK2: usize = 376;
+pub const COPY_FILE_RANGE: usize = 377;
+pub const PREADV2: usize = 378;
+pub const PWRITEV2: usize = 379;
+pub const PKEY_MPROTECT: usize = 380;
+pub const PKEY_ALLOC: usize = 381;
+pub const PKEY_FREE: usize = 382;
+pub const STATX: usize = 383;
+pub const ARCH_PRCTL: usize = 384;
+pub const IO_PGETEVENTS: usize = 385;
+pub const RSEQ: usize = 386;
+pub const SEMGET: usize = 393;
+pub const SEMCTL: usize = 394;
+pub const SHMGET: usize = 395;
+pub const SHMCTL: usize = 396;
+pub const SHMAT: usize = 397;
+pub const SHMDT: usize = 398;
+pub const MSGGET: usize = 399;
+pub const MSGSND: usize = 400;
+pub const MSGRCV: usize = 401;
+pub const MSGCTL: usize = 402;
+pub const CLOCK_GETTIME64: usize = 403;
+pub const CLOCK_SETTIME64: usize = 404;
+pub const CLOCK_ADJTIME64: usize = 405;
+pub const CLOCK_GETRES_TIME64: usize = 406;
+pub const CLOCK_NANOSLEEP_TIME64: usize = 407;
+pub const TIMER_GETTIME64: usize = 408;
+pub const TIMER_SETTIME64: usize = 409;
+pub const TIMERFD_GETTIME64: usize = 410;
+pub const TIMERFD_SETTIME64: usize = 411;
+pub const UTIMENSAT_TIME64: usize = 412;
+pub const PSELECT6_TIME64: usize = 413;
+pub const PPOLL_TIME64: usize = 414;
+pub const IO_PGETEVENTS_TIME64: usize = 416;
+pub const RECVMMSG_TIME64: usize = 417;
+pub const MQ_TIMEDSEND_TIME64: usize = 418;
+pub const MQ_TIMEDRECEIVE_TIME64: usize = 419;
+pub const SEMTIMEDOP_TIME64: usize = 420;
+pub const RT_SIGTIMEDWAIT_TIME64: usize = 421;
+pub const FUTEX_TIME64: usize = 422;
+pub const SCHED_RR_GET_INTERVAL_TIME64: usize = 423;
+pub const PIDFD_SEND_SIGNAL: usize = 424;
+pub const IO_URING_SETUP: usize = 425;
+pub const IO_URING_ENTER: usize = 426;
+pub const IO_URING_REGISTER: usize = 427;
+pub const OPEN_TREE: usize = 428;
+pub const MOVE_MOUNT: usize = 429;
+pub const FSOPEN: usize = 430;
+pub const FSCONFIG: usize = 431;
+pub const FSMOUNT: usize = 432;
+pub const FSPICK: usize = 433;
+pub const PIDFD_OPEN: usize = 434;
+pub const CLONE3: usize = 435;
+pub const CLOSE_RANGE: usize = 436;
+pub const OPENAT2: usize = 437;
+pub const PIDFD_GETFD: usize = 438;
+pub const FACCESSAT2: usize = 439;
+pub const PROCESS_MADVISE: usize = 440;
+pub const EPOLL_PWAIT2: usize = 441;
+pub const MOUNT_SETATTR: usize = 442;
+pub const QUOTACTL_FD: usize = 443;
+pub const LANDLOCK_CREATE_RULESET: usize = 444;
+pub const LANDLOCK_ADD_RULE: usize = 445;
+pub const LANDLOCK_RESTRICT_SELF: usize = 446;
+pub const MEMFD_SECRET: usize = 447;
+pub const PROCESS_MRELEASE: usize = 448;
+pub const FUTEX_WAITV: usize = 449;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 450;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    RestartSyscall = RESTART_SYSCALL,
+    Exit = EXIT,
+    Fork = FORK,
+    Read = READ,
+    Write = WRITE,
+    Open = OPEN,
+    Close = CLOSE,
+    Waitpid = WAITPID,
+    Creat = CREAT,
+    Link = LINK,
+    Unlink = UNLINK,
+    Execve = EXECVE,
+    Chdir = CHDIR,
+    Time = TIME,
+    Mknod = MKNOD,
+    Chmod = CHMOD,
+    Lchown = LCHOWN,
+    Break = BREAK,
+    Oldstat = OLDSTAT,
+    Lseek = LSEEK,
+    Getpid = GETPID,
+    Mount = MOUNT,
+    Umount = UMOUNT,
+    Setuid = SETUID,
+    Getuid = GETUID,
+    Stime = STIME,
+    Ptrace = PTRACE,
+    Alarm = ALARM,
+    Oldfstat = OLDFSTAT,
+    Pause = PAUSE,
+    Utime = UTIME,
+    Stty = STTY,
+    Gtty = GTTY,
+    Access = ACCESS,
+    Nice = NICE,
+    Ftime = FTIME,
+    Sync = SYNC,
+    Kill = KILL,
+    Rename = RENAME,
+    Mkdir = MKDIR,
+    Rmdir = RMDIR,
+    Dup = DUP,
+    Pipe = PIPE,
+    Times = TIMES,
+    Prof = PROF,
+    Brk = BRK,
+    Setgid = SETGID,
+    Getgid = GETGID,
+    Signal = SIGNAL,
+    Geteuid = GETEUID,
+    Getegid = GETEGID,
+    Acct = ACCT,
+    Umount2 = UMOUNT2,
+    Lock = LOCK,
+    Ioctl = IOCTL,
+    Fcntl = FCNTL,
+    Mpx = MPX,
+    Setpgid = SETPGID,
+    Ulimit = ULIMIT,
+    Oldolduname = OLDOLDUNAME,
+    Umask = UMASK,
+    Chroot = CHROOT,
+    Ustat = USTAT,
+    Dup2 = DUP2,
+    Getppid = GETPPID,
+    Getpgrp = GETPGRP,
+    Setsid = SETSID,
+    Sigaction = SIGACTION,
+    Sgetmask = SGETMASK,
+    Ssetmask = SSETMASK,
+    Setreuid = SETREUID,
+    Setregid = SETREGID,
+    Sigsuspend = SIGSUSPEND,
+    Sigpending = SIGPENDING,
+    Sethostname = SETHOSTNAME,
+    Setrlimit = SETRLIMIT,
+    Getrlimit = GETRLIMIT,
+    Getrusage = GETRUSAGE,
+    Gettimeofday = GETTIMEOFDAY,
+    Settimeofday = SETTIMEOFDAY,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Select = SELECT,
+    Symlink = SYMLINK,
+    Oldlstat = OLDLSTAT,
+    Readlink = READLINK,
+    Uselib = USELIB,
+    Swapon = SWAPON,
+    Reboot = REBOOT,
+    Readdir = READDIR,
+    Mmap = MMAP,
+    Munmap = MUNMAP,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    Fchmod = FCHMOD,
+    Fchown = FCHOWN,
+    Getpriority = GETPRIORITY,
+    Setpriority = SETPRIORITY,
+    Profil = PROFIL,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Ioperm = IOPERM,
+    Socketcall = SOCKETCALL,
+    Syslog = SYSLOG,
+    Setitimer = SETITIMER,
+    Getitimer = GETITIMER,
+    Stat = STAT,
+    Lstat = LSTAT,
+    Fstat = FSTAT,
+    Olduname = OLDUNAME,
+    Iopl = IOPL,
+    Vhangup = VHANGUP,
+    Idle = IDLE,
+    Vm86old = VM86OLD,
+    Wait4 = WAIT4,
+    Swapoff = SWAPOFF,
+    Sysinfo = SYSINFO,
+    Ipc = IPC,
+    Fsync = FSYNC,
+    Sigreturn = SIGRETURN,
+    Clone = CLONE,
+    Setdomainname = SETDOMAINNAME,
+    Uname = UNAME,
+    ModifyLdt = MODIFY_LDT,
+    Adjtimex = ADJTIMEX,
+    Mprotect = MPROTECT,
+    Sigprocmask = SIGPROCMASK,
+    CreateModule = CREATE_MODULE,
+    InitModule = INIT_MODULE,
+    DeleteModule = DELETE_MODULE,
+    GetKernelSyms = GET_KERNEL_SYMS,
+    Quotactl = QUOTACTL,
+    Getpgid = GETPGID,
+    Fchdir = FCHDIR,
+    Bdflush = BDFLUSH,
+    Sysfs = SYSFS,
+    Personality = PERSONALITY,
+    AfsSyscall = AFS_SYSCALL,
+    Setfsuid = SETFSUID,
+    Setfsgid = SETFSGID,
+    Llseek = _LLSEEK,
+    Getdents = GETDENTS,
+    Newselect = _NEWSELECT,
+    Flock = FLOCK,
+    Msync = MSYNC,
+    Readv = READV,
+    Writev = WRITEV,
+    Getsid = GETSID,
+    Fdatasync = FDATASYNC,
+    Sysctl = _SYSCTL,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedYield = SCHED_YIELD,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    Nanosleep = NANOSLEEP,
+    Mremap = MREMAP,
+    Setresuid = SETRESUID,
+    Getresuid = GETRESUID,
+    Vm86 = VM86,
+    QueryModule = QUERY_MODULE,
+    Poll = POLL,
+    Nfsservctl = NFSSERVCTL,
+    Setresgid = SETRESGID,
+    Getresgid = GETRESGID,
+    Prctl = PRCTL,
+    RtSigreturn = RT_SIGRETURN,
+    RtSigaction = RT_SIGACTION,
+    RtSigprocmask = RT_SIGPROCMASK,
+    RtSigpending = RT_SIGPENDING,
+    RtSigtimedwait = RT_SIGTIMEDWAIT,
+    RtSigqueueinfo = RT_SIGQUEUEINFO,
+    RtSigsuspend = RT_SIGSUSPEND,
+    Pread64 = PREAD64,
+    Pwrite64 = PWRITE64,
+    Chown = CHOWN,
+    Getcwd = GETCWD,
+    Capget = CAPGET,
+    Capset = CAPSET,
+    Sigaltstack = SIGALTSTACK,
+    Sendfile = SENDFILE,
+    Getpmsg = GETPMSG,
+    Putpmsg = PUTPMSG,
+    Vfork = VFORK,
+    Ugetrlimit = UGETRLIMIT,
+    Mmap2 = MMAP2,
+    Truncate64 = TRUNCATE64,
+    Ftruncate64 = FTRUNCATE64,
+    Stat64 = STAT64,
+    Lstat64 = LSTAT64,
+    Fstat64 = FSTAT64,
+    Lchown32 = LCHOWN32,
+    Getuid32 = GETUID32,
+    Getgid32 = GETGID32,
+    Geteuid32 = GETEUID32,
+    Getegid32 = GETEGID32,
+    Setreuid32 = SETREUID32,
+    Setregid32 = SETREGID32,
+    Getgroups32 = GETGROUPS32,
+    Setgroups32 = SETGROUPS32,
+    Fchown32 = FCHOWN32,
+    Setresuid32 = SETRESUID32,
+    Getresuid32 = GETRESUID32,
+    Setresgid32 = SETRESGID32,
+    Getresgid32 = GETRESGID32,
+    Chown32 = CHOWN32,
+    Setuid32 = SETUID32,
+    Setgid32 = SETGID32,
+    Setfsuid32 = SETFSUID32,
+    Setfsgid32 = SETFSGID32,
+    PivotRoot = PIVOT_ROOT,
+    Mincore = MINCORE,
+    Madvise = MADVISE,
+    Getdents64 = GETDENTS64,
+    Fcntl64 = FCNTL64,
+    Gettid = GETTID,
+    Readahead = READAHEAD,
+    Setxattr = SETXATTR,
+    Lsetxattr = LSETXATTR,
+    Fsetxattr = FSETXATTR,
+    Getxattr = GETXATTR,
+    Lgetxattr = LGETXATTR,
+    Fgetxattr = FGETXATTR,
+    Listxattr = LISTXATTR,
+    Llistxattr = LLISTXATTR,
+    Flistxattr = FLISTXATTR,
+    Removexattr = REMOVEXATTR,
+    Lremovexattr = LREMOVEXATTR,
+    Fremovexattr = FREMOVEXATTR,
+    Tkill = TKILL,
+    Sendfile64 = SENDFILE64,
+    Futex = FUTEX,
+    SchedSetaffinity = SCHED_SETAFFINITY,
+    SchedGetaffinity = SCHED_GETAFFINITY,
+    SetThreadArea = SET_THREAD_AREA,
+    GetThreadArea = GET_THREAD_AREA,
+    IoSetup = IO_SETUP,
+    IoDestroy = IO_DESTROY,
+    IoGetevents = IO_GETEVENTS,
+    IoSubmit = IO_SUBMIT,
+    IoCancel = IO_CANCEL,
+    Fadvise64 = FADVISE64,
+    ExitGroup = EXIT_GROUP,
+    LookupDcookie = LOOKUP_DCOOKIE,
+    EpollCreate = EPOLL_CREATE,
+    EpollCtl = EPOLL_CTL,
+    EpollWait = EPOLL_WAIT,
+    RemapFilePages = REMAP_FILE_PAGES,
+    SetTidAddress = SET_TID_ADDRESS,
+    TimerCreate = TIMER_CREATE,
+    TimerSettime = TIMER_SETTIME,
+    TimerGettime = TIMER_GETTIME,
+    TimerGetoverrun = TIMER_GETOVERRUN,
+    TimerDelete = TIMER_DELETE,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGettime = CLOCK_GETTIME,
+    ClockGetres = CLOCK_GETRES,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    Statfs64 = STATFS64,
+    Fstatfs64 = FSTATFS64,
+    Tgkill = TGKILL,
+    Utimes = UTIMES,
+    Fadvise6464 = FADVISE64_64,
+    Vserver = VSERVER,
+    Mbind = MBIND,
+    GetMempolicy = GET_MEMPOLICY,
+    SetMempolicy = SET_MEMPOLICY,
+    MqOpen = MQ_OPEN,
+    MqUnlink = MQ_UNLINK,
+    MqTimedsend = MQ_TIMEDSEND,
+    MqTimedreceive = MQ_TIMEDRECEIVE,
+    MqNotify = MQ_NOTIFY,
+    MqGetsetattr = MQ_GETSETATTR,
+    KexecLoad = KEXEC_LOAD,
+    Waitid = WAITID,
+    AddKey = ADD_KEY,
+    RequestKey = REQUEST_KEY,
+    Keyctl = KEYCTL,
+    IoprioSet = IOPRIO_SET,
+    IoprioGet = IOPRIO_GET,
+    InotifyInit = INOTIFY_INIT,
+    InotifyAddWatch = INOTIFY_ADD_WATCH,
+    InotifyRmWatch = INOTIFY_RM_WATCH,
+    MigratePages = MIGRATE_PAGES,
+    Openat = OPENAT,
+    Mkdirat = MKDIRAT,
+    Mknodat = MKNODAT,
+    Fchownat = FCHOWNAT,
+    Futimesat = FUTIMESAT,
+    Fstatat64 = FSTATAT64,
+    Unlinkat = UNLINKAT,
+    Renameat = RENAMEAT,
+    Linkat = LINKAT,
+    Symlinkat = SYMLINKAT,
+    Readlinkat = READLINKAT,
+    Fchmodat = FCHMODAT,
+    Faccessat = FACCESSAT,
+    Pselect6 = PSELECT6,
+    Ppoll = PPOLL,
+    Unshare = UNSHARE,
+    SetRobustList = SET_ROBUST_LIST,
+    GetRobustList = GET_ROBUST_LIST,
+    Splice = SPLICE,
+    SyncFileRange = SYNC_FILE_RANGE,
+    Tee = TEE,
+    Vmsplice = VMSPLICE,
+    MovePages = MOVE_PAGES,
+    Getcpu = GETCPU,
+    EpollPwait = EPOLL_PWAIT,
+    Utimensat = UTIMENSAT,
+    Signalfd = SIGNALFD,
+    TimerfdCreate = TIMERFD_CREATE,
+    Eventfd = EVENTFD,
+    Fallocate = FALLOCATE,
+    TimerfdSettime = TIMERFD_SETTIME,
+    TimerfdGettime = TIMERFD_GETTIME,
+    Signalfd4 = SIGNALFD4,
+    Eventfd2 = EVENTFD2,
+    EpollCreate1 = EPOLL_CREATE1,
+    Dup3 = DUP3,
+    Pipe2 = PIPE2,
+    InotifyInit1 = INOTIFY_INIT1,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    RtTgsigqueueinfo = RT_TGSIGQUEUEINFO,
+    PerfEventOpen = PERF_EVENT_OPEN,
+    Recvmmsg = RECVMMSG,
+    FanotifyInit = FANOTIFY_INIT,
+    FanotifyMark = FANOTIFY_MARK,
+    Prlimit64 = PRLIMIT64,
+    NameToHandleAt = NAME_TO_HANDLE_AT,
+    OpenByHandleAt = OPEN_BY_HANDLE_AT,
+    ClockAdjtime = CLOCK_ADJTIME,
+    Syncfs = SYNCFS,
+    Sendmmsg = SENDMMSG,
+    Setns = SETNS,
+    ProcessVmReadv = PROCESS_VM_READV,
+    ProcessVmWritev = PROCESS_VM_WRITEV,
+    Kcmp = KCMP,
+    FinitModule = FINIT_MODULE,
+    SchedSetattr = SCHED_SETATTR,
+    SchedGetattr = SCHED_GETATTR,
+    Renameat2 = RENAMEAT2,
+    Seccomp = SECCOMP,
+    Getrandom = GETRANDOM,
+    MemfdCreate = MEMFD_CREATE,
+    Bpf = BPF,
+    Execveat = EXECVEAT,
+    Socket = SOCKET,
+    Socketpair = SOCKETPAIR,
+    Bind = BIND,
+    Connect = CONNECT,
+    Listen = LISTEN,
+    Accept4 = ACCEPT4,
+    Getsockopt = GETSOCKOPT,
+    Setsockopt = SETSOCKOPT,
+    Getsockname = GETSOCKNAME,
+    Getpeername = GETPEERNAME,
+    Sendto = SENDTO,
+    Sendmsg = SENDMSG,
+    Recvfrom = RECVFROM,
+    Recvmsg = RECVMSG,
+    Shutdown = SHUTDOWN,
+    Userfaultfd = USERFAULTFD,
+    Membarrier = MEMBARRIER,
+    Mlock2 = MLOCK2,
+    CopyFileRange = COPY_FILE_RANGE,
+    Preadv2 = PREADV2,
+    Pwritev2 = PWRITEV2,
+    PkeyMprotect = PKEY_MPROTECT,
+    PkeyAlloc = PKEY_ALLOC,
+    PkeyFree = PKEY_FREE,
+    Statx = STATX,
+    ArchPrctl = ARCH_PRCTL,
+    IoPgetevents = IO_PGETEVENTS,
+    Rseq = RSEQ,
+    Semget = SEMGET,
+    Semctl = SEMCTL,
+    Shmget = SHMGET,
+    Shmctl = SHMCTL,
+    Shmat = SHMAT,
+    Shmdt = SHMDT,
+    Msgget = MSGGET,
+    Msgsnd = MSGSND,
+    Msgrcv = MSGRCV,
+    Msgctl = MSGCTL,
+    ClockGettime64 = CLOCK_GETTIME64,
+    ClockSettime64 = CLOCK_SETTIME64,
+    ClockAdjtime64 = CLOCK_ADJTIME64,
+    ClockGetresTime64 = CLOCK_GETRES_TIME64,
+    ClockNanosleepTime64 = CLOCK_NANOSLEEP_TIME64,
+    TimerGettime64 = TIMER_GETTIME64,
+    TimerSettime64 = TIMER_SETTIME64,
+    TimerfdGettime64 = TIMERFD_GETTIME64,
+    TimerfdSettime64 = TIMERFD_SETTIME64,
+    UtimensatTime64 = UTIMENSAT_TIME64,
+    Pselect6Time64 = PSELECT6_TIME64,
+    PpollTime64 = PPOLL_TIME64,
+    IoPgeteventsTime64 = IO_PGETEVENTS_TIME64,
+    RecvmmsgTime64 = RECVMMSG_TIME64,
+    MqTimedsendTime64 = MQ_TIMEDSEND_TIME64,
+    MqTimedreceiveTime64 = MQ_TIMEDRECEIVE_TIME64,
+    SemtimedopTime64 = SEMTIMEDOP_TIME64,
+    RtSigtimedwaitTime64 = RT_SIGTIMEDWAIT_TIME64,
+    FutexTime64 = FUTEX_TIME64,
+    SchedRrGetIntervalTime64 = SCHED_RR_GET_INTERVAL_TIME64,
+    PidfdSendSignal = PIDFD_SEND_SIGNAL,
+    IoUringSetup = IO_URING_SETUP,
+    IoUringEnter = IO_URING_ENTER,
+    IoUringRegister = IO_URING_REGISTER,
+    OpenTree = OPEN_TREE,
+    MoveMount = MOVE_MOUNT,
+    Fsopen = FSOPEN,
+    Fsconfig = FSCONFIG,
+    Fsmount = FSMOUNT,
+    Fspick = FSPICK,
+    PidfdOpen = PIDFD_OPEN,
+    Clone3 = CLONE3,
+    CloseRange = CLOSE_RANGE,
+    Openat2 = OPENAT2,
+    PidfdGetfd = PIDFD_GETFD,
+    Faccessat2 = FACCESSAT2,
+    ProcessMadvise = PROCESS_MADVISE,
+    EpollPwait2 = EPOLL_PWAIT2,
+    MountSetattr = MOUNT_SETATTR,
+    QuotactlFd = QUOTACTL_FD,
+    LandlockCreateRuleset = LANDLOCK_CREATE_RULESET,
+    LandlockAddRule = LANDLOCK_ADD_RULE,
+    LandlockRestrictSelf = LANDLOCK_RESTRICT_SELF,
+    MemfdSecret = MEMFD_SECRET,
+    ProcessMrelease = PROCESS_MRELEASE,
+    FutexWaitv = FUTEX_WAITV,
+    SetMempolicyHomeNode = SET_MEMPOLICY_HOME_NODE,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::RestartSyscall,
+        Self::Exit,
+        Self::Fork,
+        Self::Read,
+        Self::Write,
+        Self::Open,
+        Self::Close,
+        Self::Waitpid,
+        Self::Creat,
+        Self::Link,
+        Self::Unlink,
+        Self::Execve,
+        Self::Chdir,
+        Self::Time,
+        Self::Mknod,
+        Self::Chmod,
+        Self::Lchown,
+        Self::Break,
+        Self::Oldstat,
+        Self::Lseek,
+        Self::Getpid,
+        Self::Mount,
+        Self::Umount,
+        Self::Setuid,
+        Self::Getuid,
+        Self::Stime,
+        Self::Ptrace,
+        Self::Alarm,
+        Self::Oldfstat,
+        Self::Pause,
+        Self::Utime,
+        Self::Stty,
+        Self::Gtty,
+        Self::Access,
+        Self::Nice,
+        Self::Ftime,
+        Self::Sync,
+        Self::Kill,
+        Self::Rename,
+        Self::Mkdir,
+        Self::Rmdir,
+        Self::Dup,
+        Self::Pipe,
+        Self::Times,
+        Self::Prof,
+        Self::Brk,
+        Self::Setgid,
+        Self::Getgid,
+        Self::Signal,
+        Self::Geteuid,
+        Self::Getegid,
+        Self::Acct,
+        Self::Umount2,
+        Self::Lock,
+        Self::Ioctl,
+        Self::Fcntl,
+        Self::Mpx,
+        Self::Setpgid,
+        Self::Ulimit,
+        Self::Oldolduname,
+        Self::Umask,
+        Self::Chroot,
+        Self::Ustat,
+        Self::Dup2,
+        Self::Getppid,
+        Self::Getpgrp,
+        Self::Setsid,
+        Self::Sigaction,
+        Self::Sgetmask,
+        Self::Ssetmask,
+        Self::Setreuid,
+        Self::Setregid,
+        Self::Sigsuspend,
+        Self::Sigpending,
+        Self::Sethostname,
+        Self::Setrlimit,
+        Self::Getrlimit,
+        Self::Getrusage,
+        Self::Gettimeofday,
+        Self::Settimeofday,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Select,
+        Self::Symlink,
+        Self::Oldlstat,
+        Self::Readlink,
+        Self::Uselib,
+        Self::Swapon,
+        Self::Reboot,
+        Self::Readdir,
+        Self::Mmap,
+        Self::Munmap,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::Fchmod,
+        Self::Fchown,
+        Self::Getpriority,
+        Self::Setpriority,
+        Self::Profil,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Ioperm,
+        Self::Socketcall,
+        Self::Syslog,
+        Self::Setitimer,
+        Self::Getitimer,
+        Self::Stat,
+        Self::Lstat,
+        Self::Fstat,
+        Self::Olduname,
+        Self::Iopl,
+        Self::Vhangup,
+        Self::Idle,
+        Self::Vm86old,
+        Self::Wait4,
+        Self::Swapoff,
+        Self::Sysinfo,
+        Self::Ipc,
+        Self::Fsync,
+        Self::Sigreturn,
+        Self::Clone,
+        Self::Setdomainname,
+        Self::Uname,
+        Self::ModifyLdt,
+        Self::Adjtimex,
+        Self::Mprotect,
+        Self::Sigprocmask,
+        Self::CreateModule,
+        Self::InitModule,
+        Self::DeleteModule,
+        Self::GetKernelSyms,
+        Self::Quotactl,
+        Self::Getpgid,
+        Self::Fchdir,
+        Self::Bdflush,
+        Self::Sysfs,
+        Self::Personality,
+        Self::AfsSyscall,
+        Self::Setfsuid,
+        Self::Setfsgid,
+        Self::Llseek,
+        Self::Getdents,
+        Self::Newselect,
+        Self::Flock,
+        Self::Msync,
+        Self::Readv,
+        Self::Writev,
+        Self::Getsid,
+        Self::Fdatasync,
+        Self::Sysctl,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::SchedSetparam,
+        Self::SchedGetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedYield,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::Nanosleep,
+        Self::Mremap,
+        Self::Setresuid,
+        Self::Getresuid,
+        Self::Vm86,
+        Self::QueryModule,
+        Self::Poll,
+        Self::Nfsservctl,
+        Self::Setresgid,
+        Self::Getresgid,
+        Self::Prctl,
+        Self::RtSigreturn,
+        Self::RtSigaction,
+        Self::RtSigprocmask,
+        Self::RtSigpending,
+        Self::RtSigtimedwait,
+        Self::RtSigqueueinfo,
+        Self::RtSigsuspend,
+        Self::Pread64,
+        Self::Pwrite64,
+        Self::Chown,
+        Self::Getcwd,
+        Self::Capget,
+        Self::Capset,
+        Self::Sigaltstack,
+        Self::Sendfile,
+        Self::Getpmsg,
+        Self::Putpmsg,
+        Self::Vfork,
+        Self::Ugetrlimit,
+        Self::Mmap2,
+        Self::Truncate64,
+        Self::Ftruncate64,
+        Self::Stat64,
+        Self::Lstat64,
+        Self::Fstat64,
+        Self::Lchown32,
+        Self::Getuid32,
+        Self::Getgid32,
+        Self::Geteuid32,
+        Self::Getegid32,
+        Self::Setreuid32,
+        Self::Setregid32,
+        Self::Getgroups32,
+        Self::Setgroups32,
+        Self::Fchown32,
+        Self::Setresuid32,
+        Self::Getresuid32,
+        Self::Setresgid32,
+        Self::Getresgid32,
+        Self::Chown32,
+        Self::Setuid32,
+        Self::Setgid32,
+        Self::Setfsuid32,
+        Self::Setfsgid32,
+        Self::PivotRoot,
+        Self::Mincore,
+        Self::Madvise,
+        Self::Getdents64,
+        Self::Fcntl64,
+        Self::Gettid,
+        Self::Readahead,
+        Self::Setxattr,
+        Self::Lsetxattr,
+        Self::Fsetxattr,
+        Self::Getxattr,
+        Self::Lgetxattr,
+        Self::Fgetxattr,
+        Self::Listxattr,
+        Self::Llistxattr,
+        Self::Flistxattr,
+        Self::Removexattr,
+        Self::Lremovexattr,
+        Self::Fremovexattr,
+        Self::Tkill,
+        Self::Sendfile64,
+        Self::Futex,
+        Self::SchedSetaffinity,
+        Self::SchedGetaffinity,
+        Self::SetThreadArea,
+        Self::GetThreadArea,
+        Self::IoSetup,
+        Self::IoDestroy,
+        Self::IoGetevents,
+        Self::IoSubmit,
+        Self::IoCancel,
+        Self::Fadvise64,
+        Self::ExitGroup,
+        Self::LookupDcookie,
+        Self::EpollCreate,
+        Self::EpollCtl,
+        Self::EpollWait,
+        Self::RemapFilePages,
+        Self::SetTidAddress,
+        Self::TimerCreate,
+        Self::TimerSettime,
+        Self::TimerGettime,
+        Self::TimerGetoverrun,
+        Self::TimerDelete,
+        Self::ClockSettime,
+        Self::ClockGettime,
+        Self::ClockGetres,
+        Self::ClockNanosleep,
+        Self::Statfs64,
+        Self::Fstatfs64,
+        Self::Tgkill,
+        Self::Utimes,
+        Self::Fadvise6464,
+        Self::Vserver,
+        Self::Mbind,
+        Self::GetMempolicy,
+        Self::SetMempolicy,
+        Self::MqOpen,
+        Self::MqUnlink,
+        Self::MqTimedsend,
+        Self::MqTimedreceive,
+        Self::MqNotify,
+        Self::MqGetsetattr,
+        Self::KexecLoad,
+        Self::Waitid,
+        Self::AddKey,
+        Self::RequestKey,
+        Self::Keyctl,
+        Self::IoprioSet,
+        Self::IoprioGet,
+        Self::InotifyInit,
+        Self::InotifyAddWatch,
+        Self::InotifyRmWatch,
+        Self::MigratePages,
+        Self::Openat,
+        Self::Mkdirat,
+        Self::Mknodat,
+        Self::Fchownat,
+        Self::Futimesat,
+        Self::Fstatat64,
+        Self::Unlinkat,
+        Self::Renameat,
+        Self::Linkat,
+        Self::Symlinkat,
+        Self::Readlinkat,
+        Self::Fchmodat,
+        Self::Faccessat,
+        Self::Pselect6,
+        Self::Ppoll,
+        Self::Unshare,
+        Self::SetRobustList,
+        Self::GetRobustList,
+        Self::Splice,
+        Self::SyncFileRange,
+        Self::Tee,
+        Self::Vmsplice,
+        Self::MovePages,
+        Self::Getcpu,
+        Self::EpollPwait,
+        Self::Utimensat,
+        Self::Signalfd,
+        Self::TimerfdCreate,
+        Self::Eventfd,
+        Self::Fallocate,
+        Self::TimerfdSettime,
+        Self::TimerfdGettime,
+        Self::Signalfd4,
+        Self::Eventfd2,
+        Self::EpollCreate1,
+        Self::Dup3,
+        Self::Pipe2,
+        Self::InotifyInit1,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::RtTgsigqueueinfo,
+        Self::PerfEventOpen,
+        Self::Recvmmsg,
+        Self::FanotifyInit,
+        Self::FanotifyMark,
+        Self::Prlimit64,
+        Self::NameToHandleAt,
+        Self::OpenByHandleAt,
+        Self::ClockAdjtime,
+        Self::Syncfs,
+        Self::Sendmmsg,
+        Self::Setns,
+        Self::ProcessVmReadv,
+        Self::ProcessVmWritev,
+        Self::Kcmp,
+        Self::FinitModule,
+        Self::SchedSetattr,
+        Self::SchedGetattr,
+        Self::Renameat2,
+        Self::Seccomp,
+        Self::Getrandom,
+        Self::MemfdCreate,
+        Self::Bpf,
+        Self::Execveat,
+        Self::Socket,
+        Self::Socketpair,
+        Self::Bind,
+        Self::Connect,
+        Self::Listen,
+        Self::Accept4,
+        Self::Getsockopt,
+        Self::Setsockopt,
+        Self::Getsockname,
+        Self::Getpeername,
+        Self::Sendto,
+        Self::Sendmsg,
+        Self::Recvfrom,
+        Self::Recvmsg,
+        Self::Shutdown,
+        Self::Userfaultfd,
+        Self::Membarrier,
+        Self::Mlock2,
+        Self::CopyFileRange,
+        Self::Preadv2,
+        Self::Pwritev2,
+        Self::PkeyMprotect,
+        Self::PkeyAlloc,
+        Self::PkeyFree,
+        Self::Statx,
+        Self::ArchPrctl,
+        Self::IoPgetevents,
+        Self::Rseq,
+        Self::Semget,
+        Self::Semctl,
+        Self::Shmget,
+        Self::Shmctl,
+        Self::Shmat,
+        Self::Shmdt,
+        Self::Msgget,
+        Self::Msgsnd,
+        Self::Msgrcv,
+        Self::Msgctl,
+        Self::ClockGettime64,
+        Self::ClockSettime64,
+        Self::ClockAdjtime64,
+        Self::ClockGetresTime64,
+        Self::ClockNanosleepTime64,
+        Self::TimerGettime64,
+        Self::TimerSettime64,
+        Self::TimerfdGettime64,
+        Self::TimerfdSettime64,
+        Self::UtimensatTime64,
+        Self::Pselect6Time64,
+        Self::PpollTime64,
+        Self::IoPgeteventsTime64,
+        Self::RecvmmsgTime64,
+        Self::MqTimedsendTime64,
+        Self::MqTimedreceiveTime64,
+        Self::SemtimedopTime64,
+        Self::RtSigtimedwaitTime64,
+        Self::FutexTime64,
+        Self::SchedRrGetIntervalTime64,
+        Self::PidfdSendSignal,
+        Self::IoUringSetup,
+        Self::IoUringEnter,
+        Self::IoUringRegister,
+        Self::OpenTree,
+        Self::MoveMount,
+        Self::Fsopen,
+        Self::Fsconfig,
+        Self::Fsmount,
+        Self::Fspick,
+        Self::PidfdOpen,
+        Self::Clone3,
+        Self::CloseRange,
+        Self::Openat2,
+        Self::PidfdGetfd,
+        Self::Faccessat2,
+        Self::ProcessMadvise,
+        Self::EpollPwait2,
+        Self::MountSetattr,
+        Self::QuotactlFd,
+        Self::LandlockCreateRuleset,
+        Self::LandlockAddRule,
+        Self::LandlockRestrictSelf,
+        Self::MemfdSecret,
+        Self::ProcessMrelease,
+        Self::FutexWaitv,
+        Self::SetMempolicyHomeNode,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            RESTART_SYSCALL => Some(Self::RestartSyscall),
+            EXIT => Some(Self::Exit),
+            FORK => Some(Self::Fork),
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            OPEN => Some(Self::Open),
+            CLOSE => Some(Self::Close),
+            WAITPID => Some(Self::Waitpid),
+            CREAT => Some(Self::Creat),
+            LINK => Some(Self::Link),
+            UNLINK => Some(Self::Unlink),
+            EXECVE => Some(Self::Execve),
+            CHDIR => Some(Self::Chdir),
+            TIME => Some(Self::Time),
+            MKNOD => Some(Self::Mknod),
+            CHMOD => Some(Self::Chmod),
+            LCHOWN => Some(Self::Lchown),
+            BREAK => Some(Self::Break),
+            OLDSTAT => Some(Self::Oldstat),
+            LSEEK => Some(Self::Lseek),
+            GETPID => Some(Self::Getpid),
+            MOUNT => Some(Self::Mount),
+            UMOUNT => Some(Self::Umount),
+            SETUID => Some(Self::Setuid),
+            GETUID => Some(Self::Getuid),
+            STIME => Some(Self::Stime),
+            PTRACE => Some(Self::Ptrace),
+            ALARM => Some(Self::Alarm),
+            OLDFSTAT => Some(Self::Oldfstat),
+            PAUSE => Some(Self::Pause),
+            UTIME => Some(Self::Utime),
+            STTY => Some(Self::Stty),
+            GTTY => Some(Self::Gtty),
+            ACCESS => Some(Self::Access),
+            NICE => Some(Self::Nice),
+            FTIME => Some(Self::Ftime),
+            SYNC => Some(Self::Sync),
+            KILL => Some(Self::Kill),
+            RENAME => Some(Self::Rename),
+            MKDIR => Some(Self::Mkdir),
+            RMDIR => Some(Self::Rmdir),
+            DUP => Some(Self::Dup),
+            PIPE => Some(Self::Pipe),
+            TIMES => Some(Self::Times),
+            PROF => Some(Self::Prof),
+            BRK => Some(Self::Brk),
+            SETGID => Some(Self::Setgid),
+            GETGID => Some(Self::Getgid),
+            SIGNAL => Some(Self::Signal),
+            GETEUID => Some(Self::Geteuid),
+            GETEGID => Some(Self::Getegid),
+            ACCT => Some(Self::Acct),
+            UMOUNT2 => Some(Self::Umount2),
+            LOCK => Some(Self::Lock),
+            IOCTL => Some(Self::Ioctl),
+            FCNTL => Some(Self::Fcntl),
+            MPX => Some(Self::Mpx),
+            SETPGID => Some(Self::Setpgid),
+            ULIMIT => Some(Self::Ulimit),
+            OLDOLDUNAME => Some(Self::Oldolduname),
+            UMASK => Some(Self::Umask),
+            CHROOT => Some(Self::Chroot),
+            USTAT => Some(Self::Ustat),
+            DUP2 => Some(Self::Dup2),
+            GETPPID => Some(Self::Getppid),
+            GETPGRP => Some(Self::Getpgrp),
+            SETSID => Some(Self::Setsid),
+            SIGACTION => Some(Self::Sigaction),
+            SGETMASK => Some(Self::Sgetmask),
+            SSETMASK => Some(Self::Ssetmask),
+            SETREUID => Some(Self::Setreuid),
+            SETREGID => Some(Self::Setregid),
+            SIGSUSPEND => Some(Self::Sigsuspend),
+            SIGPENDING => Some(Self::Sigpending),
+            SETHOSTNAME => Some(Self::Sethostname),
+            SETRLIMIT => Some(Self::Setrlimit),
+            GETRLIMIT => Some(Self::Getrlimit),
+            GETRUSAGE => Some(Self::Getrusage),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            SELECT => Some(Self::Select),
+            SYMLINK => Some(Self::Symlink),
+            OLDLSTAT => Some(Self::Oldlstat),
+            READLINK => Some(Self::Readlink),
+            USELIB => Some(Self::Uselib),
+            SWAPON => Some(Self::Swapon),
+            REBOOT => Some(Self::Reboot),
+            READDIR => Some(Self::Readdir),
+            MMAP => Some(Self::Mmap),
+            MUNMAP => Some(Self::Munmap),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            FCHMOD => Some(Self::Fchmod),
+            FCHOWN => Some(Self::Fchown),
+            GETPRIORITY => Some(Self::Getpriority),
+            SETPRIORITY => Some(Self::Setpriority),
+            PROFIL => Some(Self::Profil),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            IOPERM => Some(Self::Ioperm),
+            SOCKETCALL => Some(Self::Socketcall),
+            SYSLOG => Some(Self::Syslog),
+            SETITIMER => Some(Self::Setitimer),
+            GETITIMER => Some(Self::Getitimer),
+            STAT => Some(Self::Stat),
+            LSTAT => Some(Self::Lstat),
+            FSTAT => Some(Self::Fstat),
+            OLDUNAME => Some(Self::Olduname),
+            IOPL => Some(Self::Iopl),
+            VHANGUP => Some(Self::Vhangup),
+            IDLE => Some(Self::Idle),
+            VM86OLD => Some(Self::Vm86old),
+            WAIT4 => Some(Self::Wait4),
+            SWAPOFF => Some(Self::Swapoff),
+            SYSINFO => Some(Self::Sysinfo),
+            IPC => Some(Self::Ipc),
+            FSYNC => Some(Self::Fsync),
+            SIGRETURN => Some(Self::Sigreturn),
+            CLONE => Some(Self::Clone),
+            SETDOMAINNAME => Some(Self::Setdomainname),
+            UNAME => Some(Self::Uname),
+            MODIFY_LDT => Some(Self::ModifyLdt),
+            ADJTIMEX => Some(Self::Adjtimex),
+            MPROTECT => Some(Self::Mprotect),
+            SIGPROCMASK => Some(Self::Sigprocmask),
+            CREATE_MODULE => Some(Self::CreateModule),
+            INIT_MODULE => Some(Self::InitModule),
+            DELETE_MODULE => Some(Self::DeleteModule),
+            GET_KERNEL_SYMS => Some(Self::GetKernelSyms),
+            QUOTACTL => Some(Self::Quotactl),
+            GETPGID => Some(Self::Getpgid),
+            FCHDIR => Some(Self::Fchdir),
+            BDFLUSH => Some(Self::Bdflush),
+            SYSFS => Some(Self::Sysfs),
+            PERSONALITY => Some(Self::Personality),
+            AFS_SYSCALL => Some(Self::AfsSyscall),
+            SETFSUID => Some(Self::Setfsuid),
+            SETFSGID => Some(Self::Setfsgid),
+            _LLSEEK => Some(Self::Llseek),
+            GETDENTS => Some(Self::Getdents),
+            _NEWSELECT => Some(Self::Newselect),
+            FLOCK => Some(Self::Flock),
+            MSYNC => Some(Self::Msync),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            GETSID => Some(Self::Getsid),
+            FDATASYNC => Some(Self::Fdatasync),
+            _SYSCTL => Some(Self::Sysctl),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_YIELD => Some(Self::SchedYield),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            NANOSLEEP => Some(Self::Nanosleep),
+            MREMAP => Some(Self::Mremap),
+            SETRESUID => Some(Self::Setresuid),
+            GETRESUID => Some(Self::Getresuid),
+            VM86 => Some(Self::Vm86),
+            QUERY_MODULE => Some(Self::QueryModule),
+            POLL => Some(Self::Poll),
+            NFSSERVCTL => Some(Self::Nfsservctl),
+            SETRESGID => Some(Self::Setresgid),
+            GETRESGID => Some(Self::Getresgid),
+            PRCTL => Some(Self::Prctl),
+            RT_SIGRETURN => Some(Self::RtSigreturn),
+            RT_SIGACTION => Some(Self::RtSigaction),
+            RT_SIGPROCMASK => Some(Self::RtSigprocmask),
+            RT_SIGPENDING => Some(Self::RtSigpending),
+            RT_SIGTIMEDWAIT => Some(Self::RtSigtimedwait),
+            RT_SIGQUEUEINFO => Some(Self::RtSigqueueinfo),
+            RT_SIGSUSPEND => Some(Self::RtSigsuspend),
+            PREAD64 => Some(Self::Pread64),
+            PWRITE64 => Some(Self::Pwrite64),
+            CHOWN => Some(Self::Chown),
+            GETCWD => Some(Self::Getcwd),
+            CAPGET => Some(Self::Capget),
+            CAPSET => Some(Self::Capset),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            SENDFILE => Some(Self::Sendfile),
+            GETPMSG => Some(Self::Getpmsg),
+            PUTPMSG => Some(Self::Putpmsg),
+            VFORK => Some(Self::Vfork),
+            UGETRLIMIT => Some(Self::Ugetrlimit),
+            MMAP2 => Some(Self::Mmap2),
+            TRUNCATE64 => Some(Self::Truncate64),
+            FTRUNCATE64 => Some(Self::Ftruncate64),
+            STAT64 => Some(Self::Stat64),
+            LSTAT64 => Some(Self::Lstat64),
+            FSTAT64 => Some(Self::Fstat64),
+            LCHOWN32 => Some(Self::Lchown32),
+            GETUID32 => Some(Self::Getuid32),
+            GETGID32 => Some(Self::Getgid32),
+            GETEUID32 => Some(Self::Geteuid32),
+            GETEGID32 => Some(Self::Getegid32),
+            SETREUID32 => Some(Self::Setreuid32),
+            SETREGID32 => Some(Self::Setregid32),
+            GETGROUPS32 => Some(Self::Getgroups32),
+            SETGROUPS32 => Some(Self::Setgroups32),
+            FCHOWN32 => Some(Self::Fchown32),
+            SETRESUID32 => Some(Self::Setresuid32),
+            GETRESUID32 => Some(Self::Getresuid32),
+            SETRESGID32 => Some(Self::Setresgid32),
+            GETRESGID32 => Some(Self::Getresgid32),
+            CHOWN32 => Some(Self::Chown32),
+            SETUID32 => Some(Self::Setuid32),
+            SETGID32 => Some(Self::Setgid32),
+            SETFSUID32 => Some(Self::Setfsuid32),
+            SETFSGID32 => Some(Self::Setfsgid32),
+            PIVOT_ROOT => Some(Self::PivotRoot),
+            MINCORE => Some(Self::Mincore),
+            MADVISE => Some(Self::Madvise),
+            GETDENTS64 => Some(Self::Getdents64),
+            FCNTL64 => Some(Self::Fcntl64),
+            GETTID => Some(Self::Gettid),
+            READAHEAD => Some(Self::Readahead),
+            SETXATTR => Some(Self::Setxattr),
+            LSETXATTR => Some(Self::Lsetxattr),
+            FSETXATTR => Some(Self::Fsetxattr),
+            GETXATTR => Some(Self::Getxattr),
+            LGETXATTR => Some(Self::Lgetxattr),
+            FGETXATTR => Some(Self::Fgetxattr),
+            LISTXATTR => Some(Self::Listxattr),
+            LLISTXATTR => Some(Self::Llistxattr),
+            FLISTXATTR => Some(Self::Flistxattr),
+            REMOVEXATTR => Some(Self::Removexattr),
+            LREMOVEXATTR => Some(Self::Lremovexattr),
+            FREMOVEXATTR => Some(Self::Fremovexattr),
+            TKILL => Some(Self::Tkill),
+            SENDFILE64 => Some(Self::Sendfile64),
+            FUTEX => Some(Self::Futex),
+            SCHED_SETAFFINITY => Some(Self::SchedSetaffinity),
+            SCHED_GETAFFINITY => Some(Self::SchedGetaffinity),
+            SET_THREAD_AREA => Some(Self::SetThreadArea),
+            GET_THREAD_AREA => Some(Self::GetThreadArea),
+            IO_SETUP => Some(Self::IoSetup),
+            IO_DESTROY => Some(Self::IoDestroy),
+            IO_GETEVENTS => Some(Self::IoGetevents),
+            IO_SUBMIT => Some(Self::IoSubmit),
+            IO_CANCEL => Some(Self::IoCancel),
+            FADVISE64 => Some(Self::Fadvise64),
+            EXIT_GROUP => Some(Self::ExitGroup),
+            LOOKUP_DCOOKIE => Some(Self::LookupDcookie),
+            EPOLL_CREATE => Some(Self::EpollCreate),
+            EPOLL_CTL => Some(Self::EpollCtl),
+            EPOLL_WAIT => Some(Self::EpollWait),
+            REMAP_FILE_PAGES => Some(Self::RemapFilePages),
+            SET_TID_ADDRESS => Some(Self::SetTidAddress),
+            TIMER_CREATE => Some(Self::TimerCreate),
+            TIMER_SETTIME => Some(Self::TimerSettime),
+            TIMER_GETTIME => Some(Self::TimerGettime),
+            TIMER_GETOVERRUN => Some(Self::TimerGetoverrun),
+            TIMER_DELETE => Some(Self::TimerDelete),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            STATFS64 => Some(Self::Statfs64),
+            FSTATFS64 => Some(Self::Fstatfs64),
+            TGKILL => Some(Self::Tgkill),
+            UTIMES => Some(Self::Utimes),
+            FADVISE64_64 => Some(Self::Fadvise6464),
+            VSERVER => Some(Self::Vserver),
+            MBIND => Some(Self::Mbind),
+            GET_MEMPOLICY => Some(Self::GetMempolicy),
+            SET_MEMPOLICY => Some(Self::SetMempolicy),
+            MQ_OPEN => Some(Self::MqOpen),
+            MQ_UNLINK => Some(Self::MqUnlink),
+            MQ_TIMEDSEND => Some(Self::MqTimedsend),
+            MQ_TIMEDRECEIVE => Some(Self::MqTimedreceive),
+            MQ_NOTIFY => Some(Self::MqNotify),
+            MQ_GETSETATTR => Some(Self::MqGetsetattr),
+            KEXEC_LOAD => Some(Self::KexecLoad),
+            WAITID => Some(Self::Waitid),
+            ADD_KEY => Some(Self::AddKey),
+            REQUEST_KEY => Some(Self::RequestKey),
+            KEYCTL => Some(Self::Keyctl),
+            IOPRIO_SET => Some(Self::IoprioSet),
+            IOPRIO_GET => Some(Self::IoprioGet),
+            INOTIFY_INIT => Some(Self::InotifyInit),
+            INOTIFY_ADD_WATCH => Some(Self::InotifyAddWatch),
+            INOTIFY_RM_WATCH => Some(Self::InotifyRmWatch),
+            MIGRATE_PAGES => Some(Self::MigratePages),
+            OPENAT => Some(Self::Openat),
+            MKDIRAT => Some(Self::Mkdirat),
+            MKNODAT => Some(Self::Mknodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FUTIMESAT => Some(Self::Futimesat),
+            FSTATAT64 => Some(Self::Fstatat64),
+            UNLINKAT => Some(Self::Unlinkat),
+            RENAMEAT => Some(Self::Renameat),
+            LINKAT => Some(Self::Linkat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            READLINKAT => Some(Self::Readlinkat),
+            FCHMODAT => Some(Self::Fchmodat),
+            FACCESSAT => Some(Self::Faccessat),
+            PSELECT6 => Some(Self::Pselect6),
+            PPOLL => Some(Self::Ppoll),
+            UNSHARE => Some(Self::Unshare),
+            SET_ROBUST_LIST => Some(Self::SetRobustList),
+            GET_ROBUST_LIST => Some(Self::GetRobustList),
+            SPLICE => Some(Self::Splice),
+            SYNC_FILE_RANGE => Some(Self::SyncFileRange),
+            TEE => Some(Self::Tee),
+            VMSPLICE => Some(Self::Vmsplice),
+            MOVE_PAGES => Some(Self::MovePages),
+            GETCPU => Some(Self::Getcpu),
+            EPOLL_PWAIT => Some(Self::EpollPwait),
+            UTIMENSAT => Some(Self::Utimensat),
+            SIGNALFD => Some(Self::Signalfd),
+            TIMERFD_CREATE => Some(Self::TimerfdCreate),
+            EVENTFD => Some(Self::Eventfd),
+            FALLOCATE => Some(Self::Fallocate),
+            TIMERFD_SETTIME => Some(Self::TimerfdSettime),
+            TIMERFD_GETTIME => Some(Self::TimerfdGettime),
+            SIGNALFD4 => Some(Self::Signalfd4),
+            EVENTFD2 => Some(Self::Eventfd2),
+            EPOLL_CREATE1 => Some(Self::EpollCreate1),
+            DUP3 => Some(Self::Dup3),
+            PIPE2 => Some(Self::Pipe2),
+            INOTIFY_INIT1 => Some(Self::InotifyInit1),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            RT_TGSIGQUEUEINFO => Some(Self::RtTgsigqueueinfo),
+            PERF_EVENT_OPEN => Some(Self::PerfEventOpen),
+            RECVMMSG => Some(Self::Recvmmsg),
+            FANOTIFY_INIT => Some(Self::FanotifyInit),
+            FANOTIFY_MARK => Some(Self::FanotifyMark),
+            PRLIMIT64 => Some(Self::Prlimit64),
+            NAME_TO_HANDLE_AT => Some(Self::NameToHandleAt),
+            OPEN_BY_HANDLE_AT => Some(Self::OpenByHandleAt),
+            CLOCK_ADJTIME => Some(Self::ClockAdjtime),
+            SYNCFS => Some(Self::Syncfs),
+            SENDMMSG => Some(Self::Sendmmsg),
+            SETNS => Some(Self::Setns),
+            PROCESS_VM_READV => Some(Self::ProcessVmReadv),
+            PROCESS_VM_WRITEV => Some(Self::ProcessVmWritev),
+            KCMP => Some(Self::Kcmp),
+            FINIT_MODULE => Some(Self::FinitModule),
+            SCHED_SETATTR => Some(Self::SchedSetattr),
+            SCHED_GETATTR => Some(Self::SchedGetattr),
+            RENAMEAT2 => Some(Self::Renameat2),
+            SECCOMP => Some(Self::Seccomp),
+            GETRANDOM => Some(Self::Getrandom),
+            MEMFD_CREATE => Some(Self::MemfdCreate),
+            BPF => Some(Self::Bpf),
+            EXECVEAT => Some(Self::Execveat),
+            SOCKET => Some(Self::Socket),
+            SOCKETPAIR => Some(Self::Socketpair),
+            BIND => Some(Self::Bind),
+            CONNECT => Some(Self::Connect),
+            LISTEN => Some(Self::Listen),
+            ACCEPT4 => Some(Self::Accept4),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            GETSOCKNAME => Some(Self::Getsockname),
+            GETPEERNAME => Some(Self::Getpeername),
+            SENDTO => Some(Self::Sendto),
+            SENDMSG => Some(Self::Sendmsg),
+            RECVFROM => Some(Self::Recvfrom),
+            RECVMSG => Some(Self::Recvmsg),
+            SHUTDOWN => Some(Self::Shutdown),
+            USERFAULTFD => Some(Self::Userfaultfd),
+            MEMBARRIER => Some(Self::Membarrier),
+            MLOCK2 => Some(Self::Mlock2),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            PREADV2 => Some(Self::Preadv2),
+            PWRITEV2 => Some(Self::Pwritev2),
+            PKEY_MPROTECT => Some(Self::PkeyMprotect),
+            PKEY_ALLOC => Some(Self::PkeyAlloc),
+            PKEY_FREE => Some(Self::PkeyFree),
+            STATX => Some(Self::Statx),
+            ARCH_PRCTL => Some(Self::ArchPrctl),
+            IO_PGETEVENTS => Some(Self::IoPgetevents),
+            RSEQ => Some(Self::Rseq),
+            SEMGET => Some(Self::Semget),
+            SEMCTL => Some(Self::Semctl),
+            SHMGET => Some(Self::Shmget),
+            SHMCTL => Some(Self::Shmctl),
+            SHMAT => Some(Self::Shmat),
+            SHMDT => Some(Self::Shmdt),
+            MSGGET => Some(Self::Msgget),
+            MSGSND => Some(Self::Msgsnd),
+            MSGRCV => Some(Self::Msgrcv),
+            MSGCTL => Some(Self::Msgctl),
+            CLOCK_GETTIME64 => Some(Self::ClockGettime64),
+            CLOCK_SETTIME64 => Some(Self::ClockSettime64),
+            CLOCK_ADJTIME64 => Some(Self::ClockAdjtime64),
+            CLOCK_GETRES_TIME64 => Some(Self::ClockGetresTime64),
+            CLOCK_NANOSLEEP_TIME64 => Some(Self::ClockNanosleepTime64),
+            TIMER_GETTIME64 => Some(Self::TimerGettime64),
+            TIMER_SETTIME64 => Some(Self::TimerSettime64),
+            TIMERFD_GETTIME64 => Some(Self::TimerfdGettime64),
+            TIMERFD_SETTIME64 => Some(Self::TimerfdSettime64),
+            UTIMENSAT_TIME64 => Some(Self::UtimensatTime64),
+            PSELECT6_TIME64 => Some(Self::Pselect6Time64),
+            PPOLL_TIME64 => Some(Self::PpollTime64),
+            IO_PGETEVENTS_TIME64 => Some(Self::IoPgeteventsTime64),
+            RECVMMSG_TIME64 => Some(Self::RecvmmsgTime64),
+            MQ_TIMEDSEND_TIME64 => Some(Self::MqTimedsendTime64),
+            MQ_TIMEDRECEIVE_TIME64 => Some(Self::MqTimedreceiveTime64),
+            SEMTIMEDOP_TIME64 => Some(Self::SemtimedopTime64),
+            RT_SIGTIMEDWAIT_TIME64 => Some(Self::RtSigtimedwaitTime64),
+            FUTEX_TIME64 => Some(Self::FutexTime64),
+            SCHED_RR_GET_INTERVAL_TIME64 => Some(Self::SchedRrGetIntervalTime64),
+            PIDFD_SEND_SIGNAL => Some(Self::PidfdSendSignal),
+            IO_URING_SETUP => Some(Self::IoUringSetup),
+            IO_URING_ENTER => Some(Self::IoUringEnter),
+            IO_URING_REGISTER => Some(Self::IoUringRegister),
+            OPEN_TREE => Some(Self::OpenTree),
+            MOVE_MOUNT => Some(Self::MoveMount),
+            FSOPEN => Some(Self::Fsopen),
+            FSCONFIG => Some(Self::Fsconfig),
+            FSMOUNT => Some(Self::Fsmount),
+            FSPICK => Some(Self::Fspick),
+            PIDFD_OPEN => Some(Self::PidfdOpen),
+            CLONE3 => Some(Self::Clone3),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            OPENAT2 => Some(Self::Openat2),
+            PIDFD_GETFD => Some(Self::PidfdGetfd),
+            FACCESSAT2 => Some(Self::Faccessat2),
+            PROCESS_MADVISE => Some(Self::ProcessMadvise),
+            EPOLL_PWAIT2 => Some(Self::EpollPwait2),
+            MOUNT_SETATTR => Some(Self::MountSetattr),
+            QUOTACTL_FD => Some(Self::QuotactlFd),
+            LANDLOCK_CREATE_RULESET => Some(Self::LandlockCreateRuleset),
+            LANDLOCK_ADD_RULE => Some(Self::LandlockAddRule),
+            LANDLOCK_RESTRICT_SELF => Some(Self::LandlockRestrictSelf),
+            MEMFD_SECRET => Some(Self::MemfdSecret),
+            PROCESS_MRELEASE => Some(Self::ProcessMrelease),
+            FUTEX_WAITV => Some(Self::FutexWaitv),
+            SET_MEMPOLICY_HOME_NODE => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "restart_syscall" => Some(Self::RestartSyscall),
+            "exit" => Some(Self::Exit),
+            "fork" => Some(Self::Fork),
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "waitpid" => Some(Self::Waitpid),
+            "creat" => Some(Self::Creat),
+            "link" => Some(Self::Link),
+            "unlink" => Some(Self::Unlink),
+            "execve" => Some(Self::Execve),
+            "chdir" => Some(Self::Chdir),
+            "time" => Some(Self::Time),
+            "mknod" => Some(Self::Mknod),
+            "chmod" => Some(Self::Chmod),
+            "lchown" => Some(Self::Lchown),
+            "break" => Some(Self::Break),
+            "oldstat" => Some(Self::Oldstat),
+            "lseek" => Some(Self::Lseek),
+            "getpid" => Some(Self::Getpid),
+            "mount" => Some(Self::Mount),
+            "umount" => Some(Self::Umount),
+            "setuid" => Some(Self::Setuid),
+            "getuid" => Some(Self::Getuid),
+            "stime" => Some(Self::Stime),
+            "ptrace" => Some(Self::Ptrace),
+            "alarm" => Some(Self::Alarm),
+            "oldfstat" => Some(Self::Oldfstat),
+            "pause" => Some(Self::Pause),
+            "utime" => Some(Self::Utime),
+            "stty" => Some(Self::Stty),
+            "gtty" => Some(Self::Gtty),
+            "access" => Some(Self::Access),
+            "nice" => Some(Self::Nice),
+            "ftime" => Some(Self::Ftime),
+            "sync" => Some(Self::Sync),
+            "kill" => Some(Self::Kill),
+            "rename" => Some(Self::Rename),
+            "mkdir" => Some(Self::Mkdir),
+            "rmdir" => Some(Self::Rmdir),
+            "dup" => Some(Self::Dup),
+            "pipe" => Some(Self::Pipe),
+            "times" => Some(Self::Times),
+            "prof" => Some(Self::Prof),
+            "brk" => Some(Self::Brk),
+            "setgid" => Some(Self::Setgid),
+            "getgid" => Some(Self::Getgid),
+            "signal" => Some(Self::Signal),
+            "geteuid" => Some(Self::Geteuid),
+            "getegid" => Some(Self::Getegid),
+            "acct" => Some(Self::Acct),
+            "umount2" => Some(Self::Umount2),
+            "lock" => Some(Self::Lock),
+            "ioctl" => Some(Self::Ioctl),
+            "fcntl" => Some(Self::Fcntl),
+            "mpx" => Some(Self::Mpx),
+            "setpgid" => Some(Self::Setpgid),
+            "ulimit" => Some(Self::Ulimit),
+            "oldolduname" => Some(Self::Oldolduname),
+            "umask" => Some(Self::Umask),
+            "chroot" => Some(Self::Chroot),
+            "ustat" => Some(Self::Ustat),
+            "dup2" => Some(Self::Dup2),
+            "getppid" => Some(Self::Getppid),
+            "getpgrp" => Some(Self::Getpgrp),
+            "setsid" => Some(Self::Setsid),
+            "sigaction" => Some(Self::Sigaction),
+            "sgetmask" => Some(Self::Sgetmask),
+            "ssetmask" => Some(Self::Ssetmask),
+            "setreuid" => Some(Self::Setreuid),
+            "setregid" => Some(Self::Setregid),
+            "sigsuspend" => Some(Self::Sigsuspend),
+            "sigpending" => Some(Self::Sigpending),
+            "sethostname" => Some(Self::Sethostname),
+            "setrlimit" => Some(Self::Setrlimit),
+            "getrlimit" => Some(Self::Getrlimit),
+            "getrusage" => Some(Self::Getrusage),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "settimeofday" => Some(Self::Settimeofday),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "select" => Some(Self::Select),
+            "symlink" => Some(Self::Symlink),
+            "oldlstat" => Some(Self::Oldlstat),
+            "readlink" => Some(Self::Readlink),
+            "uselib" => Some(Self::Uselib),
+            "swapon" => Some(Self::Swapon),
+            "reboot" => Some(Self::Reboot),
+            "readdir" => Some(Self::Readdir),
+            "mmap" => Some(Self::Mmap),
+            "munmap" => Some(Self::Munmap),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "fchmod" => Some(Self::Fchmod),
+            "fchown" => Some(Self::Fchown),
+            "getpriority" => Some(Self::Getpriority),
+            "setpriority" => Some(Self::Setpriority),
+            "profil" => Some(Self::Profil),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "ioperm" => Some(Self::Ioperm),
+            "socketcall" => Some(Self::Socketcall),
+            "syslog" => Some(Self::Syslog),
+            "setitimer" => Some(Self::Setitimer),
+            "getitimer" => Some(Self::Getitimer),
+            "stat" => Some(Self::Stat),
+            "lstat" => Some(Self::Lstat),
+            "fstat" => Some(Self::Fstat),
+            "olduname" => Some(Self::Olduname),
+            "iopl" => Some(Self::Iopl),
+            "vhangup" => Some(Self::Vhangup),
+            "idle" => Some(Self::Idle),
+            "vm86old" => Some(Self::Vm86old),
+            "wait4" => Some(Self::Wait4),
+            "swapoff" => Some(Self::Swapoff),
+            "sysinfo" => Some(Self::Sysinfo),
+            "ipc" => Some(Self::Ipc),
+            "fsync" => Some(Self::Fsync),
+            "sigreturn" => Some(Self::Sigreturn),
+            "clone" => Some(Self::Clone),
+            "setdomainname" => Some(Self::Setdomainname),
+            "uname" => Some(Self::Uname),
+            "modify_ldt" => Some(Self::ModifyLdt),
+            "adjtimex" => Some(Self::Adjtimex),
+            "mprotect" => Some(Self::Mprotect),
+            "sigprocmask" => Some(Self::Sigprocmask),
+            "create_module" => Some(Self::CreateModule),
+            "init_module" => Some(Self::InitModule),
+            "delete_module" => Some(Self::DeleteModule),
+            "get_kernel_syms" => Some(Self::GetKernelSyms),
+            "quotactl" => Some(Self::Quotactl),
+            "getpgid" => Some(Self::Getpgid),
+            "fchdir" => Some(Self::Fchdir),
+            "bdflush" => Some(Self::Bdflush),
+            "sysfs" => Some(Self::Sysfs),
+            "personality" => Some(Self::Personality),
+            "afs_syscall" => Some(Self::AfsSyscall),
+            "setfsuid" => Some(Self::Setfsuid),
+            "setfsgid" => Some(Self::Setfsgid),
+            "_llseek" => Some(Self::Llseek),
+            "getdents" => Some(Self::Getdents),
+            "_newselect" => Some(Self::Newselect),
+            "flock" => Some(Self::Flock),
+            "msync" => Some(Self::Msync),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "getsid" => Some(Self::Getsid),
+            "fdatasync" => Some(Self::Fdatasync),
+            "_sysctl" => Some(Self::Sysctl),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_yield" => Some(Self::SchedYield),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "nanosleep" => Some(Self::Nanosleep),
+            "mremap" => Some(Self::Mremap),
+            "setresuid" => Some(Self::Setresuid),
+            "getresuid" => Some(Self::Getresuid),
+            "vm86" => Some(Self::Vm86),
+            "query_module" => Some(Self::QueryModule),
+            "poll" => Some(Self::Poll),
+            "nfsservctl" => Some(Self::Nfsservctl),
+            "setresgid" => Some(Self::Setresgid),
+            "getresgid" => Some(Self::Getresgid),
+            "prctl" => Some(Self::Prctl),
+            "rt_sigreturn" => Some(Self::RtSigreturn),
+            "rt_sigaction" => Some(Self::RtSigaction),
+            "rt_sigprocmask" => Some(Self::RtSigprocmask),
+            "rt_sigpending" => Some(Self::RtSigpending),
+            "rt_sigtimedwait" => Some(Self::RtSigtimedwait),
+            "rt_sigqueueinfo" => Some(Self::RtSigqueueinfo),
+            "rt_sigsuspend" => Some(Self::RtSigsuspend),
+            "pread64" => Some(Self::Pread64),
+            "pwrite64" => Some(Self::Pwrite64),
+            "chown" => Some(Self::Chown),
+            "getcwd" => Some(Self::Getcwd),
+            "capget" => Some(Self::Capget),
+            "capset" => Some(Self::Capset),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "sendfile" => Some(Self::Sendfile),
+            "getpmsg" => Some(Self::Getpmsg),
+            "putpmsg" => Some(Self::Putpmsg),
+            "vfork" => Some(Self::Vfork),
+            "ugetrlimit" => Some(Self::Ugetrlimit),
+            "mmap2" => Some(Self::Mmap2),
+            "truncate64" => Some(Self::Truncate64),
+            "ftruncate64" => Some(Self::Ftruncate64),
+            "stat64" => Some(Self::Stat64),
+            "lstat64" => Some(Self::Lstat64),
+            "fstat64" => Some(Self::Fstat64),
+            "lchown32" => Some(Self::Lchown32),
+            "getuid32" => Some(Self::Getuid32),
+            "getgid32" => Some(Self::Getgid32),
+            "geteuid32" => Some(Self::Geteuid32),
+            "getegid32" => Some(Self::Getegid32),
+            "setreuid32" => Some(Self::Setreuid32),
+            "setregid32" => Some(Self::Setregid32),
+            "getgroups32" => Some(Self::Getgroups32),
+            "setgroups32" => Some(Self::Setgroups32),
+            "fchown32" => Some(Self::Fchown32),
+            "setresuid32" => Some(Self::Setresuid32),
+            "getresuid32" => Some(Self::Getresuid32),
+            "setresgid32" => Some(Self::Setresgid32),
+            "getresgid32" => Some(Self::Getresgid32),
+            "chown32" => Some(Self::Chown32),
+            "setuid32" => Some(Self::Setuid32),
+            "setgid32" => Some(Self::Setgid32),
+            "setfsuid32" => Some(Self::Setfsuid32),
+            "setfsgid32" => Some(Self::Setfsgid32),
+            "pivot_root" => Some(Self::PivotRoot),
+            "mincore" => Some(Self::Mincore),
+            "madvise" => Some(Self::Madvise),
+            "getdents64" => Some(Self::Getdents64),
+            "fcntl64" => Some(Self::Fcntl64),
+            "gettid" => Some(Self::Gettid),
+            "readahead" => Some(Self::Readahead),
+            "setxattr" => Some(Self::Setxattr),
+            "lsetxattr" => Some(Self::Lsetxattr),
+            "fsetxattr" => Some(Self::Fsetxattr),
+            "getxattr" => Some(Self::Getxattr),
+            "lgetxattr" => Some(Self::Lgetxattr),
+            "fgetxattr" => Some(Self::Fgetxattr),
+            "listxattr" => Some(Self::Listxattr),
+            "llistxattr" => Some(Self::Llistxattr),
+            "flistxattr" => Some(Self::Flistxattr),
+            "removexattr" => Some(Self::Removexattr),
+            "lremovexattr" => Some(Self::Lremovexattr),
+            "fremovexattr" => Some(Self::Fremovexattr),
+            "tkill" => Some(Self::Tkill),
+            "sendfile64" => Some(Self::Sendfile64),
+            "futex" => Some(Self::Futex),
+            "sched_setaffinity" => Some(Self::SchedSetaffinity),
+            "sched_getaffinity" => Some(Self::SchedGetaffinity),
+            "set_thread_area" => Some(Self::SetThreadArea),
+            "get_thread_area" => Some(Self::GetThreadArea),
+            "io_setup" => Some(Self::IoSetup),
+            "io_destroy" => Some(Self::IoDestroy),
+            "io_getevents" => Some(Self::IoGetevents),
+            "io_submit" => Some(Self::IoSubmit),
+            "io_cancel" => Some(Self::IoCancel),
+            "fadvise64" => Some(Self::Fadvise64),
+            "exit_group" => Some(Self::ExitGroup),
+            "lookup_dcookie" => Some(Self::LookupDcookie),
+            "epoll_create" => Some(Self::EpollCreate),
+            "epoll_ctl" => Some(Self::EpollCtl),
+            "epoll_wait" => Some(Self::EpollWait),
+            "remap_file_pages" => Some(Self::RemapFilePages),
+            "set_tid_address" => Some(Self::SetTidAddress),
+            "timer_create" => Some(Self::TimerCreate),
+            "timer_settime" => Some(Self::TimerSettime),
+            "timer_gettime" => Some(Self::TimerGettime),
+            "timer_getoverrun" => Some(Self::TimerGetoverrun),
+            "timer_delete" => Some(Self::TimerDelete),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "statfs64" => Some(Self::Statfs64),
+            "fstatfs64" => Some(Self::Fstatfs64),
+            "tgkill" => Some(Self::Tgkill),
+            "utimes" => Some(Self::Utimes),
+            "fadvise64_64" => Some(Self::Fadvise6464),
+            "vserver" => Some(Self::Vserver),
+            "mbind" => Some(Self::Mbind),
+            "get_mempolicy" => Some(Self::GetMempolicy),
+            "set_mempolicy" => Some(Self::SetMempolicy),
+            "mq_open" => Some(Self::MqOpen),
+            "mq_unlink" => Some(Self::MqUnlink),
+            "mq_timedsend" => Some(Self::MqTimedsend),
+            "mq_timedreceive" => Some(Self::MqTimedreceive),
+            "mq_notify" => Some(Self::MqNotify),
+            "mq_getsetattr" => Some(Self::MqGetsetattr),
+            "kexec_load" => Some(Self::KexecLoad),
+            "waitid" => Some(Self::Waitid),
+            "add_key" => Some(Self::AddKey),
+            "request_key" => Some(Self::RequestKey),
+            "keyctl" => Some(Self::Keyctl),
+            "ioprio_set" => Some(Self::IoprioSet),
+            "ioprio_get" => Some(Self::IoprioGet),
+            "inotify_init" => Some(Self::InotifyInit),
+            "inotify_add_watch" => Some(Self::InotifyAddWatch),
+            "inotify_rm_watch" => Some(Self::InotifyRmWatch),
+            "migrate_pages" => Some(Self::MigratePages),
+            "openat" => Some(Self::Openat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "mknodat" => Some(Self::Mknodat),
+            "fchownat" => Some(Self::Fchownat),
+            "futimesat" => Some(Self::Futimesat),
+            "fstatat64" => Some(Self::Fstatat64),
+            "unlinkat" => Some(Self::Unlinkat),
+            "renameat" => Some(Self::Renameat),
+            "linkat" => Some(Self::Linkat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "readlinkat" => Some(Self::Readlinkat),
+            "fchmodat" => Some(Self::Fchmodat),
+            "faccessat" => Some(Self::Faccessat),
+            "pselect6" => Some(Self::Pselect6),
+            "ppoll" => Some(Self::Ppoll),
+            "unshare" => Some(Self::Unshare),
+            "set_robust_list" => Some(Self::SetRobustList),
+            "get_robust_list" => Some(Self::GetRobustList),
+            "splice" => Some(Self::Splice),
+            "sync_file_range" => Some(Self::SyncFileRange),
+            "tee" => Some(Self::Tee),
+            "vmsplice" => Some(Self::Vmsplice),
+            "move_pages" => Some(Self::MovePages),
+            "getcpu" => Some(Self::Getcpu),
+            "epoll_pwait" => Some(Self::EpollPwait),
+            "utimensat" => Some(Self::Utimensat),
+            "signalfd" => Some(Self::Signalfd),
+            "timerfd_create" => Some(Self::TimerfdCreate),
+            "eventfd" => Some(Self::Eventfd),
+            "fallocate" => Some(Self::Fallocate),
+            "timerfd_settime" => Some(Self::TimerfdSettime),
+            "timerfd_gettime" => Some(Self::TimerfdGettime),
+            "signalfd4" => Some(Self::Signalfd4),
+            "eventfd2" => Some(Self::Eventfd2),
+            "epoll_create1" => Some(Self::EpollCreate1),
+            "dup3" => Some(Self::Dup3),
+            "pipe2" => Some(Self::Pipe2),
+            "inotify_init1" => Some(Self::InotifyInit1),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "rt_tgsigqueueinfo" => Some(Self::RtTgsigqueueinfo),
+            "perf_event_open" => Some(Self::PerfEventOpen),
+            "recvmmsg" => Some(Self::Recvmmsg),
+            "fanotify_init" => Some(Self::FanotifyInit),
+            "fanotify_mark" => Some(Self::FanotifyMark),
+            "prlimit64" => Some(Self::Prlimit64),
+            "name_to_handle_at" => Some(Self::NameToHandleAt),
+            "open_by_handle_at" => Some(Self::OpenByHandleAt),
+            "clock_adjtime" => Some(Self::ClockAdjtime),
+            "syncfs" => Some(Self::Syncfs),
+            "sendmmsg" => Some(Self::Sendmmsg),
+            "setns" => Some(Self::Setns),
+            "process_vm_readv" => Some(Self::ProcessVmReadv),
+            "process_vm_writev" => Some(Self::ProcessVmWritev),
+            "kcmp" => Some(Self::Kcmp),
+            "finit_module" => Some(Self::FinitModule),
+            "sched_setattr" => Some(Self::SchedSetattr),
+            "sched_getattr" => Some(Self::SchedGetattr),
+            "renameat2" => Some(Self::Renameat2),
+            "seccomp" => Some(Self::Seccomp),
+            "getrandom" => Some(Self::Getrandom),
+            "memfd_create" => Some(Self::MemfdCreate),
+            "bpf" => Some(Self::Bpf),
+            "execveat" => Some(Self::Execveat),
+            "socket" => Some(Self::Socket),
+            "socketpair" => Some(Self::Socketpair),
+            "bind" => Some(Self::Bind),
+            "connect" => Some(Self::Connect),
+            "listen" => Some(Self::Listen),
+            "accept4" => Some(Self::Accept4),
+            "getsockopt" => Some(Self::Getsockopt),
+            "setsockopt" => Some(Self::Setsockopt),
+            "getsockname" => Some(Self::Getsockname),
+            "getpeername" => Some(Self::Getpeername),
+            "sendto" => Some(Self::Sendto),
+            "sendmsg" => Some(Self::Sendmsg),
+            "recvfrom" => Some(Self::Recvfrom),
+            "recvmsg" => Some(Self::Recvmsg),
+            "shutdown" => Some(Self::Shutdown),
+            "userfaultfd" => Some(Self::Userfaultfd),
+            "membarrier" => Some(Self::Membarrier),
+            "mlock2" => Some(Self::Mlock2),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "preadv2" => Some(Self::Preadv2),
+            "pwritev2" => Some(Self::Pwritev2),
+            "pkey_mprotect" => Some(Self::PkeyMprotect),
+            "pkey_alloc" => Some(Self::PkeyAlloc),
+            "pkey_free" => Some(Self::PkeyFree),
+            "statx" => Some(Self::Statx),
+            "arch_prctl" => Some(Self::ArchPrctl),
+            "io_pgetevents" => Some(Self::IoPgetevents),
+            "rseq" => Some(Self::Rseq),
+            "semget" => Some(Self::Semget),
+            "semctl" => Some(Self::Semctl),
+            "shmget" => Some(Self::Shmget),
+            "shmctl" => Some(Self::Shmctl),
+            "shmat" => Some(Self::Shmat),
+            "shmdt" => Some(Self::Shmdt),
+            "msgget" => Some(Self::Msgget),
+            "msgsnd" => Some(Self::Msgsnd),
+            "msgrcv" => Some(Self::Msgrcv),
+            "msgctl" => Some(Self::Msgctl),
+            "clock_gettime64" => Some(Self::ClockGettime64),
+            "clock_settime64" => Some(Self::ClockSettime64),
+            "clock_adjtime64" => Some(Self::ClockAdjtime64),
+            "clock_getres_time64" => Some(Self::ClockGetresTime64),
+            "clock_nanosleep_time64" => Some(Self::ClockNanosleepTime64),
+            "timer_gettime64" => Some(Self::TimerGettime64),
+            "timer_settime64" => Some(Self::TimerSettime64),
+            "timerfd_gettime64" => Some(Self::TimerfdGettime64),
+            "timerfd_settime64" => Some(Self::TimerfdSettime64),
+            "utimensat_time64" => Some(Self::UtimensatTime64),
+            "pselect6_time64" => Some(Self::Pselect6Time64),
+            "ppoll_time64" => Some(Self::PpollTime64),
+            "io_pgetevents_time64" => Some(Self::IoPgeteventsTime64),
+            "recvmmsg_time64" => Some(Self::RecvmmsgTime64),
+            "mq_timedsend_time64" => Some(Self::MqTimedsendTime64),
+            "mq_timedreceive_time64" => Some(Self::MqTimedreceiveTime64),
+            "semtimedop_time64" => Some(Self::SemtimedopTime64),
+            "rt_sigtimedwait_time64" => Some(Self::RtSigtimedwaitTime64),
+            "futex_time64" => Some(Self::FutexTime64),
+            "sched_rr_get_interval_time64" => Some(Self::SchedRrGetIntervalTime64),
+            "pidfd_send_signal" => Some(Self::PidfdSendSignal),
+            "io_uring_setup" => Some(Self::IoUringSetup),
+            "io_uring_enter" => Some(Self::IoUringEnter),
+            "io_uring_register" => Some(Self::IoUringRegister),
+            "open_tree" => Some(Self::OpenTree),
+            "move_mount" => Some(Self::MoveMount),
+            "fsopen" => Some(Self::Fsopen),
+            "fsconfig" => Some(Self::Fsconfig),
+            "fsmount" => Some(Self::Fsmount),
+            "fspick" => Some(Self::Fspick),
+            "pidfd_open" => Some(Self::PidfdOpen),
+            "clone3" => Some(Self::Clone3),
+            "close_range" => Some(Self::CloseRange),
+            "openat2" => Some(Self::Openat2),
+            "pidfd_getfd" => Some(Self::PidfdGetfd),
+            "faccessat2" => Some(Self::Faccessat2),
+            "process_madvise" => Some(Self::ProcessMadvise),
+            "epoll_pwait2" => Some(Self::EpollPwait2),
+            "mount_setattr" => Some(Self::MountSetattr),
+            "quotactl_fd" => Some(Self::QuotactlFd),
+            "landlock_create_ruleset" => Some(Self::LandlockCreateRuleset),
+            "landlock_add_rule" => Some(Self::LandlockAddRule),
+            "landlock_restrict_self" => Some(Self::LandlockRestrictSelf),
+            "memfd_secret" => Some(Self::MemfdSecret),
+            "process_mrelease" => Some(Self::ProcessMrelease),
+            "futex_waitv" => Some(Self::FutexWaitv),
+            "set_mempolicy_home_node" => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::RestartSyscall => "restart_syscall",
+            Self::Exit => "exit",
+            Self::Fork => "fork",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Waitpid => "waitpid",
+            Self::Creat => "creat",
+            Self::Link => "link",
+            Self::Unlink => "unlink",
+            Self::Execve => "execve",
+            Self::Chdir => "chdir",
+            Self::Time => "time",
+            Self::Mknod => "mknod",
+            Self::Chmod => "chmod",
+            Self::Lchown => "lchown",
+            Self::Break => "break",
+            Self::Oldstat => "oldstat",
+            Self::Lseek => "lseek",
+            Self::Getpid => "getpid",
+            Self::Mount => "mount",
+            Self::Umount => "umount",
+            Self::Setuid => "setuid",
+            Self::Getuid => "getuid",
+            Self::Stime => "stime",
+            Self::Ptrace => "ptrace",
+            Self::Alarm => "alarm",
+            Self::Oldfstat => "oldfstat",
+            Self::Pause => "pause",
+            Self::Utime => "utime",
+            Self::Stty => "stty",
+            Self::Gtty => "gtty",
+            Self::Access => "access",
+            Self::Nice => "nice",
+            Self::Ftime => "ftime",
+            Self::Sync => "sync",
+            Self::Kill => "kill",
+            Self::Rename => "rename",
+            Self::Mkdir => "mkdir",
+            Self::Rmdir => "rmdir",
+            Self::Dup => "dup",
+            Self::Pipe => "pipe",
+            Self::Times => "times",
+            Self::Prof => "prof",
+            Self::Brk => "brk",
+            Self::Setgid => "setgid",
+            Self::Getgid => "getgid",
+            Self::Signal => "signal",
+            Self::Geteuid => "geteuid",
+            Self::Getegid => "getegid",
+            Self::Acct => "acct",
+            Self::Umount2 => "umount2",
+            Self::Lock => "lock",
+            Self::Ioctl => "ioctl",
+            Self::Fcntl => "fcntl",
+            Self::Mpx => "mpx",
+            Self::Setpgid => "setpgid",
+            Self::Ulimit => "ulimit",
+            Self::Oldolduname => "oldolduname",
+            Self::Umask => "umask",
+            Self::Chroot => "chroot",
+            Self::Ustat => "ustat",
+            Self::Dup2 => "dup2",
+            Self::Getppid => "getppid",
+            Self::Getpgrp => "getpgrp",
+            Self::Setsid => "setsid",
+            Self::Sigaction => "sigaction",
+            Self::Sgetmask => "sgetmask",
+            Self::Ssetmask => "ssetmask",
+            Self::Setreuid => "setreuid",
+            Self::Setregid => "setregid",
+            Self::Sigsuspend => "sigsuspend",
+            Self::Sigpending => "sigpending",
+            Self::Sethostname => "sethostname",
+            Self::Setrlimit => "setrlimit",
+            Self::Getrlimit => "getrlimit",
+            Self::Getrusage => "getrusage",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Settimeofday => "settimeofday",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Select => "select",
+            Self::Symlink => "symlink",
+            Self::Oldlstat => "oldlstat",
+            Self::Readlink => "readlink",
+            Self::Uselib => "uselib",
+            Self::Swapon => "swapon",
+            Self::Reboot => "reboot",
+            Self::Readdir => "readdir",
+            Self::Mmap => "mmap",
+            Self::Munmap => "munmap",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::Fchmod => "fchmod",
+            Self::Fchown => "fchown",
+            Self::Getpriority => "getpriority",
+            Self::Setpriority => "setpriority",
+            Self::Profil => "profil",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Ioperm => "ioperm",
+            Self::Socketcall => "socketcall",
+            Self::Syslog => "syslog",
+            Self::Setitimer => "setitimer",
+            Self::Getitimer => "getitimer",
+            Self::Stat => "stat",
+            Self::Lstat => "lstat",
+            Self::Fstat => "fstat",
+            Self::Olduname => "olduname",
+            Self::Iopl => "iopl",
+            Self::Vhangup => "vhangup",
+            Self::Idle => "idle",
+            Self::Vm86old => "vm86old",
+            Self::Wait4 => "wait4",
+            Self::Swapoff => "swapoff",
+            Self::Sysinfo => "sysinfo",
+            Self::Ipc => "ipc",
+            Self::Fsync => "fsync",
+            Self::Sigreturn => "sigreturn",
+            Self::Clone => "clone",
+            Self::Setdomainname => "setdomainname",
+            Self::Uname => "uname",
+            Self::ModifyLdt => "modify_ldt",
+            Self::Adjtimex => "adjtimex",
+            Self::Mprotect => "mprotect",
+            Self::Sigprocmask => "sigprocmask",
+            Self::CreateModule => "create_module",
+            Self::InitModule => "init_module",
+            Self::DeleteModule => "delete_module",
+            Self::GetKernelSyms => "get_kernel_syms",
+            Self::Quotactl => "quotactl",
+            Self::Getpgid => "getpgid",
+            Self::Fchdir => "fchdir",
+            Self::Bdflush => "bdflush",
+            Self::Sysfs => "sysfs",
+            Self::Personality => "personality",
+            Self::AfsSyscall => "afs_syscall",
+            Self::Setfsuid => "setfsuid",
+            Self::Setfsgid => "setfsgid",
+            Self::Llseek => "_llseek",
+            Self::Getdents => "getdents",
+            Self::Newselect => "_newselect",
+            Self::Flock => "flock",
+            Self::Msync => "msync",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Getsid => "getsid",
+            Self::Fdatasync => "fdatasync",
+            Self::Sysctl => "_sysctl",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedYield => "sched_yield",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::Nanosleep => "nanosleep",
+            Self::Mremap => "mremap",
+            Self::Setresuid => "setresuid",
+            Self::Getresuid => "getresuid",
+            Self::Vm86 => "vm86",
+            Self::QueryModule => "query_module",
+            Self::Poll => "poll",
+            Self::Nfsservctl => "nfsservctl",
+            Self::Setresgid => "setresgid",
+            Self::Getresgid => "getresgid",
+            Self::Prctl => "prctl",
+            Self::RtSigreturn => "rt_sigreturn",
+            Self::RtSigaction => "rt_sigaction",
+            Self::RtSigprocmask => "rt_sigprocmask",
+            Self::RtSigpending => "rt_sigpending",
+            Self::RtSigtimedwait => "rt_sigtimedwait",
+            Self::RtSigqueueinfo => "rt_sigqueueinfo",
+            Self::RtSigsuspend => "rt_sigsuspend",
+            Self::Pread64 => "pread64",
+            Self::Pwrite64 => "pwrite64",
+            Self::Chown => "chown",
+            Self::Getcwd => "getcwd",
+            Self::Capget => "capget",
+            Self::Capset => "capset",
+            Self::Sigaltstack => "sigaltstack",
+            Self::Sendfile => "sendfile",
+            Self::Getpmsg => "getpmsg",
+            Self::Putpmsg => "putpmsg",
+            Self::Vfork => "vfork",
+            Self::Ugetrlimit => "ugetrlimit",
+            Self::Mmap2 => "mmap2",
+            Self::Truncate64 => "truncate64",
+            Self::Ftruncate64 => "ftruncate64",
+            Self::Stat64 => "stat64",
+            Self::Lstat64 => "lstat64",
+            Self::Fstat64 => "fstat64",
+            Self::Lchown32 => "lchown32",
+            Self::Getuid32 => "getuid32",
+            Self::Getgid32 => "getgid32",
+            Self::Geteuid32 => "geteuid32",
+            Self::Getegid32 => "getegid32",
+            Self::Setreuid32 => "setreuid32",
+            Self::Setregid32 => "setregid32",
+            Self::Getgroups32 => "getgroups32",
+            Self::Setgroups32 => "setgroups32",
+            Self::Fchown32 => "fchown32",
+            Self::Setresuid32 => "setresuid32",
+            Self::Getresuid32 => "getresuid32",
+            Self::Setresgid32 => "setresgid32",
+            Self::Getresgid32 => "getresgid32",
+            Self::Chown32 => "chown32",
+            Self::Setuid32 => "setuid32",
+            Self::Setgid32 => "setgid32",
+            Self::Setfsuid32 => "setfsuid32",
+            Self::Setfsgid32 => "setfsgid32",
+            Self::PivotRoot => "pivot_root",
+            Self::Mincore => "mincore",
+            Self::Madvise => "madvise",
+            Self::Getdents64 => "getdents64",
+            Self::Fcntl64 => "fcntl64",
+            Self::Gettid => "gettid",
+            Self::Readahead => "readahead",
+            Self::Setxattr => "setxattr",
+            Self::Lsetxattr => "lsetxattr",
+            Self::Fsetxattr => "fsetxattr",
+            Self::Getxattr => "getxattr",
+            Self::Lgetxattr => "lgetxattr",
+            Self::Fgetxattr => "fgetxattr",
+            Self::Listxattr => "listxattr",
+            Self::Llistxattr => "llistxattr",
+            Self::Flistxattr => "flistxattr",
+            Self::Removexattr => "removexattr",
+            Self::Lremovexattr => "lremovexattr",
+            Self::Fremovexattr => "fremovexattr",
+            Self::Tkill => "tkill",
+            Self::Sendfile64 => "sendfile64",
+            Self::Futex => "futex",
+            Self::SchedSetaffinity => "sched_setaffinity",
+            Self::SchedGetaffinity => "sched_getaffinity",
+            Self::SetThreadArea => "set_thread_area",
+            Self::GetThreadArea => "get_thread_area",
+            Self::IoSetup => "io_setup",
+            Self::IoDestroy => "io_destroy",
+            Self::IoGetevents => "io_getevents",
+            Self::IoSubmit => "io_submit",
+            Self::IoCancel => "io_cancel",
+            Self::Fadvise64 => "fadvise64",
+            Self::ExitGroup => "exit_group",
+            Self::LookupDcookie => "lookup_dcookie",
+            Self::EpollCreate => "epoll_create",
+            Self::EpollCtl => "epoll_ctl",
+            Self::EpollWait => "epoll_wait",
+            Self::RemapFilePages => "remap_file_pages",
+            Self::SetTidAddress => "set_tid_address",
+            Self::TimerCreate => "timer_create",
+            Self::TimerSettime => "timer_settime",
+            Self::TimerGettime => "timer_gettime",
+            Self::TimerGetoverrun => "timer_getoverrun",
+            Self::TimerDelete => "timer_delete",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockGetres => "clock_getres",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::Statfs64 => "statfs64",
+            Self::Fstatfs64 => "fstatfs64",
+            Self::Tgkill => "tgkill",
+            Self::Utimes => "utimes",
+            Self::Fadvise6464 => "fadvise64_64",
+            Self::Vserver => "vserver",
+            Self::Mbind => "mbind",
+            Self::GetMempolicy => "get_mempolicy",
+            Self::SetMempolicy => "set_mempolicy",
+            Self::MqOpen => "mq_open",
+            Self::MqUnlink => "mq_unlink",
+            Self::MqTimedsend => "mq_timedsend",
+            Self::MqTimedreceive => "mq_timedreceive",
+            Self::MqNotify => "mq_notify",
+            Self::MqGetsetattr => "mq_getsetattr",
+            Self::KexecLoad => "kexec_load",
+            Self::Waitid => "waitid",
+            Self::AddKey => "add_key",
+            Self::RequestKey => "request_key",
+            Self::Keyctl => "keyctl",
+            Self::IoprioSet => "ioprio_set",
+            Self::IoprioGet => "ioprio_get",
+            Self::InotifyInit => "inotify_init",
+            Self::InotifyAddWatch => "inotify_add_watch",
+            Self::InotifyRmWatch => "inotify_rm_watch",
+            Self::MigratePages => "migrate_pages",
+            Self::Openat => "openat",
+            Self::Mkdirat => "mkdirat",
+            Self::Mknodat => "mknodat",
+            Self::Fchownat => "fchownat",
+            Self::Futimesat => "futimesat",
+            Self::Fstatat64 => "fstatat64",
+            Self::Unlinkat => "unlinkat",
+            Self::Renameat => "renameat",
+            Self::Linkat => "linkat",
+            Self::Symlinkat => "symlinkat",
+            Self::Readlinkat => "readlinkat",
+            Self::Fchmodat => "fchmodat",
+            Self::Faccessat => "faccessat",
+            Self::Pselect6 => "pselect6",
+            Self::Ppoll => "ppoll",
+            Self::Unshare => "unshare",
+            Self::SetRobustList => "set_robust_list",
+            Self::GetRobustList => "get_robust_list",
+            Self::Splice => "splice",
+            Self::SyncFileRange => "sync_file_range",
+            Self::Tee => "tee",
+            Self::Vmsplice => "vmsplice",
+            Self::MovePages => "move_pages",
+            Self::Getcpu => "getcpu",
+            Self::EpollPwait => "epoll_pwait",
+            Self::Utimensat => "utimensat",
+            Self::Signalfd => "signalfd",
+            Self::TimerfdCreate => "timerfd_create",
+            Self::Eventfd => "eventfd",
+            Self::Fallocate => "fallocate",
+            Self::TimerfdSettime => "timerfd_settime",
+            Self::TimerfdGettime => "timerfd_gettime",
+            Self::Signalfd4 => "signalfd4",
+            Self::Eventfd2 => "eventfd2",
+            Self::EpollCreate1 => "epoll_create1",
+            Self::Dup3 => "dup3",
+            Self::Pipe2 => "pipe2",
+            Self::InotifyInit1 => "inotify_init1",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::RtTgsigqueueinfo => "rt_tgsigqueueinfo",
+            Self::PerfEventOpen => "perf_event_open",
+            Self::Recvmmsg => "recvmmsg",
+            Self::FanotifyInit => "fanotify_init",
+            Self::FanotifyMark => "fanotify_mark",
+            Self::Prlimit64 => "prlimit64",
+            Self::NameToHandleAt => "name_to_handle_at",
+            Self::OpenByHandleAt => "open_by_handle_at",
+            Self::ClockAdjtime => "clock_adjtime",
+            Self::Syncfs => "syncfs",
+            Self::Sendmmsg => "sendmmsg",
+            Self::Setns => "setns",
+            Self::ProcessVmReadv => "process_vm_readv",
+            Self::ProcessVmWritev => "process_vm_writev",
+            Self::Kcmp => "kcmp",
+            Self::FinitModule => "finit_module",
+            Self::SchedSetattr => "sched_setattr",
+            Self::SchedGetattr => "sched_getattr",
+            Self::Renameat2 => "renameat2",
+            Self::Seccomp => "seccomp",
+            Self::Getrandom => "getrandom",
+            Self::MemfdCreate => "memfd_create",
+            Self::Bpf => "bpf",
+            Self::Execveat => "execveat",
+            Self::Socket => "socket",
+            Self::Socketpair => "socketpair",
+            Self::Bind => "bind",
+            Self::Connect => "connect",
+            Self::Listen => "listen",
+            Self::Accept4 => "accept4",
+            Self::Getsockopt => "getsockopt",
+            Self::Setsockopt => "setsockopt",
+            Self::Getsockname => "getsockname",
+            Self::Getpeername => "getpeername",
+            Self::Sendto => "sendto",
+            Self::Sendmsg => "sendmsg",
+            Self::Recvfrom => "recvfrom",
+            Self::Recvmsg => "recvmsg",
+            Self::Shutdown => "shutdown",
+            Self::Userfaultfd => "userfaultfd",
+            Self::Membarrier => "membarrier",
+            Self::Mlock2 => "mlock2",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Preadv2 => "preadv2",
+            Self::Pwritev2 => "pwritev2",
+            Self::PkeyMprotect => "pkey_mprotect",
+            Self::PkeyAlloc => "pkey_alloc",
+            Self::PkeyFree => "pkey_free",
+            Self::Statx => "statx",
+            Self::ArchPrctl => "arch_prctl",
+            Self::IoPgetevents => "io_pgetevents",
+            Self::Rseq => "rseq",
+            Self::Semget => "semget",
+            Self::Semctl => "semctl",
+            Self::Shmget => "shmget",
+            Self::Shmctl => "shmctl",
+            Self::Shmat => "shmat",
+            Self::Shmdt => "shmdt",
+            Self::Msgget => "msgget",
+            Self::Msgsnd => "msgsnd",
+            Self::Msgrcv => "msgrcv",
+            Self::Msgctl => "msgctl",
+            Self::ClockGettime64 => "clock_gettime64",
+            Self::ClockSettime64 => "clock_settime64",
+            Self::ClockAdjtime64 => "clock_adjtime64",
+            Self::ClockGetresTime64 => "clock_getres_time64",
+            Self::ClockNanosleepTime64 => "clock_nanosleep_time64",
+            Self::TimerGettime64 => "timer_gettime64",
+            Self::TimerSettime64 => "timer_settime64",
+            Self::TimerfdGettime64 => "timerfd_gettime64",
+            Self::TimerfdSettime64 => "timerfd_settime64",
+            Self::UtimensatTime64 => "utimensat_time64",
+            Self::Pselect6Time64 => "pselect6_time64",
+            Self::PpollTime64 => "ppoll_time64",
+            Self::IoPgeteventsTime64 => "io_pgetevents_time64",
+            Self::RecvmmsgTime64 => "recvmmsg_time64",
+            Self::MqTimedsendTime64 => "mq_timedsend_time64",
+            Self::MqTimedreceiveTime64 => "mq_timedreceive_time64",
+            Self::SemtimedopTime64 => "semtimedop_time64",
+            Self::RtSigtimedwaitTime64 => "rt_sigtimedwait_time64",
+            Self::FutexTime64 => "futex_time64",
+            Self::SchedRrGetIntervalTime64 => "sched_rr_get_interval_time64",
+            Self::PidfdSendSignal => "pidfd_send_signal",
+            Self::IoUringSetup => "io_uring_setup",
+            Self::IoUringEnter => "io_uring_enter",
+            Self::IoUringRegister => "io_uring_register",
+            Self::OpenTree => "open_tree",
+            Self::MoveMount => "move_mount",
+            Self::Fsopen => "fsopen",
+            Self::Fsconfig => "fsconfig",
+            Self::Fsmount => "fsmount",
+            Self::Fspick => "fspick",
+            Self::PidfdOpen => "pidfd_open",
+            Self::Clone3 => "clone3",
+            Self::CloseRange => "close_range",
+            Self::Openat2 => "openat2",
+            Self::PidfdGetfd => "pidfd_getfd",
+            Self::Faccessat2 => "faccessat2",
+            Self::ProcessMadvise => "process_madvise",
+            Self::EpollPwait2 => "epoll_pwait2",
+            Self::MountSetattr => "mount_setattr",
+            Self::QuotactlFd => "quotactl_fd",
+            Self::LandlockCreateRuleset => "landlock_create_ruleset",
+            Self::LandlockAddRule => "landlock_add_rule",
+            Self::LandlockRestrictSelf => "landlock_restrict_self",
+            Self::MemfdSecret => "memfd_secret",
+            Self::ProcessMrelease => "process_mrelease",
+            Self::FutexWaitv => "futex_waitv",
+            Self::SetMempolicyHomeNode => "set_mempolicy_home_node",
+        }
+    }
+}
@@ -0,0 +1,1865 @@
+//! System call numbers for `loongarch64-linux`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/asm-generic/unistd.h`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const IO_SETUP: usize = 0;
+pub const IO_DESTROY: usize = 1;
+pub const IO_SUBMIT: usize = 2;
+pub const IO_CANCEL: usize = 3;
+pub const IO_GETEVENTS: usize = 4;
+pub const SETXATTR: usize = 5;
+pub const LSETXATTR: usize = 6;
+pub const FSETXATTR: usize = 7;
+pub const GETXATTR: usize = 8;
+pub const LGETXATTR: usize = 9;
+pub const FGETXATTR: usize = 10;
+pub const LISTXATTR: usize = 11;
+pub const LLISTXATTR: usize = 12;
+pub const FLISTXATTR: usize = 13;
+pub const REMOVEXATTR: usize = 14;
+pub const LREMOVEXATTR: usize = 15;
+pub const FREMOVEXATTR: usize = 16;
+pub const GETCWD: usize = 17;
+pub const LOOKUP_DCOOKIE: usize = 18;
+pub const EVENTFD2: usize = 19;
+pub const EPOLL_CREATE1: usize = 20;
+pub const EPOLL_CTL: usize = 21;
+pub const EPOLL_PWAIT: usize = 22;
+pub const DUP: usize = 23;
+pub const DUP3: usize = 24;
+pub const FCNTL: usize = 25;
+pub const INOTIFY_INIT1: usize = 26;
+pub const INOTIFY_ADD_WATCH: usize = 27;
+pub const INOTIFY_RM_WATCH: usize = 28;
+pub const IOCTL: usize = 29;
+pub const IOPRIO_SET: usize = 30;
+pub const IOPRIO_GET: usize = 31;
+pub const FLOCK: usize = 32;
+pub const MKNODAT: usize = 33;
+pub const MKDIRAT: usize = 34;
+pub const UNLINKAT: usize = 35;
+pub const SYMLINKAT: usize = 36;
+pub const LINKAT: usize = 37;
+pub const UMOUNT2: usize = 39;
+pub const MOUNT: usize = 40;
+pub const PIVOT_ROOT: usize = 41;
+pub const NFSSERVCTL: usize = 42;
+pub const STATFS: usize = 43;
+pub const FSTATFS: usize = 44;
+pub const TRUNCATE: usize = 45;
+pub const FTRUNCATE: usize = 46;
+pub const FALLOCATE: usize = 47;
+pub const FACCESSAT: usize = 48;
+pub const CHDIR: usize = 49;
+pub const FCHDIR: usize = 50;
+pub const CHROOT: usize = 51;
+pub const FCHMOD: usize = 52;
+pub const FCHMODAT: usize = 53;
+pub const FCHOWNAT: usize = 54;
+pub const FCHOWN: usize = 55;
+pub const OPENAT: usize = 56;
+pub const CLOSE: usize = 57;
+pub const VHANGUP: usize = 58;
+pub const PIPE2: usize = 59;
+pub const QUOTACTL: usize = 60;
+pub const GETDENTS64: usize = 61;
+pub const LSEEK: usize = 62;
+pub const READ: usize = 63;
+pub const WRITE: usize = 64;
+pub const READV: usize = 65;
+pub const WRITEV: usize = 66;
+pub const PREAD64: usize = 67;
+pub const PWRITE64: usize = 68;
+pub const PREADV: usize = 69;
+pub const PWRITEV: usize = 70;
+pub const SENDFILE: usize = 71;
+pub const PSELECT6: usize = 72;
+pub const PPOLL: usize = 73;
+pub const SIGNALFD4: usize = 74;
+pub const VMSPLICE: usize = 75;
+pub const SPLICE: usize = 76;
+pub const TEE: usize = 77;
+pub const READLINKAT: usize = 78;
+pub const SYNC: usize = 81;
+pub const FSYNC: usize = 82;
+pub const FDATASYNC: usize = 83;
+pub const SYNC_FILE_RANGE: usize = 84;
+pub const TIMERFD_CREATE: usize = 85;
+pub const TIMERFD_SETTIME: usize = 86;
+pub const TIMERFD_GETTIME: usize = 87;
+pub const UTIMENSAT: usize = 88;
+pub const ACCT: usize = 89;
+pub const CAPGET: usize = 90;
+pub const CAPSET: usize = 91;
+pub const PERSONALITY: usize = 92;
+pub const EXIT: usize = 93;
+pub const EXIT_GROUP: usize = 94;
+pub const WAITID: usize = 95;
+pub const SET_TID_ADDRESS: usize = 96;
+pub const UNSHARE: usize = 97;
+pub const FUTEX: usize = 98;
+pub const SET_ROBUST_LIST: usize = 99;
+pub const GET_ROBUST_LIST: usize = 100;
+pub const NANOSLEEP: usize = 101;
+pub const GETITIMER: usize = 102;
+pub const SETITIMER: usize = 103;
+pub const KEXEC_LOAD: usize = 104;
+pub const INIT_MODULE: usize = 105;
+pub const DELETE_MODULE: usize = 106;
+pub const TIMER_CREATE: usize = 107;
+pub const TIMER_GETTIME: usize = 108;
+pub const TIMER_GETOVERRUN: usize = 109;
+pub const TIMER_SETTIME: usize = 110;
+pub const TIMER_DELETE: usize = 111;
+pub const CLOCK_SETTIME: usize = 112;
+pub const CLOCK_GETTIME: usize = 113;
+pub const CLOCK_GETRES: usize = 114;
+pub const CLOCK_NANOSLEEP: usize = 115;
+pub const SYSLOG: usize = 116;
+pub const PTRACE: usize = 117;
+pub const SCHED_SETPARAM: usize = 118;
+pub const SCHED_SETSCHEDULER: usize = 119;
+pub const SCHED_GETSCHEDULER: usize = 120;
+pub const SCHED_GETPARAM: usize = 121;
+pub const SCHED_SETAFFINITY: usize = 122;
+pub const SCHED_GETAFFINITY: usize = 123;
+pub const SCHED_YIELD: usize = 124;
+pub const SCHED_GET_PRIORITY_MAX: usize = 125;
+pub const SCHED_GET_PRIORITY_MIN: usize = 126;
+pub const SCHED_RR_GET_INTERVAL: usize = 127;
+pub const RESTART_SYSCALL: usize = 128;
+pub const KILL: usize = 129;
+pub const TKILL: usize = 130;
+pub const TGKILL: usize = 131;
+pub const SIGALTSTACK: usize = 132;
+pub const RT_SIGSUSPEND: usize = 133;
+pub const RT_SIGACTION: usize = 134;
+pub const RT_SIGPROCMASK: usize = 135;
+pub const RT_SIGPENDING: usize = 136;
+pub const RT_SIGTIMEDWAIT: usize = 137;
+pub const RT_SIGQUEUEINFO: usize = 138;
+pub const RT_SIGRETURN: usize = 139;
+pub const SETPRIORITY: usize = 140;
+pub const GETPRIORITY: usize = 141;
+pub const REBOOT: usize = 142;
+pub const SETREGID: usize = 143;
+pub const SETGID: usize = 144;
+pub const SETREUID: usize = 145;
+pub const SETUID: usize = 146;
+pub const SETRESUID: usize = 147;
+pub const GETRESUID: usize = 148;
+pub const SETRESGID: usize = 149;
+pub const GETRESGID: usize = 150;
+pub const SETFSUID: usize = 151;
+pub const SETFSGID: usize = 152;
+pub const TIMES: usize = 153;
+pub const SETPGID: usize = 154;
+pub const GETPGID: usize = 155;
+pub const GETSID: usize = 156;
+pub const SETSID: usize = 157;
+pub const GETGROUPS: usize = 158;
+pub const SETGROUPS: usize = 159;
+pub const UNAME: usize = 160;
+pub const SETHOSTNAME: usize = 161;
+pub const SETDOMAINNAME: usize = 162;
+pub const GETRUSAGE: usize = 165;
+pub const UMASK: usize = 166;
+pub const PRCTL: usize = 167;
+pub const GETCPU: usize = 168;
+pub const GETTIMEOFDAY: usize = 169;
+pub const SETTIMEOFDAY: usize = 170;
+pub const ADJTIMEX: usize = 171;
+pub const GETPID: usize = 172;
+pub const GETPPID: usize = 173;
+pub const GETUID: usize = 174;
+pub const GETEUID: usize = 175;
+pub const GETGID: usize = 176;
+pub const GETEGID: usize = 177;
+pub const GETTID: usize = 178;
+pub const SYSINFO: usize = 179;
+pub const MQ_OPEN: usize = 180;
+pub const MQ_UNLINK: usize = 181;
+pub const MQ_TIMEDSEND: usize = 182;
+pub const MQ_TIMEDRECEIVE: usize = 183;
+pub const MQ_NOTIFY: usize = 184;
+pub const MQ_GETSETATTR: usize = 185;
+pub const MSGGET: usize = 186;
+pub const MSGCTL: usize = 187;
+pub const MSGRCV: usize = 188;
+pub const MSGSND: usize = 189;
+pub const SEMGET: usize = 190;
+pub const SEMCTL: usize = 191;
+pub const SEMTIMEDOP: usize = 192;
+pub const SEMOP: usize = 193;
+pub const SHMGET: usize = 194;
+pub const SHMCTL: usize = 195;
+pub const SHMAT: usize = 196;
+pub const SHMDT: usize = 197;
+pub const SOCKET: usize = 198;
+pub const SOCKETPAIR: usize = 199;
+pub const BIND: usize = 200;
+pub const LISTEN: usize = 201;
+pub const ACCEPT: usize = 202;
+pub const CONNECT: usize = 203;
+pub const GETSOCKNAME: usize = 204;
+pub const GETPEERNAME: usize = 205;
+pub const SENDTO: usize = 206;
+pub const RECVFROM: usize = 207;
+pub const SETSOCKOPT: usize = 208;
+pub const GETSOCKOPT: usize = 209;
+pub const SHUTDOWN: usize = 210;
+pub const SENDMSG: usize = 211;
+pub const RECVMSG: usize = 212;
+pub const READAHEAD: usize = 213;
+pub const BRK: usize = 214;
+pub const MUNMAP: usize = 215;
+pub const MREMAP: usize = 216;
+pub const ADD_KEY: usize = 217;
+pub const REQUEST_KEY: usize = 218;
+pub const KEYCTL: usize = 219;
+pub const CLONE: usize = 220;
+pub const EXECVE: usize = 221;
+pub const MMAP: usize = 222;
+pub const FADVISE64: usize = 223;
+pub const SWAPON: usize = 224;
+pub const SWAPOFF: usize = 225;
+pub const MPROTECT: usize = 226;
+pub const MSYNC: usize = 227;
+pub const MLOCK: usize = 228;
+pub const MUNLOCK: usize = 229;
+pub const MLOCKALL: usize = 230;
+pub const MUNLOCKALL: usize = 231;
+pub const MINCORE: usize = 232;
+pub const MADVISE: usize = 233;
+pub const REMAP_FILE_PAGES: usize = 234;
+pub const MBIND: usize = 235;
+pub const GET_MEMPOLICY: usize = 236;
+pub const SET_MEMPOLICY: usize = 237;
+pub const MIGRATE_PAGES: usize = 238;
+pub const MOVE_PAGES: usize = 239;
+pub const RT_TGSIGQUEUEINFO: usize = 240;
+pub const PERF_EVENT_OPEN: usize = 241;
+pub const ACCEPT4: usize = 242;
+pub const RECVMMSG: usize = 243;
+pub const WAIT4: usize = 260;
+pub const PRLIMIT64: usize = 261;
+pub const FANOTIFY_INIT: usize = 262;
+pub const FANOTIFY_MARK: usize = 263;
+pub const NAME_TO_HANDLE_AT: usize = 264;
+pub const OPEN_BY_HANDLE_AT: usize = 265;
+pub const CLOCK_ADJTIME: usize = 266;
+pub const SYNCFS: usize = 267;
+pub const SETNS: usize = 268;
+pub const SENDMMSG: usize = 269;
+pub const PROCESS_VM_READV: usize = 270;
+pub const PROCESS_VM_WRITEV: usize = 271;
+pub const KCMP: usize = 272;
+pub const FINIT_MODULE: usize = 273;
+pub const SCHED_SETATTR: usize = 274;
+pub const SCHED_GETATTR: usize = 275;
+pub const RENAMEAT2: usize = 276;
+pub const SECCOMP: usize = 277;
+pub const GETRANDOM: usize = 278;
+pub const MEMFD_CREATE: usize = 279;
+pub const BPF: usize = 280;
+pub const EXECVEAT: usize = 281;
+pub const USERFAULTFD: usize = 282;
+pub const MEMBARRIER: usize = 283;
+pub const MLOCK2: usize = 284;
+pub const COPY_FILE_RANGE: usize = 285;
+pub const PREADV2: usize = 286;
+pub const PWRITEV2: usize = 287;
+pub const PKEY_MPROTECT: usize = 288;
+pub const PKEY_ALLOC: usize = 289;
+pub const PKEY_FREE: usize = 290;
+pub const STATX: usize = 291;
+pub const IO_PGETEVENTS: usize = 292;
+pub const RSEQ: usize = 293;
+pub const KEXEC_FILE_LOAD: usize = 294;
+pub const PIDFD_SEND_SIGNAL: usize = 424;
+pub const IO_URING_SETUP: usize = 425;
+pub const IO_URING_ENTER: usize = 426;
+pub const IO_URING_REGISTER: usize = 427;
+pub const OPEN_TREE: usize = 428;
+pub const MOVE_MOUNT: usize = 429;
+pub const FSOPEN: usize = 430;
+pub const FSCONFIG: usize = 431;
+pub const FSMOUNT: usize = 432;
+pub const FSPICK: usize = 433;
+pub const PIDFD_OPEN: usize = 434;
+pub const CLONE3: usize = 435;
+pub const CLOSE_RANGE: usize = 436;
+pub const OPENAT2: usize = 437;
+pub const PIDFD_GETFD: usize = 438;
+pub const FACCESSAT2: usize = 439;
+pub const PROCESS_MADVISE: usize = 440;
+pub const EPOLL_PWAIT2: usize = 441;
+pub const MOUNT_SETATTR: usize = 442;
+pub const QUOTACTL_FD: usize = 443;
+pub const LANDLOCK_CREATE_RULESET: usize = 444;
+pub const LANDLOCK_ADD_RULE: usize = 445;
+pub const LANDLOCK_RESTRICT_SELF: usize = 446;
+pub const PROCESS_MRELEASE: usize = 448;
+pub const FUTEX_WAITV: usize = 449;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 450;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    IoSetup = IO_SETUP,
+    IoDestroy = IO_DESTROY,
+    IoSubmit = IO_SUBMIT,
+    IoCancel = IO_CANCEL,
+    IoGetevents = IO_GETEVENTS,
+    Setxattr = SETXATTR,
+    Lsetxattr = LSETXATTR,
+    Fsetxattr = FSETXATTR,
+    Getxattr = GETXATTR,
+    Lgetxattr = LGETXATTR,
+    Fgetxattr = FGETXATTR,
+    Listxattr = LISTXATTR,
+    Llistxattr = LLISTXATTR,
+    Flistxattr = FLISTXATTR,
+    Removexattr = REMOVEXATTR,
+    Lremovexattr = LREMOVEXATTR,
+    Fremovexattr = FREMOVEXATTR,
+    Getcwd = GETCWD,
+    LookupDcookie = LOOKUP_DCOOKIE,
+    Eventfd2 = EVENTFD2,
+    EpollCreate1 = EPOLL_CREATE1,
+    EpollCtl = EPOLL_CTL,
+    EpollPwait = EPOLL_PWAIT,
+    Dup = DUP,
+    Dup3 = DUP3,
+    Fcntl = FCNTL,
+    InotifyInit1 = INOTIFY_INIT1,
+    InotifyAddWatch = INOTIFY_ADD_WATCH,
+    InotifyRmWatch = INOTIFY_RM_WATCH,
+    Ioctl = IOCTL,
+    IoprioSet = IOPRIO_SET,
+    IoprioGet = IOPRIO_GET,
+    Flock = FLOCK,
+    Mknodat = MKNODAT,
+    Mkdirat = MKDIRAT,
+    Unlinkat = UNLINKAT,
+    Symlinkat = SYMLINKAT,
+    Linkat = LINKAT,
+    Umount2 = UMOUNT2,
+    Mount = MOUNT,
+    PivotRoot = PIVOT_ROOT,
+    Nfsservctl = NFSSERVCTL,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    Fallocate = FALLOCATE,
+    Faccessat = FACCESSAT,
+    Chdir = CHDIR,
+    Fchdir = FCHDIR,
+    Chroot = CHROOT,
+    Fchmod = FCHMOD,
+    Fchmodat = FCHMODAT,
+    Fchownat = FCHOWNAT,
+    Fchown = FCHOWN,
+    Openat = OPENAT,
+    Close = CLOSE,
+    Vhangup = VHANGUP,
+    Pipe2 = PIPE2,
+    Quotactl = QUOTACTL,
+    Getdents64 = GETDENTS64,
+    Lseek = LSEEK,
+    Read = READ,
+    Write = WRITE,
+    Readv = READV,
+    Writev = WRITEV,
+    Pread64 = PREAD64,
+    Pwrite64 = PWRITE64,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    Sendfile = SENDFILE,
+    Pselect6 = PSELECT6,
+    Ppoll = PPOLL,
+    Signalfd4 = SIGNALFD4,
+    Vmsplice = VMSPLICE,
+    Splice = SPLICE,
+    Tee = TEE,
+    Readlinkat = READLINKAT,
+    Sync = SYNC,
+    Fsync = FSYNC,
+    Fdatasync = FDATASYNC,
+    SyncFileRange = SYNC_FILE_RANGE,
+    TimerfdCreate = TIMERFD_CREATE,
+    TimerfdSettime = TIMERFD_SETTIME,
+    TimerfdGettime = TIMERFD_GETTIME,
+    Utimensat = UTIMENSAT,
+    Acct = ACCT,
+    Capget = CAPGET,
+    Capset = CAPSET,
+    Personality = PERSONALITY,
+    Exit = EXIT,
+    ExitGroup = EXIT_GROUP,
+    Waitid = WAITID,
+    SetTidAddress = SET_TID_ADDRESS,
+    Unshare = UNSHARE,
+    Futex = FUTEX,
+    SetRobustList = SET_ROBUST_LIST,
+    GetRobustList = GET_ROBUST_LIST,
+    Nanosleep = NANOSLEEP,
+    Getitimer = GETITIMER,
+    Setitimer = SETITIMER,
+    KexecLoad = KEXEC_LOAD,
+    InitModule = INIT_MODULE,
+    DeleteModule = DELETE_MODULE,
+    TimerCreate = TIMER_CREATE,
+    TimerGettime = TIMER_GETTIME,
+    TimerGetoverrun = TIMER_GETOVERRUN,
+    TimerSettime = TIMER_SETTIME,
+    TimerDelete = TIMER_DELETE,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGettime = CLOCK_GETTIME,
+    ClockGetres = CLOCK_GETRES,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    Syslog = SYSLOG,
+    Ptrace = PTRACE,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetaffinity = SCHED_SETAFFINITY,
+    SchedGetaffinity = SCHED_GETAFFINITY,
+    SchedYield = SCHED_YIELD,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    RestartSyscall = RESTART_SYSCALL,
+    Kill = KILL,
+    Tkill = TKILL,
+    Tgkill = TGKILL,
+    Sigaltstack = SIGALTSTACK,
+    RtSigsuspend = RT_SIGSUSPEND,
+    RtSigaction = RT_SIGACTION,
+    RtSigprocmask = RT_SIGPROCMASK,
+    RtSigpending = RT_SIGPENDING,
+    RtSigtimedwait = RT_SIGTIMEDWAIT,
+    RtSigqueueinfo = RT_SIGQUEUEINFO,
+    RtSigreturn = RT_SIGRETURN,
+    Setpriority = SETPRIORITY,
+    Getpriority = GETPRIORITY,
+    Reboot = REBOOT,
+    Setregid = SETREGID,
+    Setgid = SETGID,
+    Setreuid = SETREUID,
+    Setuid = SETUID,
+    Setresuid = SETRESUID,
+    Getresuid = GETRESUID,
+    Setresgid = SETRESGID,
+    Getresgid = GETRESGID,
+    Setfsuid = SETFSUID,
+    Setfsgid = SETFSGID,
+    Times = TIMES,
+    Setpgid = SETPGID,
+    Getpgid = GETPGID,
+    Getsid = GETSID,
+    Setsid = SETSID,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Uname = UNAME,
+    Sethostname = SETHOSTNAME,
+    Setdomainname = SETDOMAINNAME,
+    Getrusage = GETRUSAGE,
+    Umask = UMASK,
+    Prctl = PRCTL,
+    Getcpu = GETCPU,
+    Gettimeofday = GETTIMEOFDAY,
+    Settimeofday = SETTIMEOFDAY,
+    Adjtimex = ADJTIMEX,
+    Getpid = GETPID,
+    Getppid = GETPPID,
+    Getuid = GETUID,
+    Geteuid = GETEUID,
+    Getgid = GETGID,
+    Getegid = GETEGID,
+    Gettid = GETTID,
+    Sysinfo = SYSINFO,
+    MqOpen = MQ_OPEN,
+    MqUnlink = MQ_UNLINK,
+    MqTimedsend = MQ_TIMEDSEND,
+    MqTimedreceive = MQ_TIMEDRECEIVE,
+    MqNotify = MQ_NOTIFY,
+    MqGetsetattr = MQ_GETSETATTR,
+    Msgget = MSGGET,
+    Msgctl = MSGCTL,
+    Msgrcv = MSGRCV,
+    Msgsnd = MSGSND,
+    Semget = SEMGET,
+    Semctl = SEMCTL,
+    Semtimedop = SEMTIMEDOP,
+    Semop = SEMOP,
+    Shmget = SHMGET,
+    Shmctl = SHMCTL,
+    Shmat = SHMAT,
+    Shmdt = SHMDT,
+    Socket = SOCKET,
+    Socketpair = SOCKETPAIR,
+    Bind = BIND,
+    Listen = LISTEN,
+    Accept = ACCEPT,
+    Connect = CONNECT,
+    Getsockname = GETSOCKNAME,
+    Getpeername = GETPEERNAME,
+    Sendto = SENDTO,
+    Recvfrom = RECVFROM,
+    Setsockopt = SETSOCKOPT,
+    Getsockopt = GETSOCKOPT,
+    Shutdown = SHUTDOWN,
+    Sendmsg = SENDMSG,
+    Recvmsg = RECVMSG,
+    Readahead = READAHEAD,
+    Brk = BRK,
+    Munmap = MUNMAP,
+    Mremap = MREMAP,
+    AddKey = ADD_KEY,
+    RequestKey = REQUEST_KEY,
+    Keyctl = KEYCTL,
+    Clone = CLONE,
+    Execve = EXECVE,
+    Mmap = MMAP,
+    Fadvise64 = FADVISE64,
+    Swapon = SWAPON,
+    Swapoff = SWAPOFF,
+    Mprotect = MPROTECT,
+    Msync = MSYNC,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    Mincore = MINCORE,
+    Madvise = MADVISE,
+    RemapFilePages = REMAP_FILE_PAGES,
+    Mbind = MBIND,
+    GetMempolicy = GET_MEMPOLICY,
+    SetMempolicy = SET_MEMPOLICY,
+    MigratePages = MIGRATE_PAGES,
+    MovePages = MOVE_PAGES,
+    RtTgsigqueueinfo = RT_TGSIGQUEUEINFO,
+    PerfEventOpen = PERF_EVENT_OPEN,
+    Accept4 = ACCEPT4,
+    Recvmmsg = RECVMMSG,
+    Wait4 = WAIT4,
+    Prlimit64 = PRLIMIT64,
+    FanotifyInit = FANOTIFY_INIT,
+    FanotifyMark = FANOTIFY_MARK,
+    NameToHandleAt = NAME_TO_HANDLE_AT,
+    OpenByHandleAt = OPEN_BY_HANDLE_AT,
+    ClockAdjtime = CLOCK_ADJTIME,
+    Syncfs = SYNCFS,
+    Setns = SETNS,
+    Sendmmsg = SENDMMSG,
+    ProcessVmReadv = PROCESS_VM_READV,
+    ProcessVmWritev = PROCESS_VM_WRITEV,
+    Kcmp = KCMP,
+    FinitModule = FINIT_MODULE,
+    SchedSetattr = SCHED_SETATTR,
+    SchedGetattr = SCHED_GETATTR,
+    Renameat2 = RENAMEAT2,
+    Seccomp = SECCOMP,
+    Getrandom = GETRANDOM,
+    MemfdCreate = MEMFD_CREATE,
+    Bpf = BPF,
+    Execveat = EXECVEAT,
+    Userfaultfd = USERFAULTFD,
+    Membarrier = MEMBARRIER,
+    Mlock2 = MLOCK2,
+    CopyFileRange = COPY_FILE_RANGE,
+    Preadv2 = PREADV2,
+    Pwritev2 = PWRITEV2,
+    PkeyMprotect = PKEY_MPROTECT,
+    PkeyAlloc = PKEY_ALLOC,
+    PkeyFree = PKEY_FREE,
+    Statx = STATX,
+    IoPgetevents = IO_PGETEVENTS,
+    Rseq = RSEQ,
+    KexecFileLoad = KEXEC_FILE_LOAD,
+    PidfdSendSignal = PIDFD_SEND_SIGNAL,
+    IoUringSetup = IO_URING_SETUP,
+    IoUringEnter = IO_URING_ENTER,
+    IoUringRegister = IO_URING_REGISTER,
+    OpenTree = OPEN_TREE,
+    MoveMount = MOVE_MOUNT,
+    Fsopen = FSOPEN,
+    Fsconfig = FSCONFIG,
+    Fsmount = FSMOUNT,
+    Fspick = FSPICK,
+    PidfdOpen = PIDFD_OPEN,
+    Clone3 = CLONE3,
+    CloseRange = CLOSE_RANGE,
+    Openat2 = OPENAT2,
+    PidfdGetfd = PIDFD_GETFD,
+    Faccessat2 = FACCESSAT2,
+    ProcessMadvise = PROCESS_MADVISE,
+    EpollPwait2 = EPOLL_PWAIT2,
+    MountSetattr = MOUNT_SETATTR,
+    QuotactlFd = QUOTACTL_FD,
+    LandlockCreateRuleset = LANDLOCK_CREATE_RULESET,
+    LandlockAddRule = LANDLOCK_ADD_RULE,
+    LandlockRestrictSelf = LANDLOCK_RESTRICT_SELF,
+    ProcessMrelease = PROCESS_MRELEASE,
+    FutexWaitv = FUTEX_WAITV,
+    SetMempolicyHomeNode = SET_MEMPOLICY_HOME_NODE,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::IoSetup,
+        Self::IoDestroy,
+        Self::IoSubmit,
+        Self::IoCancel,
+        Self::IoGetevents,
+        Self::Setxattr,
+        Self::Lsetxattr,
+        Self::Fsetxattr,
+        Self::Getxattr,
+        Self::Lgetxattr,
+        Self::Fgetxattr,
+        Self::Listxattr,
+        Self::Llistxattr,
+        Self::Flistxattr,
+        Self::Removexattr,
+        Self::Lremovexattr,
+        Self::Fremovexattr,
+        Self::Getcwd,
+        Self::LookupDcookie,
+        Self::Eventfd2,
+        Self::EpollCreate1,
+        Self::EpollCtl,
+        Self::EpollPwait,
+        Self::Dup,
+        Self::Dup3,
+        Self::Fcntl,
+        Self::InotifyInit1,
+        Self::InotifyAddWatch,
+        Self::InotifyRmWatch,
+        Self::Ioctl,
+        Self::IoprioSet,
+        Self::IoprioGet,
+        Self::Flock,
+        Self::Mknodat,
+        Self::Mkdirat,
+        Self::Unlinkat,
+        Self::Symlinkat,
+        Self::Linkat,
+        Self::Umount2,
+        Self::Mount,
+        Self::PivotRoot,
+        Self::Nfsservctl,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::Fallocate,
+        Self::Faccessat,
+        Self::Chdir,
+        Self::Fchdir,
+        Self::Chroot,
+        Self::Fchmod,
+        Self::Fchmodat,
+        Self::Fchownat,
+        Self::Fchown,
+        Self::Openat,
+        Self::Close,
+        Self::Vhangup,
+        Self::Pipe2,
+        Self::Quotactl,
+        Self::Getdents64,
+        Self::Lseek,
+        Self::Read,
+        Self::Write,
+        Self::Readv,
+        Self::Writev,
+        Self::Pread64,
+        Self::Pwrite64,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::Sendfile,
+        Self::Pselect6,
+        Self::Ppoll,
+        Self::Signalfd4,
+        Self::Vmsplice,
+        Self::Splice,
+        Self::Tee,
+        Self::Readlinkat,
+        Self::Sync,
+        Self::Fsync,
+        Self::Fdatasync,
+        Self::SyncFileRange,
+        Self::TimerfdCreate,
+        Self::TimerfdSettime,
+        Self::TimerfdGettime,
+        Self::Utimensat,
+        Self::Acct,
+        Self::Capget,
+        Self::Capset,
+        Self::Personality,
+        Self::Exit,
+        Self::ExitGroup,
+        Self::Waitid,
+        Self::SetTidAddress,
+        Self::Unshare,
+        Self::Futex,
+        Self::SetRobustList,
+        Self::GetRobustList,
+        Self::Nanosleep,
+        Self::Getitimer,
+        Self::Setitimer,
+        Self::KexecLoad,
+        Self::InitModule,
+        Self::DeleteModule,
+        Self::TimerCreate,
+        Self::TimerGettime,
+        Self::TimerGetoverrun,
+        Self::TimerSettime,
+        Self::TimerDelete,
+        Self::ClockSettime,
+        Self::ClockGettime,
+        Self::ClockGetres,
+        Self::ClockNanosleep,
+        Self::Syslog,
+        Self::Ptrace,
+        Self::SchedSetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedGetparam,
+        Self::SchedSetaffinity,
+        Self::SchedGetaffinity,
+        Self::SchedYield,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::RestartSyscall,
+        Self::Kill,
+        Self::Tkill,
+        Self::Tgkill,
+        Self::Sigaltstack,
+        Self::RtSigsuspend,
+        Self::RtSigaction,
+        Self::RtSigprocmask,
+        Self::RtSigpending,
+        Self::RtSigtimedwait,
+        Self::RtSigqueueinfo,
+        Self::RtSigreturn,
+        Self::Setpriority,
+        Self::Getpriority,
+        Self::Reboot,
+        Self::Setregid,
+        Self::Setgid,
+        Self::Setreuid,
+        Self::Setuid,
+        Self::Setresuid,
+        Self::Getresuid,
+        Self::Setresgid,
+        Self::Getresgid,
+        Self::Setfsuid,
+        Self::Setfsgid,
+        Self::Times,
+        Self::Setpgid,
+        Self::Getpgid,
+        Self::Getsid,
+        Self::Setsid,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Uname,
+        Self::Sethostname,
+        Self::Setdomainname,
+        Self::Getrusage,
+        Self::Umask,
+        Self::Prctl,
+        Self::Getcpu,
+        Self::Gettimeofday,
+        Self::Settimeofday,
+        Self::Adjtimex,
+        Self::Getpid,
+        Self::Getppid,
+        Self::Getuid,
+        Self::Geteuid,
+        Self::Getgid,
+        Self::Getegid,
+        Self::Gettid,
+        Self::Sysinfo,
+        Self::MqOpen,
+        Self::MqUnlink,
+        Self::MqTimedsend,
+        Self::MqTimedreceive,
+        Self::MqNotify,
+        Self::MqGetsetattr,
+        Self::Msgget,
+        Self::Msgctl,
+        Self::Msgrcv,
+        Self::Msgsnd,
+        Self::Semget,
+        Self::Semctl,
+        Self::Semtimedop,
+        Self::Semop,
+        Self::Shmget,
+        Self::Shmctl,
+        Self::Shmat,
+        Self::Shmdt,
+        Self::Socket,
+        Self::Socketpair,
+        Self::Bind,
+        Self::Listen,
+        Self::Accept,
+        Self::Connect,
+        Self::Getsockname,
+        Self::Getpeername,
+        Self::Sendto,
+        Self::Recvfrom,
+        Self::Setsockopt,
+        Self::Getsockopt,
+        Self::Shutdown,
+        Self::Sendmsg,
+        Self::Recvmsg,
+        Self::Readahead,
+        Self::Brk,
+        Self::Munmap,
+        Self::Mremap,
+        Self::AddKey,
+        Self::RequestKey,
+        Self::Keyctl,
+        Self::Clone,
+        Self::Execve,
+        Self::Mmap,
+        Self::Fadvise64,
+        Self::Swapon,
+        Self::Swapoff,
+        Self::Mprotect,
+        Self::Msync,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::Mincore,
+        Self::Madvise,
+        Self::RemapFilePages,
+        Self::Mbind,
+        Self::GetMempolicy,
+        Self::SetMempolicy,
+        Self::MigratePages,
+        Self::MovePages,
+        Self::RtTgsigqueueinfo,
+        Self::PerfEventOpen,
+        Self::Accept4,
+        Self::Recvmmsg,
+        Self::Wait4,
+        Self::Prlimit64,
+        Self::FanotifyInit,
+        Self::FanotifyMark,
+        Self::NameToHandleAt,
+        Self::OpenByHandleAt,
+        Self::ClockAdjtime,
+        Self::Syncfs,
+        Self::Setns,
+        Self::Sendmmsg,
+        Self::ProcessVmReadv,
+        Self::ProcessVmWritev,
+        Self::Kcmp,
+        Self::FinitModule,
+        Self::SchedSetattr,
+        Self::SchedGetattr,
+        Self::Renameat2,
+        Self::Seccomp,
+        Self::Getrandom,
+        Self::MemfdCreate,
+        Self::Bpf,
+        Self::Execveat,
+        Self::Userfaultfd,
+        Self::Membarrier,
+        Self::Mlock2,
+        Self::CopyFileRange,
+        Self::Preadv2,
+        Self::Pwritev2,
+        Self::PkeyMprotect,
+        Self::PkeyAlloc,
+        Self::PkeyFree,
+        Self::Statx,
+        Self::IoPgetevents,
+        Self::Rseq,
+        Self::KexecFileLoad,
+        Self::PidfdSendSignal,
+        Self::IoUringSetup,
+        Self::IoUringEnter,
+        Self::IoUringRegister,
+        Self::OpenTree,
+        Self::MoveMount,
+        Self::Fsopen,
+        Self::Fsconfig,
+        Self::Fsmount,
+        Self::Fspick,
+        Self::PidfdOpen,
+        Self::Clone3,
+        Self::CloseRange,
+        Self::Openat2,
+        Self::PidfdGetfd,
+        Self::Faccessat2,
+        Self::ProcessMadvise,
+        Self::EpollPwait2,
+        Self::MountSetattr,
+        Self::QuotactlFd,
+        Self::LandlockCreateRuleset,
+        Self::LandlockAddRule,
+        Self::LandlockRestrictSelf,
+        Self::ProcessMrelease,
+        Self::FutexWaitv,
+        Self::SetMempolicyHomeNode,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            IO_SETUP => Some(Self::IoSetup),
+            IO_DESTROY => Some(Self::IoDestroy),
+            IO_SUBMIT => Some(Self::IoSubmit),
+            IO_CANCEL => Some(Self::IoCancel),
+            IO_GETEVENTS => Some(Self::IoGetevents),
+            SETXATTR => Some(Self::Setxattr),
+            LSETXATTR => Some(Self::Lsetxattr),
+            FSETXATTR => Some(Self::Fsetxattr),
+            GETXATTR => Some(Self::Getxattr),
+            LGETXATTR => Some(Self::Lgetxattr),
+            FGETXATTR => Some(Self::Fgetxattr),
+            LISTXATTR => Some(Self::Listxattr),
+            LLISTXATTR => Some(Self::Llistxattr),
+            FLISTXATTR => Some(Self::Flistxattr),
+            REMOVEXATTR => Some(Self::Removexattr),
+            LREMOVEXATTR => Some(Self::Lremovexattr),
+            FREMOVEXATTR => Some(Self::Fremovexattr),
+            GETCWD => Some(Self::Getcwd),
+            LOOKUP_DCOOKIE => Some(Self::LookupDcookie),
+            EVENTFD2 => Some(Self::Eventfd2),
+            EPOLL_CREATE1 => Some(Self::EpollCreate1),
+            EPOLL_CTL => Some(Self::EpollCtl),
+            EPOLL_PWAIT => Some(Self::EpollPwait),
+            DUP => Some(Self::Dup),
+            DUP3 => Some(Self::Dup3),
+            FCNTL => Some(Self::Fcntl),
+            INOTIFY_INIT1 => Some(Self::InotifyInit1),
+            INOTIFY_ADD_WATCH => Some(Self::InotifyAddWatch),
+            INOTIFY_RM_WATCH => Some(Self::InotifyRmWatch),
+            IOCTL => Some(Self::Ioctl),
+            IOPRIO_SET => Some(Self::IoprioSet),
+            IOPRIO_GET => Some(Self::IoprioGet),
+            FLOCK => Some(Self::Flock),
+            MKNODAT => Some(Self::Mknodat),
+            MKDIRAT => Some(Self::Mkdirat),
+            UNLINKAT => Some(Self::Unlinkat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            LINKAT => Some(Self::Linkat),
+            UMOUNT2 => Some(Self::Umount2),
+            MOUNT => Some(Self::Mount),
+            PIVOT_ROOT => Some(Self::PivotRoot),
+            NFSSERVCTL => Some(Self::Nfsservctl),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            FALLOCATE => Some(Self::Fallocate),
+            FACCESSAT => Some(Self::Faccessat),
+            CHDIR => Some(Self::Chdir),
+            FCHDIR => Some(Self::Fchdir),
+            CHROOT => Some(Self::Chroot),
+            FCHMOD => Some(Self::Fchmod),
+            FCHMODAT => Some(Self::Fchmodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FCHOWN => Some(Self::Fchown),
+            OPENAT => Some(Self::Openat),
+            CLOSE => Some(Self::Close),
+            VHANGUP => Some(Self::Vhangup),
+            PIPE2 => Some(Self::Pipe2),
+            QUOTACTL => Some(Self::Quotactl),
+            GETDENTS64 => Some(Self::Getdents64),
+            LSEEK => Some(Self::Lseek),
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            PREAD64 => Some(Self::Pread64),
+            PWRITE64 => Some(Self::Pwrite64),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            SENDFILE => Some(Self::Sendfile),
+            PSELECT6 => Some(Self::Pselect6),
+            PPOLL => Some(Self::Ppoll),
+            SIGNALFD4 => Some(Self::Signalfd4),
+            VMSPLICE => Some(Self::Vmsplice),
+            SPLICE => Some(Self::Splice),
+            TEE => Some(Self::Tee),
+            READLINKAT => Some(Self::Readlinkat),
+            SYNC => Some(Self::Sync),
+            FSYNC => Some(Self::Fsync),
+            FDATASYNC => Some(Self::Fdatasync),
+            SYNC_FILE_RANGE => Some(Self::SyncFileRange),
+            TIMERFD_CREATE => Some(Self::TimerfdCreate),
+            TIMERFD_SETTIME => Some(Self::TimerfdSettime),
+            TIMERFD_GETTIME => Some(Self::TimerfdGettime),
+            UTIMENSAT => Some(Self::Utimensat),
+            ACCT => Some(Self::Acct),
+            CAPGET => Some(Self::Capget),
+            CAPSET => Some(Self::Capset),
+            PERSONALITY => Some(Self::Personality),
+            EXIT => Some(Self::Exit),
+            EXIT_GROUP => Some(Self::ExitGroup),
+            WAITID => Some(Self::Waitid),
+            SET_TID_ADDRESS => Some(Self::SetTidAddress),
+            UNSHARE => Some(Self::Unshare),
+            FUTEX => Some(Self::Futex),
+            SET_ROBUST_LIST => Some(Self::SetRobustList),
+            GET_ROBUST_LIST => Some(Self::GetRobustList),
+            NANOSLEEP => Some(Self::Nanosleep),
+            GETITIMER => Some(Self::Getitimer),
+            SETITIMER => Some(Self::Setitimer),
+            KEXEC_LOAD => Some(Self::KexecLoad),
+            INIT_MODULE => Some(Self::InitModule),
+            DELETE_MODULE => Some(Self::DeleteModule),
+            TIMER_CREATE => Some(Self::TimerCreate),
+            TIMER_GETTIME => Some(Self::TimerGettime),
+            TIMER_GETOVERRUN => Some(Self::TimerGetoverrun),
+            TIMER_SETTIME => Some(Self::TimerSettime),
+            TIMER_DELETE => Some(Self::TimerDelete),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            SYSLOG => Some(Self::Syslog),
+            PTRACE => Some(Self::Ptrace),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETAFFINITY => Some(Self::SchedSetaffinity),
+            SCHED_GETAFFINITY => Some(Self::SchedGetaffinity),
+            SCHED_YIELD => Some(Self::SchedYield),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            RESTART_SYSCALL => Some(Self::RestartSyscall),
+            KILL => Some(Self::Kill),
+            TKILL => Some(Self::Tkill),
+            TGKILL => Some(Self::Tgkill),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            RT_SIGSUSPEND => Some(Self::RtSigsuspend),
+            RT_SIGACTION => Some(Self::RtSigaction),
+            RT_SIGPROCMASK => Some(Self::RtSigprocmask),
+            RT_SIGPENDING => Some(Self::RtSigpending),
+            RT_SIGTIMEDWAIT => Some(Self::RtSigtimedwait),
+            RT_SIGQUEUEINFO => Some(Self::RtSigqueueinfo),
+            RT_SIGRETURN => Some(Self::RtSigreturn),
+            SETPRIORITY => Some(Self::Setpriority),
+            GETPRIORITY => Some(Self::Getpriority),
+            REBOOT => Some(Self::Reboot),
+            SETREGID => Some(Self::Setregid),
+            SETGID => Some(Self::Setgid),
+            SETREUID => Some(Self::Setreuid),
+            SETUID => Some(Self::Setuid),
+            SETRESUID => Some(Self::Setresuid),
+            GETRESUID => Some(Self::Getresuid),
+            SETRESGID => Some(Self::Setresgid),
+            GETRESGID => Some(Self::Getresgid),
+            SETFSUID => Some(Self::Setfsuid),
+            SETFSGID => Some(Self::Setfsgid),
+            TIMES => Some(Self::Times),
+            SETPGID => Some(Self::Setpgid),
+            GETPGID => Some(Self::Getpgid),
+            GETSID => Some(Self::Getsid),
+            SETSID => Some(Self::Setsid),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            UNAME => Some(Self::Uname),
+            SETHOSTNAME => Some(Self::Sethostname),
+            SETDOMAINNAME => Some(Self::Setdomainname),
+            GETRUSAGE => Some(Self::Getrusage),
+            UMASK => Some(Self::Umask),
+            PRCTL => Some(Self::Prctl),
+            GETCPU => Some(Self::Getcpu),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            ADJTIMEX => Some(Self::Adjtimex),
+            GETPID => Some(Self::Getpid),
+            GETPPID => Some(Self::Getppid),
+            GETUID => Some(Self::Getuid),
+            GETEUID => Some(Self::Geteuid),
+            GETGID => Some(Self::Getgid),
+            GETEGID => Some(Self::Getegid),
+            GETTID => Some(Self::Gettid),
+            SYSINFO => Some(Self::Sysinfo),
+            MQ_OPEN => Some(Self::MqOpen),
+            MQ_UNLINK => Some(Self::MqUnlink),
+            MQ_TIMEDSEND => Some(Self::MqTimedsend),
+            MQ_TIMEDRECEIVE => Some(Self::MqTimedreceive),
+            MQ_NOTIFY => Some(Self::MqNotify),
+            MQ_GETSETATTR => Some(Self::MqGetsetattr),
+            MSGGET => Some(Self::Msgget),
+            MSGCTL => Some(Self::Msgctl),
+            MSGRCV => Some(Self::Msgrcv),
+            MSGSND => Some(Self::Msgsnd),
+            SEMGET => Some(Self::Semget),
+            SEMCTL => Some(Self::Semctl),
+            SEMTIMEDOP => Some(Self::Semtimedop),
+            SEMOP => Some(Self::Semop),
+            SHMGET => Some(Self::Shmget),
+            SHMCTL => Some(Self::Shmctl),
+            SHMAT => Some(Self::Shmat),
+            SHMDT => Some(Self::Shmdt),
+            SOCKET => Some(Self::Socket),
+            SOCKETPAIR => Some(Self::Socketpair),
+            BIND => Some(Self::Bind),
+            LISTEN => Some(Self::Listen),
+            ACCEPT => Some(Self::Accept),
+            CONNECT => Some(Self::Connect),
+            GETSOCKNAME => Some(Self::Getsockname),
+            GETPEERNAME => Some(Self::Getpeername),
+            SENDTO => Some(Self::Sendto),
+            RECVFROM => Some(Self::Recvfrom),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            SHUTDOWN => Some(Self::Shutdown),
+            SENDMSG => Some(Self::Sendmsg),
+            RECVMSG => Some(Self::Recvmsg),
+            READAHEAD => Some(Self::Readahead),
+            BRK => Some(Self::Brk),
+            MUNMAP => Some(Self::Munmap),
+            MREMAP => Some(Self::Mremap),
+            ADD_KEY => Some(Self::AddKey),
+            REQUEST_KEY => Some(Self::RequestKey),
+            KEYCTL => Some(Self::Keyctl),
+            CLONE => Some(Self::Clone),
+            EXECVE => Some(Self::Execve),
+            MMAP => Some(Self::Mmap),
+            FADVISE64 => Some(Self::Fadvise64),
+            SWAPON => Some(Self::Swapon),
+            SWAPOFF => Some(Self::Swapoff),
+            MPROTECT => Some(Self::Mprotect),
+            MSYNC => Some(Self::Msync),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            MINCORE => Some(Self::Mincore),
+            MADVISE => Some(Self::Madvise),
+            REMAP_FILE_PAGES => Some(Self::RemapFilePages),
+            MBIND => Some(Self::Mbind),
+            GET_MEMPOLICY => Some(Self::GetMempolicy),
+            SET_MEMPOLICY => Some(Self::SetMempolicy),
+            MIGRATE_PAGES => Some(Self::MigratePages),
+            MOVE_PAGES => Some(Self::MovePages),
+            RT_TGSIGQUEUEINFO => Some(Self::RtTgsigqueueinfo),
+            PERF_EVENT_OPEN => Some(Self::PerfEventOpen),
+            ACCEPT4 => Some(Self::Accept4),
+            RECVMMSG => Some(Self::Recvmmsg),
+            WAIT4 => Some(Self::Wait4),
+            PRLIMIT64 => Some(Self::Prlimit64),
+            FANOTIFY_INIT => Some(Self::FanotifyInit),
+            FANOTIFY_MARK => Some(Self::FanotifyMark),
+            NAME_TO_HANDLE_AT => Some(Self::NameToHandleAt),
+            OPEN_BY_HANDLE_AT => Some(Self::OpenByHandleAt),
+            CLOCK_ADJTIME => Some(Self::ClockAdjtime),
+            SYNCFS => Some(Self::Syncfs),
+            SETNS => Some(Self::Setns),
+            SENDMMSG => Some(Self::Sendmmsg),
+            PROCESS_VM_READV => Some(Self::ProcessVmReadv),
+            PROCESS_VM_WRITEV => Some(Self::ProcessVmWritev),
+            KCMP => Some(Self::Kcmp),
+            FINIT_MODULE => Some(Self::FinitModule),
+            SCHED_SETATTR => Some(Self::SchedSetattr),
+            SCHED_GETATTR => Some(Self::SchedGetattr),
+            RENAMEAT2 => Some(Self::Renameat2),
+            SECCOMP => Some(Self::Seccomp),
+            GETRANDOM => Some(Self::Getrandom),
+            MEMFD_CREATE => Some(Self::MemfdCreate),
+            BPF => Some(Self::Bpf),
+            EXECVEAT => Some(Self::Execveat),
+            USERFAULTFD => Some(Self::Userfaultfd),
+            MEMBARRIER => Some(Self::Membarrier),
+            MLOCK2 => Some(Self::Mlock2),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            PREADV2 => Some(Self::Preadv2),
+            PWRITEV2 => Some(Self::Pwritev2),
+            PKEY_MPROTECT => Some(Self::PkeyMprotect),
+            PKEY_ALLOC => Some(Self::PkeyAlloc),
+            PKEY_FREE => Some(Self::PkeyFree),
+            STATX => Some(Self::Statx),
+            IO_PGETEVENTS => Some(Self::IoPgetevents),
+            RSEQ => Some(Self::Rseq),
+            KEXEC_FILE_LOAD => Some(Self::KexecFileLoad),
+            PIDFD_SEND_SIGNAL => Some(Self::PidfdSendSignal),
+            IO_URING_SETUP => Some(Self::IoUringSetup),
+            IO_URING_ENTER => Some(Self::IoUringEnter),
+            IO_URING_REGISTER => Some(Self::IoUringRegister),
+            OPEN_TREE => Some(Self::OpenTree),
+            MOVE_MOUNT => Some(Self::MoveMount),
+            FSOPEN => Some(Self::Fsopen),
+            FSCONFIG => Some(Self::Fsconfig),
+            FSMOUNT => Some(Self::Fsmount),
+            FSPICK => Some(Self::Fspick),
+            PIDFD_OPEN => Some(Self::PidfdOpen),
+            CLONE3 => Some(Self::Clone3),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            OPENAT2 => Some(Self::Openat2),
+            PIDFD_GETFD => Some(Self::PidfdGetfd),
+            FACCESSAT2 => Some(Self::Faccessat2),
+            PROCESS_MADVISE => Some(Self::ProcessMadvise),
+            EPOLL_PWAIT2 => Some(Self::EpollPwait2),
+            MOUNT_SETATTR => Some(Self::MountSetattr),
+            QUOTACTL_FD => Some(Self::QuotactlFd),
+            LANDLOCK_CREATE_RULESET => Some(Self::LandlockCreateRuleset),
+            LANDLOCK_ADD_RULE => Some(Self::LandlockAddRule),
+            LANDLOCK_RESTRICT_SELF => Some(Self::LandlockRestrictSelf),
+            PROCESS_MRELEASE => Some(Self::ProcessMrelease),
+            FUTEX_WAITV => Some(Self::FutexWaitv),
+            SET_MEMPOLICY_HOME_NODE => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "io_setup" => Some(Self::IoSetup),
+            "io_destroy" => Some(Self::IoDestroy),
+            "io_submit" => Some(Self::IoSubmit),
+            "io_cancel" => Some(Self::IoCancel),
+            "io_getevents" => Some(Self::IoGetevents),
+            "setxattr" => Some(Self::Setxattr),
+            "lsetxattr" => Some(Self::Lsetxattr),
+            "fsetxattr" => Some(Self::Fsetxattr),
+            "getxattr" => Some(Self::Getxattr),
+            "lgetxattr" => Some(Self::Lgetxattr),
+            "fgetxattr" => Some(Self::Fgetxattr),
+            "listxattr" => Some(Self::Listxattr),
+            "llistxattr" => Some(Self::Llistxattr),
+            "flistxattr" => Some(Self::Flistxattr),
+            "removexattr" => Some(Self::Removexattr),
+            "lremovexattr" => Some(Self::Lremovexattr),
+            "fremovexattr" => Some(Self::Fremovexattr),
+            "getcwd" => Some(Self::Getcwd),
+            "lookup_dcookie" => Some(Self::LookupDcookie),
+            "eventfd2" => Some(Self::Eventfd2),
+            "epoll_create1" => Some(Self::EpollCreate1),
+            "epoll_ctl" => Some(Self::EpollCtl),
+            "epoll_pwait" => Some(Self::EpollPwait),
+            "dup" => Some(Self::Dup),
+            "dup3" => Some(Self::Dup3),
+            "fcntl" => Some(Self::Fcntl),
+            "inotify_init1" => Some(Self::InotifyInit1),
+            "inotify_add_watch" => Some(Self::InotifyAddWatch),
+            "inotify_rm_watch" => Some(Self::InotifyRmWatch),
+            "ioctl" => Some(Self::Ioctl),
+            "ioprio_set" => Some(Self::IoprioSet),
+            "ioprio_get" => Some(Self::IoprioGet),
+            "flock" => Some(Self::Flock),
+            "mknodat" => Some(Self::Mknodat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "unlinkat" => Some(Self::Unlinkat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "linkat" => Some(Self::Linkat),
+            "umount2" => Some(Self::Umount2),
+            "mount" => Some(Self::Mount),
+            "pivot_root" => Some(Self::PivotRoot),
+            "nfsservctl" => Some(Self::Nfsservctl),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "fallocate" => Some(Self::Fallocate),
+            "faccessat" => Some(Self::Faccessat),
+            "chdir" => Some(Self::Chdir),
+            "fchdir" => Some(Self::Fchdir),
+            "chroot" => Some(Self::Chroot),
+            "fchmod" => Some(Self::Fchmod),
+            "fchmodat" => Some(Self::Fchmodat),
+            "fchownat" => Some(Self::Fchownat),
+            "fchown" => Some(Self::Fchown),
+            "openat" => Some(Self::Openat),
+            "close" => Some(Self::Close),
+            "vhangup" => Some(Self::Vhangup),
+            "pipe2" => Some(Self::Pipe2),
+            "quotactl" => Some(Self::Quotactl),
+            "getdents64" => Some(Self::Getdents64),
+            "lseek" => Some(Self::Lseek),
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "pread64" => Some(Self::Pread64),
+            "pwrite64" => Some(Self::Pwrite64),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "sendfile" => Some(Self::Sendfile),
+            "pselect6" => Some(Self::Pselect6),
+            "ppoll" => Some(Self::Ppoll),
+            "signalfd4" => Some(Self::Signalfd4),
+            "vmsplice" => Some(Self::Vmsplice),
+            "splice" => Some(Self::Splice),
+            "tee" => Some(Self::Tee),
+            "readlinkat" => Some(Self::Readlinkat),
+            "sync" => Some(Self::Sync),
+            "fsync" => Some(Self::Fsync),
+            "fdatasync" => Some(Self::Fdatasync),
+            "sync_file_range" => Some(Self::SyncFileRange),
+            "timerfd_create" => Some(Self::TimerfdCreate),
+            "timerfd_settime" => Some(Self::TimerfdSettime),
+            "timerfd_gettime" => Some(Self::TimerfdGettime),
+            "utimensat" => Some(Self::Utimensat),
+            "acct" => Some(Self::Acct),
+            "capget" => Some(Self::Capget),
+            "capset" => Some(Self::Capset),
+            "personality" => Some(Self::Personality),
+            "exit" => Some(Self::Exit),
+            "exit_group" => Some(Self::ExitGroup),
+            "waitid" => Some(Self::Waitid),
+            "set_tid_address" => Some(Self::SetTidAddress),
+            "unshare" => Some(Self::Unshare),
+            "futex" => Some(Self::Futex),
+            "set_robust_list" => Some(Self::SetRobustList),
+            "get_robust_list" => Some(Self::GetRobustList),
+            "nanosleep" => Some(Self::Nanosleep),
+            "getitimer" => Some(Self::Getitimer),
+            "setitimer" => Some(Self::Setitimer),
+            "kexec_load" => Some(Self::KexecLoad),
+            "init_module" => Some(Self::InitModule),
+            "delete_module" => Some(Self::DeleteModule),
+            "timer_create" => Some(Self::TimerCreate),
+            "timer_gettime" => Some(Self::TimerGettime),
+            "timer_getoverrun" => Some(Self::TimerGetoverrun),
+            "timer_settime" => Some(Self::TimerSettime),
+            "timer_delete" => Some(Self::TimerDelete),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "syslog" => Some(Self::Syslog),
+            "ptrace" => Some(Self::Ptrace),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setaffinity" => Some(Self::SchedSetaffinity),
+            "sched_getaffinity" => Some(Self::SchedGetaffinity),
+            "sched_yield" => Some(Self::SchedYield),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "restart_syscall" => Some(Self::RestartSyscall),
+            "kill" => Some(Self::Kill),
+            "tkill" => Some(Self::Tkill),
+            "tgkill" => Some(Self::Tgkill),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "rt_sigsuspend" => Some(Self::RtSigsuspend),
+            "rt_sigaction" => Some(Self::RtSigaction),
+            "rt_sigprocmask" => Some(Self::RtSigprocmask),
+            "rt_sigpending" => Some(Self::RtSigpending),
+            "rt_sigtimedwait" => Some(Self::RtSigtimedwait),
+            "rt_sigqueueinfo" => Some(Self::RtSigqueueinfo),
+            "rt_sigreturn" => Some(Self::RtSigreturn),
+            "setpriority" => Some(Self::Setpriority),
+            "getpriority" => Some(Self::Getpriority),
+            "reboot" => Some(Self::Reboot),
+            "setregid" => Some(Self::Setregid),
+            "setgid" => Some(Self::Setgid),
+            "setreuid" => Some(Self::Setreuid),
+            "setuid" => Some(Self::Setuid),
+            "setresuid" => Some(Self::Setresuid),
+            "getresuid" => Some(Self::Getresuid),
+            "setresgid" => Some(Self::Setresgid),
+            "getresgid" => Some(Self::Getresgid),
+            "setfsuid" => Some(Self::Setfsuid),
+            "setfsgid" => Some(Self::Setfsgid),
+            "times" => Some(Self::Times),
+            "setpgid" => Some(Self::Setpgid),
+            "getpgid" => Some(Self::Getpgid),
+            "getsid" => Some(Self::Getsid),
+            "setsid" => Some(Self::Setsid),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "uname" => Some(Self::Uname),
+            "sethostname" => Some(Self::Sethostname),
+            "setdomainname" => Some(Self::Setdomainname),
+            "getrusage" => Some(Self::Getrusage),
+            "umask" => Some(Self::Umask),
+            "prctl" => Some(Self::Prctl),
+            "getcpu" => Some(Self::Getcpu),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "settimeofday" => Some(Self::Settimeofday),
+            "adjtimex" => Some(Self::Adjtimex),
+            "getpid" => Some(Self::Getpid),
+            "getppid" => Some(Self::Getppid),
+            "getuid" => Some(Self::Getuid),
+            "geteuid" => Some(Self::Geteuid),
+            "getgid" => Some(Self::Getgid),
+            "getegid" => Some(Self::Getegid),
+            "gettid" => Some(Self::Gettid),
+            "sysinfo" => Some(Self::Sysinfo),
+            "mq_open" => Some(Self::MqOpen),
+            "mq_unlink" => Some(Self::MqUnlink),
+            "mq_timedsend" => Some(Self::MqTimedsend),
+            "mq_timedreceive" => Some(Self::MqTimedreceive),
+            "mq_notify" => Some(Self::MqNotify),
+            "mq_getsetattr" => Some(Self::MqGetsetattr),
+            "msgget" => Some(Self::Msgget),
+            "msgctl" => Some(Self::Msgctl),
+            "msgrcv" => Some(Self::Msgrcv),
+            "msgsnd" => Some(Self::Msgsnd),
+            "semget" => Some(Self::Semget),
+            "semctl" => Some(Self::Semctl),
+            "semtimedop" => Some(Self::Semtimedop),
+            "semop" => Some(Self::Semop),
+            "shmget" => Some(Self::Shmget),
+            "shmctl" => Some(Self::Shmctl),
+            "shmat" => Some(Self::Shmat),
+            "shmdt" => Some(Self::Shmdt),
+            "socket" => Some(Self::Socket),
+            "socketpair" => Some(Self::Socketpair),
+            "bind" => Some(Self::Bind),
+            "listen" => Some(Self::Listen),
+            "accept" => Some(Self::Accept),
+            "connect" => Some(Self::Connect),
+            "getsockname" => Some(Self::Getsockname),
+            "getpeername" => Some(Self::Getpeername),
+            "sendto" => Some(Self::Sendto),
+            "recvfrom" => Some(Self::Recvfrom),
+            "setsockopt" => Some(Self::Setsockopt),
+            "getsockopt" => Some(Self::Getsockopt),
+            "shutdown" => Some(Self::Shutdown),
+            "sendmsg" => Some(Self::Sendmsg),
+            "recvmsg" => Some(Self::Recvmsg),
+            "readahead" => Some(Self::Readahead),
+            "brk" => Some(Self::Brk),
+            "munmap" => Some(Self::Munmap),
+            "mremap" => Some(Self::Mremap),
+            "add_key" => Some(Self::AddKey),
+            "request_key" => Some(Self::RequestKey),
+            "keyctl" => Some(Self::Keyctl),
+            "clone" => Some(Self::Clone),
+            "execve" => Some(Self::Execve),
+            "mmap" => Some(Self::Mmap),
+            "fadvise64" => Some(Self::Fadvise64),
+            "swapon" => Some(Self::Swapon),
+            "swapoff" => Some(Self::Swapoff),
+            "mprotect" => Some(Self::Mprotect),
+            "msync" => Some(Self::Msync),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "mincore" => Some(Self::Mincore),
+            "madvise" => Some(Self::Madvise),
+            "remap_file_pages" => Some(Self::RemapFilePages),
+            "mbind" => Some(Self::Mbind),
+            "get_mempolicy" => Some(Self::GetMempolicy),
+            "set_mempolicy" => Some(Self::SetMempolicy),
+            "migrate_pages" => Some(Self::MigratePages),
+            "move_pages" => Some(Self::MovePages),
+            "rt_tgsigqueueinfo" => Some(Self::RtTgsigqueueinfo),
+            "perf_event_open" => Some(Self::PerfEventOpen),
+            "accept4" => Some(Self::Accept4),
+            "recvmmsg" => Some(Self::Recvmmsg),
+            "wait4" => Some(Self::Wait4),
+            "prlimit64" => Some(Self::Prlimit64),
+            "fanotify_init" => Some(Self::FanotifyInit),
+            "fanotify_mark" => Some(Self::FanotifyMark),
+            "name_to_handle_at" => Some(Self::NameToHandleAt),
+            "open_by_handle_at" => Some(Self::OpenByHandleAt),
+            "clock_adjtime" => Some(Self::ClockAdjtime),
+            "syncfs" => Some(Self::Syncfs),
+            "setns" => Some(Self::Setns),
+            "sendmmsg" => Some(Self::Sendmmsg),
+            "process_vm_readv" => Some(Self::ProcessVmReadv),
+            "process_vm_writev" => Some(Self::ProcessVmWritev),
+            "kcmp" => Some(Self::Kcmp),
+            "finit_module" => Some(Self::FinitModule),
+            "sched_setattr" => Some(Self::SchedSetattr),
+            "sched_getattr" => Some(Self::SchedGetattr),
+            "renameat2" => Some(Self::Renameat2),
+            "seccomp" => Some(Self::Seccomp),
+            "getrandom" => Some(Self::Getrandom),
+            "memfd_create" => Some(Self::MemfdCreate),
+            "bpf" => Some(Self::Bpf),
+            "execveat" => Some(Self::Execveat),
+            "userfaultfd" => Some(Self::Userfaultfd),
+            "membarrier" => Some(Self::Membarrier),
+            "mlock2" => Some(Self::Mlock2),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "preadv2" => Some(Self::Preadv2),
+            "pwritev2" => Some(Self::Pwritev2),
+            "pkey_mprotect" => Some(Self::PkeyMprotect),
+            "pkey_alloc" => Some(Self::PkeyAlloc),
+            "pkey_free" => Some(Self::PkeyFree),
+            "statx" => Some(Self::Statx),
+            "io_pgetevents" => Some(Self::IoPgetevents),
+            "rseq" => Some(Self::Rseq),
+            "kexec_file_load" => Some(Self::KexecFileLoad),
+            "pidfd_send_signal" => Some(Self::PidfdSendSignal),
+            "io_uring_setup" => Some(Self::IoUringSetup),
+            "io_uring_enter" => Some(Self::IoUringEnter),
+            "io_uring_register" => Some(Self::IoUringRegister),
+            "open_tree" => Some(Self::OpenTree),
+            "move_mount" => Some(Self::MoveMount),
+            "fsopen" => Some(Self::Fsopen),
+            "fsconfig" => Some(Self::Fsconfig),
+            "fsmount" => Some(Self::Fsmount),
+            "fspick" => Some(Self::Fspick),
+            "pidfd_open" => Some(Self::PidfdOpen),
+            "clone3" => Some(Self::Clone3),
+            "close_range" => Some(Self::CloseRange),
+            "openat2" => Some(Self::Openat2),
+            "pidfd_getfd" => Some(Self::PidfdGetfd),
+            "faccessat2" => Some(Self::Faccessat2),
+            "process_madvise" => Some(Self::ProcessMadvise),
+            "epoll_pwait2" => Some(Self::EpollPwait2),
+            "mount_setattr" => Some(Self::MountSetattr),
+            "quotactl_fd" => Some(Self::QuotactlFd),
+            "landlock_create_ruleset" => Some(Self::LandlockCreateRuleset),
+            "landlock_add_rule" => Some(Self::LandlockAddRule),
+            "landlock_restrict_self" => Some(Self::LandlockRestrictSelf),
+            "process_mrelease" => Some(Self::ProcessMrelease),
+            "futex_waitv" => Some(Self::FutexWaitv),
+            "set_mempolicy_home_node" => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::IoSetup => "io_setup",
+            Self::IoDestroy => "io_destroy",
+            Self::IoSubmit => "io_submit",
+            Self::IoCancel => "io_cancel",
+            Self::IoGetevents => "io_getevents",
+            Self::Setxattr => "setxattr",
+            Self::Lsetxattr => "lsetxattr",
+            Self::Fsetxattr => "fsetxattr",
+            Self::Getxattr => "getxattr",
+            Self::Lgetxattr => "lgetxattr",
+            Self::Fgetxattr => "fgetxattr",
+            Self::Listxattr => "listxattr",
+            Self::Llistxattr => "llistxattr",
+            Self::Flistxattr => "flistxattr",
+            Self::Removexattr => "removexattr",
+            Self::Lremovexattr => "lremovexattr",
+            Self::Fremovexattr => "fremovexattr",
+            Self::Getcwd => "getcwd",
+            Self::LookupDcookie => "lookup_dcookie",
+            Self::Eventfd2 => "eventfd2",
+            Self::EpollCreate1 => "epoll_create1",
+            Self::EpollCtl => "epoll_ctl",
+            Self::EpollPwait => "epoll_pwait",
+            Self::Dup => "dup",
+            Self::Dup3 => "dup3",
+            Self::Fcntl => "fcntl",
+            Self::InotifyInit1 => "inotify_init1",
+            Self::InotifyAddWatch => "inotify_add_watch",
+            Self::InotifyRmWatch => "inotify_rm_watch",
+            Self::Ioctl => "ioctl",
+            Self::IoprioSet => "ioprio_set",
+            Self::IoprioGet => "ioprio_get",
+            Self::Flock => "flock",
+            Self::Mknodat => "mknodat",
+            Self::Mkdirat => "mkdirat",
+            Self::Unlinkat => "unlinkat",
+            Self::Symlinkat => "symlinkat",
+            Self::Linkat => "linkat",
+            Self::Umount2 => "umount2",
+            Self::Mount => "mount",
+            Self::PivotRoot => "pivot_root",
+            Self::Nfsservctl => "nfsservctl",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::Fallocate => "fallocate",
+            Self::Faccessat => "faccessat",
+            Self::Chdir => "chdir",
+            Self::Fchdir => "fchdir",
+            Self::Chroot => "chroot",
+            Self::Fchmod => "fchmod",
+            Self::Fchmodat => "fchmodat",
+            Self::Fchownat => "fchownat",
+            Self::Fchown => "fchown",
+            Self::Openat => "openat",
+            Self::Close => "close",
+            Self::Vhangup => "vhangup",
+            Self::Pipe2 => "pipe2",
+            Self::Quotactl => "quotactl",
+            Self::Getdents64 => "getdents64",
+            Self::Lseek => "lseek",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Pread64 => "pread64",
+            Self::Pwrite64 => "pwrite64",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::Sendfile => "sendfile",
+            Self::Pselect6 => "pselect6",
+            Self::Ppoll => "ppoll",
+            Self::Signalfd4 => "signalfd4",
+            Self::Vmsplice => "vmsplice",
+            Self::Splice => "splice",
+            Self::Tee => "tee",
+            Self::Readlinkat => "readlinkat",
+            Self::Sync => "sync",
+            Self::Fsync => "fsync",
+            Self::Fdatasync => "fdatasync",
+            Self::SyncFileRange => "sync_file_range",
+            Self::TimerfdCreate => "timerfd_create",
+            Self::TimerfdSettime => "timerfd_settime",
+            Self::TimerfdGettime => "timerfd_gettime",
+            Self::Utimensat => "utimensat",
+            Self::Acct => "acct",
+            Self::Capget => "capget",
+            Self::Capset => "capset",
+            Self::Personality => "personality",
+            Self::Exit => "exit",
+            Self::ExitGroup => "exit_group",
+            Self::Waitid => "waitid",
+            Self::SetTidAddress => "set_tid_address",
+            Self::Unshare => "unshare",
+            Self::Futex => "futex",
+            Self::SetRobustList => "set_robust_list",
+            Self::GetRobustList => "get_robust_list",
+            Self::Nanosleep => "nanosleep",
+            Self::Getitimer => "getitimer",
+            Self::Setitimer => "setitimer",
+            Self::KexecLoad => "kexec_load",
+            Self::InitModule => "init_module",
+            Self::DeleteModule => "delete_module",
+            Self::TimerCreate => "timer_create",
+            Self::TimerGettime => "timer_gettime",
+            Self::TimerGetoverrun => "timer_getoverrun",
+            Self::TimerSettime => "timer_settime",
+            Self::TimerDelete => "timer_delete",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockGetres => "clock_getres",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::Syslog => "syslog",
+            Self::Ptrace => "ptrace",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetaffinity => "sched_setaffinity",
+            Self::SchedGetaffinity => "sched_getaffinity",
+            Self::SchedYield => "sched_yield",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::RestartSyscall => "restart_syscall",
+            Self::Kill => "kill",
+            Self::Tkill => "tkill",
+            Self::Tgkill => "tgkill",
+            Self::Sigaltstack => "sigaltstack",
+            Self::RtSigsuspend => "rt_sigsuspend",
+            Self::RtSigaction => "rt_sigaction",
+            Self::RtSigprocmask => "rt_sigprocmask",
+            Self::RtSigpending => "rt_sigpending",
+            Self::RtSigtimedwait => "rt_sigtimedwait",
+            Self::RtSigqueueinfo => "rt_sigqueueinfo",
+            Self::RtSigreturn => "rt_sigreturn",
+            Self::Setpriority => "setpriority",
+            Self::Getpriority => "getpriority",
+            Self::Reboot => "reboot",
+            Self::Setregid => "setregid",
+            Self::Setgid => "setgid",
+            Self::Setreuid => "setreuid",
+            Self::Setuid => "setuid",
+            Self::Setresuid => "setresuid",
+            Self::Getresuid => "getresuid",
+            Self::Setresgid => "setresgid",
+            Self::Getresgid => "getresgid",
+            Self::Setfsuid => "setfsuid",
+            Self::Setfsgid => "setfsgid",
+            Self::Times => "times",
+            Self::Setpgid => "setpgid",
+            Self::Getpgid => "getpgid",
+            Self::Getsid => "getsid",
+            Self::Setsid => "setsid",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Uname => "uname",
+            Self::Sethostname => "sethostname",
+            Self::Setdomainname => "setdomainname",
+            Self::Getrusage => "getrusage",
+            Self::Umask => "umask",
+            Self::Prctl => "prctl",
+            Self::Getcpu => "getcpu",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Settimeofday => "settimeofday",
+            Self::Adjtimex => "adjtimex",
+            Self::Getpid => "getpid",
+            Self::Getppid => "getppid",
+            Self::Getuid => "getuid",
+            Self::Geteuid => "geteuid",
+            Self::Getgid => "getgid",
+            Self::Getegid => "getegid",
+            Self::Gettid => "gettid",
+            Self::Sysinfo => "sysinfo",
+            Self::MqOpen => "mq_open",
+            Self::MqUnlink => "mq_unlink",
+            Self::MqTimedsend => "mq_timedsend",
+            Self::MqTimedreceive => "mq_timedreceive",
+            Self::MqNotify => "mq_notify",
+            Self::MqGetsetattr => "mq_getsetattr",
+            Self::Msgget => "msgget",
+            Self::Msgctl => "msgctl",
+            Self::Msgrcv => "msgrcv",
+            Self::Msgsnd => "msgsnd",
+            Self::Semget => "semget",
+            Self::Semctl => "semctl",
+            Self::Semtimedop => "semtimedop",
+            Self::Semop => "semop",
+            Self::Shmget => "shmget",
+            Self::Shmctl => "shmctl",
+            Self::Shmat => "shmat",
+            Self::Shmdt => "shmdt",
+            Self::Socket => "socket",
+            Self::Socketpair => "socketpair",
+            Self::Bind => "bind",
+            Self::Listen => "listen",
+            Self::Accept => "accept",
+            Self::Connect => "connect",
+            Self::Getsockname => "getsockname",
+            Self::Getpeername => "getpeername",
+            Self::Sendto => "sendto",
+            Self::Recvfrom => "recvfrom",
+            Self::Setsockopt => "setsockopt",
+            Self::Getsockopt => "getsockopt",
+            Self::Shutdown => "shutdown",
+            Self::Sendmsg => "sendmsg",
+            Self::Recvmsg => "recvmsg",
+            Self::Readahead => "readahead",
+            Self::Brk => "brk",
+            Self::Munmap => "munmap",
+            Self::Mremap => "mremap",
+            Self::AddKey => "add_key",
+            Self::RequestKey => "request_key",
+            Self::Keyctl => "keyctl",
+            Self::Clone => "clone",
+            Self::Execve => "execve",
+            Self::Mmap => "mmap",
+            Self::Fadvise64 => "fadvise64",
+            Self::Swapon => "swapon",
+            Self::Swapoff => "swapoff",
+            Self::Mprotect => "mprotect",
+            Self::Msync => "msync",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::Mincore => "mincore",
+            Self::Madvise => "madvise",
+            Self::RemapFilePages => "remap_file_pages",
+            Self::Mbind => "mbind",
+            Self::GetMempolicy => "get_mempolicy",
+            Self::SetMempolicy => "set_mempolicy",
+            Self::MigratePages => "migrate_pages",
+            Self::MovePages => "move_pages",
+            Self::RtTgsigqueueinfo => "rt_tgsigqueueinfo",
+            Self::PerfEventOpen => "perf_event_open",
+            Self::Accept4 => "accept4",
+            Self::Recvmmsg => "recvmmsg",
+            Self::Wait4 => "wait4",
+            Self::Prlimit64 => "prlimit64",
+            Self::FanotifyInit => "fanotify_init",
+            Self::FanotifyMark => "fanotify_mark",
+            Self::NameToHandleAt => "name_to_handle_at",
+            Self::OpenByHandleAt => "open_by_handle_at",
+            Self::ClockAdjtime => "clock_adjtime",
+            Self::Syncfs => "syncfs",
+            Self::Setns => "setns",
+            Self::Sendmmsg => "sendmmsg",
+            Self::ProcessVmReadv => "process_vm_readv",
+            Self::ProcessVmWritev => "process_vm_writev",
+            Self::Kcmp => "kcmp",
+            Self::FinitModule => "finit_module",
+            Self::SchedSetattr => "sched_setattr",
+            Self::SchedGetattr => "sched_getattr",
+            Self::Renameat2 => "renameat2",
+            Self::Seccomp => "seccomp",
+            Self::Getrandom => "getrandom",
+            Self::MemfdCreate => "memfd_create",
+            Self::Bpf => "bpf",
+            Self::Execveat => "execveat",
+            Self::Userfaultfd => "userfaultfd",
+            Self::Membarrier => "membarrier",
+            Self::Mlock2 => "mlock2",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Preadv2 => "preadv2",
+            Self::Pwritev2 => "pwritev2",
+            Self::PkeyMprotect => "pkey_mprotect",
+            Self::PkeyAlloc => "pkey_alloc",
+            Self::PkeyFree => "pkey_free",
+            Self::Statx => "statx",
+            Self::IoPgetevents => "io_pgetevents",
+            Self::Rseq => "rseq",
+            Self::KexecFileLoad => "kexec_file_load",
+            Self::PidfdSendSignal => "pidfd_send_signal",
+            Self::IoUringSetup => "io_uring_setup",
+            Self::IoUringEnter => "io_uring_enter",
+            Self::IoUringRegister => "io_uring_register",
+            Self::OpenTree => "open_tree",
+            Self::MoveMount => "move_mount",
+            Self::Fsopen => "fsopen",
+            Self::Fsconfig => "fsconfig",
+            Self::Fsmount => "fsmount",
+            Self::Fspick => "fspick",
+            Self::PidfdOpen => "pidfd_open",
+            Self::Clone3 => "clone3",
+            Self::CloseRange => "close_range",
+            Self::Openat2 => "openat2",
+            Self::PidfdGetfd => "pidfd_getfd",
+            Self::Faccessat2 => "faccessat2",
+            Self::ProcessMadvise => "process_madvise",
+            Self::EpollPwait2 => "epoll_pwait2",
+            Self::MountSetattr => "mount_setattr",
+            Self::QuotactlFd => "quotactl_fd",
+            Self::LandlockCreateRuleset => "landlock_create_ruleset",
+            Self::LandlockAddRule => "landlock_add_rule",
+            Self::LandlockRestrictSelf => "landlock_restrict_self",
+            Self::ProcessMrelease => "process_mrelease",
+            Self::FutexWaitv => "futex_waitv",
+            Self::SetMempolicyHomeNode => "set_mempolicy_home_node",
+        }
+    }
+}
@@ -0,0 +1,2561 @@
+//! System call numbers for `mips-linux`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/mips/syscall_o32.tbl`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const SYSCALL: usize = 4000;
+pub const EXIT: usize = 4001;
+pub const FORK: usize = 4002;
+pub const READ: usize = 4003;
+pub const WRITE: usize = 4004;
+pub const OPEN: usize = 4005;
+pub const CLOSE: usize = 4006;
+pub const WAITPID: usize = 4007;
+pub const CREAT: usize = 4008;
+pub const LINK: usize = 4009;
+pub const UNLINK: usize = 4010;
+pub const EXECVE: usize = 4011;
+pub const CHDIR: usize = 4012;
+pub const TIME: usize = 4013;
+pub const MKNOD: usize = 4014;
+pub const CHMOD: usize = 4015;
+pub const LCHOWN: usize = 4016;
+pub const BREAK: usize = 4017;
+pub const LSEEK: usize = 4019;
+pub const GETPID: usize = 4020;
+pub const MOUNT: usize = 4021;
+pub const UMOUNT: usize = 4022;
+pub const SETUID: usize = 4023;
+pub const GETUID: usize = 4024;
+pub const STIME: usize = 4025;
+pub const PTRACE: usize = 4026;
+pub const ALARM: usize = 4027;
+pub const PAUSE: usize = 4029;
+pub const UTIME: usize = 4030;
+pub const STTY: usize = 4031;
+pub const GTTY: usize = 4032;
+pub const ACCESS: usize = 4033;
+pub const NICE: usize = 4034;
+pub const FTIME: usize = 4035;
+pub const SYNC: usize = 4036;
+pub const KILL: usize = 4037;
+pub const RENAME: usize = 4038;
+pub const MKDIR: usize = 4039;
+pub const RMDIR: usize = 4040;
+pub const DUP: usize = 4041;
+pub const PIPE: usize = 4042;
+pub const TIMES: usize = 4043;
+pub const PROF: usize = 4044;
+pub const BRK: usize = 4045;
+pub const SETGID: usize = 4046;
+pub const GETGID: usize = 4047;
+pub const SIGNAL: usize = 4048;
+pub const GETEUID: usize = 4049;
+pub const GETEGID: usize = 4050;
+pub const ACCT: usize = 4051;
+pub const UMOUNT2: usize = 4052;
+pub const LOCK: usize = 4053;
+pub const IOCTL: usize = 4054;
+pub const FCNTL: usize = 4055;
+pub const MPX: usize = 4056;
+pub const SETPGID: usize = 4057;
+pub const ULIMIT: usize = 4058;
+pub const UMASK: usize = 4060;
+pub const CHROOT: usize = 4061;
+pub const USTAT: usize = 4062;
+pub const DUP2: usize = 4063;
+pub const GETPPID: usize = 4064;
+pub const GETPGRP: usize = 4065;
+pub const SETSID: usize = 4066;
+pub const SIGACTION: usize = 4067;
+pub const SGETMASK: usize = 4068;
+pub const SSETMASK: usize = 4069;
+pub const SETREUID: usize = 4070;
+pub const SETREGID: usize = 4071;
+pub const SIGSUSPEND: usize = 4072;
+pub const SIGPENDING: usize = 4073;
+pub const SETHOSTNAME: usize = 4074;
+pub const SETRLIMIT: usize = 4075;
+pub const GETRLIMIT: usize = 4076;
+pub const GETRUSAGE: usize = 4077;
+pub const GETTIMEOFDAY: usize = 4078;
+pub const SETTIMEOFDAY: usize = 4079;
+pub const GETGROUPS: usize = 4080;
+pub const SETGROUPS: usize = 4081;
+pub const SYMLINK: usize = 4083;
+pub const READLINK: usize = 4085;
+pub const USELIB: usize = 4086;
+pub const SWAPON: usize = 4087;
+pub const REBOOT: usize = 4088;
+pub const READDIR: usize = 4089;
+pub const MMAP: usize = 4090;
+pub const MUNMAP: usize = 4091;
+pub const TRUNCATE: usize = 4092;
+pub const FTRUNCATE: usize = 4093;
+pub const FCHMOD: usize = 4094;
+pub const FCHOWN: usize = 4095;
+pub const GETPRIORITY: usize = 4096;
+pub const SETPRIORITY: usize = 4097;
+pub const PROFIL: usize = 4098;
+pub const STATFS: usize = 4099;
+pub const FSTATFS: usize = 4100;
+pub const IOPERM: usize = 4101;
+pub const SOCKETCALL: usize = 4102;
+pub const SYSLOG: usize = 4103;
+pub const SETITIMER: usize = 4104;
+pub const GETITIMER: usize = 4105;
+pub const STAT: usize = 4106;
+pub const LSTAT: usize = 4107;
+pub const FSTAT: usize = 4108;
+pub const IOPL: usize = 4110;
+pub const VHANGUP: usize = 4111;
+pub const IDLE: usize = 4112;
+pub const VM86: usize = 4113;
+pub const WAIT4: usize = 4114;
+pub const SWAPOFF: usize = 4115;
+pub const SYSINFO: usize = 4116;
+pub const IPC: usize = 4117;
+pub const FSYNC: usize = 4118;
+pub const SIGRETURN: usize = 4119;
+pub const CLONE: usize = 4120;
+pub const SETDOMAINNAME: usize = 4121;
+pub const UNAME: usize = 4122;
+pub const MODIFY_LDT: usize = 4123;
+pub const ADJTIMEX: usize = 4124;
+pub const MPROTECT: usize = 4125;
+pub const SIGPROCMASK: usize = 4126;
+pub const CREATE_MODULE: usize = 4127;
+pub const INIT_MODULE: usize = 4128;
+pub const DELETE_MODULE: usize = 4129;
+pub const GET_KERNEL_SYMS: usize = 4130;
+pub const QUOTACTL: usize = 4131;
+pub const GETPGID: usize = 4132;
+pub const FCHDIR: usize = 4133;
+pub const BDFLUSH: usize = 4134;
+pub const SYSFS: usize = 4135;
+pub const PERSONALITY: usize = 4136;
+pub const AFS_SYSCALL: usize = 4137;
+pub const SETFSUID: usize = 4138;
+pub const SETFSGID: usize = 4139;
+pub const _LLSEEK: usize = 4140;
+pub const GETDENTS: usize = 4141;
+pub const _NEWSELECT: usize = 4142;
+pub const FLOCK: usize = 4143;
+pub const MSYNC: usize = 4144;
+pub const READV: usize = 4145;
+pub const WRITEV: usize = 4146;
+pub const CACHEFLUSH: usize = 4147;
+pub const CACHECTL: usize = 4148;
+pub const SYSMIPS: usize = 4149;
+pub const GETSID: usize = 4151;
+pub const FDATASYNC: usize = 4152;
+pub const _SYSCTL: usize = 4153;
+pub const MLOCK: usize = 4154;
+pub const MUNLOCK: usize = 4155;
+pub const MLOCKALL: usize = 4156;
+pub const MUNLOCKALL: usize = 4157;
+pub const SCHED_SETPARAM: usize = 4158;
+pub const SCHED_GETPARAM: usize = 4159;
+pub const SCHED_SETSCHEDULER: usize = 4160;
+pub const SCHED_GETSCHEDULER: usize = 4161;
+pub const SCHED_YIELD: usize = 4162;
+pub const SCHED_GET_PRIORITY_MAX: usize = 4163;
+pub const SCHED_GET_PRIORITY_MIN: usize = 4164;
+pub const SCHED_RR_GET_INTERVAL: usize = 4165;
+pub const NANOSLEEP: usize = 4166;
+pub const MREMAP: usize = 4167;
+pub const ACCEPT: usize = 4168;
+pub const BIND: usize = 4169;
+pub const CONNECT: usize = 4170;
+pub const GETPEERNAME: usize = 4171;
+pub const GETSOCKNAME: usize = 4172;
+pub const GETSOCKOPT: usize = 4173;
+pub const LISTEN: usize = 4174;
+pub const RECV: usize = 4175;
+pub const RECVFROM: usize = 4176;
+pub const RECVMSG: usize = 4177;
+pub const SEND: usize = 4178;
+pub const SENDMSG: usize = 4179;
+pub const SENDTO: usize = 4180;
+pub const SETSOCKOPT: usize = 4181;
+pub const SHUTDOWN: usize = 4182;
+pub const SOCKET: usize = 4183;
+pub const SOCKETPAIR: usize = 4184;
+pub const SETRESUID: usize = 4185;
+pub const GETRESUID: usize = 4186;
+pub const QUERY_MODULE: usize = 4187;
+pub const POLL: usize = 4188;
+pub const NFSSERVCTL: usize = 4189;
+pub const SETRESGID: usize = 4190;
+pub const GETRESGID: usize = 4191;
+pub const PRCTL: usize = 4192;
+pub const RT_SIGRETURN: usize = 4193;
+pub const RT_SIGACTION: usize = 4194;
+pub const RT_SIGPROCMASK: usize = 4195;
+pub const RT_SIGPENDING: usize = 4196;
+pub const RT_SIGTIMEDWAIT: usize = 4197;
+pub const RT_SIGQUEUEINFO: usize = 4198;
+pub const RT_SIGSUSPEND: usize = 4199;
+pub const PREAD64: usize = 4200;
+pub const PWRITE64: usize = 4201;
+pub const CHOWN: usize = 4202;
+pub const GETCWD: usize = 4203;
+pub const CAPGET: usize = 4204;
+pub const CAPSET: usize = 4205;
+pub const SIGALTSTACK: usize = 4206;
+pub const SENDFILE: usize = 4207;
+pub const GETPMSG: usize = 4208;
+pub const PUTPMSG: usize = 4209;
+pub const MMAP2: usize = 4210;
+pub const TRUNCATE64: usize = 4211;
+pub const FTRUNCATE64: usize = 4212;
+pub const STAT64: usize = 4213;
+pub const LSTAT64: usize = 4214;
+pub const FSTAT64: usize = 4215;
+pub const PIVOT_ROOT: usize = 4216;
+pub const MINCORE: usize = 4217;
+pub const MADVISE: usize = 4218;
+pub const GETDENTS64: usize = 4219;
+pub const FCNTL64: usize = 4220;
+pub const GETTID: usize = 4222;
+pub const READAHEAD: usize = 4223;
+pub const SETXATTR: usize = 4224;
+pub const LSETXATTR: usize = 4225;
+pub const FSETXATTR: usize = 4226;
+pub const GETXATTR: usize = 4227;
+pub const LGETXATTR: usize = 4228;
+pub const FGETXATTR: usize = 4229;
+pub const LISTXATTR: usize = 4230;
+pub const LLISTXATTR: usize = 4231;
+pub const FLISTXATTR: usize = 4232;
+pub const REMOVEXATTR: usize = 4233;
+pub const LREMOVEXATTR: usize = 4234;
+pub const FREMOVEXATTR: usize = 4235;
+pub const TKILL: usize = 4236;
+pub const SENDFILE64: usize = 4237;
+pub const FUTEX: usize = 4238;
+pub const SCHED_SETAFFINITY: usize = 4239;
+pub const SCHED_GETAFFINITY: usize = 4240;
+pub const IO_SETUP: usize = 4241;
+pub const IO_DESTROY: usize = 4242;
+pub const IO_GETEVENTS: usize = 4243;
+pub const IO_SUBMIT: usize = 4244;
+pub const IO_CANCEL: usize = 4245;
+pub const EXIT_GROUP: usize = 4246;
+pub const LOOKUP_DCOOKIE: usize = 4247;
+pub const EPOLL_CREATE: usize = 4248;
+pub const EPOLL_CTL: usize = 4249;
+pub const EPOLL_WAIT: usize = 4250;
+pub const REMAP_FILE_PAGES: usize = 4251;
+pub const SET_TID_ADDRESS: usize = 4252;
+pub const RESTART_SYSCALL: usize = 4253;
+pub const FADVISE64: usize = 4254;
+pub const STATFS64: usize = 4255;
+pub const FSTATFS64: usize = 4256;
+pub const TIMER_CREATE: usize = 4257;
+pub const TIMER_SETTIME: usize = 4258;
+pub const TIMER_GETTIME: usize = 4259;
+pub const TIMER_GETOVERRUN: usize = 4260;
+pub const TIMER_DELETE: usize = 4261;
+pub const CLOCK_SETTIME: usize = 4262;
+pub const CLOCK_GETTIME: usize = 4263;
+pub const CLOCK_GETRES: usize = 4264;
+pub const CLOCK_NANOSLEEP: usize = 4265;
+pub const TGKILL: usize = 4266;
+pub const UTIMES: usize = 4267;
+pub const MBIND: usize = 4268;
+pub const GET_MEMPOLICY: usize = 4269;
+pub const SET_MEMPOLICY: usize = 4270;
+pub const MQ_OPEN: usize = 4271;
+pub const MQ_UNLINK: usize = 4272;
+pub const MQ_TIMEDSEND: usize = 4273;
+pub const MQ_TIMEDRECEIVE: usize = 4274;
+pub const MQ_NOTIFY: usize = 4275;
+pub const MQ_GETSETATTR: usize = 4276;
+pub const VSERVER: usize = 4277;
+pub const WAITID: usize = 4278;
+pub const ADD_KEY: usize = 4280;
+pub const REQUEST_KEY: usize = 4281;
+pub const KEYCTL: usize = 4282;
+pub const SET_THREAD_AREA: usize = 4283;
+pub const INOTIFY_INIT: usize = 4284;
+pub const INOTIFY_ADD_WATCH: usize = 4285;
+pub const INOTIFY_RM_WATCH: usize = 4286;
+pub const MIGRATE_PAGES: usize = 4287;
+pub const OPENAT: usize = 4288;
+pub const MKDIRAT: usize = 4289;
+pub const MKNODAT: usize = 4290;
+pub const FCHOWNAT: usize = 4291;
+pub const FUTIMESAT: usize = 4292;
+pub const FSTATAT64: usize = 4293;
+pub const UNLINKAT: usize = 4294;
+pub const RENAMEAT: usize = 4295;
+pub const LINKAT: usize = 4296;
+pub const SYMLINKAT: usize = 4297;
+pub const READLINKAT: usize = 4298;
+pub const FCHMODAT: usize = 4299;
+pub const FACCESSAT: usize = 4300;
+pub const PSELECT6: usize = 4301;
+pub const PPOLL: usize = 4302;
+pub const UNSHARE: usize = 4303;
+pub const SPLICE: usize = 4304;
+pub const SYNC_FILE_RANGE: usize = 4305;
+pub const TEE: usize = 4306;
+pub const VMSPLICE: usize = 4307;
+pub const MOVE_PAGES: usize = 4308;
+pub const SET_ROBUST_LIST: usize = 4309;
+pub const GET_ROBUST_LIST: usize = 4310;
+pub const KEXEC_LOAD: usize = 4311;
+pub const GETCPU: usize = 4312;
+pub const EPOLL_PWAIT: usize = 4313;
+pub const IOPRIO_SET: usize = 4314;
+pub const IOPRIO_GET: usize = 4315;
+pub const UTIMENSAT: usize = 4316;
+pub const SIGNALFD: usize = 4317;
+pub const TIMERFD: usize = 4318;
+pub const EVENTFD: usize = 4319;
+pub const FALLOCATE: usize = 4320;
+pub const TIMERFD_CREATE: usize = 4321;
+pub const TIMERFD_GETTIME: usize = 4322;
+pub const TIMERFD_SETTIME: usize = 4323;
+pub const SIGNALFD4: usize = 4324;
+pub const EVENTFD2: usize = 4325;
+pub const EPOLL_CREATE1: usize = 4326;
+pub const DUP3: usize = 4327;
+pub const PIPE2: usize = 4328;
+pub const INOTIFY_INIT1: usize = 4329;
+pub const PREADV: usize = 4330;
+pub const PWRITEV: usize = 4331;
+pub const RT_TGSIGQUEUEINFO: usize = 4332;
+pub const PERF_EVENT_OPEN: usize = 4333;
+pub const ACCEPT4: usize = 4334;
+pub const RECVMMSG: usize = 4335;
+pub const FANOTIFY_INIT: usize = 4336;
+pub const FANOTIFY_MARK: usize = 4337;
+pub const PRLIMIT64: usize = 4338;
+pub const NAME_TO_HANDLE_AT: usize = 4339;
+pub const OPEN_BY_HANDLE_AT: usize = 4340;
+pub const CLOCK_ADJTIME: usize = 4341;
+pub const SYNCFS: usize = 4342;
+pub const SENDMMSG: usize = 4343;
+pub const SETNS: usize = 4344;
+pub const PROCESS_VM_READV: usize = 4345;
+pub const PROCESS_VM_WRITEV: usize = 4346;
+pub const KCMP: usize = 4347;
+pub const FINIT_MODULE: usize = 4348;
+pub const SCHED_SETATTR: usize = 4349;
+pub const SCHED_GETATTR: usize = 4350;
+pub const RENAMEAT2: usize = 4351;
+pub const SECCOMP: usize = 4352;
+pub const GETRANDOM: usize = 4353;
+pub const MEMFD_CREATE: usize = 4354;
+pub const BPF: usize = 4355;
+pub const EXECVEAT: usize = 4356;
+pub const USERFAULTFD: usize = 4357;
+pub const MEMBARRIER: usize = 4358;
+pub const MLOCK2: usize = 4359;
+pub const COPY_FILE_RANGE: usize = 4360;
+pub const PREADV2: usize = 4361;
+pub const PWRITEV2: usize = 4362;
+pub const PKEY_MPROTECT: usize = 4363;
+pub const PKEY_ALLOC: usize = 4364;
+pub const PKEY_FREE: usize = 4365;
+pub const STATX: usize = 4366;
+pub const RSEQ: usize = 4367;
+pub const IO_PGETEVENTS: usize = 4368;
+pub const SEMGET: usize = 4393;
+pub const SEMCTL: usize = 4394;
+pub const SHMGET: usize = 4395;
+pub const SHMCTL: usize = 4396;
+pub const SHMAT: usize = 4397;
+pub const SHMDT: usize = 4398;
+pub const MSGGET: usize = 4399;
+pub const MSGSND: usize = 4400;
+pub const MSGRCV: usize = 4401;
+pub const MSGCTL: usize = 4402;
+pub const CLOCK_GETTIME64: usize = 4403;
+pub const CLOCK_SETTIME64: usize = 4404;
+pub const CLOCK_ADJTIME64: usize = 4405;
+pub const CLOCK_GETRES_TIME64: usize = 4406;
+pub const CLOCK_NANOSLEEP_TIME64: usize = 4407;
+pub const TIMER_GETTIME64: usize = 4408;
+pub const TIMER_SETTIME64: usize = 4409;
+pub const TIMERFD_GETTIME64: usize = 4410;
+pub const TIMERFD_SETTIME64: usize = 4411;
+pub const UTIMENSAT_TIME64: usize = 4412;
+pub const PSELECT6_TIME64: usize = 4413;
+pub const PPOLL_TIME64: usize = 4414;
+pub const IO_PGETEVENTS_TIME64: usize = 4416;
+pub const RECVMMSG_TIME64: usize = 4417;
+pub const MQ_TIMEDSEND_TIME64: usize = 4418;
+pub const MQ_TIMEDRECEIVE_TIME64: usize = 4419;
+pub const SEMTIMEDOP_TIME64: usize = 4420;
+pub const RT_SIGTIMEDWAIT_TIME64: usize = 4421;
+pub const FUTEX_TIME64: usize = 4422;
+pub const SCHED_RR_GET_INTERVAL_TIME64: usize = 4423;
+pub const PIDFD_SEND_SIGNAL: usize = 4424;
+pub const IO_URING_SETUP: usize = 4425;
+pub const IO_URING_ENTER: usize = 4426;
+pub const IO_URING_REGISTER: usize = 4427;
+pub const OPEN_TREE: usize = 4428;
+pub const MOVE_MOUNT: usize = 4429;
+pub const FSOPEN: usize = 4430;
+pub const FSCONFIG: usize = 4431;
+pub const FSMOUNT: usize = 4432;
+pub const FSPICK: usize = 4433;
+pub const PIDFD_OPEN: usize = 4434;
+pub const CLONE3: usize = 4435;
+pub const CLOSE_RANGE: usize = 4436;
+pub const OPENAT2: usize = 4437;
+pub const PIDFD_GETFD: usize = 4438;
+pub const FACCESSAT2: usize = 4439;
+pub const PROCESS_MADVISE: usize = 4440;
+pub const EPOLL_PWAIT2: usize = 4441;
+pub const MOUNT_SETATTR: usize = 4442;
+pub const QUOTACTL_FD: usize = 4443;
+pub const LANDLOCK_CREATE_RULESET: usize = 4444;
+pub const LANDLOCK_ADD_RULE: usize = 4445;
+pub const LANDLOCK_RESTRICT_SELF: usize = 4446;
+pub const PROCESS_MRELEASE: usize = 4448;
+pub const FUTEX_WAITV: usize = 4449;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 4450;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    Syscall = SYSCALL,
+    Exit = EXIT,
+    Fork = FORK,
+    Read = READ,
+    Write = WRITE,
+    Open = OPEN,
+    Close = CLOSE,
+    Waitpid = WAITPID,
+    Creat = CREAT,
+    Link = LINK,
+    Unlink = UNLINK,
+    Execve = EXECVE,
+    Chdir = CHDIR,
+    Time = TIME,
+    Mknod = MKNOD,
+    Chmod = CHMOD,
+    Lchown = LCHOWN,
+    Break = BREAK,
+    Lseek = LSEEK,
+    Getpid = GETPID,
+    Mount = MOUNT,
+    Umount = UMOUNT,
+    Setuid = SETUID,
+    Getuid = GETUID,
+    Stime = STIME,
+    Ptrace = PTRACE,
+    Alarm = ALARM,
+    Pause = PAUSE,
+    Utime = UTIME,
+    Stty = STTY,
+    Gtty = GTTY,
+    Access = ACCESS,
+    Nice = NICE,
+    Ftime = FTIME,
+    Sync = SYNC,
+    Kill = KILL,
+    Rename = RENAME,
+    Mkdir = MKDIR,
+    Rmdir = RMDIR,
+    Dup = DUP,
+    Pipe = PIPE,
+    Times = TIMES,
+    Prof = PROF,
+    Brk = BRK,
+    Setgid = SETGID,
+    Getgid = GETGID,
+    Signal = SIGNAL,
+    Geteuid = GETEUID,
+    Getegid = GETEGID,
+    Acct = ACCT,
+    Umount2 = UMOUNT2,
+    Lock = LOCK,
+    Ioctl = IOCTL,
+    Fcntl = FCNTL,
+    Mpx = MPX,
+    Setpgid = SETPGID,
+    Ulimit = ULIMIT,
+    Umask = UMASK,
+    Chroot = CHROOT,
+    Ustat = USTAT,
+    Dup2 = DUP2,
+    Getppid = GETPPID,
+    Getpgrp = GETPGRP,
+    Setsid = SETSID,
+    Sigaction = SIGACTION,
+    Sgetmask = SGETMASK,
+    Ssetmask = SSETMASK,
+    Setreuid = SETREUID,
+    Setregid = SETREGID,
+    Sigsuspend = SIGSUSPEND,
+    Sigpending = SIGPENDING,
+    Sethostname = SETHOSTNAME,
+    Setrlimit = SETRLIMIT,
+    Getrlimit = GETRLIMIT,
+    Getrusage = GETRUSAGE,
+    Gettimeofday = GETTIMEOFDAY,
+    Settimeofday = SETTIMEOFDAY,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Symlink = SYMLINK,
+    Readlink = READLINK,
+    Uselib = USELIB,
+    Swapon = SWAPON,
+    Reboot = REBOOT,
+    Readdir = READDIR,
+    Mmap = MMAP,
+    Munmap = MUNMAP,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    Fchmod = FCHMOD,
+    Fchown = FCHOWN,
+    Getpriority = GETPRIORITY,
+    Setpriority = SETPRIORITY,
+    Profil = PROFIL,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Ioperm = IOPERM,
+    Socketcall = SOCKETCALL,
+    Syslog = SYSLOG,
+    Setitimer = SETITIMER,
+    Getitimer = GETITIMER,
+    Stat = STAT,
+    Lstat = LSTAT,
+    Fstat = FSTAT,
+    Iopl = IOPL,
+    Vhangup = VHANGUP,
+    Idle = IDLE,
+    Vm86 = VM86,
+    Wait4 = WAIT4,
+    Swapoff = SWAPOFF,
+    Sysinfo = SYSINFO,
+    Ipc = IPC,
+    Fsync = FSYNC,
+    Sigreturn = SIGRETURN,
+    Clone = CLONE,
+    Setdomainname = SETDOMAINNAME,
+    Uname = UNAME,
+    ModifyLdt = MODIFY_LDT,
+    Adjtimex = ADJTIMEX,
+    Mprotect = MPROTECT,
+    Sigprocmask = SIGPROCMASK,
+    CreateModule = CREATE_MODULE,
+    InitModule = INIT_MODULE,
+    DeleteModule = DELETE_MODULE,
+    GetKernelSyms = GET_KERNEL_SYMS,
+    Quotactl = QUOTACTL,
+    Getpgid = GETPGID,
+    Fchdir = FCHDIR,
+    Bdflush = BDFLUSH,
+    Sysfs = SYSFS,
+    Personality = PERSONALITY,
+    AfsSyscall = AFS_SYSCALL,
+    Setfsuid = SETFSUID,
+    Setfsgid = SETFSGID,
+    Llseek = _LLSEEK,
+    Getdents = GETDENTS,
+    Newselect = _NEWSELECT,
+    Flock = FLOCK,
+    Msync = MSYNC,
+    Readv = READV,
+    Writev = WRITEV,
+    Cacheflush = CACHEFLUSH,
+    Cachectl = CACHECTL,
+    Sysmips = SYSMIPS,
+    Getsid = GETSID,
+    Fdatasync = FDATASYNC,
+    Sysctl = _SYSCTL,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedYield = SCHED_YIELD,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    Nanosleep = NANOSLEEP,
+    Mremap = MREMAP,
+    Accept = ACCEPT,
+    Bind = BIND,
+    Connect = CONNECT,
+    Getpeername = GETPEERNAME,
+    Getsockname = GETSOCKNAME,
+    Getsockopt = GETSOCKOPT,
+    Listen = LISTEN,
+    Recv = RECV,
+    Recvfrom = RECVFROM,
+    Recvmsg = RECVMSG,
+    Send = SEND,
+    Sendmsg = SENDMSG,
+    Sendto = SENDTO,
+    Setsockopt = SETSOCKOPT,
+    Shutdown = SHUTDOWN,
+    Socket = SOCKET,
+    Socketpair = SOCKETPAIR,
+    Setresuid = SETRESUID,
+    Getresuid = GETRESUID,
+    QueryModule = QUERY_MODULE,
+    Poll = POLL,
+    Nfsservctl = NFSSERVCTL,
+    Setresgid = SETRESGID,
+    Getresgid = GETRESGID,
+    Prctl = PRCTL,
+    RtSigreturn = RT_SIGRETURN,
+    RtSigaction = RT_SIGACTION,
+    RtSigprocmask = RT_SIGPROCMASK,
+    RtSigpending = RT_SIGPENDING,
+    RtSigtimedwait = RT_SIGTIMEDWAIT,
+    RtSigqueueinfo = RT_SIGQUEUEINFO,
+    RtSigsuspend = RT_SIGSUSPEND,
+    Pread64 = PREAD64,
+    Pwrite64 = PWRITE64,
+    Chown = CHOWN,
+    Getcwd = GETCWD,
+    Capget = CAPGET,
+    Capset = CAPSET,
+    Sigaltstack = SIGALTSTACK,
+    Sendfile = SENDFILE,
+    Getpmsg = GETPMSG,
+    Putpmsg = PUTPMSG,
+    Mmap2 = MMAP2,
+    Truncate64 = TRUNCATE64,
+    Ftruncate64 = FTRUNCATE64,
+    Stat64 = STAT64,
+    Lstat64 = LSTAT64,
+    Fstat64 = FSTAT64,
+    PivotRoot = PIVOT_ROOT,
+    Mincore = MINCORE,
+    Madvise = MADVISE,
+    Getdents64 = GETDENTS64,
+    Fcntl64 = FCNTL64,
+    Gettid = GETTID,
+    Readahead = READAHEAD,
+    Setxattr = SETXATTR,
+    Lsetxattr = LSETXATTR,
+    Fsetxattr = FSETXATTR,
+    Getxattr = GETXATTR,
+    Lgetxattr = LGETXATTR,
+    Fgetxattr = FGETXATTR,
+    Listxattr = LISTXATTR,
+    Llistxattr = LLISTXATTR,
+    Flistxattr = FLISTXATTR,
+    Removexattr = REMOVEXATTR,
+    Lremovexattr = LREMOVEXATTR,
+    Fremovexattr = FREMOVEXATTR,
+    Tkill = TKILL,
+    Sendfile64 = SENDFILE64,
+    Futex = FUTEX,
+    SchedSetaffinity = SCHED_SETAFFINITY,
+    SchedGetaffinity = SCHED_GETAFFINITY,
+    IoSetup = IO_SETUP,
+    IoDestroy = IO_DESTROY,
+    IoGetevents = IO_GETEVENTS,
+    IoSubmit = IO_SUBMIT,
+    IoCancel = IO_CANCEL,
+    ExitGroup = EXIT_GROUP,
+    LookupDcookie = LOOKUP_DCOOKIE,
+    EpollCreate = EPOLL_CREATE,
+    EpollCtl = EPOLL_CTL,
+    EpollWait = EPOLL_WAIT,
+    RemapFilePages = REMAP_FILE_PAGES,
+    SetTidAddress = SET_TID_ADDRESS,
+    RestartSyscall = RESTART_SYSCALL,
+    Fadvise64 = FADVISE64,
+    Statfs64 = STATFS64,
+    Fstatfs64 = FSTATFS64,
+    TimerCreate = TIMER_CREATE,
+    TimerSettime = TIMER_SETTIME,
+    TimerGettime = TIMER_GETTIME,
+    TimerGetoverrun = TIMER_GETOVERRUN,
+    TimerDelete = TIMER_DELETE,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGettime = CLOCK_GETTIME,
+    ClockGetres = CLOCK_GETRES,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    Tgkill = TGKILL,
+    Utimes = UTIMES,
+    Mbind = MBIND,
+    GetMempolicy = GET_MEMPOLICY,
+    SetMempolicy = SET_MEMPOLICY,
+    MqOpen = MQ_OPEN,
+    MqUnlink = MQ_UNLINK,
+    MqTimedsend = MQ_TIMEDSEND,
+    MqTimedreceive = MQ_TIMEDRECEIVE,
+    MqNotify = MQ_NOTIFY,
+    MqGetsetattr = MQ_GETSETATTR,
+    Vserver = VSERVER,
+    Waitid = WAITID,
+    AddKey = ADD_KEY,
+    RequestKey = REQUEST_KEY,
+    Keyctl = KEYCTL,
+    SetThreadArea = SET_THREAD_AREA,
+    InotifyInit = INOTIFY_INIT,
+    InotifyAddWatch = INOTIFY_ADD_WATCH,
+    InotifyRmWatch = INOTIFY_RM_WATCH,
+    MigratePages = MIGRATE_PAGES,
+    Openat = OPENAT,
+    Mkdirat = MKDIRAT,
+    Mknodat = MKNODAT,
+    Fchownat = FCHOWNAT,
+    Futimesat = FUTIMESAT,
+    Fstatat64 = FSTATAT64,
+    Unlinkat = UNLINKAT,
+    Renameat = RENAMEAT,
+    Linkat = LINKAT,
+    Symlinkat = SYMLINKAT,
+    Readlinkat = READLINKAT,
+    Fchmodat = FCHMODAT,
+    Faccessat = FACCESSAT,
+    Pselect6 = PSELECT6,
+    Ppoll = PPOLL,
+    Unshare = UNSHARE,
+    Splice = SPLICE,
+    SyncFileRange = SYNC_FILE_RANGE,
+    Tee = TEE,
+    Vmsplice = VMSPLICE,
+    MovePages = MOVE_PAGES,
+    SetRobustList = SET_ROBUST_LIST,
+    GetRobustList = GET_ROBUST_LIST,
+    KexecLoad = KEXEC_LOAD,
+    Getcpu = GETCPU,
+    EpollPwait = EPOLL_PWAIT,
+    IoprioSet = IOPRIO_SET,
+    IoprioGet = IOPRIO_GET,
+    Utimensat = UTIMENSAT,
+    Signalfd = SIGNALFD,
+    Timerfd = TIMERFD,
+    Eventfd = EVENTFD,
+    Fallocate = FALLOCATE,
+    TimerfdCreate = TIMERFD_CREATE,
+    TimerfdGettime = TIMERFD_GETTIME,
+    TimerfdSettime = TIMERFD_SETTIME,
+    Signalfd4 = SIGNALFD4,
+    Eventfd2 = EVENTFD2,
+    EpollCreate1 = EPOLL_CREATE1,
+    Dup3 = DUP3,
+    Pipe2 = PIPE2,
+    InotifyInit1 = INOTIFY_INIT1,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    RtTgsigqueueinfo = RT_TGSIGQUEUEINFO,
+    PerfEventOpen = PERF_EVENT_OPEN,
+    Accept4 = ACCEPT4,
+    Recvmmsg = RECVMMSG,
+    FanotifyInit = FANOTIFY_INIT,
+    FanotifyMark = FANOTIFY_MARK,
+    Prlimit64 = PRLIMIT64,
+    NameToHandleAt = NAME_TO_HANDLE_AT,
+    OpenByHandleAt = OPEN_BY_HANDLE_AT,
+    ClockAdjtime = CLOCK_ADJTIME,
+    Syncfs = SYNCFS,
+    Sendmmsg = SENDMMSG,
+    Setns = SETNS,
+    ProcessVmReadv = PROCESS_VM_READV,
+    ProcessVmWritev = PROCESS_VM_WRITEV,
+    Kcmp = KCMP,
+    FinitModule = FINIT_MODULE,
+    SchedSetattr = SCHED_SETATTR,
+    SchedGetattr = SCHED_GETATTR,
+    Renameat2 = RENAMEAT2,
+    Seccomp = SECCOMP,
+    Getrandom = GETRANDOM,
+    MemfdCreate = MEMFD_CREATE,
+    Bpf = BPF,
+    Execveat = EXECVEAT,
+    Userfaultfd = USERFAULTFD,
+    Membarrier = MEMBARRIER,
+    Mlock2 = MLOCK2,
+    CopyFileRange = COPY_FILE_RANGE,
+    Preadv2 = PREADV2,
+    Pwritev2 = PWRITEV2,
+    PkeyMprotect = PKEY_MPROTECT,
+    PkeyAlloc = PKEY_ALLOC,
+    PkeyFree = PKEY_FREE,
+    Statx = STATX,
+    Rseq = RSEQ,
+    IoPgetevents = IO_PGETEVENTS,
+    Semget = SEMGET,
+    Semctl = SEMCTL,
+    Shmget = SHMGET,
+    Shmctl = SHMCTL,
+    Shmat = SHMAT,
+    Shmdt = SHMDT,
+    Msgget = MSGGET,
+    Msgsnd = MSGSND,
+    Msgrcv = MSGRCV,
+    Msgctl = MSGCTL,
+    ClockGettime64 = CLOCK_GETTIME64,
+    ClockSettime64 = CLOCK_SETTIME64,
+    ClockAdjtime64 = CLOCK_ADJTIME64,
+    ClockGetresTime64 = CLOCK_GETRES_TIME64,
+    ClockNanosleepTime64 = CLOCK_NANOSLEEP_TIME64,
+    TimerGettime64 = TIMER_GETTIME64,
+    TimerSettime64 = TIMER_SETTIME64,
+    TimerfdGettime64 = TIMERFD_GETTIME64,
+    TimerfdSettime64 = TIMERFD_SETTIME64,
+    UtimensatTime64 = UTIMENSAT_TIME64,
+    Pselect6Time64 = PSELECT6_TIME64,
+    PpollTime64 = PPOLL_TIME64,
+    IoPgeteventsTime64 = IO_PGETEVENTS_TIME64,
+    RecvmmsgTime64 = RECVMMSG_TIME64,
+    MqTimedsendTime64 = MQ_TIMEDSEND_TIME64,
+    MqTimedreceiveTime64 = MQ_TIMEDRECEIVE_TIME64,
+    SemtimedopTime64 = SEMTIMEDOP_TIME64,
+    RtSigtimedwaitTime64 = RT_SIGTIMEDWAIT_TIME64,
+    FutexTime64 = FUTEX_TIME64,
+    SchedRrGetIntervalTime64 = SCHED_RR_GET_INTERVAL_TIME64,
+    PidfdSendSignal = PIDFD_SEND_SIGNAL,
+    IoUringSetup = IO_URING_SETUP,
+    IoUringEnter = IO_URING_ENTER,
+    IoUringRegister = IO_URING_REGISTER,
+    OpenTree = OPEN_TREE,
+    MoveMount = MOVE_MOUNT,
+    Fsopen = FSOPEN,
+    Fsconfig = FSCONFIG,
+    Fsmount = FSMOUNT,
+    Fspick = FSPICK,
+    PidfdOpen = PIDFD_OPEN,
+    Clone3 = CLONE3,
+    CloseRange = CLOSE_RANGE,
+    Openat2 = OPENAT2,
+    PidfdGetfd = PIDFD_GETFD,
+    Faccessat2 = FACCESSAT2,
+    ProcessMadvise = PROCESS_MADVISE,
+    EpollPwait2 = EPOLL_PWAIT2,
+    MountSetattr = MOUNT_SETATTR,
+    QuotactlFd = QUOTACTL_FD,
+    LandlockCreateRuleset = LANDLOCK_CREATE_RULESET,
+    LandlockAddRule = LANDLOCK_ADD_RULE,
+    LandlockRestrictSelf = LANDLOCK_RESTRICT_SELF,
+    ProcessMrelease = PROCESS_MRELEASE,
+    FutexWaitv = FUTEX_WAITV,
+    SetMempolicyHomeNode = SET_MEMPOLICY_HOME_NODE,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::Syscall,
+        Self::Exit,
+        Self::Fork,
+        Self::Read,
+        Self::Write,
+        Self::Open,
+        Self::Close,
+        Self::Waitpid,
+        Self::Creat,
+        Self::Link,
+        Self::Unlink,
+        Self::Execve,
+        Self::Chdir,
+        Self::Time,
+        Self::Mknod,
+        Self::Chmod,
+        Self::Lchown,
+        Self::Break,
+        Self::Lseek,
+        Self::Getpid,
+        Self::Mount,
+        Self::Umount,
+        Self::Setuid,
+        Self::Getuid,
+        Self::Stime,
+        Self::Ptrace,
+        Self::Alarm,
+        Self::Pause,
+        Self::Utime,
+        Self::Stty,
+        Self::Gtty,
+        Self::Access,
+        Self::Nice,
+        Self::Ftime,
+        Self::Sync,
+        Self::Kill,
+        Self::Rename,
+        Self::Mkdir,
+        Self::Rmdir,
+        Self::Dup,
+        Self::Pipe,
+        Self::Times,
+        Self::Prof,
+        Self::Brk,
+        Self::Setgid,
+        Self::Getgid,
+        Self::Signal,
+        Self::Geteuid,
+        Self::Getegid,
+        Self::Acct,
+        Self::Umount2,
+        Self::Lock,
+        Self::Ioctl,
+        Self::Fcntl,
+        Self::Mpx,
+        Self::Setpgid,
+        Self::Ulimit,
+        Self::Umask,
+        Self::Chroot,
+        Self::Ustat,
+        Self::Dup2,
+        Self::Getppid,
+        Self::Getpgrp,
+        Self::Setsid,
+        Self::Sigaction,
+        Self::Sgetmask,
+        Self::Ssetmask,
+        Self::Setreuid,
+        Self::Setregid,
+        Self::Sigsuspend,
+        Self::Sigpending,
+        Self::Sethostname,
+        Self::Setrlimit,
+        Self::Getrlimit,
+        Self::Getrusage,
+        Self::Gettimeofday,
+        Self::Settimeofday,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Symlink,
+        Self::Readlink,
+        Self::Uselib,
+        Self::Swapon,
+        Self::Reboot,
+        Self::Readdir,
+        Self::Mmap,
+        Self::Munmap,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::Fchmod,
+        Self::Fchown,
+        Self::Getpriority,
+        Self::Setpriority,
+        Self::Profil,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Ioperm,
+        Self::Socketcall,
+        Self::Syslog,
+        Self::Setitimer,
+        Self::Getitimer,
+        Self::Stat,
+        Self::Lstat,
+        Self::Fstat,
+        Self::Iopl,
+        Self::Vhangup,
+        Self::Idle,
+        Self::Vm86,
+        Self::Wait4,
+        Self::Swapoff,
+        Self::Sysinfo,
+        Self::Ipc,
+        Self::Fsync,
+        Self::Sigreturn,
+        Self::Clone,
+        Self::Setdomainname,
+        Self::Uname,
+        Self::ModifyLdt,
+        Self::Adjtimex,
+        Self::Mprotect,
+        Self::Sigprocmask,
+        Self::CreateModule,
+        Self::InitModule,
+        Self::DeleteModule,
+        Self::GetKernelSyms,
+        Self::Quotactl,
+        Self::Getpgid,
+        Self::Fchdir,
+        Self::Bdflush,
+        Self::Sysfs,
+        Self::Personality,
+        Self::AfsSyscall,
+        Self::Setfsuid,
+        Self::Setfsgid,
+        Self::Llseek,
+        Self::Getdents,
+        Self::Newselect,
+        Self::Flock,
+        Self::Msync,
+        Self::Readv,
+        Self::Writev,
+        Self::Cacheflush,
+        Self::Cachectl,
+        Self::Sysmips,
+        Self::Getsid,
+        Self::Fdatasync,
+        Self::Sysctl,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::SchedSetparam,
+        Self::SchedGetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedYield,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::Nanosleep,
+        Self::Mremap,
+        Self::Accept,
+        Self::Bind,
+        Self::Connect,
+        Self::Getpeername,
+        Self::Getsockname,
+        Self::Getsockopt,
+        Self::Listen,
+        Self::Recv,
+        Self::Recvfrom,
+        Self::Recvmsg,
+        Self::Send,
+        Self::Sendmsg,
+        Self::Sendto,
+        Self::Setsockopt,
+        Self::Shutdown,
+        Self::Socket,
+        Self::Socketpair,
+        Self::Setresuid,
+        Self::Getresuid,
+        Self::QueryModule,
+        Self::Poll,
+        Self::Nfsservctl,
+        Self::Setresgid,
+        Self::Getresgid,
+        Self::Prctl,
+        Self::RtSigreturn,
+        Self::RtSigaction,
+        Self::RtSigprocmask,
+        Self::RtSigpending,
+        Self::RtSigtimedwait,
+        Self::RtSigqueueinfo,
+        Self::RtSigsuspend,
+        Self::Pread64,
+        Self::Pwrite64,
+        Self::Chown,
+        Self::Getcwd,
+        Self::Capget,
+        Self::Capset,
+        Self::Sigaltstack,
+        Self::Sendfile,
+        Self::Getpmsg,
+        Self::Putpmsg,
+        Self::Mmap2,
+        Self::Truncate64,
+        Self::Ftruncate64,
+        Self::Stat64,
+        Self::Lstat64,
+        Self::Fstat64,
+        Self::PivotRoot,
+        Self::Mincore,
+        Self::Madvise,
+        Self::Getdents64,
+        Self::Fcntl64,
+        Self::Gettid,
+        Self::Readahead,
+        Self::Setxattr,
+        Self::Lsetxattr,
+        Self::Fsetxattr,
+        Self::Getxattr,
+        Self::Lgetxattr,
+        Self::Fgetxattr,
+        Self::Listxattr,
+        Self::Llistxattr,
+        Self::Flistxattr,
+        Self::Removexattr,
+        Self::Lremovexattr,
+        Self::Fremovexattr,
+        Self::Tkill,
+        Self::Sendfile64,
+        Self::Futex,
+        Self::SchedSetaffinity,
+        Self::SchedGetaffinity,
+        Self::IoSetup,
+        Self::IoDestroy,
+        Self::IoGetevents,
+        Self::IoSubmit,
+        Self::IoCancel,
+        Self::ExitGroup,
+        Self::LookupDcookie,
+        Self::EpollCreate,
+        Self::EpollCtl,
+        Self::EpollWait,
+        Self::RemapFilePages,
+        Self::SetTidAddress,
+        Self::RestartSyscall,
+        Self::Fadvise64,
+        Self::Statfs64,
+        Self::Fstatfs64,
+        Self::TimerCreate,
+        Self::TimerSettime,
+        Self::TimerGettime,
+        Self::TimerGetoverrun,
+        Self::TimerDelete,
+        Self::ClockSettime,
+        Self::ClockGettime,
+        Self::ClockGetres,
+        Self::ClockNanosleep,
+        Self::Tgkill,
+        Self::Utimes,
+        Self::Mbind,
+        Self::GetMempolicy,
+        Self::SetMempolicy,
+        Self::MqOpen,
+        Self::MqUnlink,
+        Self::MqTimedsend,
+        Self::MqTimedreceive,
+        Self::MqNotify,
+        Self::MqGetsetattr,
+        Self::Vserver,
+        Self::Waitid,
+        Self::AddKey,
+        Self::RequestKey,
+        Self::Keyctl,
+        Self::SetThreadArea,
+        Self::InotifyInit,
+        Self::InotifyAddWatch,
+        Self::InotifyRmWatch,
+        Self::MigratePages,
+        Self::Openat,
+        Self::Mkdirat,
+        Self::Mknodat,
+        Self::Fchownat,
+        Self::Futimesat,
+        Self::Fstatat64,
+        Self::Unlinkat,
+        Self::Renameat,
+        Self::Linkat,
+        Self::Symlinkat,
+        Self::Readlinkat,
+        Self::Fchmodat,
+        Self::Faccessat,
+        Self::Pselect6,
+        Self::Ppoll,
+        Self::Unshare,
+        Self::Splice,
+        Self::SyncFileRange,
+        Self::Tee,
+        Self::Vmsplice,
+        Self::MovePages,
+        Self::SetRobustList,
+        Self::GetRobustList,
+        Self::KexecLoad,
+        Self::Getcpu,
+        Self::EpollPwait,
+        Self::IoprioSet,
+        Self::IoprioGet,
+        Self::Utimensat,
+        Self::Signalfd,
+        Self::Timerfd,
+        Self::Eventfd,
+        Self::Fallocate,
+        Self::TimerfdCreate,
+        Self::TimerfdGettime,
+        Self::TimerfdSettime,
+        Self::Signalfd4,
+        Self::Eventfd2,
+        Self::EpollCreate1,
+        Self::Dup3,
+        Self::Pipe2,
+        Self::InotifyInit1,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::RtTgsigqueueinfo,
+        Self::PerfEventOpen,
+        Self::Accept4,
+        Self::Recvmmsg,
+        Self::FanotifyInit,
+        Self::FanotifyMark,
+        Self::Prlimit64,
+        Self::NameToHandleAt,
+        Self::OpenByHandleAt,
+        Self::ClockAdjtime,
+        Self::Syncfs,
+        Self::Sendmmsg,
+        Self::Setns,
+        Self::ProcessVmReadv,
+        Self::ProcessVmWritev,
+        Self::Kcmp,
+        Self::FinitModule,
+        Self::SchedSetattr,
+        Self::SchedGetattr,
+        Self::Renameat2,
+        Self::Seccomp,
+        Self::Getrandom,
+        Self::MemfdCreate,
+        Self::Bpf,
+        Self::Execveat,
+        Self::Userfaultfd,
+        Self::Membarrier,
+        Self::Mlock2,
+        Self::CopyFileRange,
+        Self::Preadv2,
+        Self::Pwritev2,
+        Self::PkeyMprotect,
+        Self::PkeyAlloc,
+        Self::PkeyFree,
+        Self::Statx,
+        Self::Rseq,
+        Self::IoPgetevents,
+        Self::Semget,
+        Self::Semctl,
+        Self::Shmget,
+        Self::Shmctl,
+        Self::Shmat,
+        Self::Shmdt,
+        Self::Msgget,
+        Self::Msgsnd,
+        Self::Msgrcv,
+        Self::Msgctl,
+        Self::ClockGettime64,
+        Self::ClockSettime64,
+        Self::ClockAdjtime64,
+        Self::ClockGetresTime64,
+        Self::ClockNanosleepTime64,
+        Self::TimerGettime64,
+        Self::TimerSettime64,
+        Self::TimerfdGettime64,
+        Self::TimerfdSettime64,
+        Self::UtimensatTime64,
+        Self::Pselect6Time64,
+        Self::PpollTime64,
+        Self::IoPgeteventsTime64,
+        Self::RecvmmsgTime64,
+        Self::MqTimedsendTime64,
+        Self::MqTimedreceiveTime64,
+        Self::SemtimedopTime64,
+        Self::RtSigtimedwaitTime64,
+        Self::FutexTime64,
+        Self::SchedRrGetIntervalTime64,
+        Self::PidfdSendSignal,
+        Self::IoUringSetup,
+        Self::IoUringEnter,
+        Self::IoUringRegister,
+        Self::OpenTree,
+        Self::MoveMount,
+        Self::Fsopen,
+        Self::Fsconfig,
+        Self::Fsmount,
+        Self::Fspick,
+        Self::PidfdOpen,
+        Self::Clone3,
+        Self::CloseRange,
+        Self::Openat2,
+        Self::PidfdGetfd,
+        Self::Faccessat2,
+        Self::ProcessMadvise,
+        Self::EpollPwait2,
+        Self::MountSetattr,
+        Self::QuotactlFd,
+        Self::LandlockCreateRuleset,
+        Self::LandlockAddRule,
+        Self::LandlockRestrictSelf,
+        Self::ProcessMrelease,
+        Self::FutexWaitv,
+        Self::SetMempolicyHomeNode,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            SYSCALL => Some(Self::Syscall),
+            EXIT => Some(Self::Exit),
+            FORK => Some(Self::Fork),
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            OPEN => Some(Self::Open),
+            CLOSE => Some(Self::Close),
+            WAITPID => Some(Self::Waitpid),
+            CREAT => Some(Self::Creat),
+            LINK => Some(Self::Link),
+            UNLINK => Some(Self::Unlink),
+            EXECVE => Some(Self::Execve),
+            CHDIR => Some(Self::Chdir),
+            TIME => Some(Self::Time),
+            MKNOD => Some(Self::Mknod),
+            CHMOD => Some(Self::Chmod),
+            LCHOWN => Some(Self::Lchown),
+            BREAK => Some(Self::Break),
+            LSEEK => Some(Self::Lseek),
+            GETPID => Some(Self::Getpid),
+            MOUNT => Some(Self::Mount),
+            UMOUNT => Some(Self::Umount),
+            SETUID => Some(Self::Setuid),
+            GETUID => Some(Self::Getuid),
+            STIME => Some(Self::Stime),
+            PTRACE => Some(Self::Ptrace),
+            ALARM => Some(Self::Alarm),
+            PAUSE => Some(Self::Pause),
+            UTIME => Some(Self::Utime),
+            STTY => Some(Self::Stty),
+            GTTY => Some(Self::Gtty),
+            ACCESS => Some(Self::Access),
+            NICE => Some(Self::Nice),
+            FTIME => Some(Self::Ftime),
+            SYNC => Some(Self::Sync),
+            KILL => Some(Self::Kill),
+            RENAME => Some(Self::Rename),
+            MKDIR => Some(Self::Mkdir),
+            RMDIR => Some(Self::Rmdir),
+            DUP => Some(Self::Dup),
+            PIPE => Some(Self::Pipe),
+            TIMES => Some(Self::Times),
+            PROF => Some(Self::Prof),
+            BRK => Some(Self::Brk),
+            SETGID => Some(Self::Setgid),
+            GETGID => Some(Self::Getgid),
+            SIGNAL => Some(Self::Signal),
+            GETEUID => Some(Self::Geteuid),
+            GETEGID => Some(Self::Getegid),
+            ACCT => Some(Self::Acct),
+            UMOUNT2 => Some(Self::Umount2),
+            LOCK => Some(Self::Lock),
+            IOCTL => Some(Self::Ioctl),
+            FCNTL => Some(Self::Fcntl),
+            MPX => Some(Self::Mpx),
+            SETPGID => Some(Self::Setpgid),
+            ULIMIT => Some(Self::Ulimit),
+            UMASK => Some(Self::Umask),
+            CHROOT => Some(Self::Chroot),
+            USTAT => Some(Self::Ustat),
+            DUP2 => Some(Self::Dup2),
+            GETPPID => Some(Self::Getppid),
+            GETPGRP => Some(Self::Getpgrp),
+            SETSID => Some(Self::Setsid),
+            SIGACTION => Some(Self::Sigaction),
+            SGETMASK => Some(Self::Sgetmask),
+            SSETMASK => Some(Self::Ssetmask),
+            SETREUID => Some(Self::Setreuid),
+            SETREGID => Some(Self::Setregid),
+            SIGSUSPEND => Some(Self::Sigsuspend),
+            SIGPENDING => Some(Self::Sigpending),
+            SETHOSTNAME => Some(Self::Sethostname),
+            SETRLIMIT => Some(Self::Setrlimit),
+            GETRLIMIT => Some(Self::Getrlimit),
+            GETRUSAGE => Some(Self::Getrusage),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            SYMLINK => Some(Self::Symlink),
+            READLINK => Some(Self::Readlink),
+            USELIB => Some(Self::Uselib),
+            SWAPON => Some(Self::Swapon),
+            REBOOT => Some(Self::Reboot),
+            READDIR => Some(Self::Readdir),
+            MMAP => Some(Self::Mmap),
+            MUNMAP => Some(Self::Munmap),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            FCHMOD => Some(Self::Fchmod),
+            FCHOWN => Some(Self::Fchown),
+            GETPRIORITY => Some(Self::Getpriority),
+            SETPRIORITY => Some(Self::Setpriority),
+            PROFIL => Some(Self::Profil),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            IOPERM => Some(Self::Ioperm),
+            SOCKETCALL => Some(Self::Socketcall),
+            SYSLOG => Some(Self::Syslog),
+            SETITIMER => Some(Self::Setitimer),
+            GETITIMER => Some(Self::Getitimer),
+            STAT => Some(Self::Stat),
+            LSTAT => Some(Self::Lstat),
+            FSTAT => Some(Self::Fstat),
+            IOPL => Some(Self::Iopl),
+            VHANGUP => Some(Self::Vhangup),
+            IDLE => Some(Self::Idle),
+            VM86 => Some(Self::Vm86),
+            WAIT4 => Some(Self::Wait4),
+            SWAPOFF => Some(Self::Swapoff),
+            SYSINFO => Some(Self::Sysinfo),
+            IPC => Some(Self::Ipc),
+            FSYNC => Some(Self::Fsync),
+            SIGRETURN => Some(Self::Sigreturn),
+            CLONE => Some(Self::Clone),
+            SETDOMAINNAME => Some(Self::Setdomainname),
+            UNAME => Some(Self::Uname),
+            MODIFY_LDT => Some(Self::ModifyLdt),
+            ADJTIMEX => Some(Self::Adjtimex),
+            MPROTECT => Some(Self::Mprotect),
+            SIGPROCMASK => Some(Self::Sigprocmask),
+            CREATE_MODULE => Some(Self::CreateModule),
+            INIT_MODULE => Some(Self::InitModule),
+            DELETE_MODULE => Some(Self::DeleteModule),
+            GET_KERNEL_SYMS => Some(Self::GetKernelSyms),
+            QUOTACTL => Some(Self::Quotactl),
+            GETPGID => Some(Self::Getpgid),
+            FCHDIR => Some(Self::Fchdir),
+            BDFLUSH => Some(Self::Bdflush),
+            SYSFS => Some(Self::Sysfs),
+            PERSONALITY => Some(Self::Personality),
+            AFS_SYSCALL => Some(Self::AfsSyscall),
+            SETFSUID => Some(Self::Setfsuid),
+            SETFSGID => Some(Self::Setfsgid),
+            _LLSEEK => Some(Self::Llseek),
+            GETDENTS => Some(Self::Getdents),
+            _NEWSELECT => Some(Self::Newselect),
+            FLOCK => Some(Self::Flock),
+            MSYNC => Some(Self::Msync),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            CACHEFLUSH => Some(Self::Cacheflush),
+            CACHECTL => Some(Self::Cachectl),
+            SYSMIPS => Some(Self::Sysmips),
+            GETSID => Some(Self::Getsid),
+            FDATASYNC => Some(Self::Fdatasync),
+            _SYSCTL => Some(Self::Sysctl),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_YIELD => Some(Self::SchedYield),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            NANOSLEEP => Some(Self::Nanosleep),
+            MREMAP => Some(Self::Mremap),
+            ACCEPT => Some(Self::Accept),
+            BIND => Some(Self::Bind),
+            CONNECT => Some(Self::Connect),
+            GETPEERNAME => Some(Self::Getpeername),
+            GETSOCKNAME => Some(Self::Getsockname),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            LISTEN => Some(Self::Listen),
+            RECV => Some(Self::Recv),
+            RECVFROM => Some(Self::Recvfrom),
+            RECVMSG => Some(Self::Recvmsg),
+            SEND => Some(Self::Send),
+            SENDMSG => Some(Self::Sendmsg),
+            SENDTO => Some(Self::Sendto),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            SHUTDOWN => Some(Self::Shutdown),
+            SOCKET => Some(Self::Socket),
+            SOCKETPAIR => Some(Self::Socketpair),
+            SETRESUID => Some(Self::Setresuid),
+            GETRESUID => Some(Self::Getresuid),
+            QUERY_MODULE => Some(Self::QueryModule),
+            POLL => Some(Self::Poll),
+            NFSSERVCTL => Some(Self::Nfsservctl),
+            SETRESGID => Some(Self::Setresgid),
+            GETRESGID => Some(Self::Getresgid),
+            PRCTL => Some(Self::Prctl),
+            RT_SIGRETURN => Some(Self::RtSigreturn),
+            RT_SIGACTION => Some(Self::RtSigaction),
+            RT_SIGPROCMASK => Some(Self::RtSigprocmask),
+            RT_SIGPENDING => Some(Self::RtSigpending),
+            RT_SIGTIMEDWAIT => Some(Self::RtSigtimedwait),
+            RT_SIGQUEUEINFO => Some(Self::RtSigqueueinfo),
+            RT_SIGSUSPEND => Some(Self::RtSigsuspend),
+            PREAD64 => Some(Self::Pread64),
+            PWRITE64 => Some(Self::Pwrite64),
+            CHOWN => Some(Self::Chown),
+            GETCWD => Some(Self::Getcwd),
+            CAPGET => Some(Self::Capget),
+            CAPSET => Some(Self::Capset),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            SENDFILE => Some(Self::Sendfile),
+            GETPMSG => Some(Self::Getpmsg),
+            PUTPMSG => Some(Self::Putpmsg),
+            MMAP2 => Some(Self::Mmap2),
+            TRUNCATE64 => Some(Self::Truncate64),
+            FTRUNCATE64 => Some(Self::Ftruncate64),
+            STAT64 => Some(Self::Stat64),
+            LSTAT64 => Some(Self::Lstat64),
+            FSTAT64 => Some(Self::Fstat64),
+            PIVOT_ROOT => Some(Self::PivotRoot),
+            MINCORE => Some(Self::Mincore),
+            MADVISE => Some(Self::Madvise),
+            GETDENTS64 => Some(Self::Getdents64),
+            FCNTL64 => Some(Self::Fcntl64),
+            GETTID => Some(Self::Gettid),
+            READAHEAD => Some(Self::Readahead),
+            SETXATTR => Some(Self::Setxattr),
+            LSETXATTR => Some(Self::Lsetxattr),
+            FSETXATTR => Some(Self::Fsetxattr),
+            GETXATTR => Some(Self::Getxattr),
+            LGETXATTR => Some(Self::Lgetxattr),
+            FGETXATTR => Some(Self::Fgetxattr),
+            LISTXATTR => Some(Self::Listxattr),
+            LLISTXATTR => Some(Self::Llistxattr),
+            FLISTXATTR => Some(Self::Flistxattr),
+            REMOVEXATTR => Some(Self::Removexattr),
+            LREMOVEXATTR => Some(Self::Lremovexattr),
+            FREMOVEXATTR => Some(Self::Fremovexattr),
+            TKILL => Some(Self::Tkill),
+            SENDFILE64 => Some(Self::Sendfile64),
+            FUTEX => Some(Self::Futex),
+            SCHED_SETAFFINITY => Some(Self::SchedSetaffinity),
+            SCHED_GETAFFINITY => Some(Self::SchedGetaffinity),
+            IO_SETUP => Some(Self::IoSetup),
+            IO_DESTROY => Some(Self::IoDestroy),
+            IO_GETEVENTS => Some(Self::IoGetevents),
+            IO_SUBMIT => Some(Self::IoSubmit),
+            IO_CANCEL => Some(Self::IoCancel),
+            EXIT_GROUP => Some(Self::ExitGroup),
+            LOOKUP_DCOOKIE => Some(Self::LookupDcookie),
+            EPOLL_CREATE => Some(Self::EpollCreate),
+            EPOLL_CTL => Some(Self::EpollCtl),
+            EPOLL_WAIT => Some(Self::EpollWait),
+            REMAP_FILE_PAGES => Some(Self::RemapFilePages),
+            SET_TID_ADDRESS => Some(Self::SetTidAddress),
+            RESTART_SYSCALL => Some(Self::RestartSyscall),
+            FADVISE64 => Some(Self::Fadvise64),
+            STATFS64 => Some(Self::Statfs64),
+            FSTATFS64 => Some(Self::Fstatfs64),
+            TIMER_CREATE => Some(Self::TimerCreate),
+            TIMER_SETTIME => Some(Self::TimerSettime),
+            TIMER_GETTIME => Some(Self::TimerGettime),
+            TIMER_GETOVERRUN => Some(Self::TimerGetoverrun),
+            TIMER_DELETE => Some(Self::TimerDelete),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            TGKILL => Some(Self::Tgkill),
+            UTIMES => Some(Self::Utimes),
+            MBIND => Some(Self::Mbind),
+            GET_MEMPOLICY => Some(Self::GetMempolicy),
+            SET_MEMPOLICY => Some(Self::SetMempolicy),
+            MQ_OPEN => Some(Self::MqOpen),
+            MQ_UNLINK => Some(Self::MqUnlink),
+            MQ_TIMEDSEND => Some(Self::MqTimedsend),
+            MQ_TIMEDRECEIVE => Some(Self::MqTimedreceive),
+            MQ_NOTIFY => Some(Self::MqNotify),
+            MQ_GETSETATTR => Some(Self::MqGetsetattr),
+            VSERVER => Some(Self::Vserver),
+            WAITID => Some(Self::Waitid),
+            ADD_KEY => Some(Self::AddKey),
+            REQUEST_KEY => Some(Self::RequestKey),
+            KEYCTL => Some(Self::Keyctl),
+            SET_THREAD_AREA => Some(Self::SetThreadArea),
+            INOTIFY_INIT => Some(Self::InotifyInit),
+            INOTIFY_ADD_WATCH => Some(Self::InotifyAddWatch),
+            INOTIFY_RM_WATCH => Some(Self::InotifyRmWatch),
+            MIGRATE_PAGES => Some(Self::MigratePages),
+            OPENAT => Some(Self::Openat),
+            MKDIRAT => Some(Self::Mkdirat),
+            MKNODAT => Some(Self::Mknodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FUTIMESAT => Some(Self::Futimesat),
+            FSTATAT64 => Some(Self::Fstatat64),
+            UNLINKAT => Some(Self::Unlinkat),
+            RENAMEAT => Some(Self::Renameat),
+            LINKAT => Some(Self::Linkat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            READLINKAT => Some(Self::Readlinkat),
+            FCHMODAT => Some(Self::Fchmodat),
+            FACCESSAT => Some(Self::Faccessat),
+            PSELECT6 => Some(Self::Pselect6),
+            PPOLL => Some(Self::Ppoll),
+            UNSHARE => Some(Self::Unshare),
+            SPLICE => Some(Self::Splice),
+            SYNC_FILE_RANGE => Some(Self::SyncFileRange),
+            TEE => Some(Self::Tee),
+            VMSPLICE => Some(Self::Vmsplice),
+            MOVE_PAGES => Some(Self::MovePages),
+            SET_ROBUST_LIST => Some(Self::SetRobustList),
+            GET_ROBUST_LIST => Some(Self::GetRobustList),
+            KEXEC_LOAD => Some(Self::KexecLoad),
+            GETCPU => Some(Self::Getcpu),
+            EPOLL_PWAIT => Some(Self::EpollPwait),
+            IOPRIO_SET => Some(Self::IoprioSet),
+            IOPRIO_GET => Some(Self::IoprioGet),
+            UTIMENSAT => Some(Self::Utimensat),
+            SIGNALFD => Some(Self::Signalfd),
+            TIMERFD => Some(Self::Timerfd),
+            EVENTFD => Some(Self::Eventfd),
+            FALLOCATE => Some(Self::Fallocate),
+            TIMERFD_CREATE => Some(Self::TimerfdCreate),
+            TIMERFD_GETTIME => Some(Self::TimerfdGettime),
+            TIMERFD_SETTIME => Some(Self::TimerfdSettime),
+            SIGNALFD4 => Some(Self::Signalfd4),
+            EVENTFD2 => Some(Self::Eventfd2),
+            EPOLL_CREATE1 => Some(Self::EpollCreate1),
+            DUP3 => Some(Self::Dup3),
+            PIPE2 => Some(Self::Pipe2),
+            INOTIFY_INIT1 => Some(Self::InotifyInit1),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            RT_TGSIGQUEUEINFO => Some(Self::RtTgsigqueueinfo),
+            PERF_EVENT_OPEN => Some(Self::PerfEventOpen),
+            ACCEPT4 => Some(Self::Accept4),
+            RECVMMSG => Some(Self::Recvmmsg),
+            FANOTIFY_INIT => Some(Self::FanotifyInit),
+            FANOTIFY_MARK => Some(Self::FanotifyMark),
+            PRLIMIT64 => Some(Self::Prlimit64),
+            NAME_TO_HANDLE_AT => Some(Self::NameToHandleAt),
+            OPEN_BY_HANDLE_AT => Some(Self::OpenByHandleAt),
+            CLOCK_ADJTIME => Some(Self::ClockAdjtime),
+            SYNCFS => Some(Self::Syncfs),
+            SENDMMSG => Some(Self::Sendmmsg),
+            SETNS => Some(Self::Setns),
+            PROCESS_VM_READV => Some(Self::ProcessVmReadv),
+            PROCESS_VM_WRITEV => Some(Self::ProcessVmWritev),
+            KCMP => Some(Self::Kcmp),
+            FINIT_MODULE => Some(Self::FinitModule),
+            SCHED_SETATTR => Some(Self::SchedSetattr),
+            SCHED_GETATTR => Some(Self::SchedGetattr),
+            RENAMEAT2 => Some(Self::Renameat2),
+            SECCOMP => Some(Self::Seccomp),
+            GETRANDOM => Some(Self::Getrandom),
+            MEMFD_CREATE => Some(Self::MemfdCreate),
+            BPF => Some(Self::Bpf),
+            EXECVEAT => Some(Self::Execveat),
+            USERFAULTFD => Some(Self::Userfaultfd),
+            MEMBARRIER => Some(Self::Membarrier),
+            MLOCK2 => Some(Self::Mlock2),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            PREADV2 => Some(Self::Preadv2),
+            PWRITEV2 => Some(Self::Pwritev2),
+            PKEY_MPROTECT => Some(Self::PkeyMprotect),
+            PKEY_ALLOC => Some(Self::PkeyAlloc),
+            PKEY_FREE => Some(Self::PkeyFree),
+            STATX => Some(Self::Statx),
+            RSEQ => Some(Self::Rseq),
+            IO_PGETEVENTS => Some(Self::IoPgetevents),
+            SEMGET => Some(Self::Semget),
+            SEMCTL => Some(Self::Semctl),
+            SHMGET => Some(Self::Shmget),
+            SHMCTL => Some(Self::Shmctl),
+            SHMAT => Some(Self::Shmat),
+            SHMDT => Some(Self::Shmdt),
+            MSGGET => Some(Self::Msgget),
+            MSGSND => Some(Self::Msgsnd),
+            MSGRCV => Some(Self::Msgrcv),
+            MSGCTL => Some(Self::Msgctl),
+            CLOCK_GETTIME64 => Some(Self::ClockGettime64),
+            CLOCK_SETTIME64 => Some(Self::ClockSettime64),
+            CLOCK_ADJTIME64 => Some(Self::ClockAdjtime64),
+            CLOCK_GETRES_TIME64 => Some(Self::ClockGetresTime64),
+            CLOCK_NANOSLEEP_TIME64 => Some(Self::ClockNanosleepTime64),
+            TIMER_GETTIME64 => Some(Self::TimerGettime64),
+            TIMER_SETTIME64 => Some(Self::TimerSettime64),
+            TIMERFD_GETTIME64 => Some(Self::TimerfdGettime64),
+            TIMERFD_SETTIME64 => Some(Self::TimerfdSettime64),
+            UTIMENSAT_TIME64 => Some(Self::UtimensatTime64),
+            PSELECT6_TIME64 => Some(Self::Pselect6Time64),
+            PPOLL_TIME64 => Some(Self::PpollTime64),
+            IO_PGETEVENTS_TIME64 => Some(Self::IoPgeteventsTime64),
+            RECVMMSG_TIME64 => Some(Self::RecvmmsgTime64),
+            MQ_TIMEDSEND_TIME64 => Some(Self::MqTimedsendTime64),
+            MQ_TIMEDRECEIVE_TIME64 => Some(Self::MqTimedreceiveTime64),
+            SEMTIMEDOP_TIME64 => Some(Self::SemtimedopTime64),
+            RT_SIGTIMEDWAIT_TIME64 => Some(Self::RtSigtimedwaitTime64),
+            FUTEX_TIME64 => Some(Self::FutexTime64),
+            SCHED_RR_GET_INTERVAL_TIME64 => Some(Self::SchedRrGetIntervalTime64),
+            PIDFD_SEND_SIGNAL => Some(Self::PidfdSendSignal),
+            IO_URING_SETUP => Some(Self::IoUringSetup),
+            IO_URING_ENTER => Some(Self::IoUringEnter),
+            IO_URING_REGISTER => Some(Self::IoUringRegister),
+            OPEN_TREE => Some(Self::OpenTree),
+            MOVE_MOUNT => Some(Self::MoveMount),
+            FSOPEN => Some(Self::Fsopen),
+            FSCONFIG => Some(Self::Fsconfig),
+            FSMOUNT => Some(Self::Fsmount),
+            FSPICK => Some(Self::Fspick),
+            PIDFD_OPEN => Some(Self::PidfdOpen),
+            CLONE3 => Some(Self::Clone3),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            OPENAT2 => Some(Self::Openat2),
+            PIDFD_GETFD => Some(Self::PidfdGetfd),
+            FACCESSAT2 => Some(Self::Faccessat2),
+            PROCESS_MADVISE => Some(Self::ProcessMadvise),
+            EPOLL_PWAIT2 => Some(Self::EpollPwait2),
+            MOUNT_SETATTR => Some(Self::MountSetattr),
+            QUOTACTL_FD => Some(Self::QuotactlFd),
+            LANDLOCK_CREATE_RULESET => Some(Self::LandlockCreateRuleset),
+            LANDLOCK_ADD_RULE => Some(Self::LandlockAddRule),
+            LANDLOCK_RESTRICT_SELF => Some(Self::LandlockRestrictSelf),
+            PROCESS_MRELEASE => Some(Self::ProcessMrelease),
+            FUTEX_WAITV => Some(Self::FutexWaitv),
+            SET_MEMPOLICY_HOME_NODE => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "syscall" => Some(Self::Syscall),
+            "exit" => Some(Self::Exit),
+            "fork" => Some(Self::Fork),
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "waitpid" => Some(Self::Waitpid),
+            "creat" => Some(Self::Creat),
+            "link" => Some(Self::Link),
+            "unlink" => Some(Self::Unlink),
+            "execve" => Some(Self::Execve),
+            "chdir" => Some(Self::Chdir),
+            "time" => Some(Self::Time),
+            "mknod" => Some(Self::Mknod),
+            "chmod" => Some(Self::Chmod),
+            "lchown" => Some(Self::Lchown),
+            "break" => Some(Self::Break),
+            "lseek" => Some(Self::Lseek),
+            "getpid" => Some(Self::Getpid),
+            "mount" => Some(Self::Mount),
+            "umount" => Some(Self::Umount),
+            "setuid" => Some(Self::Setuid),
+            "getuid" => Some(Self::Getuid),
+            "stime" => Some(Self::Stime),
+            "ptrace" => Some(Self::Ptrace),
+            "alarm" => Some(Self::Alarm),
+            "pause" => Some(Self::Pause),
+            "utime" => Some(Self::Utime),
+            "stty" => Some(Self::Stty),
+            "gtty" => Some(Self::Gtty),
+            "access" => Some(Self::Access),
+            "nice" => Some(Self::Nice),
+            "ftime" => Some(Self::Ftime),
+            "sync" => Some(Self::Sync),
+            "kill" => Some(Self::Kill),
+            "rename" => Some(Self::Rename),
+            "mkdir" => Some(Self::Mkdir),
+            "rmdir" => Some(Self::Rmdir),
+            "dup" => Some(Self::Dup),
+            "pipe" => Some(Self::Pipe),
+            "times" => Some(Self::Times),
+            "prof" => Some(Self::Prof),
+            "brk" => Some(Self::Brk),
+            "setgid" => Some(Self::Setgid),
+            "getgid" => Some(Self::Getgid),
+            "signal" => Some(Self::Signal),
+            "geteuid" => Some(Self::Geteuid),
+            "getegid" => Some(Self::Getegid),
+            "acct" => Some(Self::Acct),
+            "umount2" => Some(Self::Umount2),
+            "lock" => Some(Self::Lock),
+            "ioctl" => Some(Self::Ioctl),
+            "fcntl" => Some(Self::Fcntl),
+            "mpx" => Some(Self::Mpx),
+            "setpgid" => Some(Self::Setpgid),
+            "ulimit" => Some(Self::Ulimit),
+            "umask" => Some(Self::Umask),
+            "chroot" => Some(Self::Chroot),
+            "ustat" => Some(Self::Ustat),
+            "dup2" => Some(Self::Dup2),
+            "getppid" => Some(Self::Getppid),
+            "getpgrp" => Some(Self::Getpgrp),
+            "setsid" => Some(Self::Setsid),
+            "sigaction" => Some(Self::Sigaction),
+            "sgetmask" => Some(Self::Sgetmask),
+            "ssetmask" => Some(Self::Ssetmask),
+            "setreuid" => Some(Self::Setreuid),
+            "setregid" => Some(Self::Setregid),
+            "sigsuspend" => Some(Self::Sigsuspend),
+            "sigpending" => Some(Self::Sigpending),
+            "sethostname" => Some(Self::Sethostname),
+            "setrlimit" => Some(Self::Setrlimit),
+            "getrlimit" => Some(Self::Getrlimit),
+            "getrusage" => Some(Self::Getrusage),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "settimeofday" => Some(Self::Settimeofday),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "symlink" => Some(Self::Symlink),
+            "readlink" => Some(Self::Readlink),
+            "uselib" => Some(Self::Uselib),
+            "swapon" => Some(Self::Swapon),
+            "reboot" => Some(Self::Reboot),
+            "readdir" => Some(Self::Readdir),
+            "mmap" => Some(Self::Mmap),
+            "munmap" => Some(Self::Munmap),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "fchmod" => Some(Self::Fchmod),
+            "fchown" => Some(Self::Fchown),
+            "getpriority" => Some(Self::Getpriority),
+            "setpriority" => Some(Self::Setpriority),
+            "profil" => Some(Self::Profil),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "ioperm" => Some(Self::Ioperm),
+            "socketcall" => Some(Self::Socketcall),
+            "syslog" => Some(Self::Syslog),
+            "setitimer" => Some(Self::Setitimer),
+            "getitimer" => Some(Self::Getitimer),
+            "stat" => Some(Self::Stat),
+            "lstat" => Some(Self::Lstat),
+            "fstat" => Some(Self::Fstat),
+            "iopl" => Some(Self::Iopl),
+            "vhangup" => Some(Self::Vhangup),
+            "idle" => Some(Self::Idle),
+            "vm86" => Some(Self::Vm86),
+            "wait4" => Some(Self::Wait4),
+            "swapoff" => Some(Self::Swapoff),
+            "sysinfo" => Some(Self::Sysinfo),
+            "ipc" => Some(Self::Ipc),
+            "fsync" => Some(Self::Fsync),
+            "sigreturn" => Some(Self::Sigreturn),
+            "clone" => Some(Self::Clone),
+            "setdomainname" => Some(Self::Setdomainname),
+            "uname" => Some(Self::Uname),
+            "modify_ldt" => Some(Self::ModifyLdt),
+            "adjtimex" => Some(Self::Adjtimex),
+            "mprotect" => Some(Self::Mprotect),
+            "sigprocmask" => Some(Self::Sigprocmask),
+            "create_module" => Some(Self::CreateModule),
+            "init_module" => Some(Self::InitModule),
+            "delete_module" => Some(Self::DeleteModule),
+            "get_kernel_syms" => Some(Self::GetKernelSyms),
+            "quotactl" => Some(Self::Quotactl),
+            "getpgid" => Some(Self::Getpgid),
+            "fchdir" => Some(Self::Fchdir),
+            "bdflush" => Some(Self::Bdflush),
+            "sysfs" => Some(Self::Sysfs),
+            "personality" => Some(Self::Personality),
+            "afs_syscall" => Some(Self::AfsSyscall),
+            "setfsuid" => Some(Self::Setfsuid),
+            "setfsgid" => Some(Self::Setfsgid),
+            "_llseek" => Some(Self::Llseek),
+            "getdents" => Some(Self::Getdents),
+            "_newselect" => Some(Self::Newselect),
+            "flock" => Some(Self::Flock),
+            "msync" => Some(Self::Msync),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "cacheflush" => Some(Self::Cacheflush),
+            "cachectl" => Some(Self::Cachectl),
+            "sysmips" => Some(Self::Sysmips),
+            "getsid" => Some(Self::Getsid),
+            "fdatasync" => Some(Self::Fdatasync),
+            "_sysctl" => Some(Self::Sysctl),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_yield" => Some(Self::SchedYield),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "nanosleep" => Some(Self::Nanosleep),
+            "mremap" => Some(Self::Mremap),
+            "accept" => Some(Self::Accept),
+            "bind" => Some(Self::Bind),
+            "connect" => Some(Self::Connect),
+            "getpeername" => Some(Self::Getpeername),
+            "getsockname" => Some(Self::Getsockname),
+            "getsockopt" => Some(Self::Getsockopt),
+            "listen" => Some(Self::Listen),
+            "recv" => Some(Self::Recv),
+            "recvfrom" => Some(Self::Recvfrom),
+            "recvmsg" => Some(Self::Recvmsg),
+            "send" => Some(Self::Send),
+            "sendmsg" => Some(Self::Sendmsg),
+            "sendto" => Some(Self::Sendto),
+            "setsockopt" => Some(Self::Setsockopt),
+            "shutdown" => Some(Self::Shutdown),
+            "socket" => Some(Self::Socket),
+            "socketpair" => Some(Self::Socketpair),
+            "setresuid" => Some(Self::Setresuid),
+            "getresuid" => Some(Self::Getresuid),
+            "query_module" => Some(Self::QueryModule),
+            "poll" => Some(Self::Poll),
+            "nfsservctl" => Some(Self::Nfsservctl),
+            "setresgid" => Some(Self::Setresgid),
+            "getresgid" => Some(Self::Getresgid),
+            "prctl" => Some(Self::Prctl),
+            "rt_sigreturn" => Some(Self::RtSigreturn),
+            "rt_sigaction" => Some(Self::RtSigaction),
+            "rt_sigprocmask" => Some(Self::RtSigprocmask),
+            "rt_sigpending" => Some(Self::RtSigpending),
+            "rt_sigtimedwait" => Some(Self::RtSigtimedwait),
+            "rt_sigqueueinfo" => Some(Self::RtSigqueueinfo),
+            "rt_sigsuspend" => Some(Self::RtSigsuspend),
+            "pread64" => Some(Self::Pread64),
+            "pwrite64" => Some(Self::Pwrite64),
+            "chown" => Some(Self::Chown),
+            "getcwd" => Some(Self::Getcwd),
+            "capget" => Some(Self::Capget),
+            "capset" => Some(Self::Capset),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "sendfile" => Some(Self::Sendfile),
+            "getpmsg" => Some(Self::Getpmsg),
+            "putpmsg" => Some(Self::Putpmsg),
+            "mmap2" => Some(Self::Mmap2),
+            "truncate64" => Some(Self::Truncate64),
+            "ftruncate64" => Some(Self::Ftruncate64),
+            "stat64" => Some(Self::Stat64),
+            "lstat64" => Some(Self::Lstat64),
+            "fstat64" => Some(Self::Fstat64),
+            "pivot_root" => Some(Self::PivotRoot),
+            "mincore" => Some(Self::Mincore),
+            "madvise" => Some(Self::Madvise),
+            "getdents64" => Some(Self::Getdents64),
+            "fcntl64" => Some(Self::Fcntl64),
+            "gettid" => Some(Self::Gettid),
+            "readahead" => Some(Self::Readahead),
+            "setxattr" => Some(Self::Setxattr),
+            "lsetxattr" => Some(Self::Lsetxattr),
+            "fsetxattr" => Some(Self::Fsetxattr),
+            "getxattr" => Some(Self::Getxattr),
+            "lgetxattr" => Some(Self::Lgetxattr),
+            "fgetxattr" => Some(Self::Fgetxattr),
+            "listxattr" => Some(Self::Listxattr),
+            "llistxattr" => Some(Self::Llistxattr),
+            "flistxattr" => Some(Self::Flistxattr),
+            "removexattr" => Some(Self::Removexattr),
+            "lremovexattr" => Some(Self::Lremovexattr),
+            "fremovexattr" => Some(Self::Fremovexattr),
+            "tkill" => Some(Self::Tkill),
+            "sendfile64" => Some(Self::Sendfile64),
+            "futex" => Some(Self::Futex),
+            "sched_setaffinity" => Some(Self::SchedSetaffinity),
+            "sched_getaffinity" => Some(Self::SchedGetaffinity),
+            "io_setup" => Some(Self::IoSetup),
+            "io_destroy" => Some(Self::IoDestroy),
+            "io_getevents" => Some(Self::IoGetevents),
+            "io_submit" => Some(Self::IoSubmit),
+            "io_cancel" => Some(Self::IoCancel),
+            "exit_group" => Some(Self::ExitGroup),
+            "lookup_dcookie" => Some(Self::LookupDcookie),
+            "epoll_create" => Some(Self::EpollCreate),
+            "epoll_ctl" => Some(Self::EpollCtl),
+            "epoll_wait" => Some(Self::EpollWait),
+            "remap_file_pages" => Some(Self::RemapFilePages),
+            "set_tid_address" => Some(Self::SetTidAddress),
+            "restart_syscall" => Some(Self::RestartSyscall),
+            "fadvise64" => Some(Self::Fadvise64),
+            "statfs64" => Some(Self::Statfs64),
+            "fstatfs64" => Some(Self::Fstatfs64),
+            "timer_create" => Some(Self::TimerCreate),
+            "timer_settime" => Some(Self::TimerSettime),
+            "timer_gettime" => Some(Self::TimerGettime),
+            "timer_getoverrun" => Some(Self::TimerGetoverrun),
+            "timer_delete" => Some(Self::TimerDelete),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "tgkill" => Some(Self::Tgkill),
+            "utimes" => Some(Self::Utimes),
+            "mbind" => Some(Self::Mbind),
+            "get_mempolicy" => Some(Self::GetMempolicy),
+            "set_mempolicy" => Some(Self::SetMempolicy),
+            "mq_open" => Some(Self::MqOpen),
+            "mq_unlink" => Some(Self::MqUnlink),
+            "mq_timedsend" => Some(Self::MqTimedsend),
+            "mq_timedreceive" => Some(Self::MqTimedreceive),
+            "mq_notify" => Some(Self::MqNotify),
+            "mq_getsetattr" => Some(Self::MqGetsetattr),
+            "vserver" => Some(Self::Vserver),
+            "waitid" => Some(Self::Waitid),
+            "add_key" => Some(Self::AddKey),
+            "request_key" => Some(Self::RequestKey),
+            "keyctl" => Some(Self::Keyctl),
+            "set_thread_area" => Some(Self::SetThreadArea),
+            "inotify_init" => Some(Self::InotifyInit),
+            "inotify_add_watch" => Some(Self::InotifyAddWatch),
+            "inotify_rm_watch" => Some(Self::InotifyRmWatch),
+            "migrate_pages" => Some(Self::MigratePages),
+            "openat" => Some(Self::Openat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "mknodat" => Some(Self::Mknodat),
+            "fchownat" => Some(Self::Fchownat),
+            "futimesat" => Some(Self::Futimesat),
+            "fstatat64" => Some(Self::Fstatat64),
+            "unlinkat" => Some(Self::Unlinkat),
+            "renameat" => Some(Self::Renameat),
+            "linkat" => Some(Self::Linkat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "readlinkat" => Some(Self::Readlinkat),
+            "fchmodat" => Some(Self::Fchmodat),
+            "faccessat" => Some(Self::Faccessat),
+            "pselect6" => Some(Self::Pselect6),
+            "ppoll" => Some(Self::Ppoll),
+            "unshare" => Some(Self::Unshare),
+            "splice" => Some(Self::Splice),
+            "sync_file_range" => Some(Self::SyncFileRange),
+            "tee" => Some(Self::Tee),
+            "vmsplice" => Some(Self::Vmsplice),
+            "move_pages" => Some(Self::MovePages),
+            "set_robust_list" => Some(Self::SetRobustList),
+            "get_robust_list" => Some(Self::GetRobustList),
+            "kexec_load" => Some(Self::KexecLoad),
+            "getcpu" => Some(Self::Getcpu),
+            "epoll_pwait" => Some(Self::EpollPwait),
+            "ioprio_set" => Some(Self::IoprioSet),
+            "ioprio_get" => Some(Self::IoprioGet),
+            "utimensat" => Some(Self::Utimensat),
+            "signalfd" => Some(Self::Signalfd),
+            "timerfd" => Some(Self::Timerfd),
+            "eventfd" => Some(Self::Eventfd),
+            "fallocate" => Some(Self::Fallocate),
+            "timerfd_create" => Some(Self::TimerfdCreate),
+            "timerfd_gettime" => Some(Self::TimerfdGettime),
+            "timerfd_settime" => Some(Self::TimerfdSettime),
+            "signalfd4" => Some(Self::Signalfd4),
+            "eventfd2" => Some(Self::Eventfd2),
+            "epoll_create1" => Some(Self::EpollCreate1),
+            "dup3" => Some(Self::Dup3),
+            "pipe2" => Some(Self::Pipe2),
+            "inotify_init1" => Some(Self::InotifyInit1),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "rt_tgsigqueueinfo" => Some(Self::RtTgsigqueueinfo),
+            "perf_event_open" => Some(Self::PerfEventOpen),
+            "accept4" => Some(Self::Accept4),
+            "recvmmsg" => Some(Self::Recvmmsg),
+            "fanotify_init" => Some(Self::FanotifyInit),
+            "fanotify_mark" => Some(Self::FanotifyMark),
+            "prlimit64" => Some(Self::Prlimit64),
+            "name_to_handle_at" => Some(Self::NameToHandleAt),
+            "open_by_handle_at" => Some(Self::OpenByHandleAt),
+            "clock_adjtime" => Some(Self::ClockAdjtime),
+            "syncfs" => Some(Self::Syncfs),
+            "sendmmsg" => Some(Self::Sendmmsg),
+            "setns" => Some(Self::Setns),
+            "process_vm_readv" => Some(Self::ProcessVmReadv),
+            "process_vm_writev" => Some(Self::ProcessVmWritev),
+            "kcmp" => Some(Self::Kcmp),
+            "finit_module" => Some(Self::FinitModule),
+            "sched_setattr" => Some(Self::SchedSetattr),
+            "sched_getattr" => Some(Self::SchedGetattr),
+            "renameat2" => Some(Self::Renameat2),
+            "seccomp" => Some(Self::Seccomp),
+            "getrandom" => Some(Self::Getrandom),
+            "memfd_create" => Some(Self::MemfdCreate),
+            "bpf" => Some(Self::Bpf),
+            "execveat" => Some(Self::Execveat),
+            "userfaultfd" => Some(Self::Userfaultfd),
+            "membarrier" => Some(Self::Membarrier),
+            "mlock2" => Some(Self::Mlock2),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "preadv2" => Some(Self::Preadv2),
+            "pwritev2" => Some(Self::Pwritev2),
+            "pkey_mprotect" => Some(Self::PkeyMprotect),
+            "pkey_alloc" => Some(Self::PkeyAlloc),
+            "pkey_free" => Some(Self::PkeyFree),
+            "statx" => Some(Self::Statx),
+            "rseq" => Some(Self::Rseq),
+            "io_pgetevents" => Some(Self::IoPgetevents),
+            "semget" => Some(Self::Semget),
+            "semctl" => Some(Self::Semctl),
+            "shmget" => Some(Self::Shmget),
+            "shmctl" => Some(Self::Shmctl),
+            "shmat" => Some(Self::Shmat),
+            "shmdt" => Some(Self::Shmdt),
+            "msgget" => Some(Self::Msgget),
+            "msgsnd" => Some(Self::Msgsnd),
+            "msgrcv" => Some(Self::Msgrcv),
+            "msgctl" => Some(Self::Msgctl),
+            "clock_gettime64" => Some(Self::ClockGettime64),
+            "clock_settime64" => Some(Self::ClockSettime64),
+            "clock_adjtime64" => Some(Self::ClockAdjtime64),
+            "clock_getres_time64" => Some(Self::ClockGetresTime64),
+            "clock_nanosleep_time64" => Some(Self::ClockNanosleepTime64),
+            "timer_gettime64" => Some(Self::TimerGettime64),
+            "timer_settime64" => Some(Self::TimerSettime64),
+            "timerfd_gettime64" => Some(Self::TimerfdGettime64),
+            "timerfd_settime64" => Some(Self::TimerfdSettime64),
+            "utimensat_time64" => Some(Self::UtimensatTime64),
+            "pselect6_time64" => Some(Self::Pselect6Time64),
+            "ppoll_time64" => Some(Self::PpollTime64),
+            "io_pgetevents_time64" => Some(Self::IoPgeteventsTime64),
+            "recvmmsg_time64" => Some(Self::RecvmmsgTime64),
+            "mq_timedsend_time64" => Some(Self::MqTimedsendTime64),
+            "mq_timedreceive_time64" => Some(Self::MqTimedreceiveTime64),
+            "semtimedop_time64" => Some(Self::SemtimedopTime64),
+            "rt_sigtimedwait_time64" => Some(Self::RtSigtimedwaitTime64),
+            "futex_time64" => Some(Self::FutexTime64),
+            "sched_rr_get_interval_time64" => Some(Self::SchedRrGetIntervalTime64),
+            "pidfd_send_signal" => Some(Self::PidfdSendSignal),
+            "io_uring_setup" => Some(Self::IoUringSetup),
+            "io_uring_enter" => Some(Self::IoUringEnter),
+            "io_uring_register" => Some(Self::IoUringRegister),
+            "open_tree" => Some(Self::OpenTree),
+            "move_mount" => Some(Self::MoveMount),
+            "fsopen" => Some(Self::Fsopen),
+            "fsconfig" => Some(Self::Fsconfig),
+            "fsmount" => Some(Self::Fsmount),
+            "fspick" => Some(Self::Fspick),
+            "pidfd_open" => Some(Self::PidfdOpen),
+            "clone3" => Some(Self::Clone3),
+            "close_range" => Some(Self::CloseRange),
+            "openat2" => Some(Self::Openat2),
+            "pidfd_getfd" => Some(Self::PidfdGetfd),
+            "faccessat2" => Some(Self::Faccessat2),
+            "process_madvise" => Some(Self::ProcessMadvise),
+            "epoll_pwait2" => Some(Self::EpollPwait2),
+            "mount_setattr" => Some(Self::MountSetattr),
+            "quotactl_fd" => Some(Self::QuotactlFd),
+            "landlock_create_ruleset" => Some(Self::LandlockCreateRuleset),
+            "landlock_add_rule" => Some(Self::LandlockAddRule),
+            "landlock_restrict_self" => Some(Self::LandlockRestrictSelf),
+            "process_mrelease" => Some(Self::ProcessMrelease),
+            "futex_waitv" => Some(Self::FutexWaitv),
+            "set_mempolicy_home_node" => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Syscall => "syscall",
+            Self::Exit => "exit",
+            Self::Fork => "fork",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Waitpid => "waitpid",
+            Self::Creat => "creat",
+            Self::Link => "link",
+            Self::Unlink => "unlink",
+            Self::Execve => "execve",
+            Self::Chdir => "chdir",
+            Self::Time => "time",
+            Self::Mknod => "mknod",
+            Self::Chmod => "chmod",
+            Self::Lchown => "lchown",
+            Self::Break => "break",
+            Self::Lseek => "lseek",
+            Self::Getpid => "getpid",
+            Self::Mount => "mount",
+            Self::Umount => "umount",
+            Self::Setuid => "setuid",
+            Self::Getuid => "getuid",
+            Self::Stime => "stime",
+            Self::Ptrace => "ptrace",
+            Self::Alarm => "alarm",
+            Self::Pause => "pause",
+            Self::Utime => "utime",
+            Self::Stty => "stty",
+            Self::Gtty => "gtty",
+            Self::Access => "access",
+            Self::Nice => "nice",
+            Self::Ftime => "ftime",
+            Self::Sync => "sync",
+            Self::Kill => "kill",
+            Self::Rename => "rename",
+            Self::Mkdir => "mkdir",
+            Self::Rmdir => "rmdir",
+            Self::Dup => "dup",
+            Self::Pipe => "pipe",
+            Self::Times => "times",
+            Self::Prof => "prof",
+            Self::Brk => "brk",
+            Self::Setgid => "setgid",
+            Self::Getgid => "getgid",
+            Self::Signal => "signal",
+            Self::Geteuid => "geteuid",
+            Self::Getegid => "getegid",
+            Self::Acct => "acct",
+            Self::Umount2 => "umount2",
+            Self::Lock => "lock",
+            Self::Ioctl => "ioctl",
+            Self::Fcntl => "fcntl",
+            Self::Mpx => "mpx",
+            Self::Setpgid => "setpgid",
+            Self::Ulimit => "ulimit",
+            Self::Umask => "umask",
+            Self::Chroot => "chroot",
+            Self::Ustat => "ustat",
+            Self::Dup2 => "dup2",
+            Self::Getppid => "getppid",
+            Self::Getpgrp => "getpgrp",
+            Self::Setsid => "setsid",
+            Self::Sigaction => "sigaction",
+            Self::Sgetmask => "sgetmask",
+            Self::Ssetmask => "ssetmask",
+            Self::Setreuid => "setreuid",
+            Self::Setregid => "setregid",
+            Self::Sigsuspend => "sigsuspend",
+            Self::Sigpending => "sigpending",
+            Self::Sethostname => "sethostname",
+            Self::Setrlimit => "setrlimit",
+            Self::Getrlimit => "getrlimit",
+            Self::Getrusage => "getrusage",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Settimeofday => "settimeofday",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Symlink => "symlink",
+            Self::Readlink => "readlink",
+            Self::Uselib => "uselib",
+            Self::Swapon => "swapon",
+            Self::Reboot => "reboot",
+            Self::Readdir => "readdir",
+            Self::Mmap => "mmap",
+            Self::Munmap => "munmap",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::Fchmod => "fchmod",
+            Self::Fchown => "fchown",
+            Self::Getpriority => "getpriority",
+            Self::Setpriority => "setpriority",
+            Self::Profil => "profil",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Ioperm => "ioperm",
+            Self::Socketcall => "socketcall",
+            Self::Syslog => "syslog",
+            Self::Setitimer => "setitimer",
+            Self::Getitimer => "getitimer",
+            Self::Stat => "stat",
+            Self::Lstat => "lstat",
+            Self::Fstat => "fstat",
+            Self::Iopl => "iopl",
+            Self::Vhangup => "vhangup",
+            Self::Idle => "idle",
+            Self::Vm86 => "vm86",
+            Self::Wait4 => "wait4",
+            Self::Swapoff => "swapoff",
+            Self::Sysinfo => "sysinfo",
+            Self::Ipc => "ipc",
+            Self::Fsync => "fsync",
+            Self::Sigreturn => "sigreturn",
+            Self::Clone => "clone",
+            Self::Setdomainname => "setdomainname",
+            Self::Uname => "uname",
+            Self::ModifyLdt => "modify_ldt",
+            Self::Adjtimex => "adjtimex",
+            Self::Mprotect => "mprotect",
+            Self::Sigprocmask => "sigprocmask",
+            Self::CreateModule => "create_module",
+            Self::InitModule => "init_module",
+            Self::DeleteModule => "delete_module",
+            Self::GetKernelSyms => "get_kernel_syms",
+            Self::Quotactl => "quotactl",
+            Self::Getpgid => "getpgid",
+            Self::Fchdir => "fchdir",
+            Self::Bdflush => "bdflush",
+            Self::Sysfs => "sysfs",
+            Self::Personality => "personality",
+            Self::AfsSyscall => "afs_syscall",
+            Self::Setfsuid => "setfsuid",
+            Self::Setfsgid => "setfsgid",
+            Self::Llseek => "_llseek",
+            Self::Getdents => "getdents",
+            Self::Newselect => "_newselect",
+            Self::Flock => "flock",
+            Self::Msync => "msync",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Cacheflush => "cacheflush",
+            Self::Cachectl => "cachectl",
+            Self::Sysmips => "sysmips",
+            Self::Getsid => "getsid",
+            Self::Fdatasync => "fdatasync",
+            Self::Sysctl => "_sysctl",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedYield => "sched_yield",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::Nanosleep => "nanosleep",
+            Self::Mremap => "mremap",
+            Self::Accept => "accept",
+            Self::Bind => "bind",
+            Self::Connect => "connect",
+            Self::Getpeername => "getpeername",
+            Self::Getsockname => "getsockname",
+            Self::Getsockopt => "getsockopt",
+            Self::Listen => "listen",
+            Self::Recv => "recv",
+            Self::Recvfrom => "recvfrom",
+            Self::Recvmsg => "recvmsg",
+            Self::Send => "send",
+            Self::Sendmsg => "sendmsg",
+            Self::Sendto => "sendto",
+            Self::Setsockopt => "setsockopt",
+            Self::Shutdown => "shutdown",
+            Self::Socket => "socket",
+            Self::Socketpair => "socketpair",
+            Self::Setresuid => "setresuid",
+            Self::Getresuid => "getresuid",
+            Self::QueryModule => "query_module",
+            Self::Poll => "poll",
+            Self::Nfsservctl => "nfsservctl",
+            Self::Setresgid => "setresgid",
+            Self::Getresgid => "getresgid",
+            Self::Prctl => "prctl",
+            Self::RtSigreturn => "rt_sigreturn",
+            Self::RtSigaction => "rt_sigaction",
+            Self::RtSigprocmask => "rt_sigprocmask",
+            Self::RtSigpending => "rt_sigpending",
+            Self::RtSigtimedwait => "rt_sigtimedwait",
+            Self::RtSigqueueinfo => "rt_sigqueueinfo",
+            Self::RtSigsuspend => "rt_sigsuspend",
+            Self::Pread64 => "pread64",
+            Self::Pwrite64 => "pwrite64",
+            Self::Chown => "chown",
+            Self::Getcwd => "getcwd",
+            Self::Capget => "capget",
+            Self::Capset => "capset",
+            Self::Sigaltstack => "sigaltstack",
+            Self::Sendfile => "sendfile",
+            Self::Getpmsg => "getpmsg",
+            Self::Putpmsg => "putpmsg",
+            Self::Mmap2 => "mmap2",
+            Self::Truncate64 => "truncate64",
+            Self::Ftruncate64 => "ftruncate64",
+            Self::Stat64 => "stat64",
+            Self::Lstat64 => "lstat64",
+            Self::Fstat64 => "fstat64",
+            Self::PivotRoot => "pivot_root",
+            Self::Mincore => "mincore",
+            Self::Madvise => "madvise",
+            Self::Getdents64 => "getdents64",
+            Self::Fcntl64 => "fcntl64",
+            Self::Gettid => "gettid",
+            Self::Readahead => "readahead",
+            Self::Setxattr => "setxattr",
+            Self::Lsetxattr => "lsetxattr",
+            Self::Fsetxattr => "fsetxattr",
+            Self::Getxattr => "getxattr",
+            Self::Lgetxattr => "lgetxattr",
+            Self::Fgetxattr => "fgetxattr",
+            Self::Listxattr => "listxattr",
+            Self::Llistxattr => "llistxattr",
+            Self::Flistxattr => "flistxattr",
+            Self::Removexattr => "removexattr",
+            Self::Lremovexattr => "lremovexattr",
+            Self::Fremovexattr => "fremovexattr",
+            Self::Tkill => "tkill",
+            Self::Sendfile64 => "sendfile64",
+            Self::Futex => "futex",
+            Self::SchedSetaffinity => "sched_setaffinity",
+            Self::SchedGetaffinity => "sched_getaffinity",
+            Self::IoSetup => "io_setup",
+            Self::IoDestroy => "io_destroy",
+            Self::IoGetevents => "io_getevents",
+            Self::IoSubmit => "io_submit",
+            Self::IoCancel => "io_cancel",
+            Self::ExitGroup => "exit_group",
+            Self::LookupDcookie => "lookup_dcookie",
+            Self::EpollCreate => "epoll_create",
+            Self::EpollCtl => "epoll_ctl",
+            Self::EpollWait => "epoll_wait",
+            Self::RemapFilePages => "remap_file_pages",
+            Self::SetTidAddress => "set_tid_address",
+            Self::RestartSyscall => "restart_syscall",
+            Self::Fadvise64 => "fadvise64",
+            Self::Statfs64 => "statfs64",
+            Self::Fstatfs64 => "fstatfs64",
+            Self::TimerCreate => "timer_create",
+            Self::TimerSettime => "timer_settime",
+            Self::TimerGettime => "timer_gettime",
+            Self::TimerGetoverrun => "timer_getoverrun",
+            Self::TimerDelete => "timer_delete",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockGetres => "clock_getres",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::Tgkill => "tgkill",
+            Self::Utimes => "utimes",
+            Self::Mbind => "mbind",
+            Self::GetMempolicy => "get_mempolicy",
+            Self::SetMempolicy => "set_mempolicy",
+            Self::MqOpen => "mq_open",
+            Self::MqUnlink => "mq_unlink",
+            Self::MqTimedsend => "mq_timedsend",
+            Self::MqTimedreceive => "mq_timedreceive",
+            Self::MqNotify => "mq_notify",
+            Self::MqGetsetattr => "mq_getsetattr",
+            Self::Vserver => "vserver",
+            Self::Waitid => "waitid",
+            Self::AddKey => "add_key",
+            Self::RequestKey => "request_key",
+            Self::Keyctl => "keyctl",
+            Self::SetThreadArea => "set_thread_area",
+            Self::InotifyInit => "inotify_init",
+            Self::InotifyAddWatch => "inotify_add_watch",
+            Self::InotifyRmWatch => "inotify_rm_watch",
+            Self::MigratePages => "migrate_pages",
+            Self::Openat => "openat",
+            Self::Mkdirat => "mkdirat",
+            Self::Mknodat => "mknodat",
+            Self::Fchownat => "fchownat",
+            Self::Futimesat => "futimesat",
+            Self::Fstatat64 => "fstatat64",
+            Self::Unlinkat => "unlinkat",
+            Self::Renameat => "renameat",
+            Self::Linkat => "linkat",
+            Self::Symlinkat => "symlinkat",
+            Self::Readlinkat => "readlinkat",
+            Self::Fchmodat => "fchmodat",
+            Self::Faccessat => "faccessat",
+            Self::Pselect6 => "pselect6",
+            Self::Ppoll => "ppoll",
+            Self::Unshare => "unshare",
+            Self::Splice => "splice",
+            Self::SyncFileRange => "sync_file_range",
+            Self::Tee => "tee",
+            Self::Vmsplice => "vmsplice",
+            Self::MovePages => "move_pages",
+            Self::SetRobustList => "set_robust_list",
+            Self::GetRobustList => "get_robust_list",
+            Self::KexecLoad => "kexec_load",
+            Self::Getcpu => "getcpu",
+            Self::EpollPwait => "epoll_pwait",
+            Self::IoprioSet => "ioprio_set",
+            Self::IoprioGet => "ioprio_get",
+            Self::Utimensat => "utimensat",
+            Self::Signalfd => "signalfd",
+            Self::Timerfd => "timerfd",
+            Self::Eventfd => "eventfd",
+            Self::Fallocate => "fallocate",
+            Self::TimerfdCreate => "timerfd_create",
+            Self::TimerfdGettime => "timerfd_gettime",
+            Self::TimerfdSettime => "timerfd_settime",
+            Self::Signalfd4 => "signalfd4",
+            Self::Eventfd2 => "eventfd2",
+            Self::EpollCreate1 => "epoll_create1",
+            Self::Dup3 => "dup3",
+            Self::Pipe2 => "pipe2",
+            Self::InotifyInit1 => "inotify_init1",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::RtTgsigqueueinfo => "rt_tgsigqueueinfo",
+            Self::PerfEventOpen => "perf_event_open",
+            Self::Accept4 => "accept4",
+            Self::Recvmmsg => "recvmmsg",
+            Self::FanotifyInit => "fanotify_init",
+            Self::FanotifyMark => "fanotify_mark",
+            Self::Prlimit64 => "prlimit64",
+            Self::NameToHandleAt => "name_to_handle_at",
+            Self::OpenByHandleAt => "open_by_handle_at",
+            Self::ClockAdjtime => "clock_adjtime",
+            Self::Syncfs => "syncfs",
+            Self::Sendmmsg => "sendmmsg",
+            Self::Setns => "setns",
+            Self::ProcessVmReadv => "process_vm_readv",
+            Self::ProcessVmWritev => "process_vm_writev",
+            Self::Kcmp => "kcmp",
+            Self::FinitModule => "finit_module",
+            Self::SchedSetattr => "sched_setattr",
+            Self::SchedGetattr => "sched_getattr",
+            Self::Renameat2 => "renameat2",
+            Self::Seccomp => "seccomp",
+            Self::Getrandom => "getrandom",
+            Self::MemfdCreate => "memfd_create",
+            Self::Bpf => "bpf",
+            Self::Execveat => "execveat",
+            Self::Userfaultfd => "userfaultfd",
+            Self::Membarrier => "membarrier",
+            Self::Mlock2 => "mlock2",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Preadv2 => "preadv2",
+            Self::Pwritev2 => "pwritev2",
+            Self::PkeyMprotect => "pkey_mprotect",
+            Self::PkeyAlloc => "pkey_alloc",
+            Self::PkeyFree => "pkey_free",
+            Self::Statx => "statx",
+            Self::Rseq => "rseq",
+            Self::IoPgetevents => "io_pgetevents",
+            Self::Semget => "semget",
+            Self::Semctl => "semctl",
+            Self::Shmget => "shmget",
+            Self::Shmctl => "shmctl",
+            Self::Shmat => "shmat",
+            Self::Shmdt => "shmdt",
+            Self::Msgget => "msgget",
+            Self::Msgsnd => "msgsnd",
+            Self::Msgrcv => "msgrcv",
+            Self::Msgctl => "msgctl",
+            Self::ClockGettime64 => "clock_gettime64",
+            Self::ClockSettime64 => "clock_settime64",
+            Self::ClockAdjtime64 => "clock_adjtime64",
+            Self::ClockGetresTime64 => "clock_getres_time64",
+            Self::ClockNanosleepTime64 => "clock_nanosleep_time64",
+            Self::TimerGettime64 => "timer_gettime64",
+            Self::TimerSettime64 => "timer_settime64",
+            Self::TimerfdGettime64 => "timerfd_gettime64",
+            Self::TimerfdSettime64 => "timerfd_settime64",
+            Self::UtimensatTime64 => "utimensat_time64",
+            Self::Pselect6Time64 => "pselect6_time64",
+            Self::PpollTime64 => "ppoll_time64",
+            Self::IoPgeteventsTime64 => "io_pgetevents_time64",
+            Self::RecvmmsgTime64 => "recvmmsg_time64",
+            Self::MqTimedsendTime64 => "mq_timedsend_time64",
+            Self::MqTimedreceiveTime64 => "mq_timedreceive_time64",
+            Self::SemtimedopTime64 => "semtimedop_time64",
+            Self::RtSigtimedwaitTime64 => "rt_sigtimedwait_time64",
+            Self::FutexTime64 => "futex_time64",
+            Self::SchedRrGetIntervalTime64 => "sched_rr_get_interval_time64",
+            Self::PidfdSendSignal => "pidfd_send_signal",
+            Self::IoUringSetup => "io_uring_setup",
+            Self::IoUringEnter => "io_uring_enter",
+            Self::IoUringRegister => "io_uring_register",
+            Self::OpenTree => "open_tree",
+            Self::MoveMount => "move_mount",
+            Self::Fsopen => "fsopen",
+            Self::Fsconfig => "fsconfig",
+            Self::Fsmount => "fsmount",
+            Self::Fspick => "fspick",
+            Self::PidfdOpen => "pidfd_open",
+            Self::Clone3 => "clone3",
+            Self::CloseRange => "close_range",
+            Self::Openat2 => "openat2",
+            Self::PidfdGetfd => "pidfd_getfd",
+            Self::Faccessat2 => "faccessat2",
+            Self::ProcessMadvise => "process_madvise",
+            Self::EpollPwait2 => "epoll_pwait2",
+            Self::MountSetattr => "mount_setattr",
+            Self::QuotactlFd => "quotactl_fd",
+            Self::LandlockCreateRuleset => "landlock_create_ruleset",
+            Self::LandlockAddRule => "landlock_add_rule",
+            Self::LandlockRestrictSelf => "landlock_restrict_self",
+            Self::ProcessMrelease => "process_mrelease",
+            Self::FutexWaitv => "futex_waitv",
+            Self::SetMempolicyHomeNode => "set_mempolicy_home_node",
+        }
+    }
+}
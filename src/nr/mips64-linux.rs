@@ -0,0 +1,2177 @@
+//! System call numbers for `mips64-linux`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/mips/syscall_n64.tbl`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const READ: usize = 5000;
+pub const WRITE: usize = 5001;
+pub const OPEN: usize = 5002;
+pub const CLOSE: usize = 5003;
+pub const STAT: usize = 5004;
+pub const FSTAT: usize = 5005;
+pub const LSTAT: usize = 5006;
+pub const POLL: usize = 5007;
+pub const LSEEK: usize = 5008;
+pub const MMAP: usize = 5009;
+pub const MPROTECT: usize = 5010;
+pub const MUNMAP: usize = 5011;
+pub const BRK: usize = 5012;
+pub const RT_SIGACTION: usize = 5013;
+pub const RT_SIGPROCMASK: usize = 5014;
+pub const IOCTL: usize = 5015;
+pub const PREAD64: usize = 5016;
+pub const PWRITE64: usize = 5017;
+pub const READV: usize = 5018;
+pub const WRITEV: usize = 5019;
+pub const ACCESS: usize = 5020;
+pub const PIPE: usize = 5021;
+pub const _NEWSELECT: usize = 5022;
+pub const SCHED_YIELD: usize = 5023;
+pub const MREMAP: usize = 5024;
+pub const MSYNC: usize = 5025;
+pub const MINCORE: usize = 5026;
+pub const MADVISE: usize = 5027;
+pub const SHMGET: usize = 5028;
+pub const SHMAT: usize = 5029;
+pub const SHMCTL: usize = 5030;
+pub const DUP: usize = 5031;
+pub const DUP2: usize = 5032;
+pub const PAUSE: usize = 5033;
+pub const NANOSLEEP: usize = 5034;
+pub const GETITIMER: usize = 5035;
+pub const SETITIMER: usize = 5036;
+pub const ALARM: usize = 5037;
+pub const GETPID: usize = 5038;
+pub const SENDFILE: usize = 5039;
+pub const SOCKET: usize = 5040;
+pub const CONNECT: usize = 5041;
+pub const ACCEPT: usize = 5042;
+pub const SENDTO: usize = 5043;
+pub const RECVFROM: usize = 5044;
+pub const SENDMSG: usize = 5045;
+pub const RECVMSG: usize = 5046;
+pub const SHUTDOWN: usize = 5047;
+pub const BIND: usize = 5048;
+pub const LISTEN: usize = 5049;
+pub const GETSOCKNAME: usize = 5050;
+pub const GETPEERNAME: usize = 5051;
+pub const SOCKETPAIR: usize = 5052;
+pub const SETSOCKOPT: usize = 5053;
+pub const GETSOCKOPT: usize = 5054;
+pub const CLONE: usize = 5055;
+pub const FORK: usize = 5056;
+pub const EXECVE: usize = 5057;
+pub const EXIT: usize = 5058;
+pub const WAIT4: usize = 5059;
+pub const KILL: usize = 5060;
+pub const UNAME: usize = 5061;
+pub const SEMGET: usize = 5062;
+pub const SEMOP: usize = 5063;
+pub const SEMCTL: usize = 5064;
+pub const SHMDT: usize = 5065;
+pub const MSGGET: usize = 5066;
+pub const MSGSND: usize = 5067;
+pub const MSGRCV: usize = 5068;
+pub const MSGCTL: usize = 5069;
+pub const FCNTL: usize = 5070;
+pub const FLOCK: usize = 5071;
+pub const FSYNC: usize = 5072;
+pub const FDATASYNC: usize = 5073;
+pub const TRUNCATE: usize = 5074;
+pub const FTRUNCATE: usize = 5075;
+pub const GETDENTS: usize = 5076;
+pub const GETCWD: usize = 5077;
+pub const CHDIR: usize = 5078;
+pub const FCHDIR: usize = 5079;
+pub const RENAME: usize = 5080;
+pub const MKDIR: usize = 5081;
+pub const RMDIR: usize = 5082;
+pub const CREAT: usize = 5083;
+pub const LINK: usize = 5084;
+pub const UNLINK: usize = 5085;
+pub const SYMLINK: usize = 5086;
+pub const READLINK: usize = 5087;
+pub const CHMOD: usize = 5088;
+pub const FCHMOD: usize = 5089;
+pub const CHOWN: usize = 5090;
+pub const FCHOWN: usize = 5091;
+pub const LCHOWN: usize = 5092;
+pub const UMASK: usize = 5093;
+pub const GETTIMEOFDAY: usize = 5094;
+pub const GETRLIMIT: usize = 5095;
+pub const GETRUSAGE: usize = 5096;
+pub const SYSINFO: usize = 5097;
+pub const TIMES: usize = 5098;
+pub const PTRACE: usize = 5099;
+pub const GETUID: usize = 5100;
+pub const SYSLOG: usize = 5101;
+pub const GETGID: usize = 5102;
+pub const SETUID: usize = 5103;
+pub const SETGID: usize = 5104;
+pub const GETEUID: usize = 5105;
+pub const GETEGID: usize = 5106;
+pub const SETPGID: usize = 5107;
+pub const GETPPID: usize = 5108;
+pub const GETPGRP: usize = 5109;
+pub const SETSID: usize = 5110;
+pub const SETREUID: usize = 5111;
+pub const SETREGID: usize = 5112;
+pub const GETGROUPS: usize = 5113;
+pub const SETGROUPS: usize = 5114;
+pub const SETRESUID: usize = 5115;
+pub const GETRESUID: usize = 5116;
+pub const SETRESGID: usize = 5117;
+pub const GETRESGID: usize = 5118;
+pub const GETPGID: usize = 5119;
+pub const SETFSUID: usize = 5120;
+pub const SETFSGID: usize = 5121;
+pub const GETSID: usize = 5122;
+pub const CAPGET: usize = 5123;
+pub const CAPSET: usize = 5124;
+pub const RT_SIGPENDING: usize = 5125;
+pub const RT_SIGTIMEDWAIT: usize = 5126;
+pub const RT_SIGQUEUEINFO: usize = 5127;
+pub const RT_SIGSUSPEND: usize = 5128;
+pub const SIGALTSTACK: usize = 5129;
+pub const UTIME: usize = 5130;
+pub const MKNOD: usize = 5131;
+pub const PERSONALITY: usize = 5132;
+pub const USTAT: usize = 5133;
+pub const STATFS: usize = 5134;
+pub const FSTATFS: usize = 5135;
+pub const SYSFS: usize = 5136;
+pub const GETPRIORITY: usize = 5137;
+pub const SETPRIORITY: usize = 5138;
+pub const SCHED_SETPARAM: usize = 5139;
+pub const SCHED_GETPARAM: usize = 5140;
+pub const SCHED_SETSCHEDULER: usize = 5141;
+pub const SCHED_GETSCHEDULER: usize = 5142;
+pub const SCHED_GET_PRIORITY_MAX: usize = 5143;
+pub const SCHED_GET_PRIORITY_MIN: usize = 5144;
+pub const SCHED_RR_GET_INTERVAL: usize = 5145;
+pub const MLOCK: usize = 5146;
+pub const MUNLOCK: usize = 5147;
+pub const MLOCKALL: usize = 5148;
+pub const MUNLOCKALL: usize = 5149;
+pub const VHANGUP: usize = 5150;
+pub const PIVOT_ROOT: usize = 5151;
+pub const _SYSCTL: usize = 5152;
+pub const PRCTL: usize = 5153;
+pub const ADJTIMEX: usize = 5154;
+pub const SETRLIMIT: usize = 5155;
+pub const CHROOT: usize = 5156;
+pub const SYNC: usize = 5157;
+pub const ACCT: usize = 5158;
+pub const SETTIMEOFDAY: usize = 5159;
+pub const MOUNT: usize = 5160;
+pub const UMOUNT2: usize = 5161;
+pub const SWAPON: usize = 5162;
+pub const SWAPOFF: usize = 5163;
+pub const REBOOT: usize = 5164;
+pub const SETHOSTNAME: usize = 5165;
+pub const SETDOMAINNAME: usize = 5166;
+pub const CREATE_MODULE: usize = 5167;
+pub const INIT_MODULE: usize = 5168;
+pub const DELETE_MODULE: usize = 5169;
+pub const GET_KERNEL_SYMS: usize = 5170;
+pub const QUERY_MODULE: usize = 5171;
+pub const QUOTACTL: usize = 5172;
+pub const NFSSERVCTL: usize = 5173;
+pub const GETPMSG: usize = 5174;
+pub const PUTPMSG: usize = 5175;
+pub const AFS_SYSCALL: usize = 5176;
+pub const GETTID: usize = 5178;
+pub const READAHEAD: usize = 5179;
+pub const SETXATTR: usize = 5180;
+pub const LSETXATTR: usize = 5181;
+pub const FSETXATTR: usize = 5182;
+pub const GETXATTR: usize = 5183;
+pub const LGETXATTR: usize = 5184;
+pub const FGETXATTR: usize = 5185;
+pub const LISTXATTR: usize = 5186;
+pub const LLISTXATTR: usize = 5187;
+pub const FLISTXATTR: usize = 5188;
+pub const REMOVEXATTR: usize = 5189;
+pub const LREMOVEXATTR: usize = 5190;
+pub const FREMOVEXATTR: usize = 5191;
+pub const TKILL: usize = 5192;
+pub const FUTEX: usize = 5194;
+pub const SCHED_SETAFFINITY: usize = 5195;
+pub const SCHED_GETAFFINITY: usize = 5196;
+pub const CACHEFLUSH: usize = 5197;
+pub const CACHECTL: usize = 5198;
+pub const SYSMIPS: usize = 5199;
+pub const IO_SETUP: usize = 5200;
+pub const IO_DESTROY: usize = 5201;
+pub const IO_GETEVENTS: usize = 5202;
+pub const IO_SUBMIT: usize = 5203;
+pub const IO_CANCEL: usize = 5204;
+pub const EXIT_GROUP: usize = 5205;
+pub const LOOKUP_DCOOKIE: usize = 5206;
+pub const EPOLL_CREATE: usize = 5207;
+pub const EPOLL_CTL: usize = 5208;
+pub const EPOLL_WAIT: usize = 5209;
+pub const REMAP_FILE_PAGES: usize = 5210;
+pub const RT_SIGRETURN: usize = 5211;
+pub const SET_TID_ADDRESS: usize = 5212;
+pub const RESTART_SYSCALL: usize = 5213;
+pub const SEMTIMEDOP: usize = 5214;
+pub const FADVISE64: usize = 5215;
+pub const TIMER_CREATE: usize = 5216;
+pub const TIMER_SETTIME: usize = 5217;
+pub const TIMER_GETTIME: usize = 5218;
+pub const TIMER_GETOVERRUN: usize = 5219;
+pub const TIMER_DELETE: usize = 5220;
+pub const CLOCK_SETTIME: usize = 5221;
+pub const CLOCK_GETTIME: usize = 5222;
+pub const CLOCK_GETRES: usize = 5223;
+pub const CLOCK_NANOSLEEP: usize = 5224;
+pub const TGKILL: usize = 5225;
+pub const UTIMES: usize = 5226;
+pub const MBIND: usize = 5227;
+pub const GET_MEMPOLICY: usize = 5228;
+pub const SET_MEMPOLICY: usize = 5229;
+pub const MQ_OPEN: usize = 5230;
+pub const MQ_UNLINK: usize = 5231;
+pub const MQ_TIMEDSEND: usize = 5232;
+pub const MQ_TIMEDRECEIVE: usize = 5233;
+pub const MQ_NOTIFY: usize = 5234;
+pub const MQ_GETSETATTR: usize = 5235;
+pub const VSERVER: usize = 5236;
+pub const WAITID: usize = 5237;
+pub const ADD_KEY: usize = 5239;
+pub const REQUEST_KEY: usize = 5240;
+pub const KEYCTL: usize = 5241;
+pub const SET_THREAD_AREA: usize = 5242;
+pub const INOTIFY_INIT: usize = 5243;
+pub const INOTIFY_ADD_WATCH: usize = 5244;
+pub const INOTIFY_RM_WATCH: usize = 5245;
+pub const MIGRATE_PAGES: usize = 5246;
+pub const OPENAT: usize = 5247;
+pub const MKDIRAT: usize = 5248;
+pub const MKNODAT: usize = 5249;
+pub const FCHOWNAT: usize = 5250;
+pub const FUTIMESAT: usize = 5251;
+pub const NEWFSTATAT: usize = 5252;
+pub const UNLINKAT: usize = 5253;
+pub const RENAMEAT: usize = 5254;
+pub const LINKAT: usize = 5255;
+pub const SYMLINKAT: usize = 5256;
+pub const READLINKAT: usize = 5257;
+pub const FCHMODAT: usize = 5258;
+pub const FACCESSAT: usize = 5259;
+pub const PSELECT6: usize = 5260;
+pub const PPOLL: usize = 5261;
+pub const UNSHARE: usize = 5262;
+pub const SPLICE: usize = 5263;
+pub const SYNC_FILE_RANGE: usize = 5264;
+pub const TEE: usize = 5265;
+pub const VMSPLICE: usize = 5266;
+pub const MOVE_PAGES: usize = 5267;
+pub const SET_ROBUST_LIST: usize = 5268;
+pub const GET_ROBUST_LIST: usize = 5269;
+pub const KEXEC_LOAD: usize = 5270;
+pub const GETCPU: usize = 5271;
+pub const EPOLL_PWAIT: usize = 5272;
+pub const IOPRIO_SET: usize = 5273;
+pub const IOPRIO_GET: usize = 5274;
+pub const UTIMENSAT: usize = 5275;
+pub const SIGNALFD: usize = 5276;
+pub const TIMERFD: usize = 5277;
+pub const EVENTFD: usize = 5278;
+pub const FALLOCATE: usize = 5279;
+pub const TIMERFD_CREATE: usize = 5280;
+pub const TIMERFD_GETTIME: usize = 5281;
+pub const TIMERFD_SETTIME: usize = 5282;
+pub const SIGNALFD4: usize = 5283;
+pub const EVENTFD2: usize = 5284;
+pub const EPOLL_CREATE1: usize = 5285;
+pub const DUP3: usize = 5286;
+pub const PIPE2: usize = 5287;
+pub const INOTIFY_INIT1: usize = 5288;
+pub const PREADV: usize = 5289;
+pub const PWRITEV: usize = 5290;
+pub const RT_TGSIGQUEUEINFO: usize = 5291;
+pub const PERF_EVENT_OPEN: usize = 5292;
+pub const ACCEPT4: usize = 5293;
+pub const RECVMMSG: usize = 5294;
+pub const FANOTIFY_INIT: usize = 5295;
+pub const FANOTIFY_MARK: usize = 5296;
+pub const PRLIMIT64: usize = 5297;
+pub const NAME_TO_HANDLE_AT: usize = 5298;
+pub const OPEN_BY_HANDLE_AT: usize = 5299;
+pub const CLOCK_ADJTIME: usize = 5300;
+pub const SYNCFS: usize = 5301;
+pub const SENDMMSG: usize = 5302;
+pub const SETNS: usize = 5303;
+pub const PROCESS_VM_READV: usize = 5304;
+pub const PROCESS_VM_WRITEV: usize = 5305;
+pub const KCMP: usize = 5306;
+pub const FINIT_MODULE: usize = 5307;
+pub const GETDENTS64: usize = 5308;
+pub const SCHED_SETATTR: usize = 5309;
+pub const SCHED_GETATTR: usize = 5310;
+pub const RENAMEAT2: usize = 5311;
+pub const SECCOMP: usize = 5312;
+pub const GETRANDOM: usize = 5313;
+pub const MEMFD_CREATE: usize = 5314;
+pub const BPF: usize = 5315;
+pub const EXECVEAT: usize = 5316;
+pub const USERFAULTFD: usize = 5317;
+pub const MEMBARRIER: usize = 5318;
+pub const MLOCK2: usize = 5319;
+pub const COPY_FILE_RANGE: usize = 5320;
+pub const PREADV2: usize = 5321;
+pub const PWRITEV2: usize = 5322;
+pub const PKEY_MPROTECT: usize = 5323;
+pub const PKEY_ALLOC: usize = 5324;
+pub const PKEY_FREE: usize = 5325;
+pub const STATX: usize = 5326;
+pub const RSEQ: usize = 5327;
+pub const IO_PGETEVENTS: usize = 5328;
+pub const PIDFD_SEND_SIGNAL: usize = 5424;
+pub const IO_URING_SETUP: usize = 5425;
+pub const IO_URING_ENTER: usize = 5426;
+pub const IO_URING_REGISTER: usize = 5427;
+pub const OPEN_TREE: usize = 5428;
+pub const MOVE_MOUNT: usize = 5429;
+pub const FSOPEN: usize = 5430;
+pub const FSCONFIG: usize = 5431;
+pub const FSMOUNT: usize = 5432;
+pub const FSPICK: usize = 5433;
+pub const PIDFD_OPEN: usize = 5434;
+pub const CLONE3: usize = 5435;
+pub const CLOSE_RANGE: usize = 5436;
+pub const OPENAT2: usize = 5437;
+pub const PIDFD_GETFD: usize = 5438;
+pub const FACCESSAT2: usize = 5439;
+pub const PROCESS_MADVISE: usize = 5440;
+pub const EPOLL_PWAIT2: usize = 5441;
+pub const MOUNT_SETATTR: usize = 5442;
+pub const QUOTACTL_FD: usize = 5443;
+pub const LANDLOCK_CREATE_RULESET: usize = 5444;
+pub const LANDLOCK_ADD_RULE: usize = 5445;
+pub const LANDLOCK_RESTRICT_SELF: usize = 5446;
+pub const PROCESS_MRELEASE: usize = 5448;
+pub const FUTEX_WAITV: usize = 5449;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 5450;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    Read = READ,
+    Write = WRITE,
+    Open = OPEN,
+    Close = CLOSE,
+    Stat = STAT,
+    Fstat = FSTAT,
+    Lstat = LSTAT,
+    Poll = POLL,
+    Lseek = LSEEK,
+    Mmap = MMAP,
+    Mprotect = MPROTECT,
+    Munmap = MUNMAP,
+    Brk = BRK,
+    RtSigaction = RT_SIGACTION,
+    RtSigprocmask = RT_SIGPROCMASK,
+    Ioctl = IOCTL,
+    Pread64 = PREAD64,
+    Pwrite64 = PWRITE64,
+    Readv = READV,
+    Writev = WRITEV,
+    Access = ACCESS,
+    Pipe = PIPE,
+    Newselect = _NEWSELECT,
+    SchedYield = SCHED_YIELD,
+    Mremap = MREMAP,
+    Msync = MSYNC,
+    Mincore = MINCORE,
+    Madvise = MADVISE,
+    Shmget = SHMGET,
+    Shmat = SHMAT,
+    Shmctl = SHMCTL,
+    Dup = DUP,
+    Dup2 = DUP2,
+    Pause = PAUSE,
+    Nanosleep = NANOSLEEP,
+    Getitimer = GETITIMER,
+    Setitimer = SETITIMER,
+    Alarm = ALARM,
+    Getpid = GETPID,
+    Sendfile = SENDFILE,
+    Socket = SOCKET,
+    Connect = CONNECT,
+    Accept = ACCEPT,
+    Sendto = SENDTO,
+    Recvfrom = RECVFROM,
+    Sendmsg = SENDMSG,
+    Recvmsg = RECVMSG,
+    Shutdown = SHUTDOWN,
+    Bind = BIND,
+    Listen = LISTEN,
+    Getsockname = GETSOCKNAME,
+    Getpeername = GETPEERNAME,
+    Socketpair = SOCKETPAIR,
+    Setsockopt = SETSOCKOPT,
+    Getsockopt = GETSOCKOPT,
+    Clone = CLONE,
+    Fork = FORK,
+    Execve = EXECVE,
+    Exit = EXIT,
+    Wait4 = WAIT4,
+    Kill = KILL,
+    Uname = UNAME,
+    Semget = SEMGET,
+    Semop = SEMOP,
+    Semctl = SEMCTL,
+    Shmdt = SHMDT,
+    Msgget = MSGGET,
+    Msgsnd = MSGSND,
+    Msgrcv = MSGRCV,
+    Msgctl = MSGCTL,
+    Fcntl = FCNTL,
+    Flock = FLOCK,
+    Fsync = FSYNC,
+    Fdatasync = FDATASYNC,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    Getdents = GETDENTS,
+    Getcwd = GETCWD,
+    Chdir = CHDIR,
+    Fchdir = FCHDIR,
+    Rename = RENAME,
+    Mkdir = MKDIR,
+    Rmdir = RMDIR,
+    Creat = CREAT,
+    Link = LINK,
+    Unlink = UNLINK,
+    Symlink = SYMLINK,
+    Readlink = READLINK,
+    Chmod = CHMOD,
+    Fchmod = FCHMOD,
+    Chown = CHOWN,
+    Fchown = FCHOWN,
+    Lchown = LCHOWN,
+    Umask = UMASK,
+    Gettimeofday = GETTIMEOFDAY,
+    Getrlimit = GETRLIMIT,
+    Getrusage = GETRUSAGE,
+    Sysinfo = SYSINFO,
+    Times = TIMES,
+    Ptrace = PTRACE,
+    Getuid = GETUID,
+    Syslog = SYSLOG,
+    Getgid = GETGID,
+    Setuid = SETUID,
+    Setgid = SETGID,
+    Geteuid = GETEUID,
+    Getegid = GETEGID,
+    Setpgid = SETPGID,
+    Getppid = GETPPID,
+    Getpgrp = GETPGRP,
+    Setsid = SETSID,
+    Setreuid = SETREUID,
+    Setregid = SETREGID,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Setresuid = SETRESUID,
+    Getresuid = GETRESUID,
+    Setresgid = SETRESGID,
+    Getresgid = GETRESGID,
+    Getpgid = GETPGID,
+    Setfsuid = SETFSUID,
+    Setfsgid = SETFSGID,
+    Getsid = GETSID,
+    Capget = CAPGET,
+    Capset = CAPSET,
+    RtSigpending = RT_SIGPENDING,
+    RtSigtimedwait = RT_SIGTIMEDWAIT,
+    RtSigqueueinfo = RT_SIGQUEUEINFO,
+    RtSigsuspend = RT_SIGSUSPEND,
+    Sigaltstack = SIGALTSTACK,
+    Utime = UTIME,
+    Mknod = MKNOD,
+    Personality = PERSONALITY,
+    Ustat = USTAT,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Sysfs = SYSFS,
+    Getpriority = GETPRIORITY,
+    Setpriority = SETPRIORITY,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    Vhangup = VHANGUP,
+    PivotRoot = PIVOT_ROOT,
+    Sysctl = _SYSCTL,
+    Prctl = PRCTL,
+    Adjtimex = ADJTIMEX,
+    Setrlimit = SETRLIMIT,
+    Chroot = CHROOT,
+    Sync = SYNC,
+    Acct = ACCT,
+    Settimeofday = SETTIMEOFDAY,
+    Mount = MOUNT,
+    Umount2 = UMOUNT2,
+    Swapon = SWAPON,
+    Swapoff = SWAPOFF,
+    Reboot = REBOOT,
+    Sethostname = SETHOSTNAME,
+    Setdomainname = SETDOMAINNAME,
+    CreateModule = CREATE_MODULE,
+    InitModule = INIT_MODULE,
+    DeleteModule = DELETE_MODULE,
+    GetKernelSyms = GET_KERNEL_SYMS,
+    QueryModule = QUERY_MODULE,
+    Quotactl = QUOTACTL,
+    Nfsservctl = NFSSERVCTL,
+    Getpmsg = GETPMSG,
+    Putpmsg = PUTPMSG,
+    AfsSyscall = AFS_SYSCALL,
+    Gettid = GETTID,
+    Readahead = READAHEAD,
+    Setxattr = SETXATTR,
+    Lsetxattr = LSETXATTR,
+    Fsetxattr = FSETXATTR,
+    Getxattr = GETXATTR,
+    Lgetxattr = LGETXATTR,
+    Fgetxattr = FGETXATTR,
+    Listxattr = LISTXATTR,
+    Llistxattr = LLISTXATTR,
+    Flistxattr = FLISTXATTR,
+    Removexattr = REMOVEXATTR,
+    Lremovexattr = LREMOVEXATTR,
+    Fremovexattr = FREMOVEXATTR,
+    Tkill = TKILL,
+    Futex = FUTEX,
+    SchedSetaffinity = SCHED_SETAFFINITY,
+    SchedGetaffinity = SCHED_GETAFFINITY,
+    Cacheflush = CACHEFLUSH,
+    Cachectl = CACHECTL,
+    Sysmips = SYSMIPS,
+    IoSetup = IO_SETUP,
+    IoDestroy = IO_DESTROY,
+    IoGetevents = IO_GETEVENTS,
+    IoSubmit = IO_SUBMIT,
+    IoCancel = IO_CANCEL,
+    ExitGroup = EXIT_GROUP,
+    LookupDcookie = LOOKUP_DCOOKIE,
+    EpollCreate = EPOLL_CREATE,
+    EpollCtl = EPOLL_CTL,
+    EpollWait = EPOLL_WAIT,
+    RemapFilePages = REMAP_FILE_PAGES,
+    RtSigreturn = RT_SIGRETURN,
+    SetTidAddress = SET_TID_ADDRESS,
+    RestartSyscall = RESTART_SYSCALL,
+    Semtimedop = SEMTIMEDOP,
+    Fadvise64 = FADVISE64,
+    TimerCreate = TIMER_CREATE,
+    TimerSettime = TIMER_SETTIME,
+    TimerGettime = TIMER_GETTIME,
+    TimerGetoverrun = TIMER_GETOVERRUN,
+    TimerDelete = TIMER_DELETE,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGettime = CLOCK_GETTIME,
+    ClockGetres = CLOCK_GETRES,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    Tgkill = TGKILL,
+    Utimes = UTIMES,
+    Mbind = MBIND,
+    GetMempolicy = GET_MEMPOLICY,
+    SetMempolicy = SET_MEMPOLICY,
+    MqOpen = MQ_OPEN,
+    MqUnlink = MQ_UNLINK,
+    MqTimedsend = MQ_TIMEDSEND,
+    MqTimedreceive = MQ_TIMEDRECEIVE,
+    MqNotify = MQ_NOTIFY,
+    MqGetsetattr = MQ_GETSETATTR,
+    Vserver = VSERVER,
+    Waitid = WAITID,
+    AddKey = ADD_KEY,
+    RequestKey = REQUEST_KEY,
+    Keyctl = KEYCTL,
+    SetThreadArea = SET_THREAD_AREA,
+    InotifyInit = INOTIFY_INIT,
+    InotifyAddWatch = INOTIFY_ADD_WATCH,
+    InotifyRmWatch = INOTIFY_RM_WATCH,
+    MigratePages = MIGRATE_PAGES,
+    Openat = OPENAT,
+    Mkdirat = MKDIRAT,
+    Mknodat = MKNODAT,
+    Fchownat = FCHOWNAT,
+    Futimesat = FUTIMESAT,
+    Newfstatat = NEWFSTATAT,
+    Unlinkat = UNLINKAT,
+    Renameat = RENAMEAT,
+    Linkat = LINKAT,
+    Symlinkat = SYMLINKAT,
+    Readlinkat = READLINKAT,
+    Fchmodat = FCHMODAT,
+    Faccessat = FACCESSAT,
+    Pselect6 = PSELECT6,
+    Ppoll = PPOLL,
+    Unshare = UNSHARE,
+    Splice = SPLICE,
+    SyncFileRange = SYNC_FILE_RANGE,
+    Tee = TEE,
+    Vmsplice = VMSPLICE,
+    MovePages = MOVE_PAGES,
+    SetRobustList = SET_ROBUST_LIST,
+    GetRobustList = GET_ROBUST_LIST,
+    KexecLoad = KEXEC_LOAD,
+    Getcpu = GETCPU,
+    EpollPwait = EPOLL_PWAIT,
+    IoprioSet = IOPRIO_SET,
+    IoprioGet = IOPRIO_GET,
+    Utimensat = UTIMENSAT,
+    Signalfd = SIGNALFD,
+    Timerfd = TIMERFD,
+    Eventfd = EVENTFD,
+    Fallocate = FALLOCATE,
+    TimerfdCreate = TIMERFD_CREATE,
+    TimerfdGettime = TIMERFD_GETTIME,
+    TimerfdSettime = TIMERFD_SETTIME,
+    Signalfd4 = SIGNALFD4,
+    Eventfd2 = EVENTFD2,
+    EpollCreate1 = EPOLL_CREATE1,
+    Dup3 = DUP3,
+    Pipe2 = PIPE2,
+    InotifyInit1 = INOTIFY_INIT1,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    RtTgsigqueueinfo = RT_TGSIGQUEUEINFO,
+    PerfEventOpen = PERF_EVENT_OPEN,
+    Accept4 = ACCEPT4,
+    Recvmmsg = RECVMMSG,
+    FanotifyInit = FANOTIFY_INIT,
+    FanotifyMark = FANOTIFY_MARK,
+    Prlimit64 = PRLIMIT64,
+    NameToHandleAt = NAME_TO_HANDLE_AT,
+    OpenByHandleAt = OPEN_BY_HANDLE_AT,
+    ClockAdjtime = CLOCK_ADJTIME,
+    Syncfs = SYNCFS,
+    Sendmmsg = SENDMMSG,
+    Setns = SETNS,
+    ProcessVmReadv = PROCESS_VM_READV,
+    ProcessVmWritev = PROCESS_VM_WRITEV,
+    Kcmp = KCMP,
+    FinitModule = FINIT_MODULE,
+    Getdents64 = GETDENTS64,
+    SchedSetattr = SCHED_SETATTR,
+    SchedGetattr = SCHED_GETATTR,
+    Renameat2 = RENAMEAT2,
+    Seccomp = SECCOMP,
+    Getrandom = GETRANDOM,
+    MemfdCreate = MEMFD_CREATE,
+    Bpf = BPF,
+    Execveat = EXECVEAT,
+    Userfaultfd = USERFAULTFD,
+    Membarrier = MEMBARRIER,
+    Mlock2 = MLOCK2,
+    CopyFileRange = COPY_FILE_RANGE,
+    Preadv2 = PREADV2,
+    Pwritev2 = PWRITEV2,
+    PkeyMprotect = PKEY_MPROTECT,
+    PkeyAlloc = PKEY_ALLOC,
+    PkeyFree = PKEY_FREE,
+    Statx = STATX,
+    Rseq = RSEQ,
+    IoPgetevents = IO_PGETEVENTS,
+    PidfdSendSignal = PIDFD_SEND_SIGNAL,
+    IoUringSetup = IO_URING_SETUP,
+    IoUringEnter = IO_URING_ENTER,
+    IoUringRegister = IO_URING_REGISTER,
+    OpenTree = OPEN_TREE,
+    MoveMount = MOVE_MOUNT,
+    Fsopen = FSOPEN,
+    Fsconfig = FSCONFIG,
+    Fsmount = FSMOUNT,
+    Fspick = FSPICK,
+    PidfdOpen = PIDFD_OPEN,
+    Clone3 = CLONE3,
+    CloseRange = CLOSE_RANGE,
+    Openat2 = OPENAT2,
+    PidfdGetfd = PIDFD_GETFD,
+    Faccessat2 = FACCESSAT2,
+    ProcessMadvise = PROCESS_MADVISE,
+    EpollPwait2 = EPOLL_PWAIT2,
+    MountSetattr = MOUNT_SETATTR,
+    QuotactlFd = QUOTACTL_FD,
+    LandlockCreateRuleset = LANDLOCK_CREATE_RULESET,
+    LandlockAddRule = LANDLOCK_ADD_RULE,
+    LandlockRestrictSelf = LANDLOCK_RESTRICT_SELF,
+    ProcessMrelease = PROCESS_MRELEASE,
+    FutexWaitv = FUTEX_WAITV,
+    SetMempolicyHomeNode = SET_MEMPOLICY_HOME_NODE,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::Read,
+        Self::Write,
+        Self::Open,
+        Self::Close,
+        Self::Stat,
+        Self::Fstat,
+        Self::Lstat,
+        Self::Poll,
+        Self::Lseek,
+        Self::Mmap,
+        Self::Mprotect,
+        Self::Munmap,
+        Self::Brk,
+        Self::RtSigaction,
+        Self::RtSigprocmask,
+        Self::Ioctl,
+        Self::Pread64,
+        Self::Pwrite64,
+        Self::Readv,
+        Self::Writev,
+        Self::Access,
+        Self::Pipe,
+        Self::Newselect,
+        Self::SchedYield,
+        Self::Mremap,
+        Self::Msync,
+        Self::Mincore,
+        Self::Madvise,
+        Self::Shmget,
+        Self::Shmat,
+        Self::Shmctl,
+        Self::Dup,
+        Self::Dup2,
+        Self::Pause,
+        Self::Nanosleep,
+        Self::Getitimer,
+        Self::Setitimer,
+        Self::Alarm,
+        Self::Getpid,
+        Self::Sendfile,
+        Self::Socket,
+        Self::Connect,
+        Self::Accept,
+        Self::Sendto,
+        Self::Recvfrom,
+        Self::Sendmsg,
+        Self::Recvmsg,
+        Self::Shutdown,
+        Self::Bind,
+        Self::Listen,
+        Self::Getsockname,
+        Self::Getpeername,
+        Self::Socketpair,
+        Self::Setsockopt,
+        Self::Getsockopt,
+        Self::Clone,
+        Self::Fork,
+        Self::Execve,
+        Self::Exit,
+        Self::Wait4,
+        Self::Kill,
+        Self::Uname,
+        Self::Semget,
+        Self::Semop,
+        Self::Semctl,
+        Self::Shmdt,
+        Self::Msgget,
+        Self::Msgsnd,
+        Self::Msgrcv,
+        Self::Msgctl,
+        Self::Fcntl,
+        Self::Flock,
+        Self::Fsync,
+        Self::Fdatasync,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::Getdents,
+        Self::Getcwd,
+        Self::Chdir,
+        Self::Fchdir,
+        Self::Rename,
+        Self::Mkdir,
+        Self::Rmdir,
+        Self::Creat,
+        Self::Link,
+        Self::Unlink,
+        Self::Symlink,
+        Self::Readlink,
+        Self::Chmod,
+        Self::Fchmod,
+        Self::Chown,
+        Self::Fchown,
+        Self::Lchown,
+        Self::Umask,
+        Self::Gettimeofday,
+        Self::Getrlimit,
+        Self::Getrusage,
+        Self::Sysinfo,
+        Self::Times,
+        Self::Ptrace,
+        Self::Getuid,
+        Self::Syslog,
+        Self::Getgid,
+        Self::Setuid,
+        Self::Setgid,
+        Self::Geteuid,
+        Self::Getegid,
+        Self::Setpgid,
+        Self::Getppid,
+        Self::Getpgrp,
+        Self::Setsid,
+        Self::Setreuid,
+        Self::Setregid,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Setresuid,
+        Self::Getresuid,
+        Self::Setresgid,
+        Self::Getresgid,
+        Self::Getpgid,
+        Self::Setfsuid,
+        Self::Setfsgid,
+        Self::Getsid,
+        Self::Capget,
+        Self::Capset,
+        Self::RtSigpending,
+        Self::RtSigtimedwait,
+        Self::RtSigqueueinfo,
+        Self::RtSigsuspend,
+        Self::Sigaltstack,
+        Self::Utime,
+        Self::Mknod,
+        Self::Personality,
+        Self::Ustat,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Sysfs,
+        Self::Getpriority,
+        Self::Setpriority,
+        Self::SchedSetparam,
+        Self::SchedGetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::Vhangup,
+        Self::PivotRoot,
+        Self::Sysctl,
+        Self::Prctl,
+        Self::Adjtimex,
+        Self::Setrlimit,
+        Self::Chroot,
+        Self::Sync,
+        Self::Acct,
+        Self::Settimeofday,
+        Self::Mount,
+        Self::Umount2,
+        Self::Swapon,
+        Self::Swapoff,
+        Self::Reboot,
+        Self::Sethostname,
+        Self::Setdomainname,
+        Self::CreateModule,
+        Self::InitModule,
+        Self::DeleteModule,
+        Self::GetKernelSyms,
+        Self::QueryModule,
+        Self::Quotactl,
+        Self::Nfsservctl,
+        Self::Getpmsg,
+        Self::Putpmsg,
+        Self::AfsSyscall,
+        Self::Gettid,
+        Self::Readahead,
+        Self::Setxattr,
+        Self::Lsetxattr,
+        Self::Fsetxattr,
+        Self::Getxattr,
+        Self::Lgetxattr,
+        Self::Fgetxattr,
+        Self::Listxattr,
+        Self::Llistxattr,
+        Self::Flistxattr,
+        Self::Removexattr,
+        Self::Lremovexattr,
+        Self::Fremovexattr,
+        Self::Tkill,
+        Self::Futex,
+        Self::SchedSetaffinity,
+        Self::SchedGetaffinity,
+        Self::Cacheflush,
+        Self::Cachectl,
+        Self::Sysmips,
+        Self::IoSetup,
+        Self::IoDestroy,
+        Self::IoGetevents,
+        Self::IoSubmit,
+        Self::IoCancel,
+        Self::ExitGroup,
+        Self::LookupDcookie,
+        Self::EpollCreate,
+        Self::EpollCtl,
+        Self::EpollWait,
+        Self::RemapFilePages,
+        Self::RtSigreturn,
+        Self::SetTidAddress,
+        Self::RestartSyscall,
+        Self::Semtimedop,
+        Self::Fadvise64,
+        Self::TimerCreate,
+        Self::TimerSettime,
+        Self::TimerGettime,
+        Self::TimerGetoverrun,
+        Self::TimerDelete,
+        Self::ClockSettime,
+        Self::ClockGettime,
+        Self::ClockGetres,
+        Self::ClockNanosleep,
+        Self::Tgkill,
+        Self::Utimes,
+        Self::Mbind,
+        Self::GetMempolicy,
+        Self::SetMempolicy,
+        Self::MqOpen,
+        Self::MqUnlink,
+        Self::MqTimedsend,
+        Self::MqTimedreceive,
+        Self::MqNotify,
+        Self::MqGetsetattr,
+        Self::Vserver,
+        Self::Waitid,
+        Self::AddKey,
+        Self::RequestKey,
+        Self::Keyctl,
+        Self::SetThreadArea,
+        Self::InotifyInit,
+        Self::InotifyAddWatch,
+        Self::InotifyRmWatch,
+        Self::MigratePages,
+        Self::Openat,
+        Self::Mkdirat,
+        Self::Mknodat,
+        Self::Fchownat,
+        Self::Futimesat,
+        Self::Newfstatat,
+        Self::Unlinkat,
+        Self::Renameat,
+        Self::Linkat,
+        Self::Symlinkat,
+        Self::Readlinkat,
+        Self::Fchmodat,
+        Self::Faccessat,
+        Self::Pselect6,
+        Self::Ppoll,
+        Self::Unshare,
+        Self::Splice,
+        Self::SyncFileRange,
+        Self::Tee,
+        Self::Vmsplice,
+        Self::MovePages,
+        Self::SetRobustList,
+        Self::GetRobustList,
+        Self::KexecLoad,
+        Self::Getcpu,
+        Self::EpollPwait,
+        Self::IoprioSet,
+        Self::IoprioGet,
+        Self::Utimensat,
+        Self::Signalfd,
+        Self::Timerfd,
+        Self::Eventfd,
+        Self::Fallocate,
+        Self::TimerfdCreate,
+        Self::TimerfdGettime,
+        Self::TimerfdSettime,
+        Self::Signalfd4,
+        Self::Eventfd2,
+        Self::EpollCreate1,
+        Self::Dup3,
+        Self::Pipe2,
+        Self::InotifyInit1,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::RtTgsigqueueinfo,
+        Self::PerfEventOpen,
+        Self::Accept4,
+        Self::Recvmmsg,
+        Self::FanotifyInit,
+        Self::FanotifyMark,
+        Self::Prlimit64,
+        Self::NameToHandleAt,
+        Self::OpenByHandleAt,
+        Self::ClockAdjtime,
+        Self::Syncfs,
+        Self::Sendmmsg,
+        Self::Setns,
+        Self::ProcessVmReadv,
+        Self::ProcessVmWritev,
+        Self::Kcmp,
+        Self::FinitModule,
+        Self::Getdents64,
+        Self::SchedSetattr,
+        Self::SchedGetattr,
+        Self::Renameat2,
+        Self::Seccomp,
+        Self::Getrandom,
+        Self::MemfdCreate,
+        Self::Bpf,
+        Self::Execveat,
+        Self::Userfaultfd,
+        Self::Membarrier,
+        Self::Mlock2,
+        Self::CopyFileRange,
+        Self::Preadv2,
+        Self::Pwritev2,
+        Self::PkeyMprotect,
+        Self::PkeyAlloc,
+        Self::PkeyFree,
+        Self::Statx,
+        Self::Rseq,
+        Self::IoPgetevents,
+        Self::PidfdSendSignal,
+        Self::IoUringSetup,
+        Self::IoUringEnter,
+        Self::IoUringRegister,
+        Self::OpenTree,
+        Self::MoveMount,
+        Self::Fsopen,
+        Self::Fsconfig,
+        Self::Fsmount,
+        Self::Fspick,
+        Self::PidfdOpen,
+        Self::Clone3,
+        Self::CloseRange,
+        Self::Openat2,
+        Self::PidfdGetfd,
+        Self::Faccessat2,
+        Self::ProcessMadvise,
+        Self::EpollPwait2,
+        Self::MountSetattr,
+        Self::QuotactlFd,
+        Self::LandlockCreateRuleset,
+        Self::LandlockAddRule,
+        Self::LandlockRestrictSelf,
+        Self::ProcessMrelease,
+        Self::FutexWaitv,
+        Self::SetMempolicyHomeNode,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            OPEN => Some(Self::Open),
+            CLOSE => Some(Self::Close),
+            STAT => Some(Self::Stat),
+            FSTAT => Some(Self::Fstat),
+            LSTAT => Some(Self::Lstat),
+            POLL => Some(Self::Poll),
+            LSEEK => Some(Self::Lseek),
+            MMAP => Some(Self::Mmap),
+            MPROTECT => Some(Self::Mprotect),
+            MUNMAP => Some(Self::Munmap),
+            BRK => Some(Self::Brk),
+            RT_SIGACTION => Some(Self::RtSigaction),
+            RT_SIGPROCMASK => Some(Self::RtSigprocmask),
+            IOCTL => Some(Self::Ioctl),
+            PREAD64 => Some(Self::Pread64),
+            PWRITE64 => Some(Self::Pwrite64),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            ACCESS => Some(Self::Access),
+            PIPE => Some(Self::Pipe),
+            _NEWSELECT => Some(Self::Newselect),
+            SCHED_YIELD => Some(Self::SchedYield),
+            MREMAP => Some(Self::Mremap),
+            MSYNC => Some(Self::Msync),
+            MINCORE => Some(Self::Mincore),
+            MADVISE => Some(Self::Madvise),
+            SHMGET => Some(Self::Shmget),
+            SHMAT => Some(Self::Shmat),
+            SHMCTL => Some(Self::Shmctl),
+            DUP => Some(Self::Dup),
+            DUP2 => Some(Self::Dup2),
+            PAUSE => Some(Self::Pause),
+            NANOSLEEP => Some(Self::Nanosleep),
+            GETITIMER => Some(Self::Getitimer),
+            SETITIMER => Some(Self::Setitimer),
+            ALARM => Some(Self::Alarm),
+            GETPID => Some(Self::Getpid),
+            SENDFILE => Some(Self::Sendfile),
+            SOCKET => Some(Self::Socket),
+            CONNECT => Some(Self::Connect),
+            ACCEPT => Some(Self::Accept),
+            SENDTO => Some(Self::Sendto),
+            RECVFROM => Some(Self::Recvfrom),
+            SENDMSG => Some(Self::Sendmsg),
+            RECVMSG => Some(Self::Recvmsg),
+            SHUTDOWN => Some(Self::Shutdown),
+            BIND => Some(Self::Bind),
+            LISTEN => Some(Self::Listen),
+            GETSOCKNAME => Some(Self::Getsockname),
+            GETPEERNAME => Some(Self::Getpeername),
+            SOCKETPAIR => Some(Self::Socketpair),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            CLONE => Some(Self::Clone),
+            FORK => Some(Self::Fork),
+            EXECVE => Some(Self::Execve),
+            EXIT => Some(Self::Exit),
+            WAIT4 => Some(Self::Wait4),
+            KILL => Some(Self::Kill),
+            UNAME => Some(Self::Uname),
+            SEMGET => Some(Self::Semget),
+            SEMOP => Some(Self::Semop),
+            SEMCTL => Some(Self::Semctl),
+            SHMDT => Some(Self::Shmdt),
+            MSGGET => Some(Self::Msgget),
+            MSGSND => Some(Self::Msgsnd),
+            MSGRCV => Some(Self::Msgrcv),
+            MSGCTL => Some(Self::Msgctl),
+            FCNTL => Some(Self::Fcntl),
+            FLOCK => Some(Self::Flock),
+            FSYNC => Some(Self::Fsync),
+            FDATASYNC => Some(Self::Fdatasync),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            GETDENTS => Some(Self::Getdents),
+            GETCWD => Some(Self::Getcwd),
+            CHDIR => Some(Self::Chdir),
+            FCHDIR => Some(Self::Fchdir),
+            RENAME => Some(Self::Rename),
+            MKDIR => Some(Self::Mkdir),
+            RMDIR => Some(Self::Rmdir),
+            CREAT => Some(Self::Creat),
+            LINK => Some(Self::Link),
+            UNLINK => Some(Self::Unlink),
+            SYMLINK => Some(Self::Symlink),
+            READLINK => Some(Self::Readlink),
+            CHMOD => Some(Self::Chmod),
+            FCHMOD => Some(Self::Fchmod),
+            CHOWN => Some(Self::Chown),
+            FCHOWN => Some(Self::Fchown),
+            LCHOWN => Some(Self::Lchown),
+            UMASK => Some(Self::Umask),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            GETRLIMIT => Some(Self::Getrlimit),
+            GETRUSAGE => Some(Self::Getrusage),
+            SYSINFO => Some(Self::Sysinfo),
+            TIMES => Some(Self::Times),
+            PTRACE => Some(Self::Ptrace),
+            GETUID => Some(Self::Getuid),
+            SYSLOG => Some(Self::Syslog),
+            GETGID => Some(Self::Getgid),
+            SETUID => Some(Self::Setuid),
+            SETGID => Some(Self::Setgid),
+            GETEUID => Some(Self::Geteuid),
+            GETEGID => Some(Self::Getegid),
+            SETPGID => Some(Self::Setpgid),
+            GETPPID => Some(Self::Getppid),
+            GETPGRP => Some(Self::Getpgrp),
+            SETSID => Some(Self::Setsid),
+            SETREUID => Some(Self::Setreuid),
+            SETREGID => Some(Self::Setregid),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            SETRESUID => Some(Self::Setresuid),
+            GETRESUID => Some(Self::Getresuid),
+            SETRESGID => Some(Self::Setresgid),
+            GETRESGID => Some(Self::Getresgid),
+            GETPGID => Some(Self::Getpgid),
+            SETFSUID => Some(Self::Setfsuid),
+            SETFSGID => Some(Self::Setfsgid),
+            GETSID => Some(Self::Getsid),
+            CAPGET => Some(Self::Capget),
+            CAPSET => Some(Self::Capset),
+            RT_SIGPENDING => Some(Self::RtSigpending),
+            RT_SIGTIMEDWAIT => Some(Self::RtSigtimedwait),
+            RT_SIGQUEUEINFO => Some(Self::RtSigqueueinfo),
+            RT_SIGSUSPEND => Some(Self::RtSigsuspend),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            UTIME => Some(Self::Utime),
+            MKNOD => Some(Self::Mknod),
+            PERSONALITY => Some(Self::Personality),
+            USTAT => Some(Self::Ustat),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            SYSFS => Some(Self::Sysfs),
+            GETPRIORITY => Some(Self::Getpriority),
+            SETPRIORITY => Some(Self::Setpriority),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            VHANGUP => Some(Self::Vhangup),
+            PIVOT_ROOT => Some(Self::PivotRoot),
+            _SYSCTL => Some(Self::Sysctl),
+            PRCTL => Some(Self::Prctl),
+            ADJTIMEX => Some(Self::Adjtimex),
+            SETRLIMIT => Some(Self::Setrlimit),
+            CHROOT => Some(Self::Chroot),
+            SYNC => Some(Self::Sync),
+            ACCT => Some(Self::Acct),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            MOUNT => Some(Self::Mount),
+            UMOUNT2 => Some(Self::Umount2),
+            SWAPON => Some(Self::Swapon),
+            SWAPOFF => Some(Self::Swapoff),
+            REBOOT => Some(Self::Reboot),
+            SETHOSTNAME => Some(Self::Sethostname),
+            SETDOMAINNAME => Some(Self::Setdomainname),
+            CREATE_MODULE => Some(Self::CreateModule),
+            INIT_MODULE => Some(Self::InitModule),
+            DELETE_MODULE => Some(Self::DeleteModule),
+            GET_KERNEL_SYMS => Some(Self::GetKernelSyms),
+            QUERY_MODULE => Some(Self::QueryModule),
+            QUOTACTL => Some(Self::Quotactl),
+            NFSSERVCTL => Some(Self::Nfsservctl),
+            GETPMSG => Some(Self::Getpmsg),
+            PUTPMSG => Some(Self::Putpmsg),
+            AFS_SYSCALL => Some(Self::AfsSyscall),
+            GETTID => Some(Self::Gettid),
+            READAHEAD => Some(Self::Readahead),
+            SETXATTR => Some(Self::Setxattr),
+            LSETXATTR => Some(Self::Lsetxattr),
+            FSETXATTR => Some(Self::Fsetxattr),
+            GETXATTR => Some(Self::Getxattr),
+            LGETXATTR => Some(Self::Lgetxattr),
+            FGETXATTR => Some(Self::Fgetxattr),
+            LISTXATTR => Some(Self::Listxattr),
+            LLISTXATTR => Some(Self::Llistxattr),
+            FLISTXATTR => Some(Self::Flistxattr),
+            REMOVEXATTR => Some(Self::Removexattr),
+            LREMOVEXATTR => Some(Self::Lremovexattr),
+            FREMOVEXATTR => Some(Self::Fremovexattr),
+            TKILL => Some(Self::Tkill),
+            FUTEX => Some(Self::Futex),
+            SCHED_SETAFFINITY => Some(Self::SchedSetaffinity),
+            SCHED_GETAFFINITY => Some(Self::SchedGetaffinity),
+            CACHEFLUSH => Some(Self::Cacheflush),
+            CACHECTL => Some(Self::Cachectl),
+            SYSMIPS => Some(Self::Sysmips),
+            IO_SETUP => Some(Self::IoSetup),
+            IO_DESTROY => Some(Self::IoDestroy),
+            IO_GETEVENTS => Some(Self::IoGetevents),
+            IO_SUBMIT => Some(Self::IoSubmit),
+            IO_CANCEL => Some(Self::IoCancel),
+            EXIT_GROUP => Some(Self::ExitGroup),
+            LOOKUP_DCOOKIE => Some(Self::LookupDcookie),
+            EPOLL_CREATE => Some(Self::EpollCreate),
+            EPOLL_CTL => Some(Self::EpollCtl),
+            EPOLL_WAIT => Some(Self::EpollWait),
+            REMAP_FILE_PAGES => Some(Self::RemapFilePages),
+            RT_SIGRETURN => Some(Self::RtSigreturn),
+            SET_TID_ADDRESS => Some(Self::SetTidAddress),
+            RESTART_SYSCALL => Some(Self::RestartSyscall),
+            SEMTIMEDOP => Some(Self::Semtimedop),
+            FADVISE64 => Some(Self::Fadvise64),
+            TIMER_CREATE => Some(Self::TimerCreate),
+            TIMER_SETTIME => Some(Self::TimerSettime),
+            TIMER_GETTIME => Some(Self::TimerGettime),
+            TIMER_GETOVERRUN => Some(Self::TimerGetoverrun),
+            TIMER_DELETE => Some(Self::TimerDelete),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            TGKILL => Some(Self::Tgkill),
+            UTIMES => Some(Self::Utimes),
+            MBIND => Some(Self::Mbind),
+            GET_MEMPOLICY => Some(Self::GetMempolicy),
+            SET_MEMPOLICY => Some(Self::SetMempolicy),
+            MQ_OPEN => Some(Self::MqOpen),
+            MQ_UNLINK => Some(Self::MqUnlink),
+            MQ_TIMEDSEND => Some(Self::MqTimedsend),
+            MQ_TIMEDRECEIVE => Some(Self::MqTimedreceive),
+            MQ_NOTIFY => Some(Self::MqNotify),
+            MQ_GETSETATTR => Some(Self::MqGetsetattr),
+            VSERVER => Some(Self::Vserver),
+            WAITID => Some(Self::Waitid),
+            ADD_KEY => Some(Self::AddKey),
+            REQUEST_KEY => Some(Self::RequestKey),
+            KEYCTL => Some(Self::Keyctl),
+            SET_THREAD_AREA => Some(Self::SetThreadArea),
+            INOTIFY_INIT => Some(Self::InotifyInit),
+            INOTIFY_ADD_WATCH => Some(Self::InotifyAddWatch),
+            INOTIFY_RM_WATCH => Some(Self::InotifyRmWatch),
+            MIGRATE_PAGES => Some(Self::MigratePages),
+            OPENAT => Some(Self::Openat),
+            MKDIRAT => Some(Self::Mkdirat),
+            MKNODAT => Some(Self::Mknodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FUTIMESAT => Some(Self::Futimesat),
+            NEWFSTATAT => Some(Self::Newfstatat),
+            UNLINKAT => Some(Self::Unlinkat),
+            RENAMEAT => Some(Self::Renameat),
+            LINKAT => Some(Self::Linkat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            READLINKAT => Some(Self::Readlinkat),
+            FCHMODAT => Some(Self::Fchmodat),
+            FACCESSAT => Some(Self::Faccessat),
+            PSELECT6 => Some(Self::Pselect6),
+            PPOLL => Some(Self::Ppoll),
+            UNSHARE => Some(Self::Unshare),
+            SPLICE => Some(Self::Splice),
+            SYNC_FILE_RANGE => Some(Self::SyncFileRange),
+            TEE => Some(Self::Tee),
+            VMSPLICE => Some(Self::Vmsplice),
+            MOVE_PAGES => Some(Self::MovePages),
+            SET_ROBUST_LIST => Some(Self::SetRobustList),
+            GET_ROBUST_LIST => Some(Self::GetRobustList),
+            KEXEC_LOAD => Some(Self::KexecLoad),
+            GETCPU => Some(Self::Getcpu),
+            EPOLL_PWAIT => Some(Self::EpollPwait),
+            IOPRIO_SET => Some(Self::IoprioSet),
+            IOPRIO_GET => Some(Self::IoprioGet),
+            UTIMENSAT => Some(Self::Utimensat),
+            SIGNALFD => Some(Self::Signalfd),
+            TIMERFD => Some(Self::Timerfd),
+            EVENTFD => Some(Self::Eventfd),
+            FALLOCATE => Some(Self::Fallocate),
+            TIMERFD_CREATE => Some(Self::TimerfdCreate),
+            TIMERFD_GETTIME => Some(Self::TimerfdGettime),
+            TIMERFD_SETTIME => Some(Self::TimerfdSettime),
+            SIGNALFD4 => Some(Self::Signalfd4),
+            EVENTFD2 => Some(Self::Eventfd2),
+            EPOLL_CREATE1 => Some(Self::EpollCreate1),
+            DUP3 => Some(Self::Dup3),
+            PIPE2 => Some(Self::Pipe2),
+            INOTIFY_INIT1 => Some(Self::InotifyInit1),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            RT_TGSIGQUEUEINFO => Some(Self::RtTgsigqueueinfo),
+            PERF_EVENT_OPEN => Some(Self::PerfEventOpen),
+            ACCEPT4 => Some(Self::Accept4),
+            RECVMMSG => Some(Self::Recvmmsg),
+            FANOTIFY_INIT => Some(Self::FanotifyInit),
+            FANOTIFY_MARK => Some(Self::FanotifyMark),
+            PRLIMIT64 => Some(Self::Prlimit64),
+            NAME_TO_HANDLE_AT => Some(Self::NameToHandleAt),
+            OPEN_BY_HANDLE_AT => Some(Self::OpenByHandleAt),
+            CLOCK_ADJTIME => Some(Self::ClockAdjtime),
+            SYNCFS => Some(Self::Syncfs),
+            SENDMMSG => Some(Self::Sendmmsg),
+            SETNS => Some(Self::Setns),
+            PROCESS_VM_READV => Some(Self::ProcessVmReadv),
+            PROCESS_VM_WRITEV => Some(Self::ProcessVmWritev),
+            KCMP => Some(Self::Kcmp),
+            FINIT_MODULE => Some(Self::FinitModule),
+            GETDENTS64 => Some(Self::Getdents64),
+            SCHED_SETATTR => Some(Self::SchedSetattr),
+            SCHED_GETATTR => Some(Self::SchedGetattr),
+            RENAMEAT2 => Some(Self::Renameat2),
+            SECCOMP => Some(Self::Seccomp),
+            GETRANDOM => Some(Self::Getrandom),
+            MEMFD_CREATE => Some(Self::MemfdCreate),
+            BPF => Some(Self::Bpf),
+            EXECVEAT => Some(Self::Execveat),
+            USERFAULTFD => Some(Self::Userfaultfd),
+            MEMBARRIER => Some(Self::Membarrier),
+            MLOCK2 => Some(Self::Mlock2),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            PREADV2 => Some(Self::Preadv2),
+            PWRITEV2 => Some(Self::Pwritev2),
+            PKEY_MPROTECT => Some(Self::PkeyMprotect),
+            PKEY_ALLOC => Some(Self::PkeyAlloc),
+            PKEY_FREE => Some(Self::PkeyFree),
+            STATX => Some(Self::Statx),
+            RSEQ => Some(Self::Rseq),
+            IO_PGETEVENTS => Some(Self::IoPgetevents),
+            PIDFD_SEND_SIGNAL => Some(Self::PidfdSendSignal),
+            IO_URING_SETUP => Some(Self::IoUringSetup),
+            IO_URING_ENTER => Some(Self::IoUringEnter),
+            IO_URING_REGISTER => Some(Self::IoUringRegister),
+            OPEN_TREE => Some(Self::OpenTree),
+            MOVE_MOUNT => Some(Self::MoveMount),
+            FSOPEN => Some(Self::Fsopen),
+            FSCONFIG => Some(Self::Fsconfig),
+            FSMOUNT => Some(Self::Fsmount),
+            FSPICK => Some(Self::Fspick),
+            PIDFD_OPEN => Some(Self::PidfdOpen),
+            CLONE3 => Some(Self::Clone3),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            OPENAT2 => Some(Self::Openat2),
+            PIDFD_GETFD => Some(Self::PidfdGetfd),
+            FACCESSAT2 => Some(Self::Faccessat2),
+            PROCESS_MADVISE => Some(Self::ProcessMadvise),
+            EPOLL_PWAIT2 => Some(Self::EpollPwait2),
+            MOUNT_SETATTR => Some(Self::MountSetattr),
+            QUOTACTL_FD => Some(Self::QuotactlFd),
+            LANDLOCK_CREATE_RULESET => Some(Self::LandlockCreateRuleset),
+            LANDLOCK_ADD_RULE => Some(Self::LandlockAddRule),
+            LANDLOCK_RESTRICT_SELF => Some(Self::LandlockRestrictSelf),
+            PROCESS_MRELEASE => Some(Self::ProcessMrelease),
+            FUTEX_WAITV => Some(Self::FutexWaitv),
+            SET_MEMPOLICY_HOME_NODE => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "stat" => Some(Self::Stat),
+            "fstat" => Some(Self::Fstat),
+            "lstat" => Some(Self::Lstat),
+            "poll" => Some(Self::Poll),
+            "lseek" => Some(Self::Lseek),
+            "mmap" => Some(Self::Mmap),
+            "mprotect" => Some(Self::Mprotect),
+            "munmap" => Some(Self::Munmap),
+            "brk" => Some(Self::Brk),
+            "rt_sigaction" => Some(Self::RtSigaction),
+            "rt_sigprocmask" => Some(Self::RtSigprocmask),
+            "ioctl" => Some(Self::Ioctl),
+            "pread64" => Some(Self::Pread64),
+            "pwrite64" => Some(Self::Pwrite64),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "access" => Some(Self::Access),
+            "pipe" => Some(Self::Pipe),
+            "_newselect" => Some(Self::Newselect),
+            "sched_yield" => Some(Self::SchedYield),
+            "mremap" => Some(Self::Mremap),
+            "msync" => Some(Self::Msync),
+            "mincore" => Some(Self::Mincore),
+            "madvise" => Some(Self::Madvise),
+            "shmget" => Some(Self::Shmget),
+            "shmat" => Some(Self::Shmat),
+            "shmctl" => Some(Self::Shmctl),
+            "dup" => Some(Self::Dup),
+            "dup2" => Some(Self::Dup2),
+            "pause" => Some(Self::Pause),
+            "nanosleep" => Some(Self::Nanosleep),
+            "getitimer" => Some(Self::Getitimer),
+            "setitimer" => Some(Self::Setitimer),
+            "alarm" => Some(Self::Alarm),
+            "getpid" => Some(Self::Getpid),
+            "sendfile" => Some(Self::Sendfile),
+            "socket" => Some(Self::Socket),
+            "connect" => Some(Self::Connect),
+            "accept" => Some(Self::Accept),
+            "sendto" => Some(Self::Sendto),
+            "recvfrom" => Some(Self::Recvfrom),
+            "sendmsg" => Some(Self::Sendmsg),
+            "recvmsg" => Some(Self::Recvmsg),
+            "shutdown" => Some(Self::Shutdown),
+            "bind" => Some(Self::Bind),
+            "listen" => Some(Self::Listen),
+            "getsockname" => Some(Self::Getsockname),
+            "getpeername" => Some(Self::Getpeername),
+            "socketpair" => Some(Self::Socketpair),
+            "setsockopt" => Some(Self::Setsockopt),
+            "getsockopt" => Some(Self::Getsockopt),
+            "clone" => Some(Self::Clone),
+            "fork" => Some(Self::Fork),
+            "execve" => Some(Self::Execve),
+            "exit" => Some(Self::Exit),
+            "wait4" => Some(Self::Wait4),
+            "kill" => Some(Self::Kill),
+            "uname" => Some(Self::Uname),
+            "semget" => Some(Self::Semget),
+            "semop" => Some(Self::Semop),
+            "semctl" => Some(Self::Semctl),
+            "shmdt" => Some(Self::Shmdt),
+            "msgget" => Some(Self::Msgget),
+            "msgsnd" => Some(Self::Msgsnd),
+            "msgrcv" => Some(Self::Msgrcv),
+            "msgctl" => Some(Self::Msgctl),
+            "fcntl" => Some(Self::Fcntl),
+            "flock" => Some(Self::Flock),
+            "fsync" => Some(Self::Fsync),
+            "fdatasync" => Some(Self::Fdatasync),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "getdents" => Some(Self::Getdents),
+            "getcwd" => Some(Self::Getcwd),
+            "chdir" => Some(Self::Chdir),
+            "fchdir" => Some(Self::Fchdir),
+            "rename" => Some(Self::Rename),
+            "mkdir" => Some(Self::Mkdir),
+            "rmdir" => Some(Self::Rmdir),
+            "creat" => Some(Self::Creat),
+            "link" => Some(Self::Link),
+            "unlink" => Some(Self::Unlink),
+            "symlink" => Some(Self::Symlink),
+            "readlink" => Some(Self::Readlink),
+            "chmod" => Some(Self::Chmod),
+            "fchmod" => Some(Self::Fchmod),
+            "chown" => Some(Self::Chown),
+            "fchown" => Some(Self::Fchown),
+            "lchown" => Some(Self::Lchown),
+            "umask" => Some(Self::Umask),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "getrlimit" => Some(Self::Getrlimit),
+            "getrusage" => Some(Self::Getrusage),
+            "sysinfo" => Some(Self::Sysinfo),
+            "times" => Some(Self::Times),
+            "ptrace" => Some(Self::Ptrace),
+            "getuid" => Some(Self::Getuid),
+            "syslog" => Some(Self::Syslog),
+            "getgid" => Some(Self::Getgid),
+            "setuid" => Some(Self::Setuid),
+            "setgid" => Some(Self::Setgid),
+            "geteuid" => Some(Self::Geteuid),
+            "getegid" => Some(Self::Getegid),
+            "setpgid" => Some(Self::Setpgid),
+            "getppid" => Some(Self::Getppid),
+            "getpgrp" => Some(Self::Getpgrp),
+            "setsid" => Some(Self::Setsid),
+            "setreuid" => Some(Self::Setreuid),
+            "setregid" => Some(Self::Setregid),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "setresuid" => Some(Self::Setresuid),
+            "getresuid" => Some(Self::Getresuid),
+            "setresgid" => Some(Self::Setresgid),
+            "getresgid" => Some(Self::Getresgid),
+            "getpgid" => Some(Self::Getpgid),
+            "setfsuid" => Some(Self::Setfsuid),
+            "setfsgid" => Some(Self::Setfsgid),
+            "getsid" => Some(Self::Getsid),
+            "capget" => Some(Self::Capget),
+            "capset" => Some(Self::Capset),
+            "rt_sigpending" => Some(Self::RtSigpending),
+            "rt_sigtimedwait" => Some(Self::RtSigtimedwait),
+            "rt_sigqueueinfo" => Some(Self::RtSigqueueinfo),
+            "rt_sigsuspend" => Some(Self::RtSigsuspend),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "utime" => Some(Self::Utime),
+            "mknod" => Some(Self::Mknod),
+            "personality" => Some(Self::Personality),
+            "ustat" => Some(Self::Ustat),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "sysfs" => Some(Self::Sysfs),
+            "getpriority" => Some(Self::Getpriority),
+            "setpriority" => Some(Self::Setpriority),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "vhangup" => Some(Self::Vhangup),
+            "pivot_root" => Some(Self::PivotRoot),
+            "_sysctl" => Some(Self::Sysctl),
+            "prctl" => Some(Self::Prctl),
+            "adjtimex" => Some(Self::Adjtimex),
+            "setrlimit" => Some(Self::Setrlimit),
+            "chroot" => Some(Self::Chroot),
+            "sync" => Some(Self::Sync),
+            "acct" => Some(Self::Acct),
+            "settimeofday" => Some(Self::Settimeofday),
+            "mount" => Some(Self::Mount),
+            "umount2" => Some(Self::Umount2),
+            "swapon" => Some(Self::Swapon),
+            "swapoff" => Some(Self::Swapoff),
+            "reboot" => Some(Self::Reboot),
+            "sethostname" => Some(Self::Sethostname),
+            "setdomainname" => Some(Self::Setdomainname),
+            "create_module" => Some(Self::CreateModule),
+            "init_module" => Some(Self::InitModule),
+            "delete_module" => Some(Self::DeleteModule),
+            "get_kernel_syms" => Some(Self::GetKernelSyms),
+            "query_module" => Some(Self::QueryModule),
+            "quotactl" => Some(Self::Quotactl),
+            "nfsservctl" => Some(Self::Nfsservctl),
+            "getpmsg" => Some(Self::Getpmsg),
+            "putpmsg" => Some(Self::Putpmsg),
+            "afs_syscall" => Some(Self::AfsSyscall),
+            "gettid" => Some(Self::Gettid),
+            "readahead" => Some(Self::Readahead),
+            "setxattr" => Some(Self::Setxattr),
+            "lsetxattr" => Some(Self::Lsetxattr),
+            "fsetxattr" => Some(Self::Fsetxattr),
+            "getxattr" => Some(Self::Getxattr),
+            "lgetxattr" => Some(Self::Lgetxattr),
+            "fgetxattr" => Some(Self::Fgetxattr),
+            "listxattr" => Some(Self::Listxattr),
+            "llistxattr" => Some(Self::Llistxattr),
+            "flistxattr" => Some(Self::Flistxattr),
+            "removexattr" => Some(Self::Removexattr),
+            "lremovexattr" => Some(Self::Lremovexattr),
+            "fremovexattr" => Some(Self::Fremovexattr),
+            "tkill" => Some(Self::Tkill),
+            "futex" => Some(Self::Futex),
+            "sched_setaffinity" => Some(Self::SchedSetaffinity),
+            "sched_getaffinity" => Some(Self::SchedGetaffinity),
+            "cacheflush" => Some(Self::Cacheflush),
+            "cachectl" => Some(Self::Cachectl),
+            "sysmips" => Some(Self::Sysmips),
+            "io_setup" => Some(Self::IoSetup),
+            "io_destroy" => Some(Self::IoDestroy),
+            "io_getevents" => Some(Self::IoGetevents),
+            "io_submit" => Some(Self::IoSubmit),
+            "io_cancel" => Some(Self::IoCancel),
+            "exit_group" => Some(Self::ExitGroup),
+            "lookup_dcookie" => Some(Self::LookupDcookie),
+            "epoll_create" => Some(Self::EpollCreate),
+            "epoll_ctl" => Some(Self::EpollCtl),
+            "epoll_wait" => Some(Self::EpollWait),
+            "remap_file_pages" => Some(Self::RemapFilePages),
+            "rt_sigreturn" => Some(Self::RtSigreturn),
+            "set_tid_address" => Some(Self::SetTidAddress),
+            "restart_syscall" => Some(Self::RestartSyscall),
+            "semtimedop" => Some(Self::Semtimedop),
+            "fadvise64" => Some(Self::Fadvise64),
+            "timer_create" => Some(Self::TimerCreate),
+            "timer_settime" => Some(Self::TimerSettime),
+            "timer_gettime" => Some(Self::TimerGettime),
+            "timer_getoverrun" => Some(Self::TimerGetoverrun),
+            "timer_delete" => Some(Self::TimerDelete),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "tgkill" => Some(Self::Tgkill),
+            "utimes" => Some(Self::Utimes),
+            "mbind" => Some(Self::Mbind),
+            "get_mempolicy" => Some(Self::GetMempolicy),
+            "set_mempolicy" => Some(Self::SetMempolicy),
+            "mq_open" => Some(Self::MqOpen),
+            "mq_unlink" => Some(Self::MqUnlink),
+            "mq_timedsend" => Some(Self::MqTimedsend),
+            "mq_timedreceive" => Some(Self::MqTimedreceive),
+            "mq_notify" => Some(Self::MqNotify),
+            "mq_getsetattr" => Some(Self::MqGetsetattr),
+            "vserver" => Some(Self::Vserver),
+            "waitid" => Some(Self::Waitid),
+            "add_key" => Some(Self::AddKey),
+            "request_key" => Some(Self::RequestKey),
+            "keyctl" => Some(Self::Keyctl),
+            "set_thread_area" => Some(Self::SetThreadArea),
+            "inotify_init" => Some(Self::InotifyInit),
+            "inotify_add_watch" => Some(Self::InotifyAddWatch),
+            "inotify_rm_watch" => Some(Self::InotifyRmWatch),
+            "migrate_pages" => Some(Self::MigratePages),
+            "openat" => Some(Self::Openat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "mknodat" => Some(Self::Mknodat),
+            "fchownat" => Some(Self::Fchownat),
+            "futimesat" => Some(Self::Futimesat),
+            "newfstatat" => Some(Self::Newfstatat),
+            "unlinkat" => Some(Self::Unlinkat),
+            "renameat" => Some(Self::Renameat),
+            "linkat" => Some(Self::Linkat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "readlinkat" => Some(Self::Readlinkat),
+            "fchmodat" => Some(Self::Fchmodat),
+            "faccessat" => Some(Self::Faccessat),
+            "pselect6" => Some(Self::Pselect6),
+            "ppoll" => Some(Self::Ppoll),
+            "unshare" => Some(Self::Unshare),
+            "splice" => Some(Self::Splice),
+            "sync_file_range" => Some(Self::SyncFileRange),
+            "tee" => Some(Self::Tee),
+            "vmsplice" => Some(Self::Vmsplice),
+            "move_pages" => Some(Self::MovePages),
+            "set_robust_list" => Some(Self::SetRobustList),
+            "get_robust_list" => Some(Self::GetRobustList),
+            "kexec_load" => Some(Self::KexecLoad),
+            "getcpu" => Some(Self::Getcpu),
+            "epoll_pwait" => Some(Self::EpollPwait),
+            "ioprio_set" => Some(Self::IoprioSet),
+            "ioprio_get" => Some(Self::IoprioGet),
+            "utimensat" => Some(Self::Utimensat),
+            "signalfd" => Some(Self::Signalfd),
+            "timerfd" => Some(Self::Timerfd),
+            "eventfd" => Some(Self::Eventfd),
+            "fallocate" => Some(Self::Fallocate),
+            "timerfd_create" => Some(Self::TimerfdCreate),
+            "timerfd_gettime" => Some(Self::TimerfdGettime),
+            "timerfd_settime" => Some(Self::TimerfdSettime),
+            "signalfd4" => Some(Self::Signalfd4),
+            "eventfd2" => Some(Self::Eventfd2),
+            "epoll_create1" => Some(Self::EpollCreate1),
+            "dup3" => Some(Self::Dup3),
+            "pipe2" => Some(Self::Pipe2),
+            "inotify_init1" => Some(Self::InotifyInit1),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "rt_tgsigqueueinfo" => Some(Self::RtTgsigqueueinfo),
+            "perf_event_open" => Some(Self::PerfEventOpen),
+            "accept4" => Some(Self::Accept4),
+            "recvmmsg" => Some(Self::Recvmmsg),
+            "fanotify_init" => Some(Self::FanotifyInit),
+            "fanotify_mark" => Some(Self::FanotifyMark),
+            "prlimit64" => Some(Self::Prlimit64),
+            "name_to_handle_at" => Some(Self::NameToHandleAt),
+            "open_by_handle_at" => Some(Self::OpenByHandleAt),
+            "clock_adjtime" => Some(Self::ClockAdjtime),
+            "syncfs" => Some(Self::Syncfs),
+            "sendmmsg" => Some(Self::Sendmmsg),
+            "setns" => Some(Self::Setns),
+            "process_vm_readv" => Some(Self::ProcessVmReadv),
+            "process_vm_writev" => Some(Self::ProcessVmWritev),
+            "kcmp" => Some(Self::Kcmp),
+            "finit_module" => Some(Self::FinitModule),
+            "getdents64" => Some(Self::Getdents64),
+            "sched_setattr" => Some(Self::SchedSetattr),
+            "sched_getattr" => Some(Self::SchedGetattr),
+            "renameat2" => Some(Self::Renameat2),
+            "seccomp" => Some(Self::Seccomp),
+            "getrandom" => Some(Self::Getrandom),
+            "memfd_create" => Some(Self::MemfdCreate),
+            "bpf" => Some(Self::Bpf),
+            "execveat" => Some(Self::Execveat),
+            "userfaultfd" => Some(Self::Userfaultfd),
+            "membarrier" => Some(Self::Membarrier),
+            "mlock2" => Some(Self::Mlock2),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "preadv2" => Some(Self::Preadv2),
+            "pwritev2" => Some(Self::Pwritev2),
+            "pkey_mprotect" => Some(Self::PkeyMprotect),
+            "pkey_alloc" => Some(Self::PkeyAlloc),
+            "pkey_free" => Some(Self::PkeyFree),
+            "statx" => Some(Self::Statx),
+            "rseq" => Some(Self::Rseq),
+            "io_pgetevents" => Some(Self::IoPgetevents),
+            "pidfd_send_signal" => Some(Self::PidfdSendSignal),
+            "io_uring_setup" => Some(Self::IoUringSetup),
+            "io_uring_enter" => Some(Self::IoUringEnter),
+            "io_uring_register" => Some(Self::IoUringRegister),
+            "open_tree" => Some(Self::OpenTree),
+            "move_mount" => Some(Self::MoveMount),
+            "fsopen" => Some(Self::Fsopen),
+            "fsconfig" => Some(Self::Fsconfig),
+            "fsmount" => Some(Self::Fsmount),
+            "fspick" => Some(Self::Fspick),
+            "pidfd_open" => Some(Self::PidfdOpen),
+            "clone3" => Some(Self::Clone3),
+            "close_range" => Some(Self::CloseRange),
+            "openat2" => Some(Self::Openat2),
+            "pidfd_getfd" => Some(Self::PidfdGetfd),
+            "faccessat2" => Some(Self::Faccessat2),
+            "process_madvise" => Some(Self::ProcessMadvise),
+            "epoll_pwait2" => Some(Self::EpollPwait2),
+            "mount_setattr" => Some(Self::MountSetattr),
+            "quotactl_fd" => Some(Self::QuotactlFd),
+            "landlock_create_ruleset" => Some(Self::LandlockCreateRuleset),
+            "landlock_add_rule" => Some(Self::LandlockAddRule),
+            "landlock_restrict_self" => Some(Self::LandlockRestrictSelf),
+            "process_mrelease" => Some(Self::ProcessMrelease),
+            "futex_waitv" => Some(Self::FutexWaitv),
+            "set_mempolicy_home_node" => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Stat => "stat",
+            Self::Fstat => "fstat",
+            Self::Lstat => "lstat",
+            Self::Poll => "poll",
+            Self::Lseek => "lseek",
+            Self::Mmap => "mmap",
+            Self::Mprotect => "mprotect",
+            Self::Munmap => "munmap",
+            Self::Brk => "brk",
+            Self::RtSigaction => "rt_sigaction",
+            Self::RtSigprocmask => "rt_sigprocmask",
+            Self::Ioctl => "ioctl",
+            Self::Pread64 => "pread64",
+            Self::Pwrite64 => "pwrite64",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Access => "access",
+            Self::Pipe => "pipe",
+            Self::Newselect => "_newselect",
+            Self::SchedYield => "sched_yield",
+            Self::Mremap => "mremap",
+            Self::Msync => "msync",
+            Self::Mincore => "mincore",
+            Self::Madvise => "madvise",
+            Self::Shmget => "shmget",
+            Self::Shmat => "shmat",
+            Self::Shmctl => "shmctl",
+            Self::Dup => "dup",
+            Self::Dup2 => "dup2",
+            Self::Pause => "pause",
+            Self::Nanosleep => "nanosleep",
+            Self::Getitimer => "getitimer",
+            Self::Setitimer => "setitimer",
+            Self::Alarm => "alarm",
+            Self::Getpid => "getpid",
+            Self::Sendfile => "sendfile",
+            Self::Socket => "socket",
+            Self::Connect => "connect",
+            Self::Accept => "accept",
+            Self::Sendto => "sendto",
+            Self::Recvfrom => "recvfrom",
+            Self::Sendmsg => "sendmsg",
+            Self::Recvmsg => "recvmsg",
+            Self::Shutdown => "shutdown",
+            Self::Bind => "bind",
+            Self::Listen => "listen",
+            Self::Getsockname => "getsockname",
+            Self::Getpeername => "getpeername",
+            Self::Socketpair => "socketpair",
+            Self::Setsockopt => "setsockopt",
+            Self::Getsockopt => "getsockopt",
+            Self::Clone => "clone",
+            Self::Fork => "fork",
+            Self::Execve => "execve",
+            Self::Exit => "exit",
+            Self::Wait4 => "wait4",
+            Self::Kill => "kill",
+            Self::Uname => "uname",
+            Self::Semget => "semget",
+            Self::Semop => "semop",
+            Self::Semctl => "semctl",
+            Self::Shmdt => "shmdt",
+            Self::Msgget => "msgget",
+            Self::Msgsnd => "msgsnd",
+            Self::Msgrcv => "msgrcv",
+            Self::Msgctl => "msgctl",
+            Self::Fcntl => "fcntl",
+            Self::Flock => "flock",
+            Self::Fsync => "fsync",
+            Self::Fdatasync => "fdatasync",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::Getdents => "getdents",
+            Self::Getcwd => "getcwd",
+            Self::Chdir => "chdir",
+            Self::Fchdir => "fchdir",
+            Self::Rename => "rename",
+            Self::Mkdir => "mkdir",
+            Self::Rmdir => "rmdir",
+            Self::Creat => "creat",
+            Self::Link => "link",
+            Self::Unlink => "unlink",
+            Self::Symlink => "symlink",
+            Self::Readlink => "readlink",
+            Self::Chmod => "chmod",
+            Self::Fchmod => "fchmod",
+            Self::Chown => "chown",
+            Self::Fchown => "fchown",
+            Self::Lchown => "lchown",
+            Self::Umask => "umask",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Getrlimit => "getrlimit",
+            Self::Getrusage => "getrusage",
+            Self::Sysinfo => "sysinfo",
+            Self::Times => "times",
+            Self::Ptrace => "ptrace",
+            Self::Getuid => "getuid",
+            Self::Syslog => "syslog",
+            Self::Getgid => "getgid",
+            Self::Setuid => "setuid",
+            Self::Setgid => "setgid",
+            Self::Geteuid => "geteuid",
+            Self::Getegid => "getegid",
+            Self::Setpgid => "setpgid",
+            Self::Getppid => "getppid",
+            Self::Getpgrp => "getpgrp",
+            Self::Setsid => "setsid",
+            Self::Setreuid => "setreuid",
+            Self::Setregid => "setregid",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Setresuid => "setresuid",
+            Self::Getresuid => "getresuid",
+            Self::Setresgid => "setresgid",
+            Self::Getresgid => "getresgid",
+            Self::Getpgid => "getpgid",
+            Self::Setfsuid => "setfsuid",
+            Self::Setfsgid => "setfsgid",
+            Self::Getsid => "getsid",
+            Self::Capget => "capget",
+            Self::Capset => "capset",
+            Self::RtSigpending => "rt_sigpending",
+            Self::RtSigtimedwait => "rt_sigtimedwait",
+            Self::RtSigqueueinfo => "rt_sigqueueinfo",
+            Self::RtSigsuspend => "rt_sigsuspend",
+            Self::Sigaltstack => "sigaltstack",
+            Self::Utime => "utime",
+            Self::Mknod => "mknod",
+            Self::Personality => "personality",
+            Self::Ustat => "ustat",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Sysfs => "sysfs",
+            Self::Getpriority => "getpriority",
+            Self::Setpriority => "setpriority",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::Vhangup => "vhangup",
+            Self::PivotRoot => "pivot_root",
+            Self::Sysctl => "_sysctl",
+            Self::Prctl => "prctl",
+            Self::Adjtimex => "adjtimex",
+            Self::Setrlimit => "setrlimit",
+            Self::Chroot => "chroot",
+            Self::Sync => "sync",
+            Self::Acct => "acct",
+            Self::Settimeofday => "settimeofday",
+            Self::Mount => "mount",
+            Self::Umount2 => "umount2",
+            Self::Swapon => "swapon",
+            Self::Swapoff => "swapoff",
+            Self::Reboot => "reboot",
+            Self::Sethostname => "sethostname",
+            Self::Setdomainname => "setdomainname",
+            Self::CreateModule => "create_module",
+            Self::InitModule => "init_module",
+            Self::DeleteModule => "delete_module",
+            Self::GetKernelSyms => "get_kernel_syms",
+            Self::QueryModule => "query_module",
+            Self::Quotactl => "quotactl",
+            Self::Nfsservctl => "nfsservctl",
+            Self::Getpmsg => "getpmsg",
+            Self::Putpmsg => "putpmsg",
+            Self::AfsSyscall => "afs_syscall",
+            Self::Gettid => "gettid",
+            Self::Readahead => "readahead",
+            Self::Setxattr => "setxattr",
+            Self::Lsetxattr => "lsetxattr",
+            Self::Fsetxattr => "fsetxattr",
+            Self::Getxattr => "getxattr",
+            Self::Lgetxattr => "lgetxattr",
+            Self::Fgetxattr => "fgetxattr",
+            Self::Listxattr => "listxattr",
+            Self::Llistxattr => "llistxattr",
+            Self::Flistxattr => "flistxattr",
+            Self::Removexattr => "removexattr",
+            Self::Lremovexattr => "lremovexattr",
+            Self::Fremovexattr => "fremovexattr",
+            Self::Tkill => "tkill",
+            Self::Futex => "futex",
+            Self::SchedSetaffinity => "sched_setaffinity",
+            Self::SchedGetaffinity => "sched_getaffinity",
+            Self::Cacheflush => "cacheflush",
+            Self::Cachectl => "cachectl",
+            Self::Sysmips => "sysmips",
+            Self::IoSetup => "io_setup",
+            Self::IoDestroy => "io_destroy",
+            Self::IoGetevents => "io_getevents",
+            Self::IoSubmit => "io_submit",
+            Self::IoCancel => "io_cancel",
+            Self::ExitGroup => "exit_group",
+            Self::LookupDcookie => "lookup_dcookie",
+            Self::EpollCreate => "epoll_create",
+            Self::EpollCtl => "epoll_ctl",
+            Self::EpollWait => "epoll_wait",
+            Self::RemapFilePages => "remap_file_pages",
+            Self::RtSigreturn => "rt_sigreturn",
+            Self::SetTidAddress => "set_tid_address",
+            Self::RestartSyscall => "restart_syscall",
+            Self::Semtimedop => "semtimedop",
+            Self::Fadvise64 => "fadvise64",
+            Self::TimerCreate => "timer_create",
+            Self::TimerSettime => "timer_settime",
+            Self::TimerGettime => "timer_gettime",
+            Self::TimerGetoverrun => "timer_getoverrun",
+            Self::TimerDelete => "timer_delete",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockGetres => "clock_getres",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::Tgkill => "tgkill",
+            Self::Utimes => "utimes",
+            Self::Mbind => "mbind",
+            Self::GetMempolicy => "get_mempolicy",
+            Self::SetMempolicy => "set_mempolicy",
+            Self::MqOpen => "mq_open",
+            Self::MqUnlink => "mq_unlink",
+            Self::MqTimedsend => "mq_timedsend",
+            Self::MqTimedreceive => "mq_timedreceive",
+            Self::MqNotify => "mq_notify",
+            Self::MqGetsetattr => "mq_getsetattr",
+            Self::Vserver => "vserver",
+            Self::Waitid => "waitid",
+            Self::AddKey => "add_key",
+            Self::RequestKey => "request_key",
+            Self::Keyctl => "keyctl",
+            Self::SetThreadArea => "set_thread_area",
+            Self::InotifyInit => "inotify_init",
+            Self::InotifyAddWatch => "inotify_add_watch",
+            Self::InotifyRmWatch => "inotify_rm_watch",
+            Self::MigratePages => "migrate_pages",
+            Self::Openat => "openat",
+            Self::Mkdirat => "mkdirat",
+            Self::Mknodat => "mknodat",
+            Self::Fchownat => "fchownat",
+            Self::Futimesat => "futimesat",
+            Self::Newfstatat => "newfstatat",
+            Self::Unlinkat => "unlinkat",
+            Self::Renameat => "renameat",
+            Self::Linkat => "linkat",
+            Self::Symlinkat => "symlinkat",
+            Self::Readlinkat => "readlinkat",
+            Self::Fchmodat => "fchmodat",
+            Self::Faccessat => "faccessat",
+            Self::Pselect6 => "pselect6",
+            Self::Ppoll => "ppoll",
+            Self::Unshare => "unshare",
+            Self::Splice => "splice",
+            Self::SyncFileRange => "sync_file_range",
+            Self::Tee => "tee",
+            Self::Vmsplice => "vmsplice",
+            Self::MovePages => "move_pages",
+            Self::SetRobustList => "set_robust_list",
+            Self::GetRobustList => "get_robust_list",
+            Self::KexecLoad => "kexec_load",
+            Self::Getcpu => "getcpu",
+            Self::EpollPwait => "epoll_pwait",
+            Self::IoprioSet => "ioprio_set",
+            Self::IoprioGet => "ioprio_get",
+            Self::Utimensat => "utimensat",
+            Self::Signalfd => "signalfd",
+            Self::Timerfd => "timerfd",
+            Self::Eventfd => "eventfd",
+            Self::Fallocate => "fallocate",
+            Self::TimerfdCreate => "timerfd_create",
+            Self::TimerfdGettime => "timerfd_gettime",
+            Self::TimerfdSettime => "timerfd_settime",
+            Self::Signalfd4 => "signalfd4",
+            Self::Eventfd2 => "eventfd2",
+            Self::EpollCreate1 => "epoll_create1",
+            Self::Dup3 => "dup3",
+            Self::Pipe2 => "pipe2",
+            Self::InotifyInit1 => "inotify_init1",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::RtTgsigqueueinfo => "rt_tgsigqueueinfo",
+            Self::PerfEventOpen => "perf_event_open",
+            Self::Accept4 => "accept4",
+            Self::Recvmmsg => "recvmmsg",
+            Self::FanotifyInit => "fanotify_init",
+            Self::FanotifyMark => "fanotify_mark",
+            Self::Prlimit64 => "prlimit64",
+            Self::NameToHandleAt => "name_to_handle_at",
+            Self::OpenByHandleAt => "open_by_handle_at",
+            Self::ClockAdjtime => "clock_adjtime",
+            Self::Syncfs => "syncfs",
+            Self::Sendmmsg => "sendmmsg",
+            Self::Setns => "setns",
+            Self::ProcessVmReadv => "process_vm_readv",
+            Self::ProcessVmWritev => "process_vm_writev",
+            Self::Kcmp => "kcmp",
+            Self::FinitModule => "finit_module",
+            Self::Getdents64 => "getdents64",
+            Self::SchedSetattr => "sched_setattr",
+            Self::SchedGetattr => "sched_getattr",
+            Self::Renameat2 => "renameat2",
+            Self::Seccomp => "seccomp",
+            Self::Getrandom => "getrandom",
+            Self::MemfdCreate => "memfd_create",
+            Self::Bpf => "bpf",
+            Self::Execveat => "execveat",
+            Self::Userfaultfd => "userfaultfd",
+            Self::Membarrier => "membarrier",
+            Self::Mlock2 => "mlock2",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Preadv2 => "preadv2",
+            Self::Pwritev2 => "pwritev2",
+            Self::PkeyMprotect => "pkey_mprotect",
+            Self::PkeyAlloc => "pkey_alloc",
+            Self::PkeyFree => "pkey_free",
+            Self::Statx => "statx",
+            Self::Rseq => "rseq",
+            Self::IoPgetevents => "io_pgetevents",
+            Self::PidfdSendSignal => "pidfd_send_signal",
+            Self::IoUringSetup => "io_uring_setup",
+            Self::IoUringEnter => "io_uring_enter",
+            Self::IoUringRegister => "io_uring_register",
+            Self::OpenTree => "open_tree",
+            Self::MoveMount => "move_mount",
+            Self::Fsopen => "fsopen",
+            Self::Fsconfig => "fsconfig",
+            Self::Fsmount => "fsmount",
+            Self::Fspick => "fspick",
+            Self::PidfdOpen => "pidfd_open",
+            Self::Clone3 => "clone3",
+            Self::CloseRange => "close_range",
+            Self::Openat2 => "openat2",
+            Self::PidfdGetfd => "pidfd_getfd",
+            Self::Faccessat2 => "faccessat2",
+            Self::ProcessMadvise => "process_madvise",
+            Self::EpollPwait2 => "epoll_pwait2",
+            Self::MountSetattr => "mount_setattr",
+            Self::QuotactlFd => "quotactl_fd",
+            Self::LandlockCreateRuleset => "landlock_create_ruleset",
+            Self::LandlockAddRule => "landlock_add_rule",
+            Self::LandlockRestrictSelf => "landlock_restrict_self",
+            Self::ProcessMrelease => "process_mrelease",
+            Self::FutexWaitv => "futex_waitv",
+            Self::SetMempolicyHomeNode => "set_mempolicy_home_node",
+        }
+    }
+}
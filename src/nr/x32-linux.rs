@@ -0,0 +1,2171 @@
+//! System call numbers for `x32-linux`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/x86/unistd_x32.h`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const READ: usize = 1_073_741_824;
+pub const WRITE: usize = 1_073_741_825;
+pub const OPEN: usize = 1_073_741_826;
+pub const CLOSE: usize = 1_073_741_827;
+pub const STAT: usize = 1_073_741_828;
+pub const FSTAT: usize = 1_073_741_829;
+pub const LSTAT: usize = 1_073_741_830;
+pub const POLL: usize = 1_073_741_831;
+pub const LSEEK: usize = 1_073_741_832;
+pub const MMAP: usize = 1_073_741_833;
+pub const MPROTECT: usize = 1_073_741_834;
+pub const MUNMAP: usize = 1_073_741_835;
+pub const BRK: usize = 1_073_741_836;
+pub const RT_SIGPROCMASK: usize = 1_073_741_838;
+pub const PREAD64: usize = 1_073_741_841;
+pub const PWRITE64: usize = 1_073_741_842;
+pub const ACCESS: usize = 1_073_741_845;
+pub const PIPE: usize = 1_073_741_846;
+pub const SELECT: usize = 1_073_741_847;
+pub const SCHED_YIELD: usize = 1_073_741_848;
+pub const MREMAP: usize = 1_073_741_849;
+pub const MSYNC: usize = 1_073_741_850;
+pub const MINCORE: usize = 1_073_741_851;
+pub const MADVISE: usize = 1_073_741_852;
+pub const SHMGET: usize = 1_073_741_853;
+pub const SHMAT: usize = 1_073_741_854;
+pub const SHMCTL: usize = 1_073_741_855;
+pub const DUP: usize = 1_073_741_856;
+pub const DUP2: usize = 1_073_741_857;
+pub const PAUSE: usize = 1_073_741_858;
+pub const NANOSLEEP: usize = 1_073_741_859;
+pub const GETITIMER: usize = 1_073_741_860;
+pub const ALARM: usize = 1_073_741_861;
+pub const SETITIMER: usize = 1_073_741_862;
+pub const GETPID: usize = 1_073_741_863;
+pub const SENDFILE: usize = 1_073_741_864;
+pub const SOCKET: usize = 1_073_741_865;
+pub const CONNECT: usize = 1_073_741_866;
+pub const ACCEPT: usize = 1_073_741_867;
+pub const SENDTO: usize = 1_073_741_868;
+pub const SHUTDOWN: usize = 1_073_741_872;
+pub const BIND: usize = 1_073_741_873;
+pub const LISTEN: usize = 1_073_741_874;
+pub const GETSOCKNAME: usize = 1_073_741_875;
+pub const GETPEERNAME: usize = 1_073_741_876;
+pub const SOCKETPAIR: usize = 1_073_741_877;
+pub const CLONE: usize = 1_073_741_880;
+pub const FORK: usize = 1_073_741_881;
+pub const VFORK: usize = 1_073_741_882;
+pub const EXIT: usize = 1_073_741_884;
+pub const WAIT4: usize = 1_073_741_885;
+pub const KILL: usize = 1_073_741_886;
+pub const UNAME: usize = 1_073_741_887;
+pub const SEMGET: usize = 1_073_741_888;
+pub const SEMOP: usize = 1_073_741_889;
+pub const SEMCTL: usize = 1_073_741_890;
+pub const SHMDT: usize = 1_073_741_891;
+pub const MSGGET: usize = 1_073_741_892;
+pub const MSGSND: usize = 1_073_741_893;
+pub const MSGRCV: usize = 1_073_741_894;
+pub const MSGCTL: usize = 1_073_741_895;
+pub const FCNTL: usize = 1_073_741_896;
+pub const FLOCK: usize = 1_073_741_897;
+pub const FSYNC: usize = 1_073_741_898;
+pub const FDATASYNC: usize = 1_073_741_899;
+pub const TRUNCATE: usize = 1_073_741_900;
+pub const FTRUNCATE: usize = 1_073_741_901;
+pub const GETDENTS: usize = 1_073_741_902;
+pub const GETCWD: usize = 1_073_741_903;
+pub const CHDIR: usize = 1_073_741_904;
+pub const FCHDIR: usize = 1_073_741_905;
+pub const RENAME: usize = 1_073_741_906;
+pub const MKDIR: usize = 1_073_741_907;
+pub const RMDIR: usize = 1_073_741_908;
+pub const CREAT: usize = 1_073_741_909;
+pub const LINK: usize = 1_073_741_910;
+pub const UNLINK: usize = 1_073_741_911;
+pub const SYMLINK: usize = 1_073_741_912;
+pub const READLINK: usize = 1_073_741_913;
+pub const CHMOD: usize = 1_073_741_914;
+pub const FCHMOD: usize = 1_073_741_915;
+pub const CHOWN: usize = 1_073_741_916;
+pub const FCHOWN: usize = 1_073_741_917;
+pub const LCHOWN: usize = 1_073_741_918;
+pub const UMASK: usize = 1_073_741_919;
+pub const GETTIMEOFDAY: usize = 1_073_741_920;
+pub const GETRLIMIT: usize = 1_073_741_921;
+pub const GETRUSAGE: usize = 1_073_741_922;
+pub const SYSINFO: usize = 1_073_741_923;
+pub const TIMES: usize = 1_073_741_924;
+pub const GETUID: usize = 1_073_741_926;
+pub const SYSLOG: usize = 1_073_741_927;
+pub const GETGID: usize = 1_073_741_928;
+pub const SETUID: usize = 1_073_741_929;
+pub const SETGID: usize = 1_073_741_930;
+pub const GETEUID: usize = 1_073_741_931;
+pub const GETEGID: usize = 1_073_741_932;
+pub const SETPGID: usize = 1_073_741_933;
+pub const GETPPID: usize = 1_073_741_934;
+pub const GETPGRP: usize = 1_073_741_935;
+pub const SETSID: usize = 1_073_741_936;
+pub const SETREUID: usize = 1_073_741_937;
+pub const SETREGID: usize = 1_073_741_938;
+pub const GETGROUPS: usize = 1_073_741_939;
+pub const SETGROUPS: usize = 1_073_741_940;
+pub const SETRESUID: usize = 1_073_741_941;
+pub const GETRESUID: usize = 1_073_741_942;
+pub const SETRESGID: usize = 1_073_741_943;
+pub const GETRESGID: usize = 1_073_741_944;
+pub const GETPGID: usize = 1_073_741_945;
+pub const SETFSUID: usize = 1_073_741_946;
+pub const SETFSGID: usize = 1_073_741_947;
+pub const GETSID: usize = 1_073_741_948;
+pub const CAPGET: usize = 1_073_741_949;
+pub const CAPSET: usize = 1_073_741_950;
+pub const RT_SIGSUSPEND: usize = 1_073_741_954;
+pub const UTIME: usize = 1_073_741_956;
+pub const MKNOD: usize = 1_073_741_957;
+pub const PERSONALITY: usize = 1_073_741_959;
+pub const USTAT: usize = 1_073_741_960;
+pub const STATFS: usize = 1_073_741_961;
+pub const FSTATFS: usize = 1_073_741_962;
+pub const SYSFS: usize = 1_073_741_963;
+pub const GETPRIORITY: usize = 1_073_741_964;
+pub const SETPRIORITY: usize = 1_073_741_965;
+pub const SCHED_SETPARAM: usize = 1_073_741_966;
+pub const SCHED_GETPARAM: usize = 1_073_741_967;
+pub const SCHED_SETSCHEDULER: usize = 1_073_741_968;
+pub const SCHED_GETSCHEDULER: usize = 1_073_741_969;
+pub const SCHED_GET_PRIORITY_MAX: usize = 1_073_741_970;
+pub const SCHED_GET_PRIORITY_MIN: usize = 1_073_741_971;
+pub const SCHED_RR_GET_INTERVAL: usize = 1_073_741_972;
+pub const MLOCK: usize = 1_073_741_973;
+pub const MUNLOCK: usize = 1_073_741_974;
+pub const MLOCKALL: usize = 1_073_741_975;
+pub const MUNLOCKALL: usize = 1_073_741_976;
+pub const VHANGUP: usize = 1_073_741_977;
+pub const MODIFY_LDT: usize = 1_073_741_978;
+pub const PIVOT_ROOT: usize = 1_073_741_979;
+pub const PRCTL: usize = 1_073_741_981;
+pub const ARCH_PRCTL: usize = 1_073_741_982;
+pub const ADJTIMEX: usize = 1_073_741_983;
+pub const SETRLIMIT: usize = 1_073_741_984;
+pub const CHROOT: usize = 1_073_741_985;
+pub const SYNC: usize = 1_073_741_986;
+pub const ACCT: usize = 1_073_741_987;
+pub const SETTIMEOFDAY: usize = 1_073_741_988;
+pub const MOUNT: usize = 1_073_741_989;
+pub const UMOUNT2: usize = 1_073_741_990;
+pub const SWAPON: usize = 1_073_741_991;
+pub const SWAPOFF: usize = 1_073_741_992;
+pub const REBOOT: usize = 1_073_741_993;
+pub const SETHOSTNAME: usize = 1_073_741_994;
+pub const SETDOMAINNAME: usize = 1_073_741_995;
+pub const IOPL: usize = 1_073_741_996;
+pub const IOPERM: usize = 1_073_741_997;
+pub const INIT_MODULE: usize = 1_073_741_999;
+pub const DELETE_MODULE: usize = 1_073_742_000;
+pub const QUOTACTL: usize = 1_073_742_003;
+pub const GETPMSG: usize = 1_073_742_005;
+pub const PUTPMSG: usize = 1_073_742_006;
+pub const AFS_SYSCALL: usize = 1_073_742_007;
+pub const TUXCALL: usize = 1_073_742_008;
+pub const SECURITY: usize = 1_073_742_009;
+pub const GETTID: usize = 1_073_742_010;
+pub const READAHEAD: usize = 1_073_742_011;
+pub const SETXATTR: usize = 1_073_742_012;
+pub const LSETXATTR: usize = 1_073_742_013;
+pub const FSETXATTR: usize = 1_073_742_014;
+pub const GETXATTR: usize = 1_073_742_015;
+pub const LGETXATTR: usize = 1_073_742_016;
+pub const FGETXATTR: usize = 1_073_742_017;
+pub const LISTXATTR: usize = 1_073_742_018;
+pub const LLISTXATTR: usize = 1_073_742_019;
+pub const FLISTXATTR: usize = 1_073_742_020;
+pub const REMOVEXATTR: usize = 1_073_742_021;
+pub const LREMOVEXATTR: usize = 1_073_742_022;
+pub const FREMOVEXATTR: usize = 1_073_742_023;
+pub const TKILL: usize = 1_073_742_024;
+pub const TIME: usize = 1_073_742_025;
+pub const FUTEX: usize = 1_073_742_026;
+pub const SCHED_SETAFFINITY: usize = 1_073_742_027;
+pub const SCHED_GETAFFINITY: usize = 1_073_742_028;
+pub const IO_DESTROY: usize = 1_073_742_031;
+pub const IO_GETEVENTS: usize = 1_073_742_032;
+pub const IO_CANCEL: usize = 1_073_742_034;
+pub const LOOKUP_DCOOKIE: usize = 1_073_742_036;
+pub const EPOLL_CREATE: usize = 1_073_742_037;
+pub const REMAP_FILE_PAGES: usize = 1_073_742_040;
+pub const GETDENTS64: usize = 1_073_742_041;
+pub const SET_TID_ADDRESS: usize = 1_073_742_042;
+pub const RESTART_SYSCALL: usize = 1_073_742_043;
+pub const SEMTIMEDOP: usize = 1_073_742_044;
+pub const FADVISE64: usize = 1_073_742_045;
+pub const TIMER_SETTIME: usize = 1_073_742_047;
+pub const TIMER_GETTIME: usize = 1_073_742_048;
+pub const TIMER_GETOVERRUN: usize = 1_073_742_049;
+pub const TIMER_DELETE: usize = 1_073_742_050;
+pub const CLOCK_SETTIME: usize = 1_073_742_051;
+pub const CLOCK_GETTIME: usize = 1_073_742_052;
+pub const CLOCK_GETRES: usize = 1_073_742_053;
+pub const CLOCK_NANOSLEEP: usize = 1_073_742_054;
+pub const EXIT_GROUP: usize = 1_073_742_055;
+pub const EPOLL_WAIT: usize = 1_073_742_056;
+pub const EPOLL_CTL: usize = 1_073_742_057;
+pub const TGKILL: usize = 1_073_742_058;
+pub const UTIMES: usize = 1_073_742_059;
+pub const MBIND: usize = 1_073_742_061;
+pub const SET_MEMPOLICY: usize = 1_073_742_062;
+pub const GET_MEMPOLICY: usize = 1_073_742_063;
+pub const MQ_OPEN: usize = 1_073_742_064;
+pub const MQ_UNLINK: usize = 1_073_742_065;
+pub const MQ_TIMEDSEND: usize = 1_073_742_066;
+pub const MQ_TIMEDRECEIVE: usize = 1_073_742_067;
+pub const MQ_GETSETATTR: usize = 1_073_742_069;
+pub const ADD_KEY: usize = 1_073_742_072;
+pub const REQUEST_KEY: usize = 1_073_742_073;
+pub const KEYCTL: usize = 1_073_742_074;
+pub const IOPRIO_SET: usize = 1_073_742_075;
+pub const IOPRIO_GET: usize = 1_073_742_076;
+pub const INOTIFY_INIT: usize = 1_073_742_077;
+pub const INOTIFY_ADD_WATCH: usize = 1_073_742_078;
+pub const INOTIFY_RM_WATCH: usize = 1_073_742_079;
+pub const MIGRATE_PAGES: usize = 1_073_742_080;
+pub const OPENAT: usize = 1_073_742_081;
+pub const MKDIRAT: usize = 1_073_742_082;
+pub const MKNODAT: usize = 1_073_742_083;
+pub const FCHOWNAT: usize = 1_073_742_084;
+pub const FUTIMESAT: usize = 1_073_742_085;
+pub const NEWFSTATAT: usize = 1_073_742_086;
+pub const UNLINKAT: usize = 1_073_742_087;
+pub const RENAMEAT: usize = 1_073_742_088;
+pub const LINKAT: usize = 1_073_742_089;
+pub const SYMLINKAT: usize = 1_073_742_090;
+pub const READLINKAT: usize = 1_073_742_091;
+pub const FCHMODAT: usize = 1_073_742_092;
+pub const FACCESSAT: usize = 1_073_742_093;
+pub const PSELECT6: usize = 1_073_742_094;
+pub const PPOLL: usize = 1_073_742_095;
+pub const UNSHARE: usize = 1_073_742_096;
+pub const SPLICE: usize = 1_073_742_099;
+pub const TEE: usize = 1_073_742_100;
+pub const SYNC_FILE_RANGE: usize = 1_073_742_101;
+pub const UTIMENSAT: usize = 1_073_742_104;
+pub const EPOLL_PWAIT: usize = 1_073_742_105;
+pub const SIGNALFD: usize = 1_073_742_106;
+pub const TIMERFD_CREATE: usize = 1_073_742_107;
+pub const EVENTFD: usize = 1_073_742_108;
+pub const FALLOCATE: usize = 1_073_742_109;
+pub const TIMERFD_SETTIME: usize = 1_073_742_110;
+pub const TIMERFD_GETTIME: usize = 1_073_742_111;
+pub const ACCEPT4: usize = 1_073_742_112;
+pub const SIGNALFD4: usize = 1_073_742_113;
+pub const EVENTFD2: usize = 1_073_742_114;
+pub const EPOLL_CREATE1: usize = 1_073_742_115;
+pub const DUP3: usize = 1_073_742_116;
+pub const PIPE2: usize = 1_073_742_117;
+pub const INOTIFY_INIT1: usize = 1_073_742_118;
+pub const PERF_EVENT_OPEN: usize = 1_073_742_122;
+pub const FANOTIFY_INIT: usize = 1_073_742_124;
+pub const FANOTIFY_MARK: usize = 1_073_742_125;
+pub const PRLIMIT64: usize = 1_073_742_126;
+pub const NAME_TO_HANDLE_AT: usize = 1_073_742_127;
+pub const OPEN_BY_HANDLE_AT: usize = 1_073_742_128;
+pub const CLOCK_ADJTIME: usize = 1_073_742_129;
+pub const SYNCFS: usize = 1_073_742_130;
+pub const SETNS: usize = 1_073_742_132;
+pub const GETCPU: usize = 1_073_742_133;
+pub const KCMP: usize = 1_073_742_136;
+pub const FINIT_MODULE: usize = 1_073_742_137;
+pub const SCHED_SETATTR: usize = 1_073_742_138;
+pub const SCHED_GETATTR: usize = 1_073_742_139;
+pub const RENAMEAT2: usize = 1_073_742_140;
+pub const SECCOMP: usize = 1_073_742_141;
+pub const GETRANDOM: usize = 1_073_742_142;
+pub const MEMFD_CREATE: usize = 1_073_742_143;
+pub const KEXEC_FILE_LOAD: usize = 1_073_742_144;
+pub const BPF: usize = 1_073_742_145;
+pub const USERFAULTFD: usize = 1_073_742_147;
+pub const MEMBARRIER: usize = 1_073_742_148;
+pub const MLOCK2: usize = 1_073_742_149;
+pub const COPY_FILE_RANGE: usize = 1_073_742_150;
+pub const PKEY_MPROTECT: usize = 1_073_742_153;
+pub const PKEY_ALLOC: usize = 1_073_742_154;
+pub const PKEY_FREE: usize = 1_073_742_155;
+pub const STATX: usize = 1_073_742_156;
+pub const IO_PGETEVENTS: usize = 1_073_742_157;
+pub const RSEQ: usize = 1_073_742_158;
+pub const PIDFD_SEND_SIGNAL: usize = 1_073_742_248;
+pub const IO_URING_SETUP: usize = 1_073_742_249;
+pub const IO_URING_ENTER: usize = 1_073_742_250;
+pub const IO_URING_REGISTER: usize = 1_073_742_251;
+pub const OPEN_TREE: usize = 1_073_742_252;
+pub const MOVE_MOUNT: usize = 1_073_742_253;
+pub const FSOPEN: usize = 1_073_742_254;
+pub const FSCONFIG: usize = 1_073_742_255;
+pub const FSMOUNT: usize = 1_073_742_256;
+pub const FSPICK: usize = 1_073_742_257;
+pub const PIDFD_OPEN: usize = 1_073_742_258;
+pub const CLONE3: usize = 1_073_742_259;
+pub const CLOSE_RANGE: usize = 1_073_742_260;
+pub const OPENAT2: usize = 1_073_742_261;
+pub const PIDFD_GETFD: usize = 1_073_742_262;
+pub const FACCESSAT2: usize = 1_073_742_263;
+pub const PROCESS_MADVISE: usize = 1_073_742_264;
+pub const EPOLL_PWAIT2: usize = 1_073_742_265;
+pub const MOUNT_SETATTR: usize = 1_073_742_266;
+pub const QUOTACTL_FD: usize = 1_073_742_267;
+pub const LANDLOCK_CREATE_RULESET: usize = 1_073_742_268;
+pub const LANDLOCK_ADD_RULE: usize = 1_073_742_269;
+pub const LANDLOCK_RESTRICT_SELF: usize = 1_073_742_270;
+pub const MEMFD_SECRET: usize = 1_073_742_271;
+pub const PROCESS_MRELEASE: usize = 1_073_742_272;
+pub const FUTEX_WAITV: usize = 1_073_742_273;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 1_073_742_274;
+pub const RT_SIGACTION: usize = 1_073_742_336;
+pub const RT_SIGRETURN: usize = 1_073_742_337;
+pub const IOCTL: usize = 1_073_742_338;
+pub const READV: usize = 1_073_742_339;
+pub const WRITEV: usize = 1_073_742_340;
+pub const RECVFROM: usize = 1_073_742_341;
+pub const SENDMSG: usize = 1_073_742_342;
+pub const RECVMSG: usize = 1_073_742_343;
+pub const EXECVE: usize = 1_073_742_344;
+pub const PTRACE: usize = 1_073_742_345;
+pub const RT_SIGPENDING: usize = 1_073_742_346;
+pub const RT_SIGTIMEDWAIT: usize = 1_073_742_347;
+pub const RT_SIGQUEUEINFO: usize = 1_073_742_348;
+pub const SIGALTSTACK: usize = 1_073_742_349;
+pub const TIMER_CREATE: usize = 1_073_742_350;
+pub const MQ_NOTIFY: usize = 1_073_742_351;
+pub const KEXEC_LOAD: usize = 1_073_742_352;
+pub const WAITID: usize = 1_073_742_353;
+pub const SET_ROBUST_LIST: usize = 1_073_742_354;
+pub const GET_ROBUST_LIST: usize = 1_073_742_355;
+pub const VMSPLICE: usize = 1_073_742_356;
+pub const MOVE_PAGES: usize = 1_073_742_357;
+pub const PREADV: usize = 1_073_742_358;
+pub const PWRITEV: usize = 1_073_742_359;
+pub const RT_TGSIGQUEUEINFO: usize = 1_073_742_360;
+pub const RECVMMSG: usize = 1_073_742_361;
+pub const SENDMMSG: usize = 1_073_742_362;
+pub const PROCESS_VM_READV: usize = 1_073_742_363;
+pub const PROCESS_VM_WRITEV: usize = 1_073_742_364;
+pub const SETSOCKOPT: usize = 1_073_742_365;
+pub const GETSOCKOPT: usize = 1_073_742_366;
+pub const IO_SETUP: usize = 1_073_742_367;
+pub const IO_SUBMIT: usize = 1_073_742_368;
+pub const EXECVEAT: usize = 1_073_742_369;
+pub const PREADV2: usize = 1_073_742_370;
+pub const PWRITEV2: usize = 1_073_742_371;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    Read = READ,
+    Write = WRITE,
+    Open = OPEN,
+    Close = CLOSE,
+    Stat = STAT,
+    Fstat = FSTAT,
+    Lstat = LSTAT,
+    Poll = POLL,
+    Lseek = LSEEK,
+    Mmap = MMAP,
+    Mprotect = MPROTECT,
+    Munmap = MUNMAP,
+    Brk = BRK,
+    RtSigprocmask = RT_SIGPROCMASK,
+    Pread64 = PREAD64,
+    Pwrite64 = PWRITE64,
+    Access = ACCESS,
+    Pipe = PIPE,
+    Select = SELECT,
+    SchedYield = SCHED_YIELD,
+    Mremap = MREMAP,
+    Msync = MSYNC,
+    Mincore = MINCORE,
+    Madvise = MADVISE,
+    Shmget = SHMGET,
+    Shmat = SHMAT,
+    Shmctl = SHMCTL,
+    Dup = DUP,
+    Dup2 = DUP2,
+    Pause = PAUSE,
+    Nanosleep = NANOSLEEP,
+    Getitimer = GETITIMER,
+    Alarm = ALARM,
+    Setitimer = SETITIMER,
+    Getpid = GETPID,
+    Sendfile = SENDFILE,
+    Socket = SOCKET,
+    Connect = CONNECT,
+    Accept = ACCEPT,
+    Sendto = SENDTO,
+    Shutdown = SHUTDOWN,
+    Bind = BIND,
+    Listen = LISTEN,
+    Getsockname = GETSOCKNAME,
+    Getpeername = GETPEERNAME,
+    Socketpair = SOCKETPAIR,
+    Clone = CLONE,
+    Fork = FORK,
+    Vfork = VFORK,
+    Exit = EXIT,
+    Wait4 = WAIT4,
+    Kill = KILL,
+    Uname = UNAME,
+    Semget = SEMGET,
+    Semop = SEMOP,
+    Semctl = SEMCTL,
+    Shmdt = SHMDT,
+    Msgget = MSGGET,
+    Msgsnd = MSGSND,
+    Msgrcv = MSGRCV,
+    Msgctl = MSGCTL,
+    Fcntl = FCNTL,
+    Flock = FLOCK,
+    Fsync = FSYNC,
+    Fdatasync = FDATASYNC,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    Getdents = GETDENTS,
+    Getcwd = GETCWD,
+    Chdir = CHDIR,
+    Fchdir = FCHDIR,
+    Rename = RENAME,
+    Mkdir = MKDIR,
+    Rmdir = RMDIR,
+    Creat = CREAT,
+    Link = LINK,
+    Unlink = UNLINK,
+    Symlink = SYMLINK,
+    Readlink = READLINK,
+    Chmod = CHMOD,
+    Fchmod = FCHMOD,
+    Chown = CHOWN,
+    Fchown = FCHOWN,
+    Lchown = LCHOWN,
+    Umask = UMASK,
+    Gettimeofday = GETTIMEOFDAY,
+    Getrlimit = GETRLIMIT,
+    Getrusage = GETRUSAGE,
+    Sysinfo = SYSINFO,
+    Times = TIMES,
+    Getuid = GETUID,
+    Syslog = SYSLOG,
+    Getgid = GETGID,
+    Setuid = SETUID,
+    Setgid = SETGID,
+    Geteuid = GETEUID,
+    Getegid = GETEGID,
+    Setpgid = SETPGID,
+    Getppid = GETPPID,
+    Getpgrp = GETPGRP,
+    Setsid = SETSID,
+    Setreuid = SETREUID,
+    Setregid = SETREGID,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Setresuid = SETRESUID,
+    Getresuid = GETRESUID,
+    Setresgid = SETRESGID,
+    Getresgid = GETRESGID,
+    Getpgid = GETPGID,
+    Setfsuid = SETFSUID,
+    Setfsgid = SETFSGID,
+    Getsid = GETSID,
+    Capget = CAPGET,
+    Capset = CAPSET,
+    RtSigsuspend = RT_SIGSUSPEND,
+    Utime = UTIME,
+    Mknod = MKNOD,
+    Personality = PERSONALITY,
+    Ustat = USTAT,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Sysfs = SYSFS,
+    Getpriority = GETPRIORITY,
+    Setpriority = SETPRIORITY,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    Vhangup = VHANGUP,
+    ModifyLdt = MODIFY_LDT,
+    PivotRoot = PIVOT_ROOT,
+    Prctl = PRCTL,
+    ArchPrctl = ARCH_PRCTL,
+    Adjtimex = ADJTIMEX,
+    Setrlimit = SETRLIMIT,
+    Chroot = CHROOT,
+    Sync = SYNC,
+    Acct = ACCT,
+    Settimeofday = SETTIMEOFDAY,
+    Mount = MOUNT,
+    Umount2 = UMOUNT2,
+    Swapon = SWAPON,
+    Swapoff = SWAPOFF,
+    Reboot = REBOOT,
+    Sethostname = SETHOSTNAME,
+    Setdomainname = SETDOMAINNAME,
+    Iopl = IOPL,
+    Ioperm = IOPERM,
+    InitModule = INIT_MODULE,
+    DeleteModule = DELETE_MODULE,
+    Quotactl = QUOTACTL,
+    Getpmsg = GETPMSG,
+    Putpmsg = PUTPMSG,
+    AfsSyscall = AFS_SYSCALL,
+    Tuxcall = TUXCALL,
+    Security = SECURITY,
+    Gettid = GETTID,
+    Readahead = READAHEAD,
+    Setxattr = SETXATTR,
+    Lsetxattr = LSETXATTR,
+    Fsetxattr = FSETXATTR,
+    Getxattr = GETXATTR,
+    Lgetxattr = LGETXATTR,
+    Fgetxattr = FGETXATTR,
+    Listxattr = LISTXATTR,
+    Llistxattr = LLISTXATTR,
+    Flistxattr = FLISTXATTR,
+    Removexattr = REMOVEXATTR,
+    Lremovexattr = LREMOVEXATTR,
+    Fremovexattr = FREMOVEXATTR,
+    Tkill = TKILL,
+    Time = TIME,
+    Futex = FUTEX,
+    SchedSetaffinity = SCHED_SETAFFINITY,
+    SchedGetaffinity = SCHED_GETAFFINITY,
+    IoDestroy = IO_DESTROY,
+    IoGetevents = IO_GETEVENTS,
+    IoCancel = IO_CANCEL,
+    LookupDcookie = LOOKUP_DCOOKIE,
+    EpollCreate = EPOLL_CREATE,
+    RemapFilePages = REMAP_FILE_PAGES,
+    Getdents64 = GETDENTS64,
+    SetTidAddress = SET_TID_ADDRESS,
+    RestartSyscall = RESTART_SYSCALL,
+    Semtimedop = SEMTIMEDOP,
+    Fadvise64 = FADVISE64,
+    TimerSettime = TIMER_SETTIME,
+    TimerGettime = TIMER_GETTIME,
+    TimerGetoverrun = TIMER_GETOVERRUN,
+    TimerDelete = TIMER_DELETE,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGettime = CLOCK_GETTIME,
+    ClockGetres = CLOCK_GETRES,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    ExitGroup = EXIT_GROUP,
+    EpollWait = EPOLL_WAIT,
+    EpollCtl = EPOLL_CTL,
+    Tgkill = TGKILL,
+    Utimes = UTIMES,
+    Mbind = MBIND,
+    SetMempolicy = SET_MEMPOLICY,
+    GetMempolicy = GET_MEMPOLICY,
+    MqOpen = MQ_OPEN,
+    MqUnlink = MQ_UNLINK,
+    MqTimedsend = MQ_TIMEDSEND,
+    MqTimedreceive = MQ_TIMEDRECEIVE,
+    MqGetsetattr = MQ_GETSETATTR,
+    AddKey = ADD_KEY,
+    RequestKey = REQUEST_KEY,
+    Keyctl = KEYCTL,
+    IoprioSet = IOPRIO_SET,
+    IoprioGet = IOPRIO_GET,
+    InotifyInit = INOTIFY_INIT,
+    InotifyAddWatch = INOTIFY_ADD_WATCH,
+    InotifyRmWatch = INOTIFY_RM_WATCH,
+    MigratePages = MIGRATE_PAGES,
+    Openat = OPENAT,
+    Mkdirat = MKDIRAT,
+    Mknodat = MKNODAT,
+    Fchownat = FCHOWNAT,
+    Futimesat = FUTIMESAT,
+    Newfstatat = NEWFSTATAT,
+    Unlinkat = UNLINKAT,
+    Renameat = RENAMEAT,
+    Linkat = LINKAT,
+    Symlinkat = SYMLINKAT,
+    Readlinkat = READLINKAT,
+    Fchmodat = FCHMODAT,
+    Faccessat = FACCESSAT,
+    Pselect6 = PSELECT6,
+    Ppoll = PPOLL,
+    Unshare = UNSHARE,
+    Splice = SPLICE,
+    Tee = TEE,
+    SyncFileRange = SYNC_FILE_RANGE,
+    Utimensat = UTIMENSAT,
+    EpollPwait = EPOLL_PWAIT,
+    Signalfd = SIGNALFD,
+    TimerfdCreate = TIMERFD_CREATE,
+    Eventfd = EVENTFD,
+    Fallocate = FALLOCATE,
+    TimerfdSettime = TIMERFD_SETTIME,
+    TimerfdGettime = TIMERFD_GETTIME,
+    Accept4 = ACCEPT4,
+    Signalfd4 = SIGNALFD4,
+    Eventfd2 = EVENTFD2,
+    EpollCreate1 = EPOLL_CREATE1,
+    Dup3 = DUP3,
+    Pipe2 = PIPE2,
+    InotifyInit1 = INOTIFY_INIT1,
+    PerfEventOpen = PERF_EVENT_OPEN,
+    FanotifyInit = FANOTIFY_INIT,
+    FanotifyMark = FANOTIFY_MARK,
+    Prlimit64 = PRLIMIT64,
+    NameToHandleAt = NAME_TO_HANDLE_AT,
+    OpenByHandleAt = OPEN_BY_HANDLE_AT,
+    ClockAdjtime = CLOCK_ADJTIME,
+    Syncfs = SYNCFS,
+    Setns = SETNS,
+    Getcpu = GETCPU,
+    Kcmp = KCMP,
+    FinitModule = FINIT_MODULE,
+    SchedSetattr = SCHED_SETATTR,
+    SchedGetattr = SCHED_GETATTR,
+    Renameat2 = RENAMEAT2,
+    Seccomp = SECCOMP,
+    Getrandom = GETRANDOM,
+    MemfdCreate = MEMFD_CREATE,
+    KexecFileLoad = KEXEC_FILE_LOAD,
+    Bpf = BPF,
+    Userfaultfd = USERFAULTFD,
+    Membarrier = MEMBARRIER,
+    Mlock2 = MLOCK2,
+    CopyFileRange = COPY_FILE_RANGE,
+    PkeyMprotect = PKEY_MPROTECT,
+    PkeyAlloc = PKEY_ALLOC,
+    PkeyFree = PKEY_FREE,
+    Statx = STATX,
+    IoPgetevents = IO_PGETEVENTS,
+    Rseq = RSEQ,
+    PidfdSendSignal = PIDFD_SEND_SIGNAL,
+    IoUringSetup = IO_URING_SETUP,
+    IoUringEnter = IO_URING_ENTER,
+    IoUringRegister = IO_URING_REGISTER,
+    OpenTree = OPEN_TREE,
+    MoveMount = MOVE_MOUNT,
+    Fsopen = FSOPEN,
+    Fsconfig = FSCONFIG,
+    Fsmount = FSMOUNT,
+    Fspick = FSPICK,
+    PidfdOpen = PIDFD_OPEN,
+    Clone3 = CLONE3,
+    CloseRange = CLOSE_RANGE,
+    Openat2 = OPENAT2,
+    PidfdGetfd = PIDFD_GETFD,
+    Faccessat2 = FACCESSAT2,
+    ProcessMadvise = PROCESS_MADVISE,
+    EpollPwait2 = EPOLL_PWAIT2,
+    MountSetattr = MOUNT_SETATTR,
+    QuotactlFd = QUOTACTL_FD,
+    LandlockCreateRuleset = LANDLOCK_CREATE_RULESET,
+    LandlockAddRule = LANDLOCK_ADD_RULE,
+    LandlockRestrictSelf = LANDLOCK_RESTRICT_SELF,
+    MemfdSecret = MEMFD_SECRET,
+    ProcessMrelease = PROCESS_MRELEASE,
+    FutexWaitv = FUTEX_WAITV,
+    SetMempolicyHomeNode = SET_MEMPOLICY_HOME_NODE,
+    RtSigaction = RT_SIGACTION,
+    RtSigreturn = RT_SIGRETURN,
+    Ioctl = IOCTL,
+    Readv = READV,
+    Writev = WRITEV,
+    Recvfrom = RECVFROM,
+    Sendmsg = SENDMSG,
+    Recvmsg = RECVMSG,
+    Execve = EXECVE,
+    Ptrace = PTRACE,
+    RtSigpending = RT_SIGPENDING,
+    RtSigtimedwait = RT_SIGTIMEDWAIT,
+    RtSigqueueinfo = RT_SIGQUEUEINFO,
+    Sigaltstack = SIGALTSTACK,
+    TimerCreate = TIMER_CREATE,
+    MqNotify = MQ_NOTIFY,
+    KexecLoad = KEXEC_LOAD,
+    Waitid = WAITID,
+    SetRobustList = SET_ROBUST_LIST,
+    GetRobustList = GET_ROBUST_LIST,
+    Vmsplice = VMSPLICE,
+    MovePages = MOVE_PAGES,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    RtTgsigqueueinfo = RT_TGSIGQUEUEINFO,
+    Recvmmsg = RECVMMSG,
+    Sendmmsg = SENDMMSG,
+    ProcessVmReadv = PROCESS_VM_READV,
+    ProcessVmWritev = PROCESS_VM_WRITEV,
+    Setsockopt = SETSOCKOPT,
+    Getsockopt = GETSOCKOPT,
+    IoSetup = IO_SETUP,
+    IoSubmit = IO_SUBMIT,
+    Execveat = EXECVEAT,
+    Preadv2 = PREADV2,
+    Pwritev2 = PWRITEV2,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::Read,
+        Self::Write,
+        Self::Open,
+        Self::Close,
+        Self::Stat,
+        Self::Fstat,
+        Self::Lstat,
+        Self::Poll,
+        Self::Lseek,
+        Self::Mmap,
+        Self::Mprotect,
+        Self::Munmap,
+        Self::Brk,
+        Self::RtSigprocmask,
+        Self::Pread64,
+        Self::Pwrite64,
+        Self::Access,
+        Self::Pipe,
+        Self::Select,
+        Self::SchedYield,
+        Self::Mremap,
+        Self::Msync,
+        Self::Mincore,
+        Self::Madvise,
+        Self::Shmget,
+        Self::Shmat,
+        Self::Shmctl,
+        Self::Dup,
+        Self::Dup2,
+        Self::Pause,
+        Self::Nanosleep,
+        Self::Getitimer,
+        Self::Alarm,
+        Self::Setitimer,
+        Self::Getpid,
+        Self::Sendfile,
+        Self::Socket,
+        Self::Connect,
+        Self::Accept,
+        Self::Sendto,
+        Self::Shutdown,
+        Self::Bind,
+        Self::Listen,
+        Self::Getsockname,
+        Self::Getpeername,
+        Self::Socketpair,
+        Self::Clone,
+        Self::Fork,
+        Self::Vfork,
+        Self::Exit,
+        Self::Wait4,
+        Self::Kill,
+        Self::Uname,
+        Self::Semget,
+        Self::Semop,
+        Self::Semctl,
+        Self::Shmdt,
+        Self::Msgget,
+        Self::Msgsnd,
+        Self::Msgrcv,
+        Self::Msgctl,
+        Self::Fcntl,
+        Self::Flock,
+        Self::Fsync,
+        Self::Fdatasync,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::Getdents,
+        Self::Getcwd,
+        Self::Chdir,
+        Self::Fchdir,
+        Self::Rename,
+        Self::Mkdir,
+        Self::Rmdir,
+        Self::Creat,
+        Self::Link,
+        Self::Unlink,
+        Self::Symlink,
+        Self::Readlink,
+        Self::Chmod,
+        Self::Fchmod,
+        Self::Chown,
+        Self::Fchown,
+        Self::Lchown,
+        Self::Umask,
+        Self::Gettimeofday,
+        Self::Getrlimit,
+        Self::Getrusage,
+        Self::Sysinfo,
+        Self::Times,
+        Self::Getuid,
+        Self::Syslog,
+        Self::Getgid,
+        Self::Setuid,
+        Self::Setgid,
+        Self::Geteuid,
+        Self::Getegid,
+        Self::Setpgid,
+        Self::Getppid,
+        Self::Getpgrp,
+        Self::Setsid,
+        Self::Setreuid,
+        Self::Setregid,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Setresuid,
+        Self::Getresuid,
+        Self::Setresgid,
+        Self::Getresgid,
+        Self::Getpgid,
+        Self::Setfsuid,
+        Self::Setfsgid,
+        Self::Getsid,
+        Self::Capget,
+        Self::Capset,
+        Self::RtSigsuspend,
+        Self::Utime,
+        Self::Mknod,
+        Self::Personality,
+        Self::Ustat,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Sysfs,
+        Self::Getpriority,
+        Self::Setpriority,
+        Self::SchedSetparam,
+        Self::SchedGetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::Vhangup,
+        Self::ModifyLdt,
+        Self::PivotRoot,
+        Self::Prctl,
+        Self::ArchPrctl,
+        Self::Adjtimex,
+        Self::Setrlimit,
+        Self::Chroot,
+        Self::Sync,
+        Self::Acct,
+        Self::Settimeofday,
+        Self::Mount,
+        Self::Umount2,
+        Self::Swapon,
+        Self::Swapoff,
+        Self::Reboot,
+        Self::Sethostname,
+        Self::Setdomainname,
+        Self::Iopl,
+        Self::Ioperm,
+        Self::InitModule,
+        Self::DeleteModule,
+        Self::Quotactl,
+        Self::Getpmsg,
+        Self::Putpmsg,
+        Self::AfsSyscall,
+        Self::Tuxcall,
+        Self::Security,
+        Self::Gettid,
+        Self::Readahead,
+        Self::Setxattr,
+        Self::Lsetxattr,
+        Self::Fsetxattr,
+        Self::Getxattr,
+        Self::Lgetxattr,
+        Self::Fgetxattr,
+        Self::Listxattr,
+        Self::Llistxattr,
+        Self::Flistxattr,
+        Self::Removexattr,
+        Self::Lremovexattr,
+        Self::Fremovexattr,
+        Self::Tkill,
+        Self::Time,
+        Self::Futex,
+        Self::SchedSetaffinity,
+        Self::SchedGetaffinity,
+        Self::IoDestroy,
+        Self::IoGetevents,
+        Self::IoCancel,
+        Self::LookupDcookie,
+        Self::EpollCreate,
+        Self::RemapFilePages,
+        Self::Getdents64,
+        Self::SetTidAddress,
+        Self::RestartSyscall,
+        Self::Semtimedop,
+        Self::Fadvise64,
+        Self::TimerSettime,
+        Self::TimerGettime,
+        Self::TimerGetoverrun,
+        Self::TimerDelete,
+        Self::ClockSettime,
+        Self::ClockGettime,
+        Self::ClockGetres,
+        Self::ClockNanosleep,
+        Self::ExitGroup,
+        Self::EpollWait,
+        Self::EpollCtl,
+        Self::Tgkill,
+        Self::Utimes,
+        Self::Mbind,
+        Self::SetMempolicy,
+        Self::GetMempolicy,
+        Self::MqOpen,
+        Self::MqUnlink,
+        Self::MqTimedsend,
+        Self::MqTimedreceive,
+        Self::MqGetsetattr,
+        Self::AddKey,
+        Self::RequestKey,
+        Self::Keyctl,
+        Self::IoprioSet,
+        Self::IoprioGet,
+        Self::InotifyInit,
+        Self::InotifyAddWatch,
+        Self::InotifyRmWatch,
+        Self::MigratePages,
+        Self::Openat,
+        Self::Mkdirat,
+        Self::Mknodat,
+        Self::Fchownat,
+        Self::Futimesat,
+        Self::Newfstatat,
+        Self::Unlinkat,
+        Self::Renameat,
+        Self::Linkat,
+        Self::Symlinkat,
+        Self::Readlinkat,
+        Self::Fchmodat,
+        Self::Faccessat,
+        Self::Pselect6,
+        Self::Ppoll,
+        Self::Unshare,
+        Self::Splice,
+        Self::Tee,
+        Self::SyncFileRange,
+        Self::Utimensat,
+        Self::EpollPwait,
+        Self::Signalfd,
+        Self::TimerfdCreate,
+        Self::Eventfd,
+        Self::Fallocate,
+        Self::TimerfdSettime,
+        Self::TimerfdGettime,
+        Self::Accept4,
+        Self::Signalfd4,
+        Self::Eventfd2,
+        Self::EpollCreate1,
+        Self::Dup3,
+        Self::Pipe2,
+        Self::InotifyInit1,
+        Self::PerfEventOpen,
+        Self::FanotifyInit,
+        Self::FanotifyMark,
+        Self::Prlimit64,
+        Self::NameToHandleAt,
+        Self::OpenByHandleAt,
+        Self::ClockAdjtime,
+        Self::Syncfs,
+        Self::Setns,
+        Self::Getcpu,
+        Self::Kcmp,
+        Self::FinitModule,
+        Self::SchedSetattr,
+        Self::SchedGetattr,
+        Self::Renameat2,
+        Self::Seccomp,
+        Self::Getrandom,
+        Self::MemfdCreate,
+        Self::KexecFileLoad,
+        Self::Bpf,
+        Self::Userfaultfd,
+        Self::Membarrier,
+        Self::Mlock2,
+        Self::CopyFileRange,
+        Self::PkeyMprotect,
+        Self::PkeyAlloc,
+        Self::PkeyFree,
+        Self::Statx,
+        Self::IoPgetevents,
+        Self::Rseq,
+        Self::PidfdSendSignal,
+        Self::IoUringSetup,
+        Self::IoUringEnter,
+        Self::IoUringRegister,
+        Self::OpenTree,
+        Self::MoveMount,
+        Self::Fsopen,
+        Self::Fsconfig,
+        Self::Fsmount,
+        Self::Fspick,
+        Self::PidfdOpen,
+        Self::Clone3,
+        Self::CloseRange,
+        Self::Openat2,
+        Self::PidfdGetfd,
+        Self::Faccessat2,
+        Self::ProcessMadvise,
+        Self::EpollPwait2,
+        Self::MountSetattr,
+        Self::QuotactlFd,
+        Self::LandlockCreateRuleset,
+        Self::LandlockAddRule,
+        Self::LandlockRestrictSelf,
+        Self::MemfdSecret,
+        Self::ProcessMrelease,
+        Self::FutexWaitv,
+        Self::SetMempolicyHomeNode,
+        Self::RtSigaction,
+        Self::RtSigreturn,
+        Self::Ioctl,
+        Self::Readv,
+        Self::Writev,
+        Self::Recvfrom,
+        Self::Sendmsg,
+        Self::Recvmsg,
+        Self::Execve,
+        Self::Ptrace,
+        Self::RtSigpending,
+        Self::RtSigtimedwait,
+        Self::RtSigqueueinfo,
+        Self::Sigaltstack,
+        Self::TimerCreate,
+        Self::MqNotify,
+        Self::KexecLoad,
+        Self::Waitid,
+        Self::SetRobustList,
+        Self::GetRobustList,
+        Self::Vmsplice,
+        Self::MovePages,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::RtTgsigqueueinfo,
+        Self::Recvmmsg,
+        Self::Sendmmsg,
+        Self::ProcessVmReadv,
+        Self::ProcessVmWritev,
+        Self::Setsockopt,
+        Self::Getsockopt,
+        Self::IoSetup,
+        Self::IoSubmit,
+        Self::Execveat,
+        Self::Preadv2,
+        Self::Pwritev2,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            OPEN => Some(Self::Open),
+            CLOSE => Some(Self::Close),
+            STAT => Some(Self::Stat),
+            FSTAT => Some(Self::Fstat),
+            LSTAT => Some(Self::Lstat),
+            POLL => Some(Self::Poll),
+            LSEEK => Some(Self::Lseek),
+            MMAP => Some(Self::Mmap),
+            MPROTECT => Some(Self::Mprotect),
+            MUNMAP => Some(Self::Munmap),
+            BRK => Some(Self::Brk),
+            RT_SIGPROCMASK => Some(Self::RtSigprocmask),
+            PREAD64 => Some(Self::Pread64),
+            PWRITE64 => Some(Self::Pwrite64),
+            ACCESS => Some(Self::Access),
+            PIPE => Some(Self::Pipe),
+            SELECT => Some(Self::Select),
+            SCHED_YIELD => Some(Self::SchedYield),
+            MREMAP => Some(Self::Mremap),
+            MSYNC => Some(Self::Msync),
+            MINCORE => Some(Self::Mincore),
+            MADVISE => Some(Self::Madvise),
+            SHMGET => Some(Self::Shmget),
+            SHMAT => Some(Self::Shmat),
+            SHMCTL => Some(Self::Shmctl),
+            DUP => Some(Self::Dup),
+            DUP2 => Some(Self::Dup2),
+            PAUSE => Some(Self::Pause),
+            NANOSLEEP => Some(Self::Nanosleep),
+            GETITIMER => Some(Self::Getitimer),
+            ALARM => Some(Self::Alarm),
+            SETITIMER => Some(Self::Setitimer),
+            GETPID => Some(Self::Getpid),
+            SENDFILE => Some(Self::Sendfile),
+            SOCKET => Some(Self::Socket),
+            CONNECT => Some(Self::Connect),
+            ACCEPT => Some(Self::Accept),
+            SENDTO => Some(Self::Sendto),
+            SHUTDOWN => Some(Self::Shutdown),
+            BIND => Some(Self::Bind),
+            LISTEN => Some(Self::Listen),
+            GETSOCKNAME => Some(Self::Getsockname),
+            GETPEERNAME => Some(Self::Getpeername),
+            SOCKETPAIR => Some(Self::Socketpair),
+            CLONE => Some(Self::Clone),
+            FORK => Some(Self::Fork),
+            VFORK => Some(Self::Vfork),
+            EXIT => Some(Self::Exit),
+            WAIT4 => Some(Self::Wait4),
+            KILL => Some(Self::Kill),
+            UNAME => Some(Self::Uname),
+            SEMGET => Some(Self::Semget),
+            SEMOP => Some(Self::Semop),
+            SEMCTL => Some(Self::Semctl),
+            SHMDT => Some(Self::Shmdt),
+            MSGGET => Some(Self::Msgget),
+            MSGSND => Some(Self::Msgsnd),
+            MSGRCV => Some(Self::Msgrcv),
+            MSGCTL => Some(Self::Msgctl),
+            FCNTL => Some(Self::Fcntl),
+            FLOCK => Some(Self::Flock),
+            FSYNC => Some(Self::Fsync),
+            FDATASYNC => Some(Self::Fdatasync),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            GETDENTS => Some(Self::Getdents),
+            GETCWD => Some(Self::Getcwd),
+            CHDIR => Some(Self::Chdir),
+            FCHDIR => Some(Self::Fchdir),
+            RENAME => Some(Self::Rename),
+            MKDIR => Some(Self::Mkdir),
+            RMDIR => Some(Self::Rmdir),
+            CREAT => Some(Self::Creat),
+            LINK => Some(Self::Link),
+            UNLINK => Some(Self::Unlink),
+            SYMLINK => Some(Self::Symlink),
+            READLINK => Some(Self::Readlink),
+            CHMOD => Some(Self::Chmod),
+            FCHMOD => Some(Self::Fchmod),
+            CHOWN => Some(Self::Chown),
+            FCHOWN => Some(Self::Fchown),
+            LCHOWN => Some(Self::Lchown),
+            UMASK => Some(Self::Umask),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            GETRLIMIT => Some(Self::Getrlimit),
+            GETRUSAGE => Some(Self::Getrusage),
+            SYSINFO => Some(Self::Sysinfo),
+            TIMES => Some(Self::Times),
+            GETUID => Some(Self::Getuid),
+            SYSLOG => Some(Self::Syslog),
+            GETGID => Some(Self::Getgid),
+            SETUID => Some(Self::Setuid),
+            SETGID => Some(Self::Setgid),
+            GETEUID => Some(Self::Geteuid),
+            GETEGID => Some(Self::Getegid),
+            SETPGID => Some(Self::Setpgid),
+            GETPPID => Some(Self::Getppid),
+            GETPGRP => Some(Self::Getpgrp),
+            SETSID => Some(Self::Setsid),
+            SETREUID => Some(Self::Setreuid),
+            SETREGID => Some(Self::Setregid),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            SETRESUID => Some(Self::Setresuid),
+            GETRESUID => Some(Self::Getresuid),
+            SETRESGID => Some(Self::Setresgid),
+            GETRESGID => Some(Self::Getresgid),
+            GETPGID => Some(Self::Getpgid),
+            SETFSUID => Some(Self::Setfsuid),
+            SETFSGID => Some(Self::Setfsgid),
+            GETSID => Some(Self::Getsid),
+            CAPGET => Some(Self::Capget),
+            CAPSET => Some(Self::Capset),
+            RT_SIGSUSPEND => Some(Self::RtSigsuspend),
+            UTIME => Some(Self::Utime),
+            MKNOD => Some(Self::Mknod),
+            PERSONALITY => Some(Self::Personality),
+            USTAT => Some(Self::Ustat),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            SYSFS => Some(Self::Sysfs),
+            GETPRIORITY => Some(Self::Getpriority),
+            SETPRIORITY => Some(Self::Setpriority),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            VHANGUP => Some(Self::Vhangup),
+            MODIFY_LDT => Some(Self::ModifyLdt),
+            PIVOT_ROOT => Some(Self::PivotRoot),
+            PRCTL => Some(Self::Prctl),
+            ARCH_PRCTL => Some(Self::ArchPrctl),
+            ADJTIMEX => Some(Self::Adjtimex),
+            SETRLIMIT => Some(Self::Setrlimit),
+            CHROOT => Some(Self::Chroot),
+            SYNC => Some(Self::Sync),
+            ACCT => Some(Self::Acct),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            MOUNT => Some(Self::Mount),
+            UMOUNT2 => Some(Self::Umount2),
+            SWAPON => Some(Self::Swapon),
+            SWAPOFF => Some(Self::Swapoff),
+            REBOOT => Some(Self::Reboot),
+            SETHOSTNAME => Some(Self::Sethostname),
+            SETDOMAINNAME => Some(Self::Setdomainname),
+            IOPL => Some(Self::Iopl),
+            IOPERM => Some(Self::Ioperm),
+            INIT_MODULE => Some(Self::InitModule),
+            DELETE_MODULE => Some(Self::DeleteModule),
+            QUOTACTL => Some(Self::Quotactl),
+            GETPMSG => Some(Self::Getpmsg),
+            PUTPMSG => Some(Self::Putpmsg),
+            AFS_SYSCALL => Some(Self::AfsSyscall),
+            TUXCALL => Some(Self::Tuxcall),
+            SECURITY => Some(Self::Security),
+            GETTID => Some(Self::Gettid),
+            READAHEAD => Some(Self::Readahead),
+            SETXATTR => Some(Self::Setxattr),
+            LSETXATTR => Some(Self::Lsetxattr),
+            FSETXATTR => Some(Self::Fsetxattr),
+            GETXATTR => Some(Self::Getxattr),
+            LGETXATTR => Some(Self::Lgetxattr),
+            FGETXATTR => Some(Self::Fgetxattr),
+            LISTXATTR => Some(Self::Listxattr),
+            LLISTXATTR => Some(Self::Llistxattr),
+            FLISTXATTR => Some(Self::Flistxattr),
+            REMOVEXATTR => Some(Self::Removexattr),
+            LREMOVEXATTR => Some(Self::Lremovexattr),
+            FREMOVEXATTR => Some(Self::Fremovexattr),
+            TKILL => Some(Self::Tkill),
+            TIME => Some(Self::Time),
+            FUTEX => Some(Self::Futex),
+            SCHED_SETAFFINITY => Some(Self::SchedSetaffinity),
+            SCHED_GETAFFINITY => Some(Self::SchedGetaffinity),
+            IO_DESTROY => Some(Self::IoDestroy),
+            IO_GETEVENTS => Some(Self::IoGetevents),
+            IO_CANCEL => Some(Self::IoCancel),
+            LOOKUP_DCOOKIE => Some(Self::LookupDcookie),
+            EPOLL_CREATE => Some(Self::EpollCreate),
+            REMAP_FILE_PAGES => Some(Self::RemapFilePages),
+            GETDENTS64 => Some(Self::Getdents64),
+            SET_TID_ADDRESS => Some(Self::SetTidAddress),
+            RESTART_SYSCALL => Some(Self::RestartSyscall),
+            SEMTIMEDOP => Some(Self::Semtimedop),
+            FADVISE64 => Some(Self::Fadvise64),
+            TIMER_SETTIME => Some(Self::TimerSettime),
+            TIMER_GETTIME => Some(Self::TimerGettime),
+            TIMER_GETOVERRUN => Some(Self::TimerGetoverrun),
+            TIMER_DELETE => Some(Self::TimerDelete),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            EXIT_GROUP => Some(Self::ExitGroup),
+            EPOLL_WAIT => Some(Self::EpollWait),
+            EPOLL_CTL => Some(Self::EpollCtl),
+            TGKILL => Some(Self::Tgkill),
+            UTIMES => Some(Self::Utimes),
+            MBIND => Some(Self::Mbind),
+            SET_MEMPOLICY => Some(Self::SetMempolicy),
+            GET_MEMPOLICY => Some(Self::GetMempolicy),
+            MQ_OPEN => Some(Self::MqOpen),
+            MQ_UNLINK => Some(Self::MqUnlink),
+            MQ_TIMEDSEND => Some(Self::MqTimedsend),
+            MQ_TIMEDRECEIVE => Some(Self::MqTimedreceive),
+            MQ_GETSETATTR => Some(Self::MqGetsetattr),
+            ADD_KEY => Some(Self::AddKey),
+            REQUEST_KEY => Some(Self::RequestKey),
+            KEYCTL => Some(Self::Keyctl),
+            IOPRIO_SET => Some(Self::IoprioSet),
+            IOPRIO_GET => Some(Self::IoprioGet),
+            INOTIFY_INIT => Some(Self::InotifyInit),
+            INOTIFY_ADD_WATCH => Some(Self::InotifyAddWatch),
+            INOTIFY_RM_WATCH => Some(Self::InotifyRmWatch),
+            MIGRATE_PAGES => Some(Self::MigratePages),
+            OPENAT => Some(Self::Openat),
+            MKDIRAT => Some(Self::Mkdirat),
+            MKNODAT => Some(Self::Mknodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FUTIMESAT => Some(Self::Futimesat),
+            NEWFSTATAT => Some(Self::Newfstatat),
+            UNLINKAT => Some(Self::Unlinkat),
+            RENAMEAT => Some(Self::Renameat),
+            LINKAT => Some(Self::Linkat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            READLINKAT => Some(Self::Readlinkat),
+            FCHMODAT => Some(Self::Fchmodat),
+            FACCESSAT => Some(Self::Faccessat),
+            PSELECT6 => Some(Self::Pselect6),
+            PPOLL => Some(Self::Ppoll),
+            UNSHARE => Some(Self::Unshare),
+            SPLICE => Some(Self::Splice),
+            TEE => Some(Self::Tee),
+            SYNC_FILE_RANGE => Some(Self::SyncFileRange),
+            UTIMENSAT => Some(Self::Utimensat),
+            EPOLL_PWAIT => Some(Self::EpollPwait),
+            SIGNALFD => Some(Self::Signalfd),
+            TIMERFD_CREATE => Some(Self::TimerfdCreate),
+            EVENTFD => Some(Self::Eventfd),
+            FALLOCATE => Some(Self::Fallocate),
+            TIMERFD_SETTIME => Some(Self::TimerfdSettime),
+            TIMERFD_GETTIME => Some(Self::TimerfdGettime),
+            ACCEPT4 => Some(Self::Accept4),
+            SIGNALFD4 => Some(Self::Signalfd4),
+            EVENTFD2 => Some(Self::Eventfd2),
+            EPOLL_CREATE1 => Some(Self::EpollCreate1),
+            DUP3 => Some(Self::Dup3),
+            PIPE2 => Some(Self::Pipe2),
+            INOTIFY_INIT1 => Some(Self::InotifyInit1),
+            PERF_EVENT_OPEN => Some(Self::PerfEventOpen),
+            FANOTIFY_INIT => Some(Self::FanotifyInit),
+            FANOTIFY_MARK => Some(Self::FanotifyMark),
+            PRLIMIT64 => Some(Self::Prlimit64),
+            NAME_TO_HANDLE_AT => Some(Self::NameToHandleAt),
+            OPEN_BY_HANDLE_AT => Some(Self::OpenByHandleAt),
+            CLOCK_ADJTIME => Some(Self::ClockAdjtime),
+            SYNCFS => Some(Self::Syncfs),
+            SETNS => Some(Self::Setns),
+            GETCPU => Some(Self::Getcpu),
+            KCMP => Some(Self::Kcmp),
+            FINIT_MODULE => Some(Self::FinitModule),
+            SCHED_SETATTR => Some(Self::SchedSetattr),
+            SCHED_GETATTR => Some(Self::SchedGetattr),
+            RENAMEAT2 => Some(Self::Renameat2),
+            SECCOMP => Some(Self::Seccomp),
+            GETRANDOM => Some(Self::Getrandom),
+            MEMFD_CREATE => Some(Self::MemfdCreate),
+            KEXEC_FILE_LOAD => Some(Self::KexecFileLoad),
+            BPF => Some(Self::Bpf),
+            USERFAULTFD => Some(Self::Userfaultfd),
+            MEMBARRIER => Some(Self::Membarrier),
+            MLOCK2 => Some(Self::Mlock2),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            PKEY_MPROTECT => Some(Self::PkeyMprotect),
+            PKEY_ALLOC => Some(Self::PkeyAlloc),
+            PKEY_FREE => Some(Self::PkeyFree),
+            STATX => Some(Self::Statx),
+            IO_PGETEVENTS => Some(Self::IoPgetevents),
+            RSEQ => Some(Self::Rseq),
+            PIDFD_SEND_SIGNAL => Some(Self::PidfdSendSignal),
+            IO_URING_SETUP => Some(Self::IoUringSetup),
+            IO_URING_ENTER => Some(Self::IoUringEnter),
+            IO_URING_REGISTER => Some(Self::IoUringRegister),
+            OPEN_TREE => Some(Self::OpenTree),
+            MOVE_MOUNT => Some(Self::MoveMount),
+            FSOPEN => Some(Self::Fsopen),
+            FSCONFIG => Some(Self::Fsconfig),
+            FSMOUNT => Some(Self::Fsmount),
+            FSPICK => Some(Self::Fspick),
+            PIDFD_OPEN => Some(Self::PidfdOpen),
+            CLONE3 => Some(Self::Clone3),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            OPENAT2 => Some(Self::Openat2),
+            PIDFD_GETFD => Some(Self::PidfdGetfd),
+            FACCESSAT2 => Some(Self::Faccessat2),
+            PROCESS_MADVISE => Some(Self::ProcessMadvise),
+            EPOLL_PWAIT2 => Some(Self::EpollPwait2),
+            MOUNT_SETATTR => Some(Self::MountSetattr),
+            QUOTACTL_FD => Some(Self::QuotactlFd),
+            LANDLOCK_CREATE_RULESET => Some(Self::LandlockCreateRuleset),
+            LANDLOCK_ADD_RULE => Some(Self::LandlockAddRule),
+            LANDLOCK_RESTRICT_SELF => Some(Self::LandlockRestrictSelf),
+            MEMFD_SECRET => Some(Self::MemfdSecret),
+            PROCESS_MRELEASE => Some(Self::ProcessMrelease),
+            FUTEX_WAITV => Some(Self::FutexWaitv),
+            SET_MEMPOLICY_HOME_NODE => Some(Self::SetMempolicyHomeNode),
+            RT_SIGACTION => Some(Self::RtSigaction),
+            RT_SIGRETURN => Some(Self::RtSigreturn),
+            IOCTL => Some(Self::Ioctl),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            RECVFROM => Some(Self::Recvfrom),
+            SENDMSG => Some(Self::Sendmsg),
+            RECVMSG => Some(Self::Recvmsg),
+            EXECVE => Some(Self::Execve),
+            PTRACE => Some(Self::Ptrace),
+            RT_SIGPENDING => Some(Self::RtSigpending),
+            RT_SIGTIMEDWAIT => Some(Self::RtSigtimedwait),
+            RT_SIGQUEUEINFO => Some(Self::RtSigqueueinfo),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            TIMER_CREATE => Some(Self::TimerCreate),
+            MQ_NOTIFY => Some(Self::MqNotify),
+            KEXEC_LOAD => Some(Self::KexecLoad),
+            WAITID => Some(Self::Waitid),
+            SET_ROBUST_LIST => Some(Self::SetRobustList),
+            GET_ROBUST_LIST => Some(Self::GetRobustList),
+            VMSPLICE => Some(Self::Vmsplice),
+            MOVE_PAGES => Some(Self::MovePages),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            RT_TGSIGQUEUEINFO => Some(Self::RtTgsigqueueinfo),
+            RECVMMSG => Some(Self::Recvmmsg),
+            SENDMMSG => Some(Self::Sendmmsg),
+            PROCESS_VM_READV => Some(Self::ProcessVmReadv),
+            PROCESS_VM_WRITEV => Some(Self::ProcessVmWritev),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            IO_SETUP => Some(Self::IoSetup),
+            IO_SUBMIT => Some(Self::IoSubmit),
+            EXECVEAT => Some(Self::Execveat),
+            PREADV2 => Some(Self::Preadv2),
+            PWRITEV2 => Some(Self::Pwritev2),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "stat" => Some(Self::Stat),
+            "fstat" => Some(Self::Fstat),
+            "lstat" => Some(Self::Lstat),
+            "poll" => Some(Self::Poll),
+            "lseek" => Some(Self::Lseek),
+            "mmap" => Some(Self::Mmap),
+            "mprotect" => Some(Self::Mprotect),
+            "munmap" => Some(Self::Munmap),
+            "brk" => Some(Self::Brk),
+            "rt_sigprocmask" => Some(Self::RtSigprocmask),
+            "pread64" => Some(Self::Pread64),
+            "pwrite64" => Some(Self::Pwrite64),
+            "access" => Some(Self::Access),
+            "pipe" => Some(Self::Pipe),
+            "select" => Some(Self::Select),
+            "sched_yield" => Some(Self::SchedYield),
+            "mremap" => Some(Self::Mremap),
+            "msync" => Some(Self::Msync),
+            "mincore" => Some(Self::Mincore),
+            "madvise" => Some(Self::Madvise),
+            "shmget" => Some(Self::Shmget),
+            "shmat" => Some(Self::Shmat),
+            "shmctl" => Some(Self::Shmctl),
+            "dup" => Some(Self::Dup),
+            "dup2" => Some(Self::Dup2),
+            "pause" => Some(Self::Pause),
+            "nanosleep" => Some(Self::Nanosleep),
+            "getitimer" => Some(Self::Getitimer),
+            "alarm" => Some(Self::Alarm),
+            "setitimer" => Some(Self::Setitimer),
+            "getpid" => Some(Self::Getpid),
+            "sendfile" => Some(Self::Sendfile),
+            "socket" => Some(Self::Socket),
+            "connect" => Some(Self::Connect),
+            "accept" => Some(Self::Accept),
+            "sendto" => Some(Self::Sendto),
+            "shutdown" => Some(Self::Shutdown),
+            "bind" => Some(Self::Bind),
+            "listen" => Some(Self::Listen),
+            "getsockname" => Some(Self::Getsockname),
+            "getpeername" => Some(Self::Getpeername),
+            "socketpair" => Some(Self::Socketpair),
+            "clone" => Some(Self::Clone),
+            "fork" => Some(Self::Fork),
+            "vfork" => Some(Self::Vfork),
+            "exit" => Some(Self::Exit),
+            "wait4" => Some(Self::Wait4),
+            "kill" => Some(Self::Kill),
+            "uname" => Some(Self::Uname),
+            "semget" => Some(Self::Semget),
+            "semop" => Some(Self::Semop),
+            "semctl" => Some(Self::Semctl),
+            "shmdt" => Some(Self::Shmdt),
+            "msgget" => Some(Self::Msgget),
+            "msgsnd" => Some(Self::Msgsnd),
+            "msgrcv" => Some(Self::Msgrcv),
+            "msgctl" => Some(Self::Msgctl),
+            "fcntl" => Some(Self::Fcntl),
+            "flock" => Some(Self::Flock),
+            "fsync" => Some(Self::Fsync),
+            "fdatasync" => Some(Self::Fdatasync),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "getdents" => Some(Self::Getdents),
+            "getcwd" => Some(Self::Getcwd),
+            "chdir" => Some(Self::Chdir),
+            "fchdir" => Some(Self::Fchdir),
+            "rename" => Some(Self::Rename),
+            "mkdir" => Some(Self::Mkdir),
+            "rmdir" => Some(Self::Rmdir),
+            "creat" => Some(Self::Creat),
+            "link" => Some(Self::Link),
+            "unlink" => Some(Self::Unlink),
+            "symlink" => Some(Self::Symlink),
+            "readlink" => Some(Self::Readlink),
+            "chmod" => Some(Self::Chmod),
+            "fchmod" => Some(Self::Fchmod),
+            "chown" => Some(Self::Chown),
+            "fchown" => Some(Self::Fchown),
+            "lchown" => Some(Self::Lchown),
+            "umask" => Some(Self::Umask),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "getrlimit" => Some(Self::Getrlimit),
+            "getrusage" => Some(Self::Getrusage),
+            "sysinfo" => Some(Self::Sysinfo),
+            "times" => Some(Self::Times),
+            "getuid" => Some(Self::Getuid),
+            "syslog" => Some(Self::Syslog),
+            "getgid" => Some(Self::Getgid),
+            "setuid" => Some(Self::Setuid),
+            "setgid" => Some(Self::Setgid),
+            "geteuid" => Some(Self::Geteuid),
+            "getegid" => Some(Self::Getegid),
+            "setpgid" => Some(Self::Setpgid),
+            "getppid" => Some(Self::Getppid),
+            "getpgrp" => Some(Self::Getpgrp),
+            "setsid" => Some(Self::Setsid),
+            "setreuid" => Some(Self::Setreuid),
+            "setregid" => Some(Self::Setregid),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "setresuid" => Some(Self::Setresuid),
+            "getresuid" => Some(Self::Getresuid),
+            "setresgid" => Some(Self::Setresgid),
+            "getresgid" => Some(Self::Getresgid),
+            "getpgid" => Some(Self::Getpgid),
+            "setfsuid" => Some(Self::Setfsuid),
+            "setfsgid" => Some(Self::Setfsgid),
+            "getsid" => Some(Self::Getsid),
+            "capget" => Some(Self::Capget),
+            "capset" => Some(Self::Capset),
+            "rt_sigsuspend" => Some(Self::RtSigsuspend),
+            "utime" => Some(Self::Utime),
+            "mknod" => Some(Self::Mknod),
+            "personality" => Some(Self::Personality),
+            "ustat" => Some(Self::Ustat),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "sysfs" => Some(Self::Sysfs),
+            "getpriority" => Some(Self::Getpriority),
+            "setpriority" => Some(Self::Setpriority),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "vhangup" => Some(Self::Vhangup),
+            "modify_ldt" => Some(Self::ModifyLdt),
+            "pivot_root" => Some(Self::PivotRoot),
+            "prctl" => Some(Self::Prctl),
+            "arch_prctl" => Some(Self::ArchPrctl),
+            "adjtimex" => Some(Self::Adjtimex),
+            "setrlimit" => Some(Self::Setrlimit),
+            "chroot" => Some(Self::Chroot),
+            "sync" => Some(Self::Sync),
+            "acct" => Some(Self::Acct),
+            "settimeofday" => Some(Self::Settimeofday),
+            "mount" => Some(Self::Mount),
+            "umount2" => Some(Self::Umount2),
+            "swapon" => Some(Self::Swapon),
+            "swapoff" => Some(Self::Swapoff),
+            "reboot" => Some(Self::Reboot),
+            "sethostname" => Some(Self::Sethostname),
+            "setdomainname" => Some(Self::Setdomainname),
+            "iopl" => Some(Self::Iopl),
+            "ioperm" => Some(Self::Ioperm),
+            "init_module" => Some(Self::InitModule),
+            "delete_module" => Some(Self::DeleteModule),
+            "quotactl" => Some(Self::Quotactl),
+            "getpmsg" => Some(Self::Getpmsg),
+            "putpmsg" => Some(Self::Putpmsg),
+            "afs_syscall" => Some(Self::AfsSyscall),
+            "tuxcall" => Some(Self::Tuxcall),
+            "security" => Some(Self::Security),
+            "gettid" => Some(Self::Gettid),
+            "readahead" => Some(Self::Readahead),
+            "setxattr" => Some(Self::Setxattr),
+            "lsetxattr" => Some(Self::Lsetxattr),
+            "fsetxattr" => Some(Self::Fsetxattr),
+            "getxattr" => Some(Self::Getxattr),
+            "lgetxattr" => Some(Self::Lgetxattr),
+            "fgetxattr" => Some(Self::Fgetxattr),
+            "listxattr" => Some(Self::Listxattr),
+            "llistxattr" => Some(Self::Llistxattr),
+            "flistxattr" => Some(Self::Flistxattr),
+            "removexattr" => Some(Self::Removexattr),
+            "lremovexattr" => Some(Self::Lremovexattr),
+            "fremovexattr" => Some(Self::Fremovexattr),
+            "tkill" => Some(Self::Tkill),
+            "time" => Some(Self::Time),
+            "futex" => Some(Self::Futex),
+            "sched_setaffinity" => Some(Self::SchedSetaffinity),
+            "sched_getaffinity" => Some(Self::SchedGetaffinity),
+            "io_destroy" => Some(Self::IoDestroy),
+            "io_getevents" => Some(Self::IoGetevents),
+            "io_cancel" => Some(Self::IoCancel),
+            "lookup_dcookie" => Some(Self::LookupDcookie),
+            "epoll_create" => Some(Self::EpollCreate),
+            "remap_file_pages" => Some(Self::RemapFilePages),
+            "getdents64" => Some(Self::Getdents64),
+            "set_tid_address" => Some(Self::SetTidAddress),
+            "restart_syscall" => Some(Self::RestartSyscall),
+            "semtimedop" => Some(Self::Semtimedop),
+            "fadvise64" => Some(Self::Fadvise64),
+            "timer_settime" => Some(Self::TimerSettime),
+            "timer_gettime" => Some(Self::TimerGettime),
+            "timer_getoverrun" => Some(Self::TimerGetoverrun),
+            "timer_delete" => Some(Self::TimerDelete),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "exit_group" => Some(Self::ExitGroup),
+            "epoll_wait" => Some(Self::EpollWait),
+            "epoll_ctl" => Some(Self::EpollCtl),
+            "tgkill" => Some(Self::Tgkill),
+            "utimes" => Some(Self::Utimes),
+            "mbind" => Some(Self::Mbind),
+            "set_mempolicy" => Some(Self::SetMempolicy),
+            "get_mempolicy" => Some(Self::GetMempolicy),
+            "mq_open" => Some(Self::MqOpen),
+            "mq_unlink" => Some(Self::MqUnlink),
+            "mq_timedsend" => Some(Self::MqTimedsend),
+            "mq_timedreceive" => Some(Self::MqTimedreceive),
+            "mq_getsetattr" => Some(Self::MqGetsetattr),
+            "add_key" => Some(Self::AddKey),
+            "request_key" => Some(Self::RequestKey),
+            "keyctl" => Some(Self::Keyctl),
+            "ioprio_set" => Some(Self::IoprioSet),
+            "ioprio_get" => Some(Self::IoprioGet),
+            "inotify_init" => Some(Self::InotifyInit),
+            "inotify_add_watch" => Some(Self::InotifyAddWatch),
+            "inotify_rm_watch" => Some(Self::InotifyRmWatch),
+            "migrate_pages" => Some(Self::MigratePages),
+            "openat" => Some(Self::Openat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "mknodat" => Some(Self::Mknodat),
+            "fchownat" => Some(Self::Fchownat),
+            "futimesat" => Some(Self::Futimesat),
+            "newfstatat" => Some(Self::Newfstatat),
+            "unlinkat" => Some(Self::Unlinkat),
+            "renameat" => Some(Self::Renameat),
+            "linkat" => Some(Self::Linkat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "readlinkat" => Some(Self::Readlinkat),
+            "fchmodat" => Some(Self::Fchmodat),
+            "faccessat" => Some(Self::Faccessat),
+            "pselect6" => Some(Self::Pselect6),
+            "ppoll" => Some(Self::Ppoll),
+            "unshare" => Some(Self::Unshare),
+            "splice" => Some(Self::Splice),
+            "tee" => Some(Self::Tee),
+            "sync_file_range" => Some(Self::SyncFileRange),
+            "utimensat" => Some(Self::Utimensat),
+            "epoll_pwait" => Some(Self::EpollPwait),
+            "signalfd" => Some(Self::Signalfd),
+            "timerfd_create" => Some(Self::TimerfdCreate),
+            "eventfd" => Some(Self::Eventfd),
+            "fallocate" => Some(Self::Fallocate),
+            "timerfd_settime" => Some(Self::TimerfdSettime),
+            "timerfd_gettime" => Some(Self::TimerfdGettime),
+            "accept4" => Some(Self::Accept4),
+            "signalfd4" => Some(Self::Signalfd4),
+            "eventfd2" => Some(Self::Eventfd2),
+            "epoll_create1" => Some(Self::EpollCreate1),
+            "dup3" => Some(Self::Dup3),
+            "pipe2" => Some(Self::Pipe2),
+            "inotify_init1" => Some(Self::InotifyInit1),
+            "perf_event_open" => Some(Self::PerfEventOpen),
+            "fanotify_init" => Some(Self::FanotifyInit),
+            "fanotify_mark" => Some(Self::FanotifyMark),
+            "prlimit64" => Some(Self::Prlimit64),
+            "name_to_handle_at" => Some(Self::NameToHandleAt),
+            "open_by_handle_at" => Some(Self::OpenByHandleAt),
+            "clock_adjtime" => Some(Self::ClockAdjtime),
+            "syncfs" => Some(Self::Syncfs),
+            "setns" => Some(Self::Setns),
+            "getcpu" => Some(Self::Getcpu),
+            "kcmp" => Some(Self::Kcmp),
+            "finit_module" => Some(Self::FinitModule),
+            "sched_setattr" => Some(Self::SchedSetattr),
+            "sched_getattr" => Some(Self::SchedGetattr),
+            "renameat2" => Some(Self::Renameat2),
+            "seccomp" => Some(Self::Seccomp),
+            "getrandom" => Some(Self::Getrandom),
+            "memfd_create" => Some(Self::MemfdCreate),
+            "kexec_file_load" => Some(Self::KexecFileLoad),
+            "bpf" => Some(Self::Bpf),
+            "userfaultfd" => Some(Self::Userfaultfd),
+            "membarrier" => Some(Self::Membarrier),
+            "mlock2" => Some(Self::Mlock2),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "pkey_mprotect" => Some(Self::PkeyMprotect),
+            "pkey_alloc" => Some(Self::PkeyAlloc),
+            "pkey_free" => Some(Self::PkeyFree),
+            "statx" => Some(Self::Statx),
+            "io_pgetevents" => Some(Self::IoPgetevents),
+            "rseq" => Some(Self::Rseq),
+            "pidfd_send_signal" => Some(Self::PidfdSendSignal),
+            "io_uring_setup" => Some(Self::IoUringSetup),
+            "io_uring_enter" => Some(Self::IoUringEnter),
+            "io_uring_register" => Some(Self::IoUringRegister),
+            "open_tree" => Some(Self::OpenTree),
+            "move_mount" => Some(Self::MoveMount),
+            "fsopen" => Some(Self::Fsopen),
+            "fsconfig" => Some(Self::Fsconfig),
+            "fsmount" => Some(Self::Fsmount),
+            "fspick" => Some(Self::Fspick),
+            "pidfd_open" => Some(Self::PidfdOpen),
+            "clone3" => Some(Self::Clone3),
+            "close_range" => Some(Self::CloseRange),
+            "openat2" => Some(Self::Openat2),
+            "pidfd_getfd" => Some(Self::PidfdGetfd),
+            "faccessat2" => Some(Self::Faccessat2),
+            "process_madvise" => Some(Self::ProcessMadvise),
+            "epoll_pwait2" => Some(Self::EpollPwait2),
+            "mount_setattr" => Some(Self::MountSetattr),
+            "quotactl_fd" => Some(Self::QuotactlFd),
+            "landlock_create_ruleset" => Some(Self::LandlockCreateRuleset),
+            "landlock_add_rule" => Some(Self::LandlockAddRule),
+            "landlock_restrict_self" => Some(Self::LandlockRestrictSelf),
+            "memfd_secret" => Some(Self::MemfdSecret),
+            "process_mrelease" => Some(Self::ProcessMrelease),
+            "futex_waitv" => Some(Self::FutexWaitv),
+            "set_mempolicy_home_node" => Some(Self::SetMempolicyHomeNode),
+            "rt_sigaction" => Some(Self::RtSigaction),
+            "rt_sigreturn" => Some(Self::RtSigreturn),
+            "ioctl" => Some(Self::Ioctl),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "recvfrom" => Some(Self::Recvfrom),
+            "sendmsg" => Some(Self::Sendmsg),
+            "recvmsg" => Some(Self::Recvmsg),
+            "execve" => Some(Self::Execve),
+            "ptrace" => Some(Self::Ptrace),
+            "rt_sigpending" => Some(Self::RtSigpending),
+            "rt_sigtimedwait" => Some(Self::RtSigtimedwait),
+            "rt_sigqueueinfo" => Some(Self::RtSigqueueinfo),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "timer_create" => Some(Self::TimerCreate),
+            "mq_notify" => Some(Self::MqNotify),
+            "kexec_load" => Some(Self::KexecLoad),
+            "waitid" => Some(Self::Waitid),
+            "set_robust_list" => Some(Self::SetRobustList),
+            "get_robust_list" => Some(Self::GetRobustList),
+            "vmsplice" => Some(Self::Vmsplice),
+            "move_pages" => Some(Self::MovePages),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "rt_tgsigqueueinfo" => Some(Self::RtTgsigqueueinfo),
+            "recvmmsg" => Some(Self::Recvmmsg),
+            "sendmmsg" => Some(Self::Sendmmsg),
+            "process_vm_readv" => Some(Self::ProcessVmReadv),
+            "process_vm_writev" => Some(Self::ProcessVmWritev),
+            "setsockopt" => Some(Self::Setsockopt),
+            "getsockopt" => Some(Self::Getsockopt),
+            "io_setup" => Some(Self::IoSetup),
+            "io_submit" => Some(Self::IoSubmit),
+            "execveat" => Some(Self::Execveat),
+            "preadv2" => Some(Self::Preadv2),
+            "pwritev2" => Some(Self::Pwritev2),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Stat => "stat",
+            Self::Fstat => "fstat",
+            Self::Lstat => "lstat",
+            Self::Poll => "poll",
+            Self::Lseek => "lseek",
+            Self::Mmap => "mmap",
+            Self::Mprotect => "mprotect",
+            Self::Munmap => "munmap",
+            Self::Brk => "brk",
+            Self::RtSigprocmask => "rt_sigprocmask",
+            Self::Pread64 => "pread64",
+            Self::Pwrite64 => "pwrite64",
+            Self::Access => "access",
+            Self::Pipe => "pipe",
+            Self::Select => "select",
+            Self::SchedYield => "sched_yield",
+            Self::Mremap => "mremap",
+            Self::Msync => "msync",
+            Self::Mincore => "mincore",
+            Self::Madvise => "madvise",
+            Self::Shmget => "shmget",
+            Self::Shmat => "shmat",
+            Self::Shmctl => "shmctl",
+            Self::Dup => "dup",
+            Self::Dup2 => "dup2",
+            Self::Pause => "pause",
+            Self::Nanosleep => "nanosleep",
+            Self::Getitimer => "getitimer",
+            Self::Alarm => "alarm",
+            Self::Setitimer => "setitimer",
+            Self::Getpid => "getpid",
+            Self::Sendfile => "sendfile",
+            Self::Socket => "socket",
+            Self::Connect => "connect",
+            Self::Accept => "accept",
+            Self::Sendto => "sendto",
+            Self::Shutdown => "shutdown",
+            Self::Bind => "bind",
+            Self::Listen => "listen",
+            Self::Getsockname => "getsockname",
+            Self::Getpeername => "getpeername",
+            Self::Socketpair => "socketpair",
+            Self::Clone => "clone",
+            Self::Fork => "fork",
+            Self::Vfork => "vfork",
+            Self::Exit => "exit",
+            Self::Wait4 => "wait4",
+            Self::Kill => "kill",
+            Self::Uname => "uname",
+            Self::Semget => "semget",
+            Self::Semop => "semop",
+            Self::Semctl => "semctl",
+            Self::Shmdt => "shmdt",
+            Self::Msgget => "msgget",
+            Self::Msgsnd => "msgsnd",
+            Self::Msgrcv => "msgrcv",
+            Self::Msgctl => "msgctl",
+            Self::Fcntl => "fcntl",
+            Self::Flock => "flock",
+            Self::Fsync => "fsync",
+            Self::Fdatasync => "fdatasync",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::Getdents => "getdents",
+            Self::Getcwd => "getcwd",
+            Self::Chdir => "chdir",
+            Self::Fchdir => "fchdir",
+            Self::Rename => "rename",
+            Self::Mkdir => "mkdir",
+            Self::Rmdir => "rmdir",
+            Self::Creat => "creat",
+            Self::Link => "link",
+            Self::Unlink => "unlink",
+            Self::Symlink => "symlink",
+            Self::Readlink => "readlink",
+            Self::Chmod => "chmod",
+            Self::Fchmod => "fchmod",
+            Self::Chown => "chown",
+            Self::Fchown => "fchown",
+            Self::Lchown => "lchown",
+            Self::Umask => "umask",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Getrlimit => "getrlimit",
+            Self::Getrusage => "getrusage",
+            Self::Sysinfo => "sysinfo",
+            Self::Times => "times",
+            Self::Getuid => "getuid",
+            Self::Syslog => "syslog",
+            Self::Getgid => "getgid",
+            Self::Setuid => "setuid",
+            Self::Setgid => "setgid",
+            Self::Geteuid => "geteuid",
+            Self::Getegid => "getegid",
+            Self::Setpgid => "setpgid",
+            Self::Getppid => "getppid",
+            Self::Getpgrp => "getpgrp",
+            Self::Setsid => "setsid",
+            Self::Setreuid => "setreuid",
+            Self::Setregid => "setregid",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Setresuid => "setresuid",
+            Self::Getresuid => "getresuid",
+            Self::Setresgid => "setresgid",
+            Self::Getresgid => "getresgid",
+            Self::Getpgid => "getpgid",
+            Self::Setfsuid => "setfsuid",
+            Self::Setfsgid => "setfsgid",
+            Self::Getsid => "getsid",
+            Self::Capget => "capget",
+            Self::Capset => "capset",
+            Self::RtSigsuspend => "rt_sigsuspend",
+            Self::Utime => "utime",
+            Self::Mknod => "mknod",
+            Self::Personality => "personality",
+            Self::Ustat => "ustat",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Sysfs => "sysfs",
+            Self::Getpriority => "getpriority",
+            Self::Setpriority => "setpriority",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::Vhangup => "vhangup",
+            Self::ModifyLdt => "modify_ldt",
+            Self::PivotRoot => "pivot_root",
+            Self::Prctl => "prctl",
+            Self::ArchPrctl => "arch_prctl",
+            Self::Adjtimex => "adjtimex",
+            Self::Setrlimit => "setrlimit",
+            Self::Chroot => "chroot",
+            Self::Sync => "sync",
+            Self::Acct => "acct",
+            Self::Settimeofday => "settimeofday",
+            Self::Mount => "mount",
+            Self::Umount2 => "umount2",
+            Self::Swapon => "swapon",
+            Self::Swapoff => "swapoff",
+            Self::Reboot => "reboot",
+            Self::Sethostname => "sethostname",
+            Self::Setdomainname => "setdomainname",
+            Self::Iopl => "iopl",
+            Self::Ioperm => "ioperm",
+            Self::InitModule => "init_module",
+            Self::DeleteModule => "delete_module",
+            Self::Quotactl => "quotactl",
+            Self::Getpmsg => "getpmsg",
+            Self::Putpmsg => "putpmsg",
+            Self::AfsSyscall => "afs_syscall",
+            Self::Tuxcall => "tuxcall",
+            Self::Security => "security",
+            Self::Gettid => "gettid",
+            Self::Readahead => "readahead",
+            Self::Setxattr => "setxattr",
+            Self::Lsetxattr => "lsetxattr",
+            Self::Fsetxattr => "fsetxattr",
+            Self::Getxattr => "getxattr",
+            Self::Lgetxattr => "lgetxattr",
+            Self::Fgetxattr => "fgetxattr",
+            Self::Listxattr => "listxattr",
+            Self::Llistxattr => "llistxattr",
+            Self::Flistxattr => "flistxattr",
+            Self::Removexattr => "removexattr",
+            Self::Lremovexattr => "lremovexattr",
+            Self::Fremovexattr => "fremovexattr",
+            Self::Tkill => "tkill",
+            Self::Time => "time",
+            Self::Futex => "futex",
+            Self::SchedSetaffinity => "sched_setaffinity",
+            Self::SchedGetaffinity => "sched_getaffinity",
+            Self::IoDestroy => "io_destroy",
+            Self::IoGetevents => "io_getevents",
+            Self::IoCancel => "io_cancel",
+            Self::LookupDcookie => "lookup_dcookie",
+            Self::EpollCreate => "epoll_create",
+            Self::RemapFilePages => "remap_file_pages",
+            Self::Getdents64 => "getdents64",
+            Self::SetTidAddress => "set_tid_address",
+            Self::RestartSyscall => "restart_syscall",
+            Self::Semtimedop => "semtimedop",
+            Self::Fadvise64 => "fadvise64",
+            Self::TimerSettime => "timer_settime",
+            Self::TimerGettime => "timer_gettime",
+            Self::TimerGetoverrun => "timer_getoverrun",
+            Self::TimerDelete => "timer_delete",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockGetres => "clock_getres",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::ExitGroup => "exit_group",
+            Self::EpollWait => "epoll_wait",
+            Self::EpollCtl => "epoll_ctl",
+            Self::Tgkill => "tgkill",
+            Self::Utimes => "utimes",
+            Self::Mbind => "mbind",
+            Self::SetMempolicy => "set_mempolicy",
+            Self::GetMempolicy => "get_mempolicy",
+            Self::MqOpen => "mq_open",
+            Self::MqUnlink => "mq_unlink",
+            Self::MqTimedsend => "mq_timedsend",
+            Self::MqTimedreceive => "mq_timedreceive",
+            Self::MqGetsetattr => "mq_getsetattr",
+            Self::AddKey => "add_key",
+            Self::RequestKey => "request_key",
+            Self::Keyctl => "keyctl",
+            Self::IoprioSet => "ioprio_set",
+            Self::IoprioGet => "ioprio_get",
+            Self::InotifyInit => "inotify_init",
+            Self::InotifyAddWatch => "inotify_add_watch",
+            Self::InotifyRmWatch => "inotify_rm_watch",
+            Self::MigratePages => "migrate_pages",
+            Self::Openat => "openat",
+            Self::Mkdirat => "mkdirat",
+            Self::Mknodat => "mknodat",
+            Self::Fchownat => "fchownat",
+            Self::Futimesat => "futimesat",
+            Self::Newfstatat => "newfstatat",
+            Self::Unlinkat => "unlinkat",
+            Self::Renameat => "renameat",
+            Self::Linkat => "linkat",
+            Self::Symlinkat => "symlinkat",
+            Self::Readlinkat => "readlinkat",
+            Self::Fchmodat => "fchmodat",
+            Self::Faccessat => "faccessat",
+            Self::Pselect6 => "pselect6",
+            Self::Ppoll => "ppoll",
+            Self::Unshare => "unshare",
+            Self::Splice => "splice",
+            Self::Tee => "tee",
+            Self::SyncFileRange => "sync_file_range",
+            Self::Utimensat => "utimensat",
+            Self::EpollPwait => "epoll_pwait",
+            Self::Signalfd => "signalfd",
+            Self::TimerfdCreate => "timerfd_create",
+            Self::Eventfd => "eventfd",
+            Self::Fallocate => "fallocate",
+            Self::TimerfdSettime => "timerfd_settime",
+            Self::TimerfdGettime => "timerfd_gettime",
+            Self::Accept4 => "accept4",
+            Self::Signalfd4 => "signalfd4",
+            Self::Eventfd2 => "eventfd2",
+            Self::EpollCreate1 => "epoll_create1",
+            Self::Dup3 => "dup3",
+            Self::Pipe2 => "pipe2",
+            Self::InotifyInit1 => "inotify_init1",
+            Self::PerfEventOpen => "perf_event_open",
+            Self::FanotifyInit => "fanotify_init",
+            Self::FanotifyMark => "fanotify_mark",
+            Self::Prlimit64 => "prlimit64",
+            Self::NameToHandleAt => "name_to_handle_at",
+            Self::OpenByHandleAt => "open_by_handle_at",
+            Self::ClockAdjtime => "clock_adjtime",
+            Self::Syncfs => "syncfs",
+            Self::Setns => "setns",
+            Self::Getcpu => "getcpu",
+            Self::Kcmp => "kcmp",
+            Self::FinitModule => "finit_module",
+            Self::SchedSetattr => "sched_setattr",
+            Self::SchedGetattr => "sched_getattr",
+            Self::Renameat2 => "renameat2",
+            Self::Seccomp => "seccomp",
+            Self::Getrandom => "getrandom",
+            Self::MemfdCreate => "memfd_create",
+            Self::KexecFileLoad => "kexec_file_load",
+            Self::Bpf => "bpf",
+            Self::Userfaultfd => "userfaultfd",
+            Self::Membarrier => "membarrier",
+            Self::Mlock2 => "mlock2",
+            Self::CopyFileRange => "copy_file_range",
+            Self::PkeyMprotect => "pkey_mprotect",
+            Self::PkeyAlloc => "pkey_alloc",
+            Self::PkeyFree => "pkey_free",
+            Self::Statx => "statx",
+            Self::IoPgetevents => "io_pgetevents",
+            Self::Rseq => "rseq",
+            Self::PidfdSendSignal => "pidfd_send_signal",
+            Self::IoUringSetup => "io_uring_setup",
+            Self::IoUringEnter => "io_uring_enter",
+            Self::IoUringRegister => "io_uring_register",
+            Self::OpenTree => "open_tree",
+            Self::MoveMount => "move_mount",
+            Self::Fsopen => "fsopen",
+            Self::Fsconfig => "fsconfig",
+            Self::Fsmount => "fsmount",
+            Self::Fspick => "fspick",
+            Self::PidfdOpen => "pidfd_open",
+            Self::Clone3 => "clone3",
+            Self::CloseRange => "close_range",
+            Self::Openat2 => "openat2",
+            Self::PidfdGetfd => "pidfd_getfd",
+            Self::Faccessat2 => "faccessat2",
+            Self::ProcessMadvise => "process_madvise",
+            Self::EpollPwait2 => "epoll_pwait2",
+            Self::MountSetattr => "mount_setattr",
+            Self::QuotactlFd => "quotactl_fd",
+            Self::LandlockCreateRuleset => "landlock_create_ruleset",
+            Self::LandlockAddRule => "landlock_add_rule",
+            Self::LandlockRestrictSelf => "landlock_restrict_self",
+            Self::MemfdSecret => "memfd_secret",
+            Self::ProcessMrelease => "process_mrelease",
+            Self::FutexWaitv => "futex_waitv",
+            Self::SetMempolicyHomeNode => "set_mempolicy_home_node",
+            Self::RtSigaction => "rt_sigaction",
+            Self::RtSigreturn => "rt_sigreturn",
+            Self::Ioctl => "ioctl",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Recvfrom => "recvfrom",
+            Self::Sendmsg => "sendmsg",
+            Self::Recvmsg => "recvmsg",
+            Self::Execve => "execve",
+            Self::Ptrace => "ptrace",
+            Self::RtSigpending => "rt_sigpending",
+            Self::RtSigtimedwait => "rt_sigtimedwait",
+            Self::RtSigqueueinfo => "rt_sigqueueinfo",
+            Self::Sigaltstack => "sigaltstack",
+            Self::TimerCreate => "timer_create",
+            Self::MqNotify => "mq_notify",
+            Self::KexecLoad => "kexec_load",
+            Self::Waitid => "waitid",
+            Self::SetRobustList => "set_robust_list",
+            Self::GetRobustList => "get_robust_list",
+            Self::Vmsplice => "vmsplice",
+            Self::MovePages => "move_pages",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::RtTgsigqueueinfo => "rt_tgsigqueueinfo",
+            Self::Recvmmsg => "recvmmsg",
+            Self::Sendmmsg => "sendmmsg",
+            Self::ProcessVmReadv => "process_vm_readv",
+            Self::ProcessVmWritev => "process_vm_writev",
+            Self::Setsockopt => "setsockopt",
+            Self::Getsockopt => "getsockopt",
+            Self::IoSetup => "io_setup",
+            Self::IoSubmit => "io_submit",
+            Self::Execveat => "execveat",
+            Self::Preadv2 => "preadv2",
+            Self::Pwritev2 => "pwritev2",
+        }
+    }
+}
@@ -0,0 +1,2435 @@
+//! System call numbers for `x86_64-freebsd`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/freebsd/syscalls.master`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const SYSCALL: usize = 0;
+pub const EXIT: usize = 1;
+pub const FORK: usize = 2;
+pub const READ: usize = 3;
+pub const WRITE: usize = 4;
+pub const OPEN: usize = 5;
+pub const CLOSE: usize = 6;
+pub const WAIT4: usize = 7;
+pub const LINK: usize = 9;
+pub const UNLINK: usize = 10;
+pub const CHDIR: usize = 12;
+pub const FCHDIR: usize = 13;
+pub const CHMOD: usize = 15;
+pub const CHOWN: usize = 16;
+pub const BREAK: usize = 17;
+pub const GETPID: usize = 20;
+pub const MOUNT: usize = 21;
+pub const UNMOUNT: usize = 22;
+pub const SETUID: usize = 23;
+pub const GETUID: usize = 24;
+pub const GETEUID: usize = 25;
+pub const PTRACE: usize = 26;
+pub const RECVMSG: usize = 27;
+pub const SENDMSG: usize = 28;
+pub const RECVFROM: usize = 29;
+pub const ACCEPT: usize = 30;
+pub const GETPEERNAME: usize = 31;
+pub const GETSOCKNAME: usize = 32;
+pub const ACCESS: usize = 33;
+pub const CHFLAGS: usize = 34;
+pub const FCHFLAGS: usize = 35;
+pub const SYNC: usize = 36;
+pub const KILL: usize = 37;
+pub const GETPPID: usize = 39;
+pub const DUP: usize = 41;
+pub const GETEGID: usize = 43;
+pub const PROFIL: usize = 44;
+pub const KTRACE: usize = 45;
+pub const GETGID: usize = 47;
+pub const GETLOGIN: usize = 49;
+pub const SETLOGIN: usize = 50;
+pub const ACCT: usize = 51;
+pub const SIGALTSTACK: usize = 53;
+pub const IOCTL: usize = 54;
+pub const REBOOT: usize = 55;
+pub const REVOKE: usize = 56;
+pub const SYMLINK: usize = 57;
+pub const READLINK: usize = 58;
+pub const EXECVE: usize = 59;
+pub const UMASK: usize = 60;
+pub const CHROOT: usize = 61;
+pub const MSYNC: usize = 65;
+pub const VFORK: usize = 66;
+pub const SBRK: usize = 69;
+pub const SSTK: usize = 70;
+pub const MUNMAP: usize = 73;
+pub const MPROTECT: usize = 74;
+pub const MADVISE: usize = 75;
+pub const MINCORE: usize = 78;
+pub const GETGROUPS: usize = 79;
+pub const SETGROUPS: usize = 80;
+pub const GETPGRP: usize = 81;
+pub const SETPGID: usize = 82;
+pub const SETITIMER: usize = 83;
+pub const SWAPON: usize = 85;
+pub const GETITIMER: usize = 86;
+pub const GETDTABLESIZE: usize = 89;
+pub const DUP2: usize = 90;
+pub const FCNTL: usize = 92;
+pub const SELECT: usize = 93;
+pub const FSYNC: usize = 95;
+pub const SETPRIORITY: usize = 96;
+pub const SOCKET: usize = 97;
+pub const CONNECT: usize = 98;
+pub const GETPRIORITY: usize = 100;
+pub const BIND: usize = 104;
+pub const SETSOCKOPT: usize = 105;
+pub const LISTEN: usize = 106;
+pub const GETTIMEOFDAY: usize = 116;
+pub const GETRUSAGE: usize = 117;
+pub const GETSOCKOPT: usize = 118;
+pub const READV: usize = 120;
+pub const WRITEV: usize = 121;
+pub const SETTIMEOFDAY: usize = 122;
+pub const FCHOWN: usize = 123;
+pub const FCHMOD: usize = 124;
+pub const SETREUID: usize = 126;
+pub const SETREGID: usize = 127;
+pub const RENAME: usize = 128;
+pub const FLOCK: usize = 131;
+pub const MKFIFO: usize = 132;
+pub const SENDTO: usize = 133;
+pub const SHUTDOWN: usize = 134;
+pub const SOCKETPAIR: usize = 135;
+pub const MKDIR: usize = 136;
+pub const RMDIR: usize = 137;
+pub const UTIMES: usize = 138;
+pub const ADJTIME: usize = 140;
+pub const SETSID: usize = 147;
+pub const QUOTACTL: usize = 148;
+pub const NLM_SYSCALL: usize = 154;
+pub const NFSSVC: usize = 155;
+pub const LGETFH: usize = 160;
+pub const GETFH: usize = 161;
+pub const SYSARCH: usize = 165;
+pub const RTPRIO: usize = 166;
+pub const SEMSYS: usize = 169;
+pub const MSGSYS: usize = 170;
+pub const SHMSYS: usize = 171;
+pub const SETFIB: usize = 175;
+pub const NTP_ADJTIME: usize = 176;
+pub const SETGID: usize = 181;
+pub const SETEGID: usize = 182;
+pub const SETEUID: usize = 183;
+pub const PATHCONF: usize = 191;
+pub const FPATHCONF: usize = 192;
+pub const GETRLIMIT: usize = 194;
+pub const SETRLIMIT: usize = 195;
+pub const __SYSCALL: usize = 198;
+pub const __SYSCTL: usize = 202;
+pub const MLOCK: usize = 203;
+pub const MUNLOCK: usize = 204;
+pub const UNDELETE: usize = 205;
+pub const FUTIMES: usize = 206;
+pub const GETPGID: usize = 207;
+pub const POLL: usize = 209;
+pub const SEMGET: usize = 221;
+pub const SEMOP: usize = 222;
+pub const MSGGET: usize = 225;
+pub const MSGSND: usize = 226;
+pub const MSGRCV: usize = 227;
+pub const SHMAT: usize = 228;
+pub const SHMDT: usize = 230;
+pub const SHMGET: usize = 231;
+pub const CLOCK_GETTIME: usize = 232;
+pub const CLOCK_SETTIME: usize = 233;
+pub const CLOCK_GETRES: usize = 234;
+pub const KTIMER_CREATE: usize = 235;
+pub const KTIMER_DELETE: usize = 236;
+pub const KTIMER_SETTIME: usize = 237;
+pub const KTIMER_GETTIME: usize = 238;
+pub const KTIMER_GETOVERRUN: usize = 239;
+pub const NANOSLEEP: usize = 240;
+pub const FFCLOCK_GETCOUNTER: usize = 241;
+pub const FFCLOCK_SETESTIMATE: usize = 242;
+pub const FFCLOCK_GETESTIMATE: usize = 243;
+pub const CLOCK_NANOSLEEP: usize = 244;
+pub const CLOCK_GETCPUCLOCKID2: usize = 247;
+pub const NTP_GETTIME: usize = 248;
+pub const MINHERIT: usize = 250;
+pub const RFORK: usize = 251;
+pub const ISSETUGID: usize = 253;
+pub const LCHOWN: usize = 254;
+pub const AIO_READ: usize = 255;
+pub const AIO_WRITE: usize = 256;
+pub const LIO_LISTIO: usize = 257;
+pub const LCHMOD: usize = 274;
+pub const LUTIMES: usize = 276;
+pub const PREADV: usize = 289;
+pub const PWRITEV: usize = 290;
+pub const FHOPEN: usize = 298;
+pub const MODNEXT: usize = 300;
+pub const MODSTAT: usize = 301;
+pub const MODFNEXT: usize = 302;
+pub const MODFIND: usize = 303;
+pub const KLDLOAD: usize = 304;
+pub const KLDUNLOAD: usize = 305;
+pub const KLDFIND: usize = 306;
+pub const KLDNEXT: usize = 307;
+pub const KLDSTAT: usize = 308;
+pub const KLDFIRSTMOD: usize = 309;
+pub const GETSID: usize = 310;
+pub const SETRESUID: usize = 311;
+pub const SETRESGID: usize = 312;
+pub const AIO_RETURN: usize = 314;
+pub const AIO_SUSPEND: usize = 315;
+pub const AIO_CANCEL: usize = 316;
+pub const AIO_ERROR: usize = 317;
+pub const YIELD: usize = 321;
+pub const MLOCKALL: usize = 324;
+pub const MUNLOCKALL: usize = 325;
+pub const __GETCWD: usize = 326;
+pub const SCHED_SETPARAM: usize = 327;
+pub const SCHED_GETPARAM: usize = 328;
+pub const SCHED_SETSCHEDULER: usize = 329;
+pub const SCHED_GETSCHEDULER: usize = 330;
+pub const SCHED_YIELD: usize = 331;
+pub const SCHED_GET_PRIORITY_MAX: usize = 332;
+pub const SCHED_GET_PRIORITY_MIN: usize = 333;
+pub const SCHED_RR_GET_INTERVAL: usize = 334;
+pub const UTRACE: usize = 335;
+pub const KLDSYM: usize = 337;
+pub const JAIL: usize = 338;
+pub const NNPFS_SYSCALL: usize = 339;
+pub const SIGPROCMASK: usize = 340;
+pub const SIGSUSPEND: usize = 341;
+pub const SIGPENDING: usize = 343;
+pub const SIGTIMEDWAIT: usize = 345;
+pub const SIGWAITINFO: usize = 346;
+pub const __ACL_GET_FILE: usize = 347;
+pub const __ACL_SET_FILE: usize = 348;
+pub const __ACL_GET_FD: usize = 349;
+pub const __ACL_SET_FD: usize = 350;
+pub const __ACL_DELETE_FILE: usize = 351;
+pub const __ACL_DELETE_FD: usize = 352;
+pub const __ACL_ACLCHECK_FILE: usize = 353;
+pub const __ACL_ACLCHECK_FD: usize = 354;
+pub const EXTATTRCTL: usize = 355;
+pub const EXTATTR_SET_FILE: usize = 356;
+pub const EXTATTR_GET_FILE: usize = 357;
+pub const EXTATTR_DELETE_FILE: usize = 358;
+pub const AIO_WAITCOMPLETE: usize = 359;
+pub const GETRESUID: usize = 360;
+pub const GETRESGID: usize = 361;
+pub const KQUEUE: usize = 362;
+pub const EXTATTR_SET_FD: usize = 371;
+pub const EXTATTR_GET_FD: usize = 372;
+pub const EXTATTR_DELETE_FD: usize = 373;
+pub const __SETUGID: usize = 374;
+pub const EACCESS: usize = 376;
+pub const AFS3_SYSCALL: usize = 377;
+pub const NMOUNT: usize = 378;
+pub const __MAC_GET_PROC: usize = 384;
+pub const __MAC_SET_PROC: usize = 385;
+pub const __MAC_GET_FD: usize = 386;
+pub const __MAC_GET_FILE: usize = 387;
+pub const __MAC_SET_FD: usize = 388;
+pub const __MAC_SET_FILE: usize = 389;
+pub const KENV: usize = 390;
+pub const LCHFLAGS: usize = 391;
+pub const UUIDGEN: usize = 392;
+pub const SENDFILE: usize = 393;
+pub const MAC_SYSCALL: usize = 394;
+pub const KSEM_CLOSE: usize = 400;
+pub const KSEM_POST: usize = 401;
+pub const KSEM_WAIT: usize = 402;
+pub const KSEM_TRYWAIT: usize = 403;
+pub const KSEM_INIT: usize = 404;
+pub const KSEM_OPEN: usize = 405;
+pub const KSEM_UNLINK: usize = 406;
+pub const KSEM_GETVALUE: usize = 407;
+pub const KSEM_DESTROY: usize = 408;
+pub const __MAC_GET_PID: usize = 409;
+pub const __MAC_GET_LINK: usize = 410;
+pub const __MAC_SET_LINK: usize = 411;
+pub const EXTATTR_SET_LINK: usize = 412;
+pub const EXTATTR_GET_LINK: usize = 413;
+pub const EXTATTR_DELETE_LINK: usize = 414;
+pub const __MAC_EXECVE: usize = 415;
+pub const SIGACTION: usize = 416;
+pub const SIGRETURN: usize = 417;
+pub const GETCONTEXT: usize = 421;
+pub const SETCONTEXT: usize = 422;
+pub const SWAPCONTEXT: usize = 423;
+pub const SWAPOFF: usize = 424;
+pub const __ACL_GET_LINK: usize = 425;
+pub const __ACL_SET_LINK: usize = 426;
+pub const __ACL_DELETE_LINK: usize = 427;
+pub const __ACL_ACLCHECK_LINK: usize = 428;
+pub const SIGWAIT: usize = 429;
+pub const THR_CREATE: usize = 430;
+pub const THR_EXIT: usize = 431;
+pub const THR_SELF: usize = 432;
+pub const THR_KILL: usize = 433;
+pub const JAIL_ATTACH: usize = 436;
+pub const EXTATTR_LIST_FD: usize = 437;
+pub const EXTATTR_LIST_FILE: usize = 438;
+pub const EXTATTR_LIST_LINK: usize = 439;
+pub const KSEM_TIMEDWAIT: usize = 441;
+pub const THR_SUSPEND: usize = 442;
+pub const THR_WAKE: usize = 443;
+pub const KLDUNLOADF: usize = 444;
+pub const AUDIT: usize = 445;
+pub const AUDITON: usize = 446;
+pub const GETAUID: usize = 447;
+pub const SETAUID: usize = 448;
+pub const GETAUDIT: usize = 449;
+pub const SETAUDIT: usize = 450;
+pub const GETAUDIT_ADDR: usize = 451;
+pub const SETAUDIT_ADDR: usize = 452;
+pub const AUDITCTL: usize = 453;
+pub const _UMTX_OP: usize = 454;
+pub const THR_NEW: usize = 455;
+pub const SIGQUEUE: usize = 456;
+pub const KMQ_OPEN: usize = 457;
+pub const KMQ_SETATTR: usize = 458;
+pub const KMQ_TIMEDRECEIVE: usize = 459;
+pub const KMQ_TIMEDSEND: usize = 460;
+pub const KMQ_NOTIFY: usize = 461;
+pub const KMQ_UNLINK: usize = 462;
+pub const ABORT2: usize = 463;
+pub const THR_SET_NAME: usize = 464;
+pub const AIO_FSYNC: usize = 465;
+pub const RTPRIO_THREAD: usize = 466;
+pub const SCTP_PEELOFF: usize = 471;
+pub const SCTP_GENERIC_SENDMSG: usize = 472;
+pub const SCTP_GENERIC_SENDMSG_IOV: usize = 473;
+pub const SCTP_GENERIC_RECVMSG: usize = 474;
+pub const PREAD: usize = 475;
+pub const PWRITE: usize = 476;
+pub const MMAP: usize = 477;
+pub const LSEEK: usize = 478;
+pub const TRUNCATE: usize = 479;
+pub const FTRUNCATE: usize = 480;
+pub const THR_KILL2: usize = 481;
+pub const SHM_UNLINK: usize = 483;
+pub const CPUSET: usize = 484;
+pub const CPUSET_SETID: usize = 485;
+pub const CPUSET_GETID: usize = 486;
+pub const CPUSET_GETAFFINITY: usize = 487;
+pub const CPUSET_SETAFFINITY: usize = 488;
+pub const FACCESSAT: usize = 489;
+pub const FCHMODAT: usize = 490;
+pub const FCHOWNAT: usize = 491;
+pub const FEXECVE: usize = 492;
+pub const FUTIMESAT: usize = 494;
+pub const LINKAT: usize = 495;
+pub const MKDIRAT: usize = 496;
+pub const MKFIFOAT: usize = 497;
+pub const OPENAT: usize = 499;
+pub const READLINKAT: usize = 500;
+pub const RENAMEAT: usize = 501;
+pub const SYMLINKAT: usize = 502;
+pub const UNLINKAT: usize = 503;
+pub const POSIX_OPENPT: usize = 504;
+pub const GSSD_SYSCALL: usize = 505;
+pub const JAIL_GET: usize = 506;
+pub const JAIL_SET: usize = 507;
+pub const JAIL_REMOVE: usize = 508;
+pub const __SEMCTL: usize = 510;
+pub const MSGCTL: usize = 511;
+pub const SHMCTL: usize = 512;
+pub const LPATHCONF: usize = 513;
+pub const __CAP_RIGHTS_GET: usize = 515;
+pub const CAP_ENTER: usize = 516;
+pub const CAP_GETMODE: usize = 517;
+pub const PDFORK: usize = 518;
+pub const PDKILL: usize = 519;
+pub const PDGETPID: usize = 520;
+pub const PSELECT: usize = 522;
+pub const GETLOGINCLASS: usize = 523;
+pub const SETLOGINCLASS: usize = 524;
+pub const RCTL_GET_RACCT: usize = 525;
+pub const RCTL_GET_RULES: usize = 526;
+pub const RCTL_GET_LIMITS: usize = 527;
+pub const RCTL_ADD_RULE: usize = 528;
+pub const RCTL_REMOVE_RULE: usize = 529;
+pub const POSIX_FALLOCATE: usize = 530;
+pub const POSIX_FADVISE: usize = 531;
+pub const WAIT6: usize = 532;
+pub const CAP_RIGHTS_LIMIT: usize = 533;
+pub const CAP_IOCTLS_LIMIT: usize = 534;
+pub const CAP_IOCTLS_GET: usize = 535;
+pub const CAP_FCNTLS_LIMIT: usize = 536;
+pub const CAP_FCNTLS_GET: usize = 537;
+pub const BINDAT: usize = 538;
+pub const CONNECTAT: usize = 539;
+pub const CHFLAGSAT: usize = 540;
+pub const ACCEPT4: usize = 541;
+pub const PIPE2: usize = 542;
+pub const AIO_MLOCK: usize = 543;
+pub const PROCCTL: usize = 544;
+pub const PPOLL: usize = 545;
+pub const FUTIMENS: usize = 546;
+pub const UTIMENSAT: usize = 547;
+pub const FDATASYNC: usize = 550;
+pub const FSTAT: usize = 551;
+pub const FSTATAT: usize = 552;
+pub const FHSTAT: usize = 553;
+pub const GETDIRENTRIES: usize = 554;
+pub const STATFS: usize = 555;
+pub const FSTATFS: usize = 556;
+pub const GETFSSTAT: usize = 557;
+pub const FHSTATFS: usize = 558;
+pub const MKNODAT: usize = 559;
+pub const KEVENT: usize = 560;
+pub const CPUSET_GETDOMAIN: usize = 561;
+pub const CPUSET_SETDOMAIN: usize = 562;
+pub const GETRANDOM: usize = 563;
+pub const GETFHAT: usize = 564;
+pub const FHLINK: usize = 565;
+pub const FHLINKAT: usize = 566;
+pub const FHREADLINK: usize = 567;
+pub const FUNLINKAT: usize = 568;
+pub const COPY_FILE_RANGE: usize = 569;
+pub const __SYSCTLBYNAME: usize = 570;
+pub const SHM_OPEN2: usize = 571;
+pub const SHM_RENAME: usize = 572;
+pub const SIGFASTBLOCK: usize = 573;
+pub const __REALPATHAT: usize = 574;
+pub const CLOSE_RANGE: usize = 575;
+pub const RPCTLS_SYSCALL: usize = 576;
+pub const __SPECIALFD: usize = 577;
+pub const AIO_WRITEV: usize = 578;
+pub const AIO_READV: usize = 579;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    Syscall = SYSCALL,
+    Exit = EXIT,
+    Fork = FORK,
+    Read = READ,
+    Write = WRITE,
+    Open = OPEN,
+    Close = CLOSE,
+    Wait4 = WAIT4,
+    Link = LINK,
+    Unlink = UNLINK,
+    Chdir = CHDIR,
+    Fchdir = FCHDIR,
+    Chmod = CHMOD,
+    Chown = CHOWN,
+    Break = BREAK,
+    Getpid = GETPID,
+    Mount = MOUNT,
+    Unmount = UNMOUNT,
+    Setuid = SETUID,
+    Getuid = GETUID,
+    Geteuid = GETEUID,
+    Ptrace = PTRACE,
+    Recvmsg = RECVMSG,
+    Sendmsg = SENDMSG,
+    Recvfrom = RECVFROM,
+    Accept = ACCEPT,
+    Getpeername = GETPEERNAME,
+    Getsockname = GETSOCKNAME,
+    Access = ACCESS,
+    Chflags = CHFLAGS,
+    Fchflags = FCHFLAGS,
+    Sync = SYNC,
+    Kill = KILL,
+    Getppid = GETPPID,
+    Dup = DUP,
+    Getegid = GETEGID,
+    Profil = PROFIL,
+    Ktrace = KTRACE,
+    Getgid = GETGID,
+    Getlogin = GETLOGIN,
+    Setlogin = SETLOGIN,
+    Acct = ACCT,
+    Sigaltstack = SIGALTSTACK,
+    Ioctl = IOCTL,
+    Reboot = REBOOT,
+    Revoke = REVOKE,
+    Symlink = SYMLINK,
+    Readlink = READLINK,
+    Execve = EXECVE,
+    Umask = UMASK,
+    Chroot = CHROOT,
+    Msync = MSYNC,
+    Vfork = VFORK,
+    Sbrk = SBRK,
+    Sstk = SSTK,
+    Munmap = MUNMAP,
+    Mprotect = MPROTECT,
+    Madvise = MADVISE,
+    Mincore = MINCORE,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Getpgrp = GETPGRP,
+    Setpgid = SETPGID,
+    Setitimer = SETITIMER,
+    Swapon = SWAPON,
+    Getitimer = GETITIMER,
+    Getdtablesize = GETDTABLESIZE,
+    Dup2 = DUP2,
+    Fcntl = FCNTL,
+    Select = SELECT,
+    Fsync = FSYNC,
+    Setpriority = SETPRIORITY,
+    Socket = SOCKET,
+    Connect = CONNECT,
+    Getpriority = GETPRIORITY,
+    Bind = BIND,
+    Setsockopt = SETSOCKOPT,
+    Listen = LISTEN,
+    Gettimeofday = GETTIMEOFDAY,
+    Getrusage = GETRUSAGE,
+    Getsockopt = GETSOCKOPT,
+    Readv = READV,
+    Writev = WRITEV,
+    Settimeofday = SETTIMEOFDAY,
+    Fchown = FCHOWN,
+    Fchmod = FCHMOD,
+    Setreuid = SETREUID,
+    Setregid = SETREGID,
+    Rename = RENAME,
+    Flock = FLOCK,
+    Mkfifo = MKFIFO,
+    Sendto = SENDTO,
+    Shutdown = SHUTDOWN,
+    Socketpair = SOCKETPAIR,
+    Mkdir = MKDIR,
+    Rmdir = RMDIR,
+    Utimes = UTIMES,
+    Adjtime = ADJTIME,
+    Setsid = SETSID,
+    Quotactl = QUOTACTL,
+    NlmSyscall = NLM_SYSCALL,
+    Nfssvc = NFSSVC,
+    Lgetfh = LGETFH,
+    Getfh = GETFH,
+    Sysarch = SYSARCH,
+    Rtprio = RTPRIO,
+    Semsys = SEMSYS,
+    Msgsys = MSGSYS,
+    Shmsys = SHMSYS,
+    Setfib = SETFIB,
+    NtpAdjtime = NTP_ADJTIME,
+    Setgid = SETGID,
+    Setegid = SETEGID,
+    Seteuid = SETEUID,
+    Pathconf = PATHCONF,
+    Fpathconf = FPATHCONF,
+    Getrlimit = GETRLIMIT,
+    Setrlimit = SETRLIMIT,
+    QuadSyscall = __SYSCALL,
+    Sysctl = __SYSCTL,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Undelete = UNDELETE,
+    Futimes = FUTIMES,
+    Getpgid = GETPGID,
+    Poll = POLL,
+    Semget = SEMGET,
+    Semop = SEMOP,
+    Msgget = MSGGET,
+    Msgsnd = MSGSND,
+    Msgrcv = MSGRCV,
+    Shmat = SHMAT,
+    Shmdt = SHMDT,
+    Shmget = SHMGET,
+    ClockGettime = CLOCK_GETTIME,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGetres = CLOCK_GETRES,
+    KtimerCreate = KTIMER_CREATE,
+    KtimerDelete = KTIMER_DELETE,
+    KtimerSettime = KTIMER_SETTIME,
+    KtimerGettime = KTIMER_GETTIME,
+    KtimerGetoverrun = KTIMER_GETOVERRUN,
+    Nanosleep = NANOSLEEP,
+    FfclockGetcounter = FFCLOCK_GETCOUNTER,
+    FfclockSetestimate = FFCLOCK_SETESTIMATE,
+    FfclockGetestimate = FFCLOCK_GETESTIMATE,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    ClockGetcpuclockid2 = CLOCK_GETCPUCLOCKID2,
+    NtpGettime = NTP_GETTIME,
+    Minherit = MINHERIT,
+    Rfork = RFORK,
+    Issetugid = ISSETUGID,
+    Lchown = LCHOWN,
+    AioRead = AIO_READ,
+    AioWrite = AIO_WRITE,
+    LioListio = LIO_LISTIO,
+    Lchmod = LCHMOD,
+    Lutimes = LUTIMES,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    Fhopen = FHOPEN,
+    Modnext = MODNEXT,
+    Modstat = MODSTAT,
+    Modfnext = MODFNEXT,
+    Modfind = MODFIND,
+    Kldload = KLDLOAD,
+    Kldunload = KLDUNLOAD,
+    Kldfind = KLDFIND,
+    Kldnext = KLDNEXT,
+    Kldstat = KLDSTAT,
+    Kldfirstmod = KLDFIRSTMOD,
+    Getsid = GETSID,
+    Setresuid = SETRESUID,
+    Setresgid = SETRESGID,
+    AioReturn = AIO_RETURN,
+    AioSuspend = AIO_SUSPEND,
+    AioCancel = AIO_CANCEL,
+    AioError = AIO_ERROR,
+    Yield = YIELD,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    Getcwd = __GETCWD,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedYield = SCHED_YIELD,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    Utrace = UTRACE,
+    Kldsym = KLDSYM,
+    Jail = JAIL,
+    NnpfsSyscall = NNPFS_SYSCALL,
+    Sigprocmask = SIGPROCMASK,
+    Sigsuspend = SIGSUSPEND,
+    Sigpending = SIGPENDING,
+    Sigtimedwait = SIGTIMEDWAIT,
+    Sigwaitinfo = SIGWAITINFO,
+    AclGetFile = __ACL_GET_FILE,
+    AclSetFile = __ACL_SET_FILE,
+    AclGetFd = __ACL_GET_FD,
+    AclSetFd = __ACL_SET_FD,
+    AclDeleteFile = __ACL_DELETE_FILE,
+    AclDeleteFd = __ACL_DELETE_FD,
+    AclAclcheckFile = __ACL_ACLCHECK_FILE,
+    AclAclcheckFd = __ACL_ACLCHECK_FD,
+    Extattrctl = EXTATTRCTL,
+    ExtattrSetFile = EXTATTR_SET_FILE,
+    ExtattrGetFile = EXTATTR_GET_FILE,
+    ExtattrDeleteFile = EXTATTR_DELETE_FILE,
+    AioWaitcomplete = AIO_WAITCOMPLETE,
+    Getresuid = GETRESUID,
+    Getresgid = GETRESGID,
+    Kqueue = KQUEUE,
+    ExtattrSetFd = EXTATTR_SET_FD,
+    ExtattrGetFd = EXTATTR_GET_FD,
+    ExtattrDeleteFd = EXTATTR_DELETE_FD,
+    Setugid = __SETUGID,
+    Eaccess = EACCESS,
+    Afs3Syscall = AFS3_SYSCALL,
+    Nmount = NMOUNT,
+    MacGetProc = __MAC_GET_PROC,
+    MacSetProc = __MAC_SET_PROC,
+    MacGetFd = __MAC_GET_FD,
+    MacGetFile = __MAC_GET_FILE,
+    MacSetFd = __MAC_SET_FD,
+    MacSetFile = __MAC_SET_FILE,
+    Kenv = KENV,
+    Lchflags = LCHFLAGS,
+    Uuidgen = UUIDGEN,
+    Sendfile = SENDFILE,
+    MacSyscall = MAC_SYSCALL,
+    KsemClose = KSEM_CLOSE,
+    KsemPost = KSEM_POST,
+    KsemWait = KSEM_WAIT,
+    KsemTrywait = KSEM_TRYWAIT,
+    KsemInit = KSEM_INIT,
+    KsemOpen = KSEM_OPEN,
+    KsemUnlink = KSEM_UNLINK,
+    KsemGetvalue = KSEM_GETVALUE,
+    KsemDestroy = KSEM_DESTROY,
+    MacGetPid = __MAC_GET_PID,
+    MacGetLink = __MAC_GET_LINK,
+    MacSetLink = __MAC_SET_LINK,
+    ExtattrSetLink = EXTATTR_SET_LINK,
+    ExtattrGetLink = EXTATTR_GET_LINK,
+    ExtattrDeleteLink = EXTATTR_DELETE_LINK,
+    MacExecve = __MAC_EXECVE,
+    Sigaction = SIGACTION,
+    Sigreturn = SIGRETURN,
+    Getcontext = GETCONTEXT,
+    Setcontext = SETCONTEXT,
+    Swapcontext = SWAPCONTEXT,
+    Swapoff = SWAPOFF,
+    AclGetLink = __ACL_GET_LINK,
+    AclSetLink = __ACL_SET_LINK,
+    AclDeleteLink = __ACL_DELETE_LINK,
+    AclAclcheckLink = __ACL_ACLCHECK_LINK,
+    Sigwait = SIGWAIT,
+    ThrCreate = THR_CREATE,
+    ThrExit = THR_EXIT,
+    ThrSelf = THR_SELF,
+    ThrKill = THR_KILL,
+    JailAttach = JAIL_ATTACH,
+    ExtattrListFd = EXTATTR_LIST_FD,
+    ExtattrListFile = EXTATTR_LIST_FILE,
+    ExtattrListLink = EXTATTR_LIST_LINK,
+    KsemTimedwait = KSEM_TIMEDWAIT,
+    ThrSuspend = THR_SUSPEND,
+    ThrWake = THR_WAKE,
+    Kldunloadf = KLDUNLOADF,
+    Audit = AUDIT,
+    Auditon = AUDITON,
+    Getauid = GETAUID,
+    Setauid = SETAUID,
+    Getaudit = GETAUDIT,
+    Setaudit = SETAUDIT,
+    GetauditAddr = GETAUDIT_ADDR,
+    SetauditAddr = SETAUDIT_ADDR,
+    Auditctl = AUDITCTL,
+    UmtxOp = _UMTX_OP,
+    ThrNew = THR_NEW,
+    Sigqueue = SIGQUEUE,
+    KmqOpen = KMQ_OPEN,
+    KmqSetattr = KMQ_SETATTR,
+    KmqTimedreceive = KMQ_TIMEDRECEIVE,
+    KmqTimedsend = KMQ_TIMEDSEND,
+    KmqNotify = KMQ_NOTIFY,
+    KmqUnlink = KMQ_UNLINK,
+    Abort2 = ABORT2,
+    ThrSetName = THR_SET_NAME,
+    AioFsync = AIO_FSYNC,
+    RtprioThread = RTPRIO_THREAD,
+    SctpPeeloff = SCTP_PEELOFF,
+    SctpGenericSendmsg = SCTP_GENERIC_SENDMSG,
+    SctpGenericSendmsgIov = SCTP_GENERIC_SENDMSG_IOV,
+    SctpGenericRecvmsg = SCTP_GENERIC_RECVMSG,
+    Pread = PREAD,
+    Pwrite = PWRITE,
+    Mmap = MMAP,
+    Lseek = LSEEK,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    ThrKill2 = THR_KILL2,
+    ShmUnlink = SHM_UNLINK,
+    Cpuset = CPUSET,
+    CpusetSetid = CPUSET_SETID,
+    CpusetGetid = CPUSET_GETID,
+    CpusetGetaffinity = CPUSET_GETAFFINITY,
+    CpusetSetaffinity = CPUSET_SETAFFINITY,
+    Faccessat = FACCESSAT,
+    Fchmodat = FCHMODAT,
+    Fchownat = FCHOWNAT,
+    Fexecve = FEXECVE,
+    Futimesat = FUTIMESAT,
+    Linkat = LINKAT,
+    Mkdirat = MKDIRAT,
+    Mkfifoat = MKFIFOAT,
+    Openat = OPENAT,
+    Readlinkat = READLINKAT,
+    Renameat = RENAMEAT,
+    Symlinkat = SYMLINKAT,
+    Unlinkat = UNLINKAT,
+    PosixOpenpt = POSIX_OPENPT,
+    GssdSyscall = GSSD_SYSCALL,
+    JailGet = JAIL_GET,
+    JailSet = JAIL_SET,
+    JailRemove = JAIL_REMOVE,
+    Semctl = __SEMCTL,
+    Msgctl = MSGCTL,
+    Shmctl = SHMCTL,
+    Lpathconf = LPATHCONF,
+    CapRightsGet = __CAP_RIGHTS_GET,
+    CapEnter = CAP_ENTER,
+    CapGetmode = CAP_GETMODE,
+    Pdfork = PDFORK,
+    Pdkill = PDKILL,
+    Pdgetpid = PDGETPID,
+    Pselect = PSELECT,
+    Getloginclass = GETLOGINCLASS,
+    Setloginclass = SETLOGINCLASS,
+    RctlGetRacct = RCTL_GET_RACCT,
+    RctlGetRules = RCTL_GET_RULES,
+    RctlGetLimits = RCTL_GET_LIMITS,
+    RctlAddRule = RCTL_ADD_RULE,
+    RctlRemoveRule = RCTL_REMOVE_RULE,
+    PosixFallocate = POSIX_FALLOCATE,
+    PosixFadvise = POSIX_FADVISE,
+    Wait6 = WAIT6,
+    CapRightsLimit = CAP_RIGHTS_LIMIT,
+    CapIoctlsLimit = CAP_IOCTLS_LIMIT,
+    CapIoctlsGet = CAP_IOCTLS_GET,
+    CapFcntlsLimit = CAP_FCNTLS_LIMIT,
+    CapFcntlsGet = CAP_FCNTLS_GET,
+    Bindat = BINDAT,
+    Connectat = CONNECTAT,
+    Chflagsat = CHFLAGSAT,
+    Accept4 = ACCEPT4,
+    Pipe2 = PIPE2,
+    AioMlock = AIO_MLOCK,
+    Procctl = PROCCTL,
+    Ppoll = PPOLL,
+    Futimens = FUTIMENS,
+    Utimensat = UTIMENSAT,
+    Fdatasync = FDATASYNC,
+    Fstat = FSTAT,
+    Fstatat = FSTATAT,
+    Fhstat = FHSTAT,
+    Getdirentries = GETDIRENTRIES,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Getfsstat = GETFSSTAT,
+    Fhstatfs = FHSTATFS,
+    Mknodat = MKNODAT,
+    Kevent = KEVENT,
+    CpusetGetdomain = CPUSET_GETDOMAIN,
+    CpusetSetdomain = CPUSET_SETDOMAIN,
+    Getrandom = GETRANDOM,
+    Getfhat = GETFHAT,
+    Fhlink = FHLINK,
+    Fhlinkat = FHLINKAT,
+    Fhreadlink = FHREADLINK,
+    Funlinkat = FUNLINKAT,
+    CopyFileRange = COPY_FILE_RANGE,
+    Sysctlbyname = __SYSCTLBYNAME,
+    ShmOpen2 = SHM_OPEN2,
+    ShmRename = SHM_RENAME,
+    Sigfastblock = SIGFASTBLOCK,
+    Realpathat = __REALPATHAT,
+    CloseRange = CLOSE_RANGE,
+    RpctlsSyscall = RPCTLS_SYSCALL,
+    Specialfd = __SPECIALFD,
+    AioWritev = AIO_WRITEV,
+    AioReadv = AIO_READV,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::Syscall,
+        Self::Exit,
+        Self::Fork,
+        Self::Read,
+        Self::Write,
+        Self::Open,
+        Self::Close,
+        Self::Wait4,
+        Self::Link,
+        Self::Unlink,
+        Self::Chdir,
+        Self::Fchdir,
+        Self::Chmod,
+        Self::Chown,
+        Self::Break,
+        Self::Getpid,
+        Self::Mount,
+        Self::Unmount,
+        Self::Setuid,
+        Self::Getuid,
+        Self::Geteuid,
+        Self::Ptrace,
+        Self::Recvmsg,
+        Self::Sendmsg,
+        Self::Recvfrom,
+        Self::Accept,
+        Self::Getpeername,
+        Self::Getsockname,
+        Self::Access,
+        Self::Chflags,
+        Self::Fchflags,
+        Self::Sync,
+        Self::Kill,
+        Self::Getppid,
+        Self::Dup,
+        Self::Getegid,
+        Self::Profil,
+        Self::Ktrace,
+        Self::Getgid,
+        Self::Getlogin,
+        Self::Setlogin,
+        Self::Acct,
+        Self::Sigaltstack,
+        Self::Ioctl,
+        Self::Reboot,
+        Self::Revoke,
+        Self::Symlink,
+        Self::Readlink,
+        Self::Execve,
+        Self::Umask,
+        Self::Chroot,
+        Self::Msync,
+        Self::Vfork,
+        Self::Sbrk,
+        Self::Sstk,
+        Self::Munmap,
+        Self::Mprotect,
+        Self::Madvise,
+        Self::Mincore,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Getpgrp,
+        Self::Setpgid,
+        Self::Setitimer,
+        Self::Swapon,
+        Self::Getitimer,
+        Self::Getdtablesize,
+        Self::Dup2,
+        Self::Fcntl,
+        Self::Select,
+        Self::Fsync,
+        Self::Setpriority,
+        Self::Socket,
+        Self::Connect,
+        Self::Getpriority,
+        Self::Bind,
+        Self::Setsockopt,
+        Self::Listen,
+        Self::Gettimeofday,
+        Self::Getrusage,
+        Self::Getsockopt,
+        Self::Readv,
+        Self::Writev,
+        Self::Settimeofday,
+        Self::Fchown,
+        Self::Fchmod,
+        Self::Setreuid,
+        Self::Setregid,
+        Self::Rename,
+        Self::Flock,
+        Self::Mkfifo,
+        Self::Sendto,
+        Self::Shutdown,
+        Self::Socketpair,
+        Self::Mkdir,
+        Self::Rmdir,
+        Self::Utimes,
+        Self::Adjtime,
+        Self::Setsid,
+        Self::Quotactl,
+        Self::NlmSyscall,
+        Self::Nfssvc,
+        Self::Lgetfh,
+        Self::Getfh,
+        Self::Sysarch,
+        Self::Rtprio,
+        Self::Semsys,
+        Self::Msgsys,
+        Self::Shmsys,
+        Self::Setfib,
+        Self::NtpAdjtime,
+        Self::Setgid,
+        Self::Setegid,
+        Self::Seteuid,
+        Self::Pathconf,
+        Self::Fpathconf,
+        Self::Getrlimit,
+        Self::Setrlimit,
+        Self::QuadSyscall,
+        Self::Sysctl,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Undelete,
+        Self::Futimes,
+        Self::Getpgid,
+        Self::Poll,
+        Self::Semget,
+        Self::Semop,
+        Self::Msgget,
+        Self::Msgsnd,
+        Self::Msgrcv,
+        Self::Shmat,
+        Self::Shmdt,
+        Self::Shmget,
+        Self::ClockGettime,
+        Self::ClockSettime,
+        Self::ClockGetres,
+        Self::KtimerCreate,
+        Self::KtimerDelete,
+        Self::KtimerSettime,
+        Self::KtimerGettime,
+        Self::KtimerGetoverrun,
+        Self::Nanosleep,
+        Self::FfclockGetcounter,
+        Self::FfclockSetestimate,
+        Self::FfclockGetestimate,
+        Self::ClockNanosleep,
+        Self::ClockGetcpuclockid2,
+        Self::NtpGettime,
+        Self::Minherit,
+        Self::Rfork,
+        Self::Issetugid,
+        Self::Lchown,
+        Self::AioRead,
+        Self::AioWrite,
+        Self::LioListio,
+        Self::Lchmod,
+        Self::Lutimes,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::Fhopen,
+        Self::Modnext,
+        Self::Modstat,
+        Self::Modfnext,
+        Self::Modfind,
+        Self::Kldload,
+        Self::Kldunload,
+        Self::Kldfind,
+        Self::Kldnext,
+        Self::Kldstat,
+        Self::Kldfirstmod,
+        Self::Getsid,
+        Self::Setresuid,
+        Self::Setresgid,
+        Self::AioReturn,
+        Self::AioSuspend,
+        Self::AioCancel,
+        Self::AioError,
+        Self::Yield,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::Getcwd,
+        Self::SchedSetparam,
+        Self::SchedGetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedYield,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::Utrace,
+        Self::Kldsym,
+        Self::Jail,
+        Self::NnpfsSyscall,
+        Self::Sigprocmask,
+        Self::Sigsuspend,
+        Self::Sigpending,
+        Self::Sigtimedwait,
+        Self::Sigwaitinfo,
+        Self::AclGetFile,
+        Self::AclSetFile,
+        Self::AclGetFd,
+        Self::AclSetFd,
+        Self::AclDeleteFile,
+        Self::AclDeleteFd,
+        Self::AclAclcheckFile,
+        Self::AclAclcheckFd,
+        Self::Extattrctl,
+        Self::ExtattrSetFile,
+        Self::ExtattrGetFile,
+        Self::ExtattrDeleteFile,
+        Self::AioWaitcomplete,
+        Self::Getresuid,
+        Self::Getresgid,
+        Self::Kqueue,
+        Self::ExtattrSetFd,
+        Self::ExtattrGetFd,
+        Self::ExtattrDeleteFd,
+        Self::Setugid,
+        Self::Eaccess,
+        Self::Afs3Syscall,
+        Self::Nmount,
+        Self::MacGetProc,
+        Self::MacSetProc,
+        Self::MacGetFd,
+        Self::MacGetFile,
+        Self::MacSetFd,
+        Self::MacSetFile,
+        Self::Kenv,
+        Self::Lchflags,
+        Self::Uuidgen,
+        Self::Sendfile,
+        Self::MacSyscall,
+        Self::KsemClose,
+        Self::KsemPost,
+        Self::KsemWait,
+        Self::KsemTrywait,
+        Self::KsemInit,
+        Self::KsemOpen,
+        Self::KsemUnlink,
+        Self::KsemGetvalue,
+        Self::KsemDestroy,
+        Self::MacGetPid,
+        Self::MacGetLink,
+        Self::MacSetLink,
+        Self::ExtattrSetLink,
+        Self::ExtattrGetLink,
+        Self::ExtattrDeleteLink,
+        Self::MacExecve,
+        Self::Sigaction,
+        Self::Sigreturn,
+        Self::Getcontext,
+        Self::Setcontext,
+        Self::Swapcontext,
+        Self::Swapoff,
+        Self::AclGetLink,
+        Self::AclSetLink,
+        Self::AclDeleteLink,
+        Self::AclAclcheckLink,
+        Self::Sigwait,
+        Self::ThrCreate,
+        Self::ThrExit,
+        Self::ThrSelf,
+        Self::ThrKill,
+        Self::JailAttach,
+        Self::ExtattrListFd,
+        Self::ExtattrListFile,
+        Self::ExtattrListLink,
+        Self::KsemTimedwait,
+        Self::ThrSuspend,
+        Self::ThrWake,
+        Self::Kldunloadf,
+        Self::Audit,
+        Self::Auditon,
+        Self::Getauid,
+        Self::Setauid,
+        Self::Getaudit,
+        Self::Setaudit,
+        Self::GetauditAddr,
+        Self::SetauditAddr,
+        Self::Auditctl,
+        Self::UmtxOp,
+        Self::ThrNew,
+        Self::Sigqueue,
+        Self::KmqOpen,
+        Self::KmqSetattr,
+        Self::KmqTimedreceive,
+        Self::KmqTimedsend,
+        Self::KmqNotify,
+        Self::KmqUnlink,
+        Self::Abort2,
+        Self::ThrSetName,
+        Self::AioFsync,
+        Self::RtprioThread,
+        Self::SctpPeeloff,
+        Self::SctpGenericSendmsg,
+        Self::SctpGenericSendmsgIov,
+        Self::SctpGenericRecvmsg,
+        Self::Pread,
+        Self::Pwrite,
+        Self::Mmap,
+        Self::Lseek,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::ThrKill2,
+        Self::ShmUnlink,
+        Self::Cpuset,
+        Self::CpusetSetid,
+        Self::CpusetGetid,
+        Self::CpusetGetaffinity,
+        Self::CpusetSetaffinity,
+        Self::Faccessat,
+        Self::Fchmodat,
+        Self::Fchownat,
+        Self::Fexecve,
+        Self::Futimesat,
+        Self::Linkat,
+        Self::Mkdirat,
+        Self::Mkfifoat,
+        Self::Openat,
+        Self::Readlinkat,
+        Self::Renameat,
+        Self::Symlinkat,
+        Self::Unlinkat,
+        Self::PosixOpenpt,
+        Self::GssdSyscall,
+        Self::JailGet,
+        Self::JailSet,
+        Self::JailRemove,
+        Self::Semctl,
+        Self::Msgctl,
+        Self::Shmctl,
+        Self::Lpathconf,
+        Self::CapRightsGet,
+        Self::CapEnter,
+        Self::CapGetmode,
+        Self::Pdfork,
+        Self::Pdkill,
+        Self::Pdgetpid,
+        Self::Pselect,
+        Self::Getloginclass,
+        Self::Setloginclass,
+        Self::RctlGetRacct,
+        Self::RctlGetRules,
+        Self::RctlGetLimits,
+        Self::RctlAddRule,
+        Self::RctlRemoveRule,
+        Self::PosixFallocate,
+        Self::PosixFadvise,
+        Self::Wait6,
+        Self::CapRightsLimit,
+        Self::CapIoctlsLimit,
+        Self::CapIoctlsGet,
+        Self::CapFcntlsLimit,
+        Self::CapFcntlsGet,
+        Self::Bindat,
+        Self::Connectat,
+        Self::Chflagsat,
+        Self::Accept4,
+        Self::Pipe2,
+        Self::AioMlock,
+        Self::Procctl,
+        Self::Ppoll,
+        Self::Futimens,
+        Self::Utimensat,
+        Self::Fdatasync,
+        Self::Fstat,
+        Self::Fstatat,
+        Self::Fhstat,
+        Self::Getdirentries,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Getfsstat,
+        Self::Fhstatfs,
+        Self::Mknodat,
+        Self::Kevent,
+        Self::CpusetGetdomain,
+        Self::CpusetSetdomain,
+        Self::Getrandom,
+        Self::Getfhat,
+        Self::Fhlink,
+        Self::Fhlinkat,
+        Self::Fhreadlink,
+        Self::Funlinkat,
+        Self::CopyFileRange,
+        Self::Sysctlbyname,
+        Self::ShmOpen2,
+        Self::ShmRename,
+        Self::Sigfastblock,
+        Self::Realpathat,
+        Self::CloseRange,
+        Self::RpctlsSyscall,
+        Self::Specialfd,
+        Self::AioWritev,
+        Self::AioReadv,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            SYSCALL => Some(Self::Syscall),
+            EXIT => Some(Self::Exit),
+            FORK => Some(Self::Fork),
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            OPEN => Some(Self::Open),
+            CLOSE => Some(Self::Close),
+            WAIT4 => Some(Self::Wait4),
+            LINK => Some(Self::Link),
+            UNLINK => Some(Self::Unlink),
+            CHDIR => Some(Self::Chdir),
+            FCHDIR => Some(Self::Fchdir),
+            CHMOD => Some(Self::Chmod),
+            CHOWN => Some(Self::Chown),
+            BREAK => Some(Self::Break),
+            GETPID => Some(Self::Getpid),
+            MOUNT => Some(Self::Mount),
+            UNMOUNT => Some(Self::Unmount),
+            SETUID => Some(Self::Setuid),
+            GETUID => Some(Self::Getuid),
+            GETEUID => Some(Self::Geteuid),
+            PTRACE => Some(Self::Ptrace),
+            RECVMSG => Some(Self::Recvmsg),
+            SENDMSG => Some(Self::Sendmsg),
+            RECVFROM => Some(Self::Recvfrom),
+            ACCEPT => Some(Self::Accept),
+            GETPEERNAME => Some(Self::Getpeername),
+            GETSOCKNAME => Some(Self::Getsockname),
+            ACCESS => Some(Self::Access),
+            CHFLAGS => Some(Self::Chflags),
+            FCHFLAGS => Some(Self::Fchflags),
+            SYNC => Some(Self::Sync),
+            KILL => Some(Self::Kill),
+            GETPPID => Some(Self::Getppid),
+            DUP => Some(Self::Dup),
+            GETEGID => Some(Self::Getegid),
+            PROFIL => Some(Self::Profil),
+            KTRACE => Some(Self::Ktrace),
+            GETGID => Some(Self::Getgid),
+            GETLOGIN => Some(Self::Getlogin),
+            SETLOGIN => Some(Self::Setlogin),
+            ACCT => Some(Self::Acct),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            IOCTL => Some(Self::Ioctl),
+            REBOOT => Some(Self::Reboot),
+            REVOKE => Some(Self::Revoke),
+            SYMLINK => Some(Self::Symlink),
+            READLINK => Some(Self::Readlink),
+            EXECVE => Some(Self::Execve),
+            UMASK => Some(Self::Umask),
+            CHROOT => Some(Self::Chroot),
+            MSYNC => Some(Self::Msync),
+            VFORK => Some(Self::Vfork),
+            SBRK => Some(Self::Sbrk),
+            SSTK => Some(Self::Sstk),
+            MUNMAP => Some(Self::Munmap),
+            MPROTECT => Some(Self::Mprotect),
+            MADVISE => Some(Self::Madvise),
+            MINCORE => Some(Self::Mincore),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            GETPGRP => Some(Self::Getpgrp),
+            SETPGID => Some(Self::Setpgid),
+            SETITIMER => Some(Self::Setitimer),
+            SWAPON => Some(Self::Swapon),
+            GETITIMER => Some(Self::Getitimer),
+            GETDTABLESIZE => Some(Self::Getdtablesize),
+            DUP2 => Some(Self::Dup2),
+            FCNTL => Some(Self::Fcntl),
+            SELECT => Some(Self::Select),
+            FSYNC => Some(Self::Fsync),
+            SETPRIORITY => Some(Self::Setpriority),
+            SOCKET => Some(Self::Socket),
+            CONNECT => Some(Self::Connect),
+            GETPRIORITY => Some(Self::Getpriority),
+            BIND => Some(Self::Bind),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            LISTEN => Some(Self::Listen),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            GETRUSAGE => Some(Self::Getrusage),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            FCHOWN => Some(Self::Fchown),
+            FCHMOD => Some(Self::Fchmod),
+            SETREUID => Some(Self::Setreuid),
+            SETREGID => Some(Self::Setregid),
+            RENAME => Some(Self::Rename),
+            FLOCK => Some(Self::Flock),
+            MKFIFO => Some(Self::Mkfifo),
+            SENDTO => Some(Self::Sendto),
+            SHUTDOWN => Some(Self::Shutdown),
+            SOCKETPAIR => Some(Self::Socketpair),
+            MKDIR => Some(Self::Mkdir),
+            RMDIR => Some(Self::Rmdir),
+            UTIMES => Some(Self::Utimes),
+            ADJTIME => Some(Self::Adjtime),
+            SETSID => Some(Self::Setsid),
+            QUOTACTL => Some(Self::Quotactl),
+            NLM_SYSCALL => Some(Self::NlmSyscall),
+            NFSSVC => Some(Self::Nfssvc),
+            LGETFH => Some(Self::Lgetfh),
+            GETFH => Some(Self::Getfh),
+            SYSARCH => Some(Self::Sysarch),
+            RTPRIO => Some(Self::Rtprio),
+            SEMSYS => Some(Self::Semsys),
+            MSGSYS => Some(Self::Msgsys),
+            SHMSYS => Some(Self::Shmsys),
+            SETFIB => Some(Self::Setfib),
+            NTP_ADJTIME => Some(Self::NtpAdjtime),
+            SETGID => Some(Self::Setgid),
+            SETEGID => Some(Self::Setegid),
+            SETEUID => Some(Self::Seteuid),
+            PATHCONF => Some(Self::Pathconf),
+            FPATHCONF => Some(Self::Fpathconf),
+            GETRLIMIT => Some(Self::Getrlimit),
+            SETRLIMIT => Some(Self::Setrlimit),
+            __SYSCALL => Some(Self::QuadSyscall),
+            __SYSCTL => Some(Self::Sysctl),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            UNDELETE => Some(Self::Undelete),
+            FUTIMES => Some(Self::Futimes),
+            GETPGID => Some(Self::Getpgid),
+            POLL => Some(Self::Poll),
+            SEMGET => Some(Self::Semget),
+            SEMOP => Some(Self::Semop),
+            MSGGET => Some(Self::Msgget),
+            MSGSND => Some(Self::Msgsnd),
+            MSGRCV => Some(Self::Msgrcv),
+            SHMAT => Some(Self::Shmat),
+            SHMDT => Some(Self::Shmdt),
+            SHMGET => Some(Self::Shmget),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            KTIMER_CREATE => Some(Self::KtimerCreate),
+            KTIMER_DELETE => Some(Self::KtimerDelete),
+            KTIMER_SETTIME => Some(Self::KtimerSettime),
+            KTIMER_GETTIME => Some(Self::KtimerGettime),
+            KTIMER_GETOVERRUN => Some(Self::KtimerGetoverrun),
+            NANOSLEEP => Some(Self::Nanosleep),
+            FFCLOCK_GETCOUNTER => Some(Self::FfclockGetcounter),
+            FFCLOCK_SETESTIMATE => Some(Self::FfclockSetestimate),
+            FFCLOCK_GETESTIMATE => Some(Self::FfclockGetestimate),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            CLOCK_GETCPUCLOCKID2 => Some(Self::ClockGetcpuclockid2),
+            NTP_GETTIME => Some(Self::NtpGettime),
+            MINHERIT => Some(Self::Minherit),
+            RFORK => Some(Self::Rfork),
+            ISSETUGID => Some(Self::Issetugid),
+            LCHOWN => Some(Self::Lchown),
+            AIO_READ => Some(Self::AioRead),
+            AIO_WRITE => Some(Self::AioWrite),
+            LIO_LISTIO => Some(Self::LioListio),
+            LCHMOD => Some(Self::Lchmod),
+            LUTIMES => Some(Self::Lutimes),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            FHOPEN => Some(Self::Fhopen),
+            MODNEXT => Some(Self::Modnext),
+            MODSTAT => Some(Self::Modstat),
+            MODFNEXT => Some(Self::Modfnext),
+            MODFIND => Some(Self::Modfind),
+            KLDLOAD => Some(Self::Kldload),
+            KLDUNLOAD => Some(Self::Kldunload),
+            KLDFIND => Some(Self::Kldfind),
+            KLDNEXT => Some(Self::Kldnext),
+            KLDSTAT => Some(Self::Kldstat),
+            KLDFIRSTMOD => Some(Self::Kldfirstmod),
+            GETSID => Some(Self::Getsid),
+            SETRESUID => Some(Self::Setresuid),
+            SETRESGID => Some(Self::Setresgid),
+            AIO_RETURN => Some(Self::AioReturn),
+            AIO_SUSPEND => Some(Self::AioSuspend),
+            AIO_CANCEL => Some(Self::AioCancel),
+            AIO_ERROR => Some(Self::AioError),
+            YIELD => Some(Self::Yield),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            __GETCWD => Some(Self::Getcwd),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_YIELD => Some(Self::SchedYield),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            UTRACE => Some(Self::Utrace),
+            KLDSYM => Some(Self::Kldsym),
+            JAIL => Some(Self::Jail),
+            NNPFS_SYSCALL => Some(Self::NnpfsSyscall),
+            SIGPROCMASK => Some(Self::Sigprocmask),
+            SIGSUSPEND => Some(Self::Sigsuspend),
+            SIGPENDING => Some(Self::Sigpending),
+            SIGTIMEDWAIT => Some(Self::Sigtimedwait),
+            SIGWAITINFO => Some(Self::Sigwaitinfo),
+            __ACL_GET_FILE => Some(Self::AclGetFile),
+            __ACL_SET_FILE => Some(Self::AclSetFile),
+            __ACL_GET_FD => Some(Self::AclGetFd),
+            __ACL_SET_FD => Some(Self::AclSetFd),
+            __ACL_DELETE_FILE => Some(Self::AclDeleteFile),
+            __ACL_DELETE_FD => Some(Self::AclDeleteFd),
+            __ACL_ACLCHECK_FILE => Some(Self::AclAclcheckFile),
+            __ACL_ACLCHECK_FD => Some(Self::AclAclcheckFd),
+            EXTATTRCTL => Some(Self::Extattrctl),
+            EXTATTR_SET_FILE => Some(Self::ExtattrSetFile),
+            EXTATTR_GET_FILE => Some(Self::ExtattrGetFile),
+            EXTATTR_DELETE_FILE => Some(Self::ExtattrDeleteFile),
+            AIO_WAITCOMPLETE => Some(Self::AioWaitcomplete),
+            GETRESUID => Some(Self::Getresuid),
+            GETRESGID => Some(Self::Getresgid),
+            KQUEUE => Some(Self::Kqueue),
+            EXTATTR_SET_FD => Some(Self::ExtattrSetFd),
+            EXTATTR_GET_FD => Some(Self::ExtattrGetFd),
+            EXTATTR_DELETE_FD => Some(Self::ExtattrDeleteFd),
+            __SETUGID => Some(Self::Setugid),
+            EACCESS => Some(Self::Eaccess),
+            AFS3_SYSCALL => Some(Self::Afs3Syscall),
+            NMOUNT => Some(Self::Nmount),
+            __MAC_GET_PROC => Some(Self::MacGetProc),
+            __MAC_SET_PROC => Some(Self::MacSetProc),
+            __MAC_GET_FD => Some(Self::MacGetFd),
+            __MAC_GET_FILE => Some(Self::MacGetFile),
+            __MAC_SET_FD => Some(Self::MacSetFd),
+            __MAC_SET_FILE => Some(Self::MacSetFile),
+            KENV => Some(Self::Kenv),
+            LCHFLAGS => Some(Self::Lchflags),
+            UUIDGEN => Some(Self::Uuidgen),
+            SENDFILE => Some(Self::Sendfile),
+            MAC_SYSCALL => Some(Self::MacSyscall),
+            KSEM_CLOSE => Some(Self::KsemClose),
+            KSEM_POST => Some(Self::KsemPost),
+            KSEM_WAIT => Some(Self::KsemWait),
+            KSEM_TRYWAIT => Some(Self::KsemTrywait),
+            KSEM_INIT => Some(Self::KsemInit),
+            KSEM_OPEN => Some(Self::KsemOpen),
+            KSEM_UNLINK => Some(Self::KsemUnlink),
+            KSEM_GETVALUE => Some(Self::KsemGetvalue),
+            KSEM_DESTROY => Some(Self::KsemDestroy),
+            __MAC_GET_PID => Some(Self::MacGetPid),
+            __MAC_GET_LINK => Some(Self::MacGetLink),
+            __MAC_SET_LINK => Some(Self::MacSetLink),
+            EXTATTR_SET_LINK => Some(Self::ExtattrSetLink),
+            EXTATTR_GET_LINK => Some(Self::ExtattrGetLink),
+            EXTATTR_DELETE_LINK => Some(Self::ExtattrDeleteLink),
+            __MAC_EXECVE => Some(Self::MacExecve),
+            SIGACTION => Some(Self::Sigaction),
+            SIGRETURN => Some(Self::Sigreturn),
+            GETCONTEXT => Some(Self::Getcontext),
+            SETCONTEXT => Some(Self::Setcontext),
+            SWAPCONTEXT => Some(Self::Swapcontext),
+            SWAPOFF => Some(Self::Swapoff),
+            __ACL_GET_LINK => Some(Self::AclGetLink),
+            __ACL_SET_LINK => Some(Self::AclSetLink),
+            __ACL_DELETE_LINK => Some(Self::AclDeleteLink),
+            __ACL_ACLCHECK_LINK => Some(Self::AclAclcheckLink),
+            SIGWAIT => Some(Self::Sigwait),
+            THR_CREATE => Some(Self::ThrCreate),
+            THR_EXIT => Some(Self::ThrExit),
+            THR_SELF => Some(Self::ThrSelf),
+            THR_KILL => Some(Self::ThrKill),
+            JAIL_ATTACH => Some(Self::JailAttach),
+            EXTATTR_LIST_FD => Some(Self::ExtattrListFd),
+            EXTATTR_LIST_FILE => Some(Self::ExtattrListFile),
+            EXTATTR_LIST_LINK => Some(Self::ExtattrListLink),
+            KSEM_TIMEDWAIT => Some(Self::KsemTimedwait),
+            THR_SUSPEND => Some(Self::ThrSuspend),
+            THR_WAKE => Some(Self::ThrWake),
+            KLDUNLOADF => Some(Self::Kldunloadf),
+            AUDIT => Some(Self::Audit),
+            AUDITON => Some(Self::Auditon),
+            GETAUID => Some(Self::Getauid),
+            SETAUID => Some(Self::Setauid),
+            GETAUDIT => Some(Self::Getaudit),
+            SETAUDIT => Some(Self::Setaudit),
+            GETAUDIT_ADDR => Some(Self::GetauditAddr),
+            SETAUDIT_ADDR => Some(Self::SetauditAddr),
+            AUDITCTL => Some(Self::Auditctl),
+            _UMTX_OP => Some(Self::UmtxOp),
+            THR_NEW => Some(Self::ThrNew),
+            SIGQUEUE => Some(Self::Sigqueue),
+            KMQ_OPEN => Some(Self::KmqOpen),
+            KMQ_SETATTR => Some(Self::KmqSetattr),
+            KMQ_TIMEDRECEIVE => Some(Self::KmqTimedreceive),
+            KMQ_TIMEDSEND => Some(Self::KmqTimedsend),
+            KMQ_NOTIFY => Some(Self::KmqNotify),
+            KMQ_UNLINK => Some(Self::KmqUnlink),
+            ABORT2 => Some(Self::Abort2),
+            THR_SET_NAME => Some(Self::ThrSetName),
+            AIO_FSYNC => Some(Self::AioFsync),
+            RTPRIO_THREAD => Some(Self::RtprioThread),
+            SCTP_PEELOFF => Some(Self::SctpPeeloff),
+            SCTP_GENERIC_SENDMSG => Some(Self::SctpGenericSendmsg),
+            SCTP_GENERIC_SENDMSG_IOV => Some(Self::SctpGenericSendmsgIov),
+            SCTP_GENERIC_RECVMSG => Some(Self::SctpGenericRecvmsg),
+            PREAD => Some(Self::Pread),
+            PWRITE => Some(Self::Pwrite),
+            MMAP => Some(Self::Mmap),
+            LSEEK => Some(Self::Lseek),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            THR_KILL2 => Some(Self::ThrKill2),
+            SHM_UNLINK => Some(Self::ShmUnlink),
+            CPUSET => Some(Self::Cpuset),
+            CPUSET_SETID => Some(Self::CpusetSetid),
+            CPUSET_GETID => Some(Self::CpusetGetid),
+            CPUSET_GETAFFINITY => Some(Self::CpusetGetaffinity),
+            CPUSET_SETAFFINITY => Some(Self::CpusetSetaffinity),
+            FACCESSAT => Some(Self::Faccessat),
+            FCHMODAT => Some(Self::Fchmodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FEXECVE => Some(Self::Fexecve),
+            FUTIMESAT => Some(Self::Futimesat),
+            LINKAT => Some(Self::Linkat),
+            MKDIRAT => Some(Self::Mkdirat),
+            MKFIFOAT => Some(Self::Mkfifoat),
+            OPENAT => Some(Self::Openat),
+            READLINKAT => Some(Self::Readlinkat),
+            RENAMEAT => Some(Self::Renameat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            UNLINKAT => Some(Self::Unlinkat),
+            POSIX_OPENPT => Some(Self::PosixOpenpt),
+            GSSD_SYSCALL => Some(Self::GssdSyscall),
+            JAIL_GET => Some(Self::JailGet),
+            JAIL_SET => Some(Self::JailSet),
+            JAIL_REMOVE => Some(Self::JailRemove),
+            __SEMCTL => Some(Self::Semctl),
+            MSGCTL => Some(Self::Msgctl),
+            SHMCTL => Some(Self::Shmctl),
+            LPATHCONF => Some(Self::Lpathconf),
+            __CAP_RIGHTS_GET => Some(Self::CapRightsGet),
+            CAP_ENTER => Some(Self::CapEnter),
+            CAP_GETMODE => Some(Self::CapGetmode),
+            PDFORK => Some(Self::Pdfork),
+            PDKILL => Some(Self::Pdkill),
+            PDGETPID => Some(Self::Pdgetpid),
+            PSELECT => Some(Self::Pselect),
+            GETLOGINCLASS => Some(Self::Getloginclass),
+            SETLOGINCLASS => Some(Self::Setloginclass),
+            RCTL_GET_RACCT => Some(Self::RctlGetRacct),
+            RCTL_GET_RULES => Some(Self::RctlGetRules),
+            RCTL_GET_LIMITS => Some(Self::RctlGetLimits),
+            RCTL_ADD_RULE => Some(Self::RctlAddRule),
+            RCTL_REMOVE_RULE => Some(Self::RctlRemoveRule),
+            POSIX_FALLOCATE => Some(Self::PosixFallocate),
+            POSIX_FADVISE => Some(Self::PosixFadvise),
+            WAIT6 => Some(Self::Wait6),
+            CAP_RIGHTS_LIMIT => Some(Self::CapRightsLimit),
+            CAP_IOCTLS_LIMIT => Some(Self::CapIoctlsLimit),
+            CAP_IOCTLS_GET => Some(Self::CapIoctlsGet),
+            CAP_FCNTLS_LIMIT => Some(Self::CapFcntlsLimit),
+            CAP_FCNTLS_GET => Some(Self::CapFcntlsGet),
+            BINDAT => Some(Self::Bindat),
+            CONNECTAT => Some(Self::Connectat),
+            CHFLAGSAT => Some(Self::Chflagsat),
+            ACCEPT4 => Some(Self::Accept4),
+            PIPE2 => Some(Self::Pipe2),
+            AIO_MLOCK => Some(Self::AioMlock),
+            PROCCTL => Some(Self::Procctl),
+            PPOLL => Some(Self::Ppoll),
+            FUTIMENS => Some(Self::Futimens),
+            UTIMENSAT => Some(Self::Utimensat),
+            FDATASYNC => Some(Self::Fdatasync),
+            FSTAT => Some(Self::Fstat),
+            FSTATAT => Some(Self::Fstatat),
+            FHSTAT => Some(Self::Fhstat),
+            GETDIRENTRIES => Some(Self::Getdirentries),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            GETFSSTAT => Some(Self::Getfsstat),
+            FHSTATFS => Some(Self::Fhstatfs),
+            MKNODAT => Some(Self::Mknodat),
+            KEVENT => Some(Self::Kevent),
+            CPUSET_GETDOMAIN => Some(Self::CpusetGetdomain),
+            CPUSET_SETDOMAIN => Some(Self::CpusetSetdomain),
+            GETRANDOM => Some(Self::Getrandom),
+            GETFHAT => Some(Self::Getfhat),
+            FHLINK => Some(Self::Fhlink),
+            FHLINKAT => Some(Self::Fhlinkat),
+            FHREADLINK => Some(Self::Fhreadlink),
+            FUNLINKAT => Some(Self::Funlinkat),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            __SYSCTLBYNAME => Some(Self::Sysctlbyname),
+            SHM_OPEN2 => Some(Self::ShmOpen2),
+            SHM_RENAME => Some(Self::ShmRename),
+            SIGFASTBLOCK => Some(Self::Sigfastblock),
+            __REALPATHAT => Some(Self::Realpathat),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            RPCTLS_SYSCALL => Some(Self::RpctlsSyscall),
+            __SPECIALFD => Some(Self::Specialfd),
+            AIO_WRITEV => Some(Self::AioWritev),
+            AIO_READV => Some(Self::AioReadv),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "syscall" => Some(Self::Syscall),
+            "exit" => Some(Self::Exit),
+            "fork" => Some(Self::Fork),
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "wait4" => Some(Self::Wait4),
+            "link" => Some(Self::Link),
+            "unlink" => Some(Self::Unlink),
+            "chdir" => Some(Self::Chdir),
+            "fchdir" => Some(Self::Fchdir),
+            "chmod" => Some(Self::Chmod),
+            "chown" => Some(Self::Chown),
+            "break" => Some(Self::Break),
+            "getpid" => Some(Self::Getpid),
+            "mount" => Some(Self::Mount),
+            "unmount" => Some(Self::Unmount),
+            "setuid" => Some(Self::Setuid),
+            "getuid" => Some(Self::Getuid),
+            "geteuid" => Some(Self::Geteuid),
+            "ptrace" => Some(Self::Ptrace),
+            "recvmsg" => Some(Self::Recvmsg),
+            "sendmsg" => Some(Self::Sendmsg),
+            "recvfrom" => Some(Self::Recvfrom),
+            "accept" => Some(Self::Accept),
+            "getpeername" => Some(Self::Getpeername),
+            "getsockname" => Some(Self::Getsockname),
+            "access" => Some(Self::Access),
+            "chflags" => Some(Self::Chflags),
+            "fchflags" => Some(Self::Fchflags),
+            "sync" => Some(Self::Sync),
+            "kill" => Some(Self::Kill),
+            "getppid" => Some(Self::Getppid),
+            "dup" => Some(Self::Dup),
+            "getegid" => Some(Self::Getegid),
+            "profil" => Some(Self::Profil),
+            "ktrace" => Some(Self::Ktrace),
+            "getgid" => Some(Self::Getgid),
+            "getlogin" => Some(Self::Getlogin),
+            "setlogin" => Some(Self::Setlogin),
+            "acct" => Some(Self::Acct),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "ioctl" => Some(Self::Ioctl),
+            "reboot" => Some(Self::Reboot),
+            "revoke" => Some(Self::Revoke),
+            "symlink" => Some(Self::Symlink),
+            "readlink" => Some(Self::Readlink),
+            "execve" => Some(Self::Execve),
+            "umask" => Some(Self::Umask),
+            "chroot" => Some(Self::Chroot),
+            "msync" => Some(Self::Msync),
+            "vfork" => Some(Self::Vfork),
+            "sbrk" => Some(Self::Sbrk),
+            "sstk" => Some(Self::Sstk),
+            "munmap" => Some(Self::Munmap),
+            "mprotect" => Some(Self::Mprotect),
+            "madvise" => Some(Self::Madvise),
+            "mincore" => Some(Self::Mincore),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "getpgrp" => Some(Self::Getpgrp),
+            "setpgid" => Some(Self::Setpgid),
+            "setitimer" => Some(Self::Setitimer),
+            "swapon" => Some(Self::Swapon),
+            "getitimer" => Some(Self::Getitimer),
+            "getdtablesize" => Some(Self::Getdtablesize),
+            "dup2" => Some(Self::Dup2),
+            "fcntl" => Some(Self::Fcntl),
+            "select" => Some(Self::Select),
+            "fsync" => Some(Self::Fsync),
+            "setpriority" => Some(Self::Setpriority),
+            "socket" => Some(Self::Socket),
+            "connect" => Some(Self::Connect),
+            "getpriority" => Some(Self::Getpriority),
+            "bind" => Some(Self::Bind),
+            "setsockopt" => Some(Self::Setsockopt),
+            "listen" => Some(Self::Listen),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "getrusage" => Some(Self::Getrusage),
+            "getsockopt" => Some(Self::Getsockopt),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "settimeofday" => Some(Self::Settimeofday),
+            "fchown" => Some(Self::Fchown),
+            "fchmod" => Some(Self::Fchmod),
+            "setreuid" => Some(Self::Setreuid),
+            "setregid" => Some(Self::Setregid),
+            "rename" => Some(Self::Rename),
+            "flock" => Some(Self::Flock),
+            "mkfifo" => Some(Self::Mkfifo),
+            "sendto" => Some(Self::Sendto),
+            "shutdown" => Some(Self::Shutdown),
+            "socketpair" => Some(Self::Socketpair),
+            "mkdir" => Some(Self::Mkdir),
+            "rmdir" => Some(Self::Rmdir),
+            "utimes" => Some(Self::Utimes),
+            "adjtime" => Some(Self::Adjtime),
+            "setsid" => Some(Self::Setsid),
+            "quotactl" => Some(Self::Quotactl),
+            "nlm_syscall" => Some(Self::NlmSyscall),
+            "nfssvc" => Some(Self::Nfssvc),
+            "lgetfh" => Some(Self::Lgetfh),
+            "getfh" => Some(Self::Getfh),
+            "sysarch" => Some(Self::Sysarch),
+            "rtprio" => Some(Self::Rtprio),
+            "semsys" => Some(Self::Semsys),
+            "msgsys" => Some(Self::Msgsys),
+            "shmsys" => Some(Self::Shmsys),
+            "setfib" => Some(Self::Setfib),
+            "ntp_adjtime" => Some(Self::NtpAdjtime),
+            "setgid" => Some(Self::Setgid),
+            "setegid" => Some(Self::Setegid),
+            "seteuid" => Some(Self::Seteuid),
+            "pathconf" => Some(Self::Pathconf),
+            "fpathconf" => Some(Self::Fpathconf),
+            "getrlimit" => Some(Self::Getrlimit),
+            "setrlimit" => Some(Self::Setrlimit),
+            "__syscall" => Some(Self::QuadSyscall),
+            "__sysctl" => Some(Self::Sysctl),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "undelete" => Some(Self::Undelete),
+            "futimes" => Some(Self::Futimes),
+            "getpgid" => Some(Self::Getpgid),
+            "poll" => Some(Self::Poll),
+            "semget" => Some(Self::Semget),
+            "semop" => Some(Self::Semop),
+            "msgget" => Some(Self::Msgget),
+            "msgsnd" => Some(Self::Msgsnd),
+            "msgrcv" => Some(Self::Msgrcv),
+            "shmat" => Some(Self::Shmat),
+            "shmdt" => Some(Self::Shmdt),
+            "shmget" => Some(Self::Shmget),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "ktimer_create" => Some(Self::KtimerCreate),
+            "ktimer_delete" => Some(Self::KtimerDelete),
+            "ktimer_settime" => Some(Self::KtimerSettime),
+            "ktimer_gettime" => Some(Self::KtimerGettime),
+            "ktimer_getoverrun" => Some(Self::KtimerGetoverrun),
+            "nanosleep" => Some(Self::Nanosleep),
+            "ffclock_getcounter" => Some(Self::FfclockGetcounter),
+            "ffclock_setestimate" => Some(Self::FfclockSetestimate),
+            "ffclock_getestimate" => Some(Self::FfclockGetestimate),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "clock_getcpuclockid2" => Some(Self::ClockGetcpuclockid2),
+            "ntp_gettime" => Some(Self::NtpGettime),
+            "minherit" => Some(Self::Minherit),
+            "rfork" => Some(Self::Rfork),
+            "issetugid" => Some(Self::Issetugid),
+            "lchown" => Some(Self::Lchown),
+            "aio_read" => Some(Self::AioRead),
+            "aio_write" => Some(Self::AioWrite),
+            "lio_listio" => Some(Self::LioListio),
+            "lchmod" => Some(Self::Lchmod),
+            "lutimes" => Some(Self::Lutimes),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "fhopen" => Some(Self::Fhopen),
+            "modnext" => Some(Self::Modnext),
+            "modstat" => Some(Self::Modstat),
+            "modfnext" => Some(Self::Modfnext),
+            "modfind" => Some(Self::Modfind),
+            "kldload" => Some(Self::Kldload),
+            "kldunload" => Some(Self::Kldunload),
+            "kldfind" => Some(Self::Kldfind),
+            "kldnext" => Some(Self::Kldnext),
+            "kldstat" => Some(Self::Kldstat),
+            "kldfirstmod" => Some(Self::Kldfirstmod),
+            "getsid" => Some(Self::Getsid),
+            "setresuid" => Some(Self::Setresuid),
+            "setresgid" => Some(Self::Setresgid),
+            "aio_return" => Some(Self::AioReturn),
+            "aio_suspend" => Some(Self::AioSuspend),
+            "aio_cancel" => Some(Self::AioCancel),
+            "aio_error" => Some(Self::AioError),
+            "yield" => Some(Self::Yield),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "__getcwd" => Some(Self::Getcwd),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_yield" => Some(Self::SchedYield),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "utrace" => Some(Self::Utrace),
+            "kldsym" => Some(Self::Kldsym),
+            "jail" => Some(Self::Jail),
+            "nnpfs_syscall" => Some(Self::NnpfsSyscall),
+            "sigprocmask" => Some(Self::Sigprocmask),
+            "sigsuspend" => Some(Self::Sigsuspend),
+            "sigpending" => Some(Self::Sigpending),
+            "sigtimedwait" => Some(Self::Sigtimedwait),
+            "sigwaitinfo" => Some(Self::Sigwaitinfo),
+            "__acl_get_file" => Some(Self::AclGetFile),
+            "__acl_set_file" => Some(Self::AclSetFile),
+            "__acl_get_fd" => Some(Self::AclGetFd),
+            "__acl_set_fd" => Some(Self::AclSetFd),
+            "__acl_delete_file" => Some(Self::AclDeleteFile),
+            "__acl_delete_fd" => Some(Self::AclDeleteFd),
+            "__acl_aclcheck_file" => Some(Self::AclAclcheckFile),
+            "__acl_aclcheck_fd" => Some(Self::AclAclcheckFd),
+            "extattrctl" => Some(Self::Extattrctl),
+            "extattr_set_file" => Some(Self::ExtattrSetFile),
+            "extattr_get_file" => Some(Self::ExtattrGetFile),
+            "extattr_delete_file" => Some(Self::ExtattrDeleteFile),
+            "aio_waitcomplete" => Some(Self::AioWaitcomplete),
+            "getresuid" => Some(Self::Getresuid),
+            "getresgid" => Some(Self::Getresgid),
+            "kqueue" => Some(Self::Kqueue),
+            "extattr_set_fd" => Some(Self::ExtattrSetFd),
+            "extattr_get_fd" => Some(Self::ExtattrGetFd),
+            "extattr_delete_fd" => Some(Self::ExtattrDeleteFd),
+            "__setugid" => Some(Self::Setugid),
+            "eaccess" => Some(Self::Eaccess),
+            "afs3_syscall" => Some(Self::Afs3Syscall),
+            "nmount" => Some(Self::Nmount),
+            "__mac_get_proc" => Some(Self::MacGetProc),
+            "__mac_set_proc" => Some(Self::MacSetProc),
+            "__mac_get_fd" => Some(Self::MacGetFd),
+            "__mac_get_file" => Some(Self::MacGetFile),
+            "__mac_set_fd" => Some(Self::MacSetFd),
+            "__mac_set_file" => Some(Self::MacSetFile),
+            "kenv" => Some(Self::Kenv),
+            "lchflags" => Some(Self::Lchflags),
+            "uuidgen" => Some(Self::Uuidgen),
+            "sendfile" => Some(Self::Sendfile),
+            "mac_syscall" => Some(Self::MacSyscall),
+            "ksem_close" => Some(Self::KsemClose),
+            "ksem_post" => Some(Self::KsemPost),
+            "ksem_wait" => Some(Self::KsemWait),
+            "ksem_trywait" => Some(Self::KsemTrywait),
+            "ksem_init" => Some(Self::KsemInit),
+            "ksem_open" => Some(Self::KsemOpen),
+            "ksem_unlink" => Some(Self::KsemUnlink),
+            "ksem_getvalue" => Some(Self::KsemGetvalue),
+            "ksem_destroy" => Some(Self::KsemDestroy),
+            "__mac_get_pid" => Some(Self::MacGetPid),
+            "__mac_get_link" => Some(Self::MacGetLink),
+            "__mac_set_link" => Some(Self::MacSetLink),
+            "extattr_set_link" => Some(Self::ExtattrSetLink),
+            "extattr_get_link" => Some(Self::ExtattrGetLink),
+            "extattr_delete_link" => Some(Self::ExtattrDeleteLink),
+            "__mac_execve" => Some(Self::MacExecve),
+            "sigaction" => Some(Self::Sigaction),
+            "sigreturn" => Some(Self::Sigreturn),
+            "getcontext" => Some(Self::Getcontext),
+            "setcontext" => Some(Self::Setcontext),
+            "swapcontext" => Some(Self::Swapcontext),
+            "swapoff" => Some(Self::Swapoff),
+            "__acl_get_link" => Some(Self::AclGetLink),
+            "__acl_set_link" => Some(Self::AclSetLink),
+            "__acl_delete_link" => Some(Self::AclDeleteLink),
+            "__acl_aclcheck_link" => Some(Self::AclAclcheckLink),
+            "sigwait" => Some(Self::Sigwait),
+            "thr_create" => Some(Self::ThrCreate),
+            "thr_exit" => Some(Self::ThrExit),
+            "thr_self" => Some(Self::ThrSelf),
+            "thr_kill" => Some(Self::ThrKill),
+            "jail_attach" => Some(Self::JailAttach),
+            "extattr_list_fd" => Some(Self::ExtattrListFd),
+            "extattr_list_file" => Some(Self::ExtattrListFile),
+            "extattr_list_link" => Some(Self::ExtattrListLink),
+            "ksem_timedwait" => Some(Self::KsemTimedwait),
+            "thr_suspend" => Some(Self::ThrSuspend),
+            "thr_wake" => Some(Self::ThrWake),
+            "kldunloadf" => Some(Self::Kldunloadf),
+            "audit" => Some(Self::Audit),
+            "auditon" => Some(Self::Auditon),
+            "getauid" => Some(Self::Getauid),
+            "setauid" => Some(Self::Setauid),
+            "getaudit" => Some(Self::Getaudit),
+            "setaudit" => Some(Self::Setaudit),
+            "getaudit_addr" => Some(Self::GetauditAddr),
+            "setaudit_addr" => Some(Self::SetauditAddr),
+            "auditctl" => Some(Self::Auditctl),
+            "_umtx_op" => Some(Self::UmtxOp),
+            "thr_new" => Some(Self::ThrNew),
+            "sigqueue" => Some(Self::Sigqueue),
+            "kmq_open" => Some(Self::KmqOpen),
+            "kmq_setattr" => Some(Self::KmqSetattr),
+            "kmq_timedreceive" => Some(Self::KmqTimedreceive),
+            "kmq_timedsend" => Some(Self::KmqTimedsend),
+            "kmq_notify" => Some(Self::KmqNotify),
+            "kmq_unlink" => Some(Self::KmqUnlink),
+            "abort2" => Some(Self::Abort2),
+            "thr_set_name" => Some(Self::ThrSetName),
+            "aio_fsync" => Some(Self::AioFsync),
+            "rtprio_thread" => Some(Self::RtprioThread),
+            "sctp_peeloff" => Some(Self::SctpPeeloff),
+            "sctp_generic_sendmsg" => Some(Self::SctpGenericSendmsg),
+            "sctp_generic_sendmsg_iov" => Some(Self::SctpGenericSendmsgIov),
+            "sctp_generic_recvmsg" => Some(Self::SctpGenericRecvmsg),
+            "pread" => Some(Self::Pread),
+            "pwrite" => Some(Self::Pwrite),
+            "mmap" => Some(Self::Mmap),
+            "lseek" => Some(Self::Lseek),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "thr_kill2" => Some(Self::ThrKill2),
+            "shm_unlink" => Some(Self::ShmUnlink),
+            "cpuset" => Some(Self::Cpuset),
+            "cpuset_setid" => Some(Self::CpusetSetid),
+            "cpuset_getid" => Some(Self::CpusetGetid),
+            "cpuset_getaffinity" => Some(Self::CpusetGetaffinity),
+            "cpuset_setaffinity" => Some(Self::CpusetSetaffinity),
+            "faccessat" => Some(Self::Faccessat),
+            "fchmodat" => Some(Self::Fchmodat),
+            "fchownat" => Some(Self::Fchownat),
+            "fexecve" => Some(Self::Fexecve),
+            "futimesat" => Some(Self::Futimesat),
+            "linkat" => Some(Self::Linkat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "mkfifoat" => Some(Self::Mkfifoat),
+            "openat" => Some(Self::Openat),
+            "readlinkat" => Some(Self::Readlinkat),
+            "renameat" => Some(Self::Renameat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "unlinkat" => Some(Self::Unlinkat),
+            "posix_openpt" => Some(Self::PosixOpenpt),
+            "gssd_syscall" => Some(Self::GssdSyscall),
+            "jail_get" => Some(Self::JailGet),
+            "jail_set" => Some(Self::JailSet),
+            "jail_remove" => Some(Self::JailRemove),
+            "__semctl" => Some(Self::Semctl),
+            "msgctl" => Some(Self::Msgctl),
+            "shmctl" => Some(Self::Shmctl),
+            "lpathconf" => Some(Self::Lpathconf),
+            "__cap_rights_get" => Some(Self::CapRightsGet),
+            "cap_enter" => Some(Self::CapEnter),
+            "cap_getmode" => Some(Self::CapGetmode),
+            "pdfork" => Some(Self::Pdfork),
+            "pdkill" => Some(Self::Pdkill),
+            "pdgetpid" => Some(Self::Pdgetpid),
+            "pselect" => Some(Self::Pselect),
+            "getloginclass" => Some(Self::Getloginclass),
+            "setloginclass" => Some(Self::Setloginclass),
+            "rctl_get_racct" => Some(Self::RctlGetRacct),
+            "rctl_get_rules" => Some(Self::RctlGetRules),
+            "rctl_get_limits" => Some(Self::RctlGetLimits),
+            "rctl_add_rule" => Some(Self::RctlAddRule),
+            "rctl_remove_rule" => Some(Self::RctlRemoveRule),
+            "posix_fallocate" => Some(Self::PosixFallocate),
+            "posix_fadvise" => Some(Self::PosixFadvise),
+            "wait6" => Some(Self::Wait6),
+            "cap_rights_limit" => Some(Self::CapRightsLimit),
+            "cap_ioctls_limit" => Some(Self::CapIoctlsLimit),
+            "cap_ioctls_get" => Some(Self::CapIoctlsGet),
+            "cap_fcntls_limit" => Some(Self::CapFcntlsLimit),
+            "cap_fcntls_get" => Some(Self::CapFcntlsGet),
+            "bindat" => Some(Self::Bindat),
+            "connectat" => Some(Self::Connectat),
+            "chflagsat" => Some(Self::Chflagsat),
+            "accept4" => Some(Self::Accept4),
+            "pipe2" => Some(Self::Pipe2),
+            "aio_mlock" => Some(Self::AioMlock),
+            "procctl" => Some(Self::Procctl),
+            "ppoll" => Some(Self::Ppoll),
+            "futimens" => Some(Self::Futimens),
+            "utimensat" => Some(Self::Utimensat),
+            "fdatasync" => Some(Self::Fdatasync),
+            "fstat" => Some(Self::Fstat),
+            "fstatat" => Some(Self::Fstatat),
+            "fhstat" => Some(Self::Fhstat),
+            "getdirentries" => Some(Self::Getdirentries),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "getfsstat" => Some(Self::Getfsstat),
+            "fhstatfs" => Some(Self::Fhstatfs),
+            "mknodat" => Some(Self::Mknodat),
+            "kevent" => Some(Self::Kevent),
+            "cpuset_getdomain" => Some(Self::CpusetGetdomain),
+            "cpuset_setdomain" => Some(Self::CpusetSetdomain),
+            "getrandom" => Some(Self::Getrandom),
+            "getfhat" => Some(Self::Getfhat),
+            "fhlink" => Some(Self::Fhlink),
+            "fhlinkat" => Some(Self::Fhlinkat),
+            "fhreadlink" => Some(Self::Fhreadlink),
+            "funlinkat" => Some(Self::Funlinkat),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "__sysctlbyname" => Some(Self::Sysctlbyname),
+            "shm_open2" => Some(Self::ShmOpen2),
+            "shm_rename" => Some(Self::ShmRename),
+            "sigfastblock" => Some(Self::Sigfastblock),
+            "__realpathat" => Some(Self::Realpathat),
+            "close_range" => Some(Self::CloseRange),
+            "rpctls_syscall" => Some(Self::RpctlsSyscall),
+            "__specialfd" => Some(Self::Specialfd),
+            "aio_writev" => Some(Self::AioWritev),
+            "aio_readv" => Some(Self::AioReadv),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Syscall => "syscall",
+            Self::Exit => "exit",
+            Self::Fork => "fork",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Wait4 => "wait4",
+            Self::Link => "link",
+            Self::Unlink => "unlink",
+            Self::Chdir => "chdir",
+            Self::Fchdir => "fchdir",
+            Self::Chmod => "chmod",
+            Self::Chown => "chown",
+            Self::Break => "break",
+            Self::Getpid => "getpid",
+            Self::Mount => "mount",
+            Self::Unmount => "unmount",
+            Self::Setuid => "setuid",
+            Self::Getuid => "getuid",
+            Self::Geteuid => "geteuid",
+            Self::Ptrace => "ptrace",
+            Self::Recvmsg => "recvmsg",
+            Self::Sendmsg => "sendmsg",
+            Self::Recvfrom => "recvfrom",
+            Self::Accept => "accept",
+            Self::Getpeername => "getpeername",
+            Self::Getsockname => "getsockname",
+            Self::Access => "access",
+            Self::Chflags => "chflags",
+            Self::Fchflags => "fchflags",
+            Self::Sync => "sync",
+            Self::Kill => "kill",
+            Self::Getppid => "getppid",
+            Self::Dup => "dup",
+            Self::Getegid => "getegid",
+            Self::Profil => "profil",
+            Self::Ktrace => "ktrace",
+            Self::Getgid => "getgid",
+            Self::Getlogin => "getlogin",
+            Self::Setlogin => "setlogin",
+            Self::Acct => "acct",
+            Self::Sigaltstack => "sigaltstack",
+            Self::Ioctl => "ioctl",
+            Self::Reboot => "reboot",
+            Self::Revoke => "revoke",
+            Self::Symlink => "symlink",
+            Self::Readlink => "readlink",
+            Self::Execve => "execve",
+            Self::Umask => "umask",
+            Self::Chroot => "chroot",
+            Self::Msync => "msync",
+            Self::Vfork => "vfork",
+            Self::Sbrk => "sbrk",
+            Self::Sstk => "sstk",
+            Self::Munmap => "munmap",
+            Self::Mprotect => "mprotect",
+            Self::Madvise => "madvise",
+            Self::Mincore => "mincore",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Getpgrp => "getpgrp",
+            Self::Setpgid => "setpgid",
+            Self::Setitimer => "setitimer",
+            Self::Swapon => "swapon",
+            Self::Getitimer => "getitimer",
+            Self::Getdtablesize => "getdtablesize",
+            Self::Dup2 => "dup2",
+            Self::Fcntl => "fcntl",
+            Self::Select => "select",
+            Self::Fsync => "fsync",
+            Self::Setpriority => "setpriority",
+            Self::Socket => "socket",
+            Self::Connect => "connect",
+            Self::Getpriority => "getpriority",
+            Self::Bind => "bind",
+            Self::Setsockopt => "setsockopt",
+            Self::Listen => "listen",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Getrusage => "getrusage",
+            Self::Getsockopt => "getsockopt",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Settimeofday => "settimeofday",
+            Self::Fchown => "fchown",
+            Self::Fchmod => "fchmod",
+            Self::Setreuid => "setreuid",
+            Self::Setregid => "setregid",
+            Self::Rename => "rename",
+            Self::Flock => "flock",
+            Self::Mkfifo => "mkfifo",
+            Self::Sendto => "sendto",
+            Self::Shutdown => "shutdown",
+            Self::Socketpair => "socketpair",
+            Self::Mkdir => "mkdir",
+            Self::Rmdir => "rmdir",
+            Self::Utimes => "utimes",
+            Self::Adjtime => "adjtime",
+            Self::Setsid => "setsid",
+            Self::Quotactl => "quotactl",
+            Self::NlmSyscall => "nlm_syscall",
+            Self::Nfssvc => "nfssvc",
+            Self::Lgetfh => "lgetfh",
+            Self::Getfh => "getfh",
+            Self::Sysarch => "sysarch",
+            Self::Rtprio => "rtprio",
+            Self::Semsys => "semsys",
+            Self::Msgsys => "msgsys",
+            Self::Shmsys => "shmsys",
+            Self::Setfib => "setfib",
+            Self::NtpAdjtime => "ntp_adjtime",
+            Self::Setgid => "setgid",
+            Self::Setegid => "setegid",
+            Self::Seteuid => "seteuid",
+            Self::Pathconf => "pathconf",
+            Self::Fpathconf => "fpathconf",
+            Self::Getrlimit => "getrlimit",
+            Self::Setrlimit => "setrlimit",
+            Self::QuadSyscall => "__syscall",
+            Self::Sysctl => "__sysctl",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Undelete => "undelete",
+            Self::Futimes => "futimes",
+            Self::Getpgid => "getpgid",
+            Self::Poll => "poll",
+            Self::Semget => "semget",
+            Self::Semop => "semop",
+            Self::Msgget => "msgget",
+            Self::Msgsnd => "msgsnd",
+            Self::Msgrcv => "msgrcv",
+            Self::Shmat => "shmat",
+            Self::Shmdt => "shmdt",
+            Self::Shmget => "shmget",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGetres => "clock_getres",
+            Self::KtimerCreate => "ktimer_create",
+            Self::KtimerDelete => "ktimer_delete",
+            Self::KtimerSettime => "ktimer_settime",
+            Self::KtimerGettime => "ktimer_gettime",
+            Self::KtimerGetoverrun => "ktimer_getoverrun",
+            Self::Nanosleep => "nanosleep",
+            Self::FfclockGetcounter => "ffclock_getcounter",
+            Self::FfclockSetestimate => "ffclock_setestimate",
+            Self::FfclockGetestimate => "ffclock_getestimate",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::ClockGetcpuclockid2 => "clock_getcpuclockid2",
+            Self::NtpGettime => "ntp_gettime",
+            Self::Minherit => "minherit",
+            Self::Rfork => "rfork",
+            Self::Issetugid => "issetugid",
+            Self::Lchown => "lchown",
+            Self::AioRead => "aio_read",
+            Self::AioWrite => "aio_write",
+            Self::LioListio => "lio_listio",
+            Self::Lchmod => "lchmod",
+            Self::Lutimes => "lutimes",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::Fhopen => "fhopen",
+            Self::Modnext => "modnext",
+            Self::Modstat => "modstat",
+            Self::Modfnext => "modfnext",
+            Self::Modfind => "modfind",
+            Self::Kldload => "kldload",
+            Self::Kldunload => "kldunload",
+            Self::Kldfind => "kldfind",
+            Self::Kldnext => "kldnext",
+            Self::Kldstat => "kldstat",
+            Self::Kldfirstmod => "kldfirstmod",
+            Self::Getsid => "getsid",
+            Self::Setresuid => "setresuid",
+            Self::Setresgid => "setresgid",
+            Self::AioReturn => "aio_return",
+            Self::AioSuspend => "aio_suspend",
+            Self::AioCancel => "aio_cancel",
+            Self::AioError => "aio_error",
+            Self::Yield => "yield",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::Getcwd => "__getcwd",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedYield => "sched_yield",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::Utrace => "utrace",
+            Self::Kldsym => "kldsym",
+            Self::Jail => "jail",
+            Self::NnpfsSyscall => "nnpfs_syscall",
+            Self::Sigprocmask => "sigprocmask",
+            Self::Sigsuspend => "sigsuspend",
+            Self::Sigpending => "sigpending",
+            Self::Sigtimedwait => "sigtimedwait",
+            Self::Sigwaitinfo => "sigwaitinfo",
+            Self::AclGetFile => "__acl_get_file",
+            Self::AclSetFile => "__acl_set_file",
+            Self::AclGetFd => "__acl_get_fd",
+            Self::AclSetFd => "__acl_set_fd",
+            Self::AclDeleteFile => "__acl_delete_file",
+            Self::AclDeleteFd => "__acl_delete_fd",
+            Self::AclAclcheckFile => "__acl_aclcheck_file",
+            Self::AclAclcheckFd => "__acl_aclcheck_fd",
+            Self::Extattrctl => "extattrctl",
+            Self::ExtattrSetFile => "extattr_set_file",
+            Self::ExtattrGetFile => "extattr_get_file",
+            Self::ExtattrDeleteFile => "extattr_delete_file",
+            Self::AioWaitcomplete => "aio_waitcomplete",
+            Self::Getresuid => "getresuid",
+            Self::Getresgid => "getresgid",
+            Self::Kqueue => "kqueue",
+            Self::ExtattrSetFd => "extattr_set_fd",
+            Self::ExtattrGetFd => "extattr_get_fd",
+            Self::ExtattrDeleteFd => "extattr_delete_fd",
+            Self::Setugid => "__setugid",
+            Self::Eaccess => "eaccess",
+            Self::Afs3Syscall => "afs3_syscall",
+            Self::Nmount => "nmount",
+            Self::MacGetProc => "__mac_get_proc",
+            Self::MacSetProc => "__mac_set_proc",
+            Self::MacGetFd => "__mac_get_fd",
+            Self::MacGetFile => "__mac_get_file",
+            Self::MacSetFd => "__mac_set_fd",
+            Self::MacSetFile => "__mac_set_file",
+            Self::Kenv => "kenv",
+            Self::Lchflags => "lchflags",
+            Self::Uuidgen => "uuidgen",
+            Self::Sendfile => "sendfile",
+            Self::MacSyscall => "mac_syscall",
+            Self::KsemClose => "ksem_close",
+            Self::KsemPost => "ksem_post",
+            Self::KsemWait => "ksem_wait",
+            Self::KsemTrywait => "ksem_trywait",
+            Self::KsemInit => "ksem_init",
+            Self::KsemOpen => "ksem_open",
+            Self::KsemUnlink => "ksem_unlink",
+            Self::KsemGetvalue => "ksem_getvalue",
+            Self::KsemDestroy => "ksem_destroy",
+            Self::MacGetPid => "__mac_get_pid",
+            Self::MacGetLink => "__mac_get_link",
+            Self::MacSetLink => "__mac_set_link",
+            Self::ExtattrSetLink => "extattr_set_link",
+            Self::ExtattrGetLink => "extattr_get_link",
+            Self::ExtattrDeleteLink => "extattr_delete_link",
+            Self::MacExecve => "__mac_execve",
+            Self::Sigaction => "sigaction",
+            Self::Sigreturn => "sigreturn",
+            Self::Getcontext => "getcontext",
+            Self::Setcontext => "setcontext",
+            Self::Swapcontext => "swapcontext",
+            Self::Swapoff => "swapoff",
+            Self::AclGetLink => "__acl_get_link",
+            Self::AclSetLink => "__acl_set_link",
+            Self::AclDeleteLink => "__acl_delete_link",
+            Self::AclAclcheckLink => "__acl_aclcheck_link",
+            Self::Sigwait => "sigwait",
+            Self::ThrCreate => "thr_create",
+            Self::ThrExit => "thr_exit",
+            Self::ThrSelf => "thr_self",
+            Self::ThrKill => "thr_kill",
+            Self::JailAttach => "jail_attach",
+            Self::ExtattrListFd => "extattr_list_fd",
+            Self::ExtattrListFile => "extattr_list_file",
+            Self::ExtattrListLink => "extattr_list_link",
+            Self::KsemTimedwait => "ksem_timedwait",
+            Self::ThrSuspend => "thr_suspend",
+            Self::ThrWake => "thr_wake",
+            Self::Kldunloadf => "kldunloadf",
+            Self::Audit => "audit",
+            Self::Auditon => "auditon",
+            Self::Getauid => "getauid",
+            Self::Setauid => "setauid",
+            Self::Getaudit => "getaudit",
+            Self::Setaudit => "setaudit",
+            Self::GetauditAddr => "getaudit_addr",
+            Self::SetauditAddr => "setaudit_addr",
+            Self::Auditctl => "auditctl",
+            Self::UmtxOp => "_umtx_op",
+            Self::ThrNew => "thr_new",
+            Self::Sigqueue => "sigqueue",
+            Self::KmqOpen => "kmq_open",
+            Self::KmqSetattr => "kmq_setattr",
+            Self::KmqTimedreceive => "kmq_timedreceive",
+            Self::KmqTimedsend => "kmq_timedsend",
+            Self::KmqNotify => "kmq_notify",
+            Self::KmqUnlink => "kmq_unlink",
+            Self::Abort2 => "abort2",
+            Self::ThrSetName => "thr_set_name",
+            Self::AioFsync => "aio_fsync",
+            Self::RtprioThread => "rtprio_thread",
+            Self::SctpPeeloff => "sctp_peeloff",
+            Self::SctpGenericSendmsg => "sctp_generic_sendmsg",
+            Self::SctpGenericSendmsgIov => "sctp_generic_sendmsg_iov",
+            Self::SctpGenericRecvmsg => "sctp_generic_recvmsg",
+            Self::Pread => "pread",
+            Self::Pwrite => "pwrite",
+            Self::Mmap => "mmap",
+            Self::Lseek => "lseek",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::ThrKill2 => "thr_kill2",
+            Self::ShmUnlink => "shm_unlink",
+            Self::Cpuset => "cpuset",
+            Self::CpusetSetid => "cpuset_setid",
+            Self::CpusetGetid => "cpuset_getid",
+            Self::CpusetGetaffinity => "cpuset_getaffinity",
+            Self::CpusetSetaffinity => "cpuset_setaffinity",
+            Self::Faccessat => "faccessat",
+            Self::Fchmodat => "fchmodat",
+            Self::Fchownat => "fchownat",
+            Self::Fexecve => "fexecve",
+            Self::Futimesat => "futimesat",
+            Self::Linkat => "linkat",
+            Self::Mkdirat => "mkdirat",
+            Self::Mkfifoat => "mkfifoat",
+            Self::Openat => "openat",
+            Self::Readlinkat => "readlinkat",
+            Self::Renameat => "renameat",
+            Self::Symlinkat => "symlinkat",
+            Self::Unlinkat => "unlinkat",
+            Self::PosixOpenpt => "posix_openpt",
+            Self::GssdSyscall => "gssd_syscall",
+            Self::JailGet => "jail_get",
+            Self::JailSet => "jail_set",
+            Self::JailRemove => "jail_remove",
+            Self::Semctl => "__semctl",
+            Self::Msgctl => "msgctl",
+            Self::Shmctl => "shmctl",
+            Self::Lpathconf => "lpathconf",
+            Self::CapRightsGet => "__cap_rights_get",
+            Self::CapEnter => "cap_enter",
+            Self::CapGetmode => "cap_getmode",
+            Self::Pdfork => "pdfork",
+            Self::Pdkill => "pdkill",
+            Self::Pdgetpid => "pdgetpid",
+            Self::Pselect => "pselect",
+            Self::Getloginclass => "getloginclass",
+            Self::Setloginclass => "setloginclass",
+            Self::RctlGetRacct => "rctl_get_racct",
+            Self::RctlGetRules => "rctl_get_rules",
+            Self::RctlGetLimits => "rctl_get_limits",
+            Self::RctlAddRule => "rctl_add_rule",
+            Self::RctlRemoveRule => "rctl_remove_rule",
+            Self::PosixFallocate => "posix_fallocate",
+            Self::PosixFadvise => "posix_fadvise",
+            Self::Wait6 => "wait6",
+            Self::CapRightsLimit => "cap_rights_limit",
+            Self::CapIoctlsLimit => "cap_ioctls_limit",
+            Self::CapIoctlsGet => "cap_ioctls_get",
+            Self::CapFcntlsLimit => "cap_fcntls_limit",
+            Self::CapFcntlsGet => "cap_fcntls_get",
+            Self::Bindat => "bindat",
+            Self::Connectat => "connectat",
+            Self::Chflagsat => "chflagsat",
+            Self::Accept4 => "accept4",
+            Self::Pipe2 => "pipe2",
+            Self::AioMlock => "aio_mlock",
+            Self::Procctl => "procctl",
+            Self::Ppoll => "ppoll",
+            Self::Futimens => "futimens",
+            Self::Utimensat => "utimensat",
+            Self::Fdatasync => "fdatasync",
+            Self::Fstat => "fstat",
+            Self::Fstatat => "fstatat",
+            Self::Fhstat => "fhstat",
+            Self::Getdirentries => "getdirentries",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Getfsstat => "getfsstat",
+            Self::Fhstatfs => "fhstatfs",
+            Self::Mknodat => "mknodat",
+            Self::Kevent => "kevent",
+            Self::CpusetGetdomain => "cpuset_getdomain",
+            Self::CpusetSetdomain => "cpuset_setdomain",
+            Self::Getrandom => "getrandom",
+            Self::Getfhat => "getfhat",
+            Self::Fhlink => "fhlink",
+            Self::Fhlinkat => "fhlinkat",
+            Self::Fhreadlink => "fhreadlink",
+            Self::Funlinkat => "funlinkat",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Sysctlbyname => "__sysctlbyname",
+            Self::ShmOpen2 => "shm_open2",
+            Self::ShmRename => "shm_rename",
+            Self::Sigfastblock => "sigfastblock",
+            Self::Realpathat => "__realpathat",
+            Self::CloseRange => "close_range",
+            Self::RpctlsSyscall => "rpctls_syscall",
+            Self::Specialfd => "__specialfd",
+            Self::AioWritev => "aio_writev",
+            Self::AioReadv => "aio_readv",
+        }
+    }
+}
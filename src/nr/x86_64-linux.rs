@@ -0,0 +1,2237 @@
+//! System call numbers for `x86_64-linux`.
+//!
+//! Generated by `tools/gen-nr.py` from `syscalls/x86/unistd_64.h`, do not edit.
+//!
+
+use ::core::option::Option::{
+    self,
+    None,
+    Some,
+};
+
+pub const READ: usize = 0;
+pub const WRITE: usize = 1;
+pub const OPEN: usize = 2;
+pub const CLOSE: usize = 3;
+pub const STAT: usize = 4;
+pub const FSTAT: usize = 5;
+pub const LSTAT: usize = 6;
+pub const POLL: usize = 7;
+pub const LSEEK: usize = 8;
+pub const MMAP: usize = 9;
+pub const MPROTECT: usize = 10;
+pub const MUNMAP: usize = 11;
+pub const BRK: usize = 12;
+pub const RT_SIGACTION: usize = 13;
+pub const RT_SIGPROCMASK: usize = 14;
+pub const RT_SIGRETURN: usize = 15;
+pub const IOCTL: usize = 16;
+pub const PREAD64: usize = 17;
+pub const PWRITE64: usize = 18;
+pub const READV: usize = 19;
+pub const WRITEV: usize = 20;
+pub const ACCESS: usize = 21;
+pub const PIPE: usize = 22;
+pub const SELECT: usize = 23;
+pub const SCHED_YIELD: usize = 24;
+pub const MREMAP: usize = 25;
+pub const MSYNC: usize = 26;
+pub const MINCORE: usize = 27;
+pub const MADVISE: usize = 28;
+pub const SHMGET: usize = 29;
+pub const SHMAT: usize = 30;
+pub const SHMCTL: usize = 31;
+pub const DUP: usize = 32;
+pub const DUP2: usize = 33;
+pub const PAUSE: usize = 34;
+pub const NANOSLEEP: usize = 35;
+pub const GETITIMER: usize = 36;
+pub const ALARM: usize = 37;
+pub const SETITIMER: usize = 38;
+pub const GETPID: usize = 39;
+pub const SENDFILE: usize = 40;
+pub const SOCKET: usize = 41;
+pub const CONNECT: usize = 42;
+pub const ACCEPT: usize = 43;
+pub const SENDTO: usize = 44;
+pub const RECVFROM: usize = 45;
+pub const SENDMSG: usize = 46;
+pub const RECVMSG: usize = 47;
+pub const SHUTDOWN: usize = 48;
+pub const BIND: usize = 49;
+pub const LISTEN: usize = 50;
+pub const GETSOCKNAME: usize = 51;
+pub const GETPEERNAME: usize = 52;
+pub const SOCKETPAIR: usize = 53;
+pub const SETSOCKOPT: usize = 54;
+pub const GETSOCKOPT: usize = 55;
+pub const CLONE: usize = 56;
+pub const FORK: usize = 57;
+pub const VFORK: usize = 58;
+pub const EXECVE: usize = 59;
+pub const EXIT: usize = 60;
+pub const WAIT4: usize = 61;
+pub const KILL: usize = 62;
+pub const UNAME: usize = 63;
+pub const SEMGET: usize = 64;
+pub const SEMOP: usize = 65;
+pub const SEMCTL: usize = 66;
+pub const SHMDT: usize = 67;
+pub const MSGGET: usize = 68;
+pub const MSGSND: usize = 69;
+pub const MSGRCV: usize = 70;
+pub const MSGCTL: usize = 71;
+pub const FCNTL: usize = 72;
+pub const FLOCK: usize = 73;
+pub const FSYNC: usize = 74;
+pub const FDATASYNC: usize = 75;
+pub const TRUNCATE: usize = 76;
+pub const FTRUNCATE: usize = 77;
+pub const GETDENTS: usize = 78;
+pub const GETCWD: usize = 79;
+pub const CHDIR: usize = 80;
+pub const FCHDIR: usize = 81;
+pub const RENAME: usize = 82;
+pub const MKDIR: usize = 83;
+pub const RMDIR: usize = 84;
+pub const CREAT: usize = 85;
+pub const LINK: usize = 86;
+pub const UNLINK: usize = 87;
+pub const SYMLINK: usize = 88;
+pub const READLINK: usize = 89;
+pub const CHMOD: usize = 90;
+pub const FCHMOD: usize = 91;
+pub const CHOWN: usize = 92;
+pub const FCHOWN: usize = 93;
+pub const LCHOWN: usize = 94;
+pub const UMASK: usize = 95;
+pub const GETTIMEOFDAY: usize = 96;
+pub const GETRLIMIT: usize = 97;
+pub const GETRUSAGE: usize = 98;
+pub const SYSINFO: usize = 99;
+pub const TIMES: usize = 100;
+pub const PTRACE: usize = 101;
+pub const GETUID: usize = 102;
+pub const SYSLOG: usize = 103;
+pub const GETGID: usize = 104;
+pub const SETUID: usize = 105;
+pub const SETGID: usize = 106;
+pub const GETEUID: usize = 107;
+pub const GETEGID: usize = 108;
+pub const SETPGID: usize = 109;
+pub const GETPPID: usize = 110;
+pub const GETPGRP: usize = 111;
+pub const SETSID: usize = 112;
+pub const SETREUID: usize = 113;
+pub const SETREGID: usize = 114;
+pub const GETGROUPS: usize = 115;
+pub const SETGROUPS: usize = 116;
+pub const SETRESUID: usize = 117;
+pub const GETRESUID: usize = 118;
+pub const SETRESGID: usize = 119;
+pub const GETRESGID: usize = 120;
+pub const GETPGID: usize = 121;
+pub const SETFSUID: usize = 122;
+pub const SETFSGID: usize = 123;
+pub const GETSID: usize = 124;
+pub const CAPGET: usize = 125;
+pub const CAPSET: usize = 126;
+pub const RT_SIGPENDING: usize = 127;
+pub const RT_SIGTIMEDWAIT: usize = 128;
+pub const RT_SIGQUEUEINFO: usize = 129;
+pub const RT_SIGSUSPEND: usize = 130;
+pub const SIGALTSTACK: usize = 131;
+pub const UTIME: usize = 132;
+pub const MKNOD: usize = 133;
+pub const USELIB: usize = 134;
+pub const PERSONALITY: usize = 135;
+pub const USTAT: usize = 136;
+pub const STATFS: usize = 137;
+pub const FSTATFS: usize = 138;
+pub const SYSFS: usize = 139;
+pub const GETPRIORITY: usize = 140;
+pub const SETPRIORITY: usize = 141;
+pub const SCHED_SETPARAM: usize = 142;
+pub const SCHED_GETPARAM: usize = 143;
+pub const SCHED_SETSCHEDULER: usize = 144;
+pub const SCHED_GETSCHEDULER: usize = 145;
+pub const SCHED_GET_PRIORITY_MAX: usize = 146;
+pub const SCHED_GET_PRIORITY_MIN: usize = 147;
+pub const SCHED_RR_GET_INTERVAL: usize = 148;
+pub const MLOCK: usize = 149;
+pub const MUNLOCK: usize = 150;
+pub const MLOCKALL: usize = 151;
+pub const MUNLOCKALL: usize = 152;
+pub const VHANGUP: usize = 153;
+pub const MODIFY_LDT: usize = 154;
+pub const PIVOT_ROOT: usize = 155;
+pub const _SYSCTL: usize = 156;
+pub const PRCTL: usize = 157;
+pub const ARCH_PRCTL: usize = 158;
+pub const ADJTIMEX: usize = 159;
+pub const SETRLIMIT: usize = 160;
+pub const CHROOT: usize = 161;
+pub const SYNC: usize = 162;
+pub const ACCT: usize = 163;
+pub const SETTIMEOFDAY: usize = 164;
+pub const MOUNT: usize = 165;
+pub const UMOUNT2: usize = 166;
+pub const SWAPON: usize = 167;
+pub const SWAPOFF: usize = 168;
+pub const REBOOT: usize = 169;
+pub const SETHOSTNAME: usize = 170;
+pub const SETDOMAINNAME: usize = 171;
+pub const IOPL: usize = 172;
+pub const IOPERM: usize = 173;
+pub const CREATE_MODULE: usize = 174;
+pub const INIT_MODULE: usize = 175;
+pub const DELETE_MODULE: usize = 176;
+pub const GET_KERNEL_SYMS: usize = 177;
+pub const QUERY_MODULE: usize = 178;
+pub const QUOTACTL: usize = 179;
+pub const NFSSERVCTL: usize = 180;
+pub const GETPMSG: usize = 181;
+pub const PUTPMSG: usize = 182;
+pub const AFS_SYSCALL: usize = 183;
+pub const TUXCALL: usize = 184;
+pub const SECURITY: usize = 185;
+pub const GETTID: usize = 186;
+pub const READAHEAD: usize = 187;
+pub const SETXATTR: usize = 188;
+pub const LSETXATTR: usize = 189;
+pub const FSETXATTR: usize = 190;
+pub const GETXATTR: usize = 191;
+pub const LGETXATTR: usize = 192;
+pub const FGETXATTR: usize = 193;
+pub const LISTXATTR: usize = 194;
+pub const LLISTXATTR: usize = 195;
+pub const FLISTXATTR: usize = 196;
+pub const REMOVEXATTR: usize = 197;
+pub const LREMOVEXATTR: usize = 198;
+pub const FREMOVEXATTR: usize = 199;
+pub const TKILL: usize = 200;
+pub const TIME: usize = 201;
+pub const FUTEX: usize = 202;
+pub const SCHED_SETAFFINITY: usize = 203;
+pub const SCHED_GETAFFINITY: usize = 204;
+pub const SET_THREAD_AREA: usize = 205;
+pub const IO_SETUP: usize = 206;
+pub const IO_DESTROY: usize = 207;
+pub const IO_GETEVENTS: usize = 208;
+pub const IO_SUBMIT: usize = 209;
+pub const IO_CANCEL: usize = 210;
+pub const GET_THREAD_AREA: usize = 211;
+pub const LOOKUP_DCOOKIE: usize = 212;
+pub const EPOLL_CREATE: usize = 213;
+pub const EPOLL_CTL_OLD: usize = 214;
+pub const EPOLL_WAIT_OLD: usize = 215;
+pub const REMAP_FILE_PAGES: usize = 216;
+pub const GETDENTS64: usize = 217;
+pub const SET_TID_ADDRESS: usize = 218;
+pub const RESTART_SYSCALL: usize = 219;
+pub const SEMTIMEDOP: usize = 220;
+pub const FADVISE64: usize = 221;
+pub const TIMER_CREATE: usize = 222;
+pub const TIMER_SETTIME: usize = 223;
+pub const TIMER_GETTIME: usize = 224;
+pub const TIMER_GETOVERRUN: usize = 225;
+pub const TIMER_DELETE: usize = 226;
+pub const CLOCK_SETTIME: usize = 227;
+pub const CLOCK_GETTIME: usize = 228;
+pub const CLOCK_GETRES: usize = 229;
+pub const CLOCK_NANOSLEEP: usize = 230;
+pub const EXIT_GROUP: usize = 231;
+pub const EPOLL_WAIT: usize = 232;
+pub const EPOLL_CTL: usize = 233;
+pub const TGKILL: usize = 234;
+pub const UTIMES: usize = 235;
+pub const VSERVER: usize = 236;
+pub const MBIND: usize = 237;
+pub const SET_MEMPOLICY: usize = 238;
+pub const GET_MEMPOLICY: usize = 239;
+pub const MQ_OPEN: usize = 240;
+pub const MQ_UNLINK: usize = 241;
+pub const MQ_TIMEDSEND: usize = 242;
+pub const MQ_TIMEDRECEIVE: usize = 243;
+pub const MQ_NOTIFY: usize = 244;
+pub const MQ_GETSETATTR: usize = 245;
+pub const KEXEC_LOAD: usize = 246;
+pub const WAITID: usize = 247;
+pub const ADD_KEY: usize = 248;
+pub const REQUEST_KEY: usize = 249;
+pub const KEYCTL: usize = 250;
+pub const IOPRIO_SET: usize = 251;
+pub const IOPRIO_GET: usize = 252;
+pub const INOTIFY_INIT: usize = 253;
+pub const INOTIFY_ADD_WATCH: usize = 254;
+pub const INOTIFY_RM_WATCH: usize = 255;
+pub const MIGRATE_PAGES: usize = 256;
+pub const OPENAT: usize = 257;
+pub const MKDIRAT: usize = 258;
+pub const MKNODAT: usize = 259;
+pub const FCHOWNAT: usize = 260;
+pub const FUTIMESAT: usize = 261;
+pub const NEWFSTATAT: usize = 262;
+pub const UNLINKAT: usize = 263;
+pub const RENAMEAT: usize = 264;
+pub const LINKAT: usize = 265;
+pub const SYMLINKAT: usize = 266;
+pub const READLINKAT: usize = 267;
+pub const FCHMODAT: usize = 268;
+pub const FACCESSAT: usize = 269;
+pub const PSELECT6: usize = 270;
+pub const PPOLL: usize = 271;
+pub const UNSHARE: usize = 272;
+pub const SET_ROBUST_LIST: usize = 273;
+pub const GET_ROBUST_LIST: usize = 274;
+pub const SPLICE: usize = 275;
+pub const TEE: usize = 276;
+pub const SYNC_FILE_RANGE: usize = 277;
+pub const VMSPLICE: usize = 278;
+pub const MOVE_PAGES: usize = 279;
+pub const UTIMENSAT: usize = 280;
+pub const EPOLL_PWAIT: usize = 281;
+pub const SIGNALFD: usize = 282;
+pub const TIMERFD_CREATE: usize = 283;
+pub const EVENTFD: usize = 284;
+pub const FALLOCATE: usize = 285;
+pub const TIMERFD_SETTIME: usize = 286;
+pub const TIMERFD_GETTIME: usize = 287;
+pub const ACCEPT4: usize = 288;
+pub const SIGNALFD4: usize = 289;
+pub const EVENTFD2: usize = 290;
+pub const EPOLL_CREATE1: usize = 291;
+pub const DUP3: usize = 292;
+pub const PIPE2: usize = 293;
+pub const INOTIFY_INIT1: usize = 294;
+pub const PREADV: usize = 295;
+pub const PWRITEV: usize = 296;
+pub const RT_TGSIGQUEUEINFO: usize = 297;
+pub const PERF_EVENT_OPEN: usize = 298;
+pub const RECVMMSG: usize = 299;
+pub const FANOTIFY_INIT: usize = 300;
+pub const FANOTIFY_MARK: usize = 301;
+pub const PRLIMIT64: usize = 302;
+pub const NAME_TO_HANDLE_AT: usize = 303;
+pub const OPEN_BY_HANDLE_AT: usize = 304;
+pub const CLOCK_ADJTIME: usize = 305;
+pub const SYNCFS: usize = 306;
+pub const SENDMMSG: usize = 307;
+pub const SETNS: usize = 308;
+pub const GETCPU: usize = 309;
+pub const PROCESS_VM_READV: usize = 310;
+pub const PROCESS_VM_WRITEV: usize = 311;
+pub const KCMP: usize = 312;
+pub const FINIT_MODULE: usize = 313;
+pub const SCHED_SETATTR: usize = 314;
+pub const SCHED_GETATTR: usize = 315;
+pub const RENAMEAT2: usize = 316;
+pub const SECCOMP: usize = 317;
+pub const GETRANDOM: usize = 318;
+pub const MEMFD_CREATE: usize = 319;
+pub const KEXEC_FILE_LOAD: usize = 320;
+pub const BPF: usize = 321;
+pub const EXECVEAT: usize = 322;
+pub const USERFAULTFD: usize = 323;
+pub const MEMBARRIER: usize = 324;
+pub const MLOCK2: usize = 325;
+pub const COPY_FILE_RANGE: usize = 326;
+pub const PREADV2: usize = 327;
+pub const PWRITEV2: usize = 328;
+pub const PKEY_MPROTECT: usize = 329;
+pub const PKEY_ALLOC: usize = 330;
+pub const PKEY_FREE: usize = 331;
+pub const STATX: usize = 332;
+pub const IO_PGETEVENTS: usize = 333;
+pub const RSEQ: usize = 334;
+pub const PIDFD_SEND_SIGNAL: usize = 424;
+pub const IO_URING_SETUP: usize = 425;
+pub const IO_URING_ENTER: usize = 426;
+pub const IO_URING_REGISTER: usize = 427;
+pub const OPEN_TREE: usize = 428;
+pub const MOVE_MOUNT: usize = 429;
+pub const FSOPEN: usize = 430;
+pub const FSCONFIG: usize = 431;
+pub const FSMOUNT: usize = 432;
+pub const FSPICK: usize = 433;
+pub const PIDFD_OPEN: usize = 434;
+pub const CLONE3: usize = 435;
+pub const CLOSE_RANGE: usize = 436;
+pub const OPENAT2: usize = 437;
+pub const PIDFD_GETFD: usize = 438;
+pub const FACCESSAT2: usize = 439;
+pub const PROCESS_MADVISE: usize = 440;
+pub const EPOLL_PWAIT2: usize = 441;
+pub const MOUNT_SETATTR: usize = 442;
+pub const QUOTACTL_FD: usize = 443;
+pub const LANDLOCK_CREATE_RULESET: usize = 444;
+pub const LANDLOCK_ADD_RULE: usize = 445;
+pub const LANDLOCK_RESTRICT_SELF: usize = 446;
+pub const MEMFD_SECRET: usize = 447;
+pub const PROCESS_MRELEASE: usize = 448;
+pub const FUTEX_WAITV: usize = 449;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 450;
+
+/// A system call known on this platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(usize)]
+pub enum Sysno {
+    Read = READ,
+    Write = WRITE,
+    Open = OPEN,
+    Close = CLOSE,
+    Stat = STAT,
+    Fstat = FSTAT,
+    Lstat = LSTAT,
+    Poll = POLL,
+    Lseek = LSEEK,
+    Mmap = MMAP,
+    Mprotect = MPROTECT,
+    Munmap = MUNMAP,
+    Brk = BRK,
+    RtSigaction = RT_SIGACTION,
+    RtSigprocmask = RT_SIGPROCMASK,
+    RtSigreturn = RT_SIGRETURN,
+    Ioctl = IOCTL,
+    Pread64 = PREAD64,
+    Pwrite64 = PWRITE64,
+    Readv = READV,
+    Writev = WRITEV,
+    Access = ACCESS,
+    Pipe = PIPE,
+    Select = SELECT,
+    SchedYield = SCHED_YIELD,
+    Mremap = MREMAP,
+    Msync = MSYNC,
+    Mincore = MINCORE,
+    Madvise = MADVISE,
+    Shmget = SHMGET,
+    Shmat = SHMAT,
+    Shmctl = SHMCTL,
+    Dup = DUP,
+    Dup2 = DUP2,
+    Pause = PAUSE,
+    Nanosleep = NANOSLEEP,
+    Getitimer = GETITIMER,
+    Alarm = ALARM,
+    Setitimer = SETITIMER,
+    Getpid = GETPID,
+    Sendfile = SENDFILE,
+    Socket = SOCKET,
+    Connect = CONNECT,
+    Accept = ACCEPT,
+    Sendto = SENDTO,
+    Recvfrom = RECVFROM,
+    Sendmsg = SENDMSG,
+    Recvmsg = RECVMSG,
+    Shutdown = SHUTDOWN,
+    Bind = BIND,
+    Listen = LISTEN,
+    Getsockname = GETSOCKNAME,
+    Getpeername = GETPEERNAME,
+    Socketpair = SOCKETPAIR,
+    Setsockopt = SETSOCKOPT,
+    Getsockopt = GETSOCKOPT,
+    Clone = CLONE,
+    Fork = FORK,
+    Vfork = VFORK,
+    Execve = EXECVE,
+    Exit = EXIT,
+    Wait4 = WAIT4,
+    Kill = KILL,
+    Uname = UNAME,
+    Semget = SEMGET,
+    Semop = SEMOP,
+    Semctl = SEMCTL,
+    Shmdt = SHMDT,
+    Msgget = MSGGET,
+    Msgsnd = MSGSND,
+    Msgrcv = MSGRCV,
+    Msgctl = MSGCTL,
+    Fcntl = FCNTL,
+    Flock = FLOCK,
+    Fsync = FSYNC,
+    Fdatasync = FDATASYNC,
+    Truncate = TRUNCATE,
+    Ftruncate = FTRUNCATE,
+    Getdents = GETDENTS,
+    Getcwd = GETCWD,
+    Chdir = CHDIR,
+    Fchdir = FCHDIR,
+    Rename = RENAME,
+    Mkdir = MKDIR,
+    Rmdir = RMDIR,
+    Creat = CREAT,
+    Link = LINK,
+    Unlink = UNLINK,
+    Symlink = SYMLINK,
+    Readlink = READLINK,
+    Chmod = CHMOD,
+    Fchmod = FCHMOD,
+    Chown = CHOWN,
+    Fchown = FCHOWN,
+    Lchown = LCHOWN,
+    Umask = UMASK,
+    Gettimeofday = GETTIMEOFDAY,
+    Getrlimit = GETRLIMIT,
+    Getrusage = GETRUSAGE,
+    Sysinfo = SYSINFO,
+    Times = TIMES,
+    Ptrace = PTRACE,
+    Getuid = GETUID,
+    Syslog = SYSLOG,
+    Getgid = GETGID,
+    Setuid = SETUID,
+    Setgid = SETGID,
+    Geteuid = GETEUID,
+    Getegid = GETEGID,
+    Setpgid = SETPGID,
+    Getppid = GETPPID,
+    Getpgrp = GETPGRP,
+    Setsid = SETSID,
+    Setreuid = SETREUID,
+    Setregid = SETREGID,
+    Getgroups = GETGROUPS,
+    Setgroups = SETGROUPS,
+    Setresuid = SETRESUID,
+    Getresuid = GETRESUID,
+    Setresgid = SETRESGID,
+    Getresgid = GETRESGID,
+    Getpgid = GETPGID,
+    Setfsuid = SETFSUID,
+    Setfsgid = SETFSGID,
+    Getsid = GETSID,
+    Capget = CAPGET,
+    Capset = CAPSET,
+    RtSigpending = RT_SIGPENDING,
+    RtSigtimedwait = RT_SIGTIMEDWAIT,
+    RtSigqueueinfo = RT_SIGQUEUEINFO,
+    RtSigsuspend = RT_SIGSUSPEND,
+    Sigaltstack = SIGALTSTACK,
+    Utime = UTIME,
+    Mknod = MKNOD,
+    Uselib = USELIB,
+    Personality = PERSONALITY,
+    Ustat = USTAT,
+    Statfs = STATFS,
+    Fstatfs = FSTATFS,
+    Sysfs = SYSFS,
+    Getpriority = GETPRIORITY,
+    Setpriority = SETPRIORITY,
+    SchedSetparam = SCHED_SETPARAM,
+    SchedGetparam = SCHED_GETPARAM,
+    SchedSetscheduler = SCHED_SETSCHEDULER,
+    SchedGetscheduler = SCHED_GETSCHEDULER,
+    SchedGetPriorityMax = SCHED_GET_PRIORITY_MAX,
+    SchedGetPriorityMin = SCHED_GET_PRIORITY_MIN,
+    SchedRrGetInterval = SCHED_RR_GET_INTERVAL,
+    Mlock = MLOCK,
+    Munlock = MUNLOCK,
+    Mlockall = MLOCKALL,
+    Munlockall = MUNLOCKALL,
+    Vhangup = VHANGUP,
+    ModifyLdt = MODIFY_LDT,
+    PivotRoot = PIVOT_ROOT,
+    Sysctl = _SYSCTL,
+    Prctl = PRCTL,
+    ArchPrctl = ARCH_PRCTL,
+    Adjtimex = ADJTIMEX,
+    Setrlimit = SETRLIMIT,
+    Chroot = CHROOT,
+    Sync = SYNC,
+    Acct = ACCT,
+    Settimeofday = SETTIMEOFDAY,
+    Mount = MOUNT,
+    Umount2 = UMOUNT2,
+    Swapon = SWAPON,
+    Swapoff = SWAPOFF,
+    Reboot = REBOOT,
+    Sethostname = SETHOSTNAME,
+    Setdomainname = SETDOMAINNAME,
+    Iopl = IOPL,
+    Ioperm = IOPERM,
+    CreateModule = CREATE_MODULE,
+    InitModule = INIT_MODULE,
+    DeleteModule = DELETE_MODULE,
+    GetKernelSyms = GET_KERNEL_SYMS,
+    QueryModule = QUERY_MODULE,
+    Quotactl = QUOTACTL,
+    Nfsservctl = NFSSERVCTL,
+    Getpmsg = GETPMSG,
+    Putpmsg = PUTPMSG,
+    AfsSyscall = AFS_SYSCALL,
+    Tuxcall = TUXCALL,
+    Security = SECURITY,
+    Gettid = GETTID,
+    Readahead = READAHEAD,
+    Setxattr = SETXATTR,
+    Lsetxattr = LSETXATTR,
+    Fsetxattr = FSETXATTR,
+    Getxattr = GETXATTR,
+    Lgetxattr = LGETXATTR,
+    Fgetxattr = FGETXATTR,
+    Listxattr = LISTXATTR,
+    Llistxattr = LLISTXATTR,
+    Flistxattr = FLISTXATTR,
+    Removexattr = REMOVEXATTR,
+    Lremovexattr = LREMOVEXATTR,
+    Fremovexattr = FREMOVEXATTR,
+    Tkill = TKILL,
+    Time = TIME,
+    Futex = FUTEX,
+    SchedSetaffinity = SCHED_SETAFFINITY,
+    SchedGetaffinity = SCHED_GETAFFINITY,
+    SetThreadArea = SET_THREAD_AREA,
+    IoSetup = IO_SETUP,
+    IoDestroy = IO_DESTROY,
+    IoGetevents = IO_GETEVENTS,
+    IoSubmit = IO_SUBMIT,
+    IoCancel = IO_CANCEL,
+    GetThreadArea = GET_THREAD_AREA,
+    LookupDcookie = LOOKUP_DCOOKIE,
+    EpollCreate = EPOLL_CREATE,
+    EpollCtlOld = EPOLL_CTL_OLD,
+    EpollWaitOld = EPOLL_WAIT_OLD,
+    RemapFilePages = REMAP_FILE_PAGES,
+    Getdents64 = GETDENTS64,
+    SetTidAddress = SET_TID_ADDRESS,
+    RestartSyscall = RESTART_SYSCALL,
+    Semtimedop = SEMTIMEDOP,
+    Fadvise64 = FADVISE64,
+    TimerCreate = TIMER_CREATE,
+    TimerSettime = TIMER_SETTIME,
+    TimerGettime = TIMER_GETTIME,
+    TimerGetoverrun = TIMER_GETOVERRUN,
+    TimerDelete = TIMER_DELETE,
+    ClockSettime = CLOCK_SETTIME,
+    ClockGettime = CLOCK_GETTIME,
+    ClockGetres = CLOCK_GETRES,
+    ClockNanosleep = CLOCK_NANOSLEEP,
+    ExitGroup = EXIT_GROUP,
+    EpollWait = EPOLL_WAIT,
+    EpollCtl = EPOLL_CTL,
+    Tgkill = TGKILL,
+    Utimes = UTIMES,
+    Vserver = VSERVER,
+    Mbind = MBIND,
+    SetMempolicy = SET_MEMPOLICY,
+    GetMempolicy = GET_MEMPOLICY,
+    MqOpen = MQ_OPEN,
+    MqUnlink = MQ_UNLINK,
+    MqTimedsend = MQ_TIMEDSEND,
+    MqTimedreceive = MQ_TIMEDRECEIVE,
+    MqNotify = MQ_NOTIFY,
+    MqGetsetattr = MQ_GETSETATTR,
+    KexecLoad = KEXEC_LOAD,
+    Waitid = WAITID,
+    AddKey = ADD_KEY,
+    RequestKey = REQUEST_KEY,
+    Keyctl = KEYCTL,
+    IoprioSet = IOPRIO_SET,
+    IoprioGet = IOPRIO_GET,
+    InotifyInit = INOTIFY_INIT,
+    InotifyAddWatch = INOTIFY_ADD_WATCH,
+    InotifyRmWatch = INOTIFY_RM_WATCH,
+    MigratePages = MIGRATE_PAGES,
+    Openat = OPENAT,
+    Mkdirat = MKDIRAT,
+    Mknodat = MKNODAT,
+    Fchownat = FCHOWNAT,
+    Futimesat = FUTIMESAT,
+    Newfstatat = NEWFSTATAT,
+    Unlinkat = UNLINKAT,
+    Renameat = RENAMEAT,
+    Linkat = LINKAT,
+    Symlinkat = SYMLINKAT,
+    Readlinkat = READLINKAT,
+    Fchmodat = FCHMODAT,
+    Faccessat = FACCESSAT,
+    Pselect6 = PSELECT6,
+    Ppoll = PPOLL,
+    Unshare = UNSHARE,
+    SetRobustList = SET_ROBUST_LIST,
+    GetRobustList = GET_ROBUST_LIST,
+    Splice = SPLICE,
+    Tee = TEE,
+    SyncFileRange = SYNC_FILE_RANGE,
+    Vmsplice = VMSPLICE,
+    MovePages = MOVE_PAGES,
+    Utimensat = UTIMENSAT,
+    EpollPwait = EPOLL_PWAIT,
+    Signalfd = SIGNALFD,
+    TimerfdCreate = TIMERFD_CREATE,
+    Eventfd = EVENTFD,
+    Fallocate = FALLOCATE,
+    TimerfdSettime = TIMERFD_SETTIME,
+    TimerfdGettime = TIMERFD_GETTIME,
+    Accept4 = ACCEPT4,
+    Signalfd4 = SIGNALFD4,
+    Eventfd2 = EVENTFD2,
+    EpollCreate1 = EPOLL_CREATE1,
+    Dup3 = DUP3,
+    Pipe2 = PIPE2,
+    InotifyInit1 = INOTIFY_INIT1,
+    Preadv = PREADV,
+    Pwritev = PWRITEV,
+    RtTgsigqueueinfo = RT_TGSIGQUEUEINFO,
+    PerfEventOpen = PERF_EVENT_OPEN,
+    Recvmmsg = RECVMMSG,
+    FanotifyInit = FANOTIFY_INIT,
+    FanotifyMark = FANOTIFY_MARK,
+    Prlimit64 = PRLIMIT64,
+    NameToHandleAt = NAME_TO_HANDLE_AT,
+    OpenByHandleAt = OPEN_BY_HANDLE_AT,
+    ClockAdjtime = CLOCK_ADJTIME,
+    Syncfs = SYNCFS,
+    Sendmmsg = SENDMMSG,
+    Setns = SETNS,
+    Getcpu = GETCPU,
+    ProcessVmReadv = PROCESS_VM_READV,
+    ProcessVmWritev = PROCESS_VM_WRITEV,
+    Kcmp = KCMP,
+    FinitModule = FINIT_MODULE,
+    SchedSetattr = SCHED_SETATTR,
+    SchedGetattr = SCHED_GETATTR,
+    Renameat2 = RENAMEAT2,
+    Seccomp = SECCOMP,
+    Getrandom = GETRANDOM,
+    MemfdCreate = MEMFD_CREATE,
+    KexecFileLoad = KEXEC_FILE_LOAD,
+    Bpf = BPF,
+    Execveat = EXECVEAT,
+    Userfaultfd = USERFAULTFD,
+    Membarrier = MEMBARRIER,
+    Mlock2 = MLOCK2,
+    CopyFileRange = COPY_FILE_RANGE,
+    Preadv2 = PREADV2,
+    Pwritev2 = PWRITEV2,
+    PkeyMprotect = PKEY_MPROTECT,
+    PkeyAlloc = PKEY_ALLOC,
+    PkeyFree = PKEY_FREE,
+    Statx = STATX,
+    IoPgetevents = IO_PGETEVENTS,
+    Rseq = RSEQ,
+    PidfdSendSignal = PIDFD_SEND_SIGNAL,
+    IoUringSetup = IO_URING_SETUP,
+    IoUringEnter = IO_URING_ENTER,
+    IoUringRegister = IO_URING_REGISTER,
+    OpenTree = OPEN_TREE,
+    MoveMount = MOVE_MOUNT,
+    Fsopen = FSOPEN,
+    Fsconfig = FSCONFIG,
+    Fsmount = FSMOUNT,
+    Fspick = FSPICK,
+    PidfdOpen = PIDFD_OPEN,
+    Clone3 = CLONE3,
+    CloseRange = CLOSE_RANGE,
+    Openat2 = OPENAT2,
+    PidfdGetfd = PIDFD_GETFD,
+    Faccessat2 = FACCESSAT2,
+    ProcessMadvise = PROCESS_MADVISE,
+    EpollPwait2 = EPOLL_PWAIT2,
+    MountSetattr = MOUNT_SETATTR,
+    QuotactlFd = QUOTACTL_FD,
+    LandlockCreateRuleset = LANDLOCK_CREATE_RULESET,
+    LandlockAddRule = LANDLOCK_ADD_RULE,
+    LandlockRestrictSelf = LANDLOCK_RESTRICT_SELF,
+    MemfdSecret = MEMFD_SECRET,
+    ProcessMrelease = PROCESS_MRELEASE,
+    FutexWaitv = FUTEX_WAITV,
+    SetMempolicyHomeNode = SET_MEMPOLICY_HOME_NODE,
+}
+
+impl Sysno {
+    /// Every system call known on this platform, in order of number.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::Read,
+        Self::Write,
+        Self::Open,
+        Self::Close,
+        Self::Stat,
+        Self::Fstat,
+        Self::Lstat,
+        Self::Poll,
+        Self::Lseek,
+        Self::Mmap,
+        Self::Mprotect,
+        Self::Munmap,
+        Self::Brk,
+        Self::RtSigaction,
+        Self::RtSigprocmask,
+        Self::RtSigreturn,
+        Self::Ioctl,
+        Self::Pread64,
+        Self::Pwrite64,
+        Self::Readv,
+        Self::Writev,
+        Self::Access,
+        Self::Pipe,
+        Self::Select,
+        Self::SchedYield,
+        Self::Mremap,
+        Self::Msync,
+        Self::Mincore,
+        Self::Madvise,
+        Self::Shmget,
+        Self::Shmat,
+        Self::Shmctl,
+        Self::Dup,
+        Self::Dup2,
+        Self::Pause,
+        Self::Nanosleep,
+        Self::Getitimer,
+        Self::Alarm,
+        Self::Setitimer,
+        Self::Getpid,
+        Self::Sendfile,
+        Self::Socket,
+        Self::Connect,
+        Self::Accept,
+        Self::Sendto,
+        Self::Recvfrom,
+        Self::Sendmsg,
+        Self::Recvmsg,
+        Self::Shutdown,
+        Self::Bind,
+        Self::Listen,
+        Self::Getsockname,
+        Self::Getpeername,
+        Self::Socketpair,
+        Self::Setsockopt,
+        Self::Getsockopt,
+        Self::Clone,
+        Self::Fork,
+        Self::Vfork,
+        Self::Execve,
+        Self::Exit,
+        Self::Wait4,
+        Self::Kill,
+        Self::Uname,
+        Self::Semget,
+        Self::Semop,
+        Self::Semctl,
+        Self::Shmdt,
+        Self::Msgget,
+        Self::Msgsnd,
+        Self::Msgrcv,
+        Self::Msgctl,
+        Self::Fcntl,
+        Self::Flock,
+        Self::Fsync,
+        Self::Fdatasync,
+        Self::Truncate,
+        Self::Ftruncate,
+        Self::Getdents,
+        Self::Getcwd,
+        Self::Chdir,
+        Self::Fchdir,
+        Self::Rename,
+        Self::Mkdir,
+        Self::Rmdir,
+        Self::Creat,
+        Self::Link,
+        Self::Unlink,
+        Self::Symlink,
+        Self::Readlink,
+        Self::Chmod,
+        Self::Fchmod,
+        Self::Chown,
+        Self::Fchown,
+        Self::Lchown,
+        Self::Umask,
+        Self::Gettimeofday,
+        Self::Getrlimit,
+        Self::Getrusage,
+        Self::Sysinfo,
+        Self::Times,
+        Self::Ptrace,
+        Self::Getuid,
+        Self::Syslog,
+        Self::Getgid,
+        Self::Setuid,
+        Self::Setgid,
+        Self::Geteuid,
+        Self::Getegid,
+        Self::Setpgid,
+        Self::Getppid,
+        Self::Getpgrp,
+        Self::Setsid,
+        Self::Setreuid,
+        Self::Setregid,
+        Self::Getgroups,
+        Self::Setgroups,
+        Self::Setresuid,
+        Self::Getresuid,
+        Self::Setresgid,
+        Self::Getresgid,
+        Self::Getpgid,
+        Self::Setfsuid,
+        Self::Setfsgid,
+        Self::Getsid,
+        Self::Capget,
+        Self::Capset,
+        Self::RtSigpending,
+        Self::RtSigtimedwait,
+        Self::RtSigqueueinfo,
+        Self::RtSigsuspend,
+        Self::Sigaltstack,
+        Self::Utime,
+        Self::Mknod,
+        Self::Uselib,
+        Self::Personality,
+        Self::Ustat,
+        Self::Statfs,
+        Self::Fstatfs,
+        Self::Sysfs,
+        Self::Getpriority,
+        Self::Setpriority,
+        Self::SchedSetparam,
+        Self::SchedGetparam,
+        Self::SchedSetscheduler,
+        Self::SchedGetscheduler,
+        Self::SchedGetPriorityMax,
+        Self::SchedGetPriorityMin,
+        Self::SchedRrGetInterval,
+        Self::Mlock,
+        Self::Munlock,
+        Self::Mlockall,
+        Self::Munlockall,
+        Self::Vhangup,
+        Self::ModifyLdt,
+        Self::PivotRoot,
+        Self::Sysctl,
+        Self::Prctl,
+        Self::ArchPrctl,
+        Self::Adjtimex,
+        Self::Setrlimit,
+        Self::Chroot,
+        Self::Sync,
+        Self::Acct,
+        Self::Settimeofday,
+        Self::Mount,
+        Self::Umount2,
+        Self::Swapon,
+        Self::Swapoff,
+        Self::Reboot,
+        Self::Sethostname,
+        Self::Setdomainname,
+        Self::Iopl,
+        Self::Ioperm,
+        Self::CreateModule,
+        Self::InitModule,
+        Self::DeleteModule,
+        Self::GetKernelSyms,
+        Self::QueryModule,
+        Self::Quotactl,
+        Self::Nfsservctl,
+        Self::Getpmsg,
+        Self::Putpmsg,
+        Self::AfsSyscall,
+        Self::Tuxcall,
+        Self::Security,
+        Self::Gettid,
+        Self::Readahead,
+        Self::Setxattr,
+        Self::Lsetxattr,
+        Self::Fsetxattr,
+        Self::Getxattr,
+        Self::Lgetxattr,
+        Self::Fgetxattr,
+        Self::Listxattr,
+        Self::Llistxattr,
+        Self::Flistxattr,
+        Self::Removexattr,
+        Self::Lremovexattr,
+        Self::Fremovexattr,
+        Self::Tkill,
+        Self::Time,
+        Self::Futex,
+        Self::SchedSetaffinity,
+        Self::SchedGetaffinity,
+        Self::SetThreadArea,
+        Self::IoSetup,
+        Self::IoDestroy,
+        Self::IoGetevents,
+        Self::IoSubmit,
+        Self::IoCancel,
+        Self::GetThreadArea,
+        Self::LookupDcookie,
+        Self::EpollCreate,
+        Self::EpollCtlOld,
+        Self::EpollWaitOld,
+        Self::RemapFilePages,
+        Self::Getdents64,
+        Self::SetTidAddress,
+        Self::RestartSyscall,
+        Self::Semtimedop,
+        Self::Fadvise64,
+        Self::TimerCreate,
+        Self::TimerSettime,
+        Self::TimerGettime,
+        Self::TimerGetoverrun,
+        Self::TimerDelete,
+        Self::ClockSettime,
+        Self::ClockGettime,
+        Self::ClockGetres,
+        Self::ClockNanosleep,
+        Self::ExitGroup,
+        Self::EpollWait,
+        Self::EpollCtl,
+        Self::Tgkill,
+        Self::Utimes,
+        Self::Vserver,
+        Self::Mbind,
+        Self::SetMempolicy,
+        Self::GetMempolicy,
+        Self::MqOpen,
+        Self::MqUnlink,
+        Self::MqTimedsend,
+        Self::MqTimedreceive,
+        Self::MqNotify,
+        Self::MqGetsetattr,
+        Self::KexecLoad,
+        Self::Waitid,
+        Self::AddKey,
+        Self::RequestKey,
+        Self::Keyctl,
+        Self::IoprioSet,
+        Self::IoprioGet,
+        Self::InotifyInit,
+        Self::InotifyAddWatch,
+        Self::InotifyRmWatch,
+        Self::MigratePages,
+        Self::Openat,
+        Self::Mkdirat,
+        Self::Mknodat,
+        Self::Fchownat,
+        Self::Futimesat,
+        Self::Newfstatat,
+        Self::Unlinkat,
+        Self::Renameat,
+        Self::Linkat,
+        Self::Symlinkat,
+        Self::Readlinkat,
+        Self::Fchmodat,
+        Self::Faccessat,
+        Self::Pselect6,
+        Self::Ppoll,
+        Self::Unshare,
+        Self::SetRobustList,
+        Self::GetRobustList,
+        Self::Splice,
+        Self::Tee,
+        Self::SyncFileRange,
+        Self::Vmsplice,
+        Self::MovePages,
+        Self::Utimensat,
+        Self::EpollPwait,
+        Self::Signalfd,
+        Self::TimerfdCreate,
+        Self::Eventfd,
+        Self::Fallocate,
+        Self::TimerfdSettime,
+        Self::TimerfdGettime,
+        Self::Accept4,
+        Self::Signalfd4,
+        Self::Eventfd2,
+        Self::EpollCreate1,
+        Self::Dup3,
+        Self::Pipe2,
+        Self::InotifyInit1,
+        Self::Preadv,
+        Self::Pwritev,
+        Self::RtTgsigqueueinfo,
+        Self::PerfEventOpen,
+        Self::Recvmmsg,
+        Self::FanotifyInit,
+        Self::FanotifyMark,
+        Self::Prlimit64,
+        Self::NameToHandleAt,
+        Self::OpenByHandleAt,
+        Self::ClockAdjtime,
+        Self::Syncfs,
+        Self::Sendmmsg,
+        Self::Setns,
+        Self::Getcpu,
+        Self::ProcessVmReadv,
+        Self::ProcessVmWritev,
+        Self::Kcmp,
+        Self::FinitModule,
+        Self::SchedSetattr,
+        Self::SchedGetattr,
+        Self::Renameat2,
+        Self::Seccomp,
+        Self::Getrandom,
+        Self::MemfdCreate,
+        Self::KexecFileLoad,
+        Self::Bpf,
+        Self::Execveat,
+        Self::Userfaultfd,
+        Self::Membarrier,
+        Self::Mlock2,
+        Self::CopyFileRange,
+        Self::Preadv2,
+        Self::Pwritev2,
+        Self::PkeyMprotect,
+        Self::PkeyAlloc,
+        Self::PkeyFree,
+        Self::Statx,
+        Self::IoPgetevents,
+        Self::Rseq,
+        Self::PidfdSendSignal,
+        Self::IoUringSetup,
+        Self::IoUringEnter,
+        Self::IoUringRegister,
+        Self::OpenTree,
+        Self::MoveMount,
+        Self::Fsopen,
+        Self::Fsconfig,
+        Self::Fsmount,
+        Self::Fspick,
+        Self::PidfdOpen,
+        Self::Clone3,
+        Self::CloseRange,
+        Self::Openat2,
+        Self::PidfdGetfd,
+        Self::Faccessat2,
+        Self::ProcessMadvise,
+        Self::EpollPwait2,
+        Self::MountSetattr,
+        Self::QuotactlFd,
+        Self::LandlockCreateRuleset,
+        Self::LandlockAddRule,
+        Self::LandlockRestrictSelf,
+        Self::MemfdSecret,
+        Self::ProcessMrelease,
+        Self::FutexWaitv,
+        Self::SetMempolicyHomeNode,
+    ];
+
+    /// Returns the system call with the given number, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            READ => Some(Self::Read),
+            WRITE => Some(Self::Write),
+            OPEN => Some(Self::Open),
+            CLOSE => Some(Self::Close),
+            STAT => Some(Self::Stat),
+            FSTAT => Some(Self::Fstat),
+            LSTAT => Some(Self::Lstat),
+            POLL => Some(Self::Poll),
+            LSEEK => Some(Self::Lseek),
+            MMAP => Some(Self::Mmap),
+            MPROTECT => Some(Self::Mprotect),
+            MUNMAP => Some(Self::Munmap),
+            BRK => Some(Self::Brk),
+            RT_SIGACTION => Some(Self::RtSigaction),
+            RT_SIGPROCMASK => Some(Self::RtSigprocmask),
+            RT_SIGRETURN => Some(Self::RtSigreturn),
+            IOCTL => Some(Self::Ioctl),
+            PREAD64 => Some(Self::Pread64),
+            PWRITE64 => Some(Self::Pwrite64),
+            READV => Some(Self::Readv),
+            WRITEV => Some(Self::Writev),
+            ACCESS => Some(Self::Access),
+            PIPE => Some(Self::Pipe),
+            SELECT => Some(Self::Select),
+            SCHED_YIELD => Some(Self::SchedYield),
+            MREMAP => Some(Self::Mremap),
+            MSYNC => Some(Self::Msync),
+            MINCORE => Some(Self::Mincore),
+            MADVISE => Some(Self::Madvise),
+            SHMGET => Some(Self::Shmget),
+            SHMAT => Some(Self::Shmat),
+            SHMCTL => Some(Self::Shmctl),
+            DUP => Some(Self::Dup),
+            DUP2 => Some(Self::Dup2),
+            PAUSE => Some(Self::Pause),
+            NANOSLEEP => Some(Self::Nanosleep),
+            GETITIMER => Some(Self::Getitimer),
+            ALARM => Some(Self::Alarm),
+            SETITIMER => Some(Self::Setitimer),
+            GETPID => Some(Self::Getpid),
+            SENDFILE => Some(Self::Sendfile),
+            SOCKET => Some(Self::Socket),
+            CONNECT => Some(Self::Connect),
+            ACCEPT => Some(Self::Accept),
+            SENDTO => Some(Self::Sendto),
+            RECVFROM => Some(Self::Recvfrom),
+            SENDMSG => Some(Self::Sendmsg),
+            RECVMSG => Some(Self::Recvmsg),
+            SHUTDOWN => Some(Self::Shutdown),
+            BIND => Some(Self::Bind),
+            LISTEN => Some(Self::Listen),
+            GETSOCKNAME => Some(Self::Getsockname),
+            GETPEERNAME => Some(Self::Getpeername),
+            SOCKETPAIR => Some(Self::Socketpair),
+            SETSOCKOPT => Some(Self::Setsockopt),
+            GETSOCKOPT => Some(Self::Getsockopt),
+            CLONE => Some(Self::Clone),
+            FORK => Some(Self::Fork),
+            VFORK => Some(Self::Vfork),
+            EXECVE => Some(Self::Execve),
+            EXIT => Some(Self::Exit),
+            WAIT4 => Some(Self::Wait4),
+            KILL => Some(Self::Kill),
+            UNAME => Some(Self::Uname),
+            SEMGET => Some(Self::Semget),
+            SEMOP => Some(Self::Semop),
+            SEMCTL => Some(Self::Semctl),
+            SHMDT => Some(Self::Shmdt),
+            MSGGET => Some(Self::Msgget),
+            MSGSND => Some(Self::Msgsnd),
+            MSGRCV => Some(Self::Msgrcv),
+            MSGCTL => Some(Self::Msgctl),
+            FCNTL => Some(Self::Fcntl),
+            FLOCK => Some(Self::Flock),
+            FSYNC => Some(Self::Fsync),
+            FDATASYNC => Some(Self::Fdatasync),
+            TRUNCATE => Some(Self::Truncate),
+            FTRUNCATE => Some(Self::Ftruncate),
+            GETDENTS => Some(Self::Getdents),
+            GETCWD => Some(Self::Getcwd),
+            CHDIR => Some(Self::Chdir),
+            FCHDIR => Some(Self::Fchdir),
+            RENAME => Some(Self::Rename),
+            MKDIR => Some(Self::Mkdir),
+            RMDIR => Some(Self::Rmdir),
+            CREAT => Some(Self::Creat),
+            LINK => Some(Self::Link),
+            UNLINK => Some(Self::Unlink),
+            SYMLINK => Some(Self::Symlink),
+            READLINK => Some(Self::Readlink),
+            CHMOD => Some(Self::Chmod),
+            FCHMOD => Some(Self::Fchmod),
+            CHOWN => Some(Self::Chown),
+            FCHOWN => Some(Self::Fchown),
+            LCHOWN => Some(Self::Lchown),
+            UMASK => Some(Self::Umask),
+            GETTIMEOFDAY => Some(Self::Gettimeofday),
+            GETRLIMIT => Some(Self::Getrlimit),
+            GETRUSAGE => Some(Self::Getrusage),
+            SYSINFO => Some(Self::Sysinfo),
+            TIMES => Some(Self::Times),
+            PTRACE => Some(Self::Ptrace),
+            GETUID => Some(Self::Getuid),
+            SYSLOG => Some(Self::Syslog),
+            GETGID => Some(Self::Getgid),
+            SETUID => Some(Self::Setuid),
+            SETGID => Some(Self::Setgid),
+            GETEUID => Some(Self::Geteuid),
+            GETEGID => Some(Self::Getegid),
+            SETPGID => Some(Self::Setpgid),
+            GETPPID => Some(Self::Getppid),
+            GETPGRP => Some(Self::Getpgrp),
+            SETSID => Some(Self::Setsid),
+            SETREUID => Some(Self::Setreuid),
+            SETREGID => Some(Self::Setregid),
+            GETGROUPS => Some(Self::Getgroups),
+            SETGROUPS => Some(Self::Setgroups),
+            SETRESUID => Some(Self::Setresuid),
+            GETRESUID => Some(Self::Getresuid),
+            SETRESGID => Some(Self::Setresgid),
+            GETRESGID => Some(Self::Getresgid),
+            GETPGID => Some(Self::Getpgid),
+            SETFSUID => Some(Self::Setfsuid),
+            SETFSGID => Some(Self::Setfsgid),
+            GETSID => Some(Self::Getsid),
+            CAPGET => Some(Self::Capget),
+            CAPSET => Some(Self::Capset),
+            RT_SIGPENDING => Some(Self::RtSigpending),
+            RT_SIGTIMEDWAIT => Some(Self::RtSigtimedwait),
+            RT_SIGQUEUEINFO => Some(Self::RtSigqueueinfo),
+            RT_SIGSUSPEND => Some(Self::RtSigsuspend),
+            SIGALTSTACK => Some(Self::Sigaltstack),
+            UTIME => Some(Self::Utime),
+            MKNOD => Some(Self::Mknod),
+            USELIB => Some(Self::Uselib),
+            PERSONALITY => Some(Self::Personality),
+            USTAT => Some(Self::Ustat),
+            STATFS => Some(Self::Statfs),
+            FSTATFS => Some(Self::Fstatfs),
+            SYSFS => Some(Self::Sysfs),
+            GETPRIORITY => Some(Self::Getpriority),
+            SETPRIORITY => Some(Self::Setpriority),
+            SCHED_SETPARAM => Some(Self::SchedSetparam),
+            SCHED_GETPARAM => Some(Self::SchedGetparam),
+            SCHED_SETSCHEDULER => Some(Self::SchedSetscheduler),
+            SCHED_GETSCHEDULER => Some(Self::SchedGetscheduler),
+            SCHED_GET_PRIORITY_MAX => Some(Self::SchedGetPriorityMax),
+            SCHED_GET_PRIORITY_MIN => Some(Self::SchedGetPriorityMin),
+            SCHED_RR_GET_INTERVAL => Some(Self::SchedRrGetInterval),
+            MLOCK => Some(Self::Mlock),
+            MUNLOCK => Some(Self::Munlock),
+            MLOCKALL => Some(Self::Mlockall),
+            MUNLOCKALL => Some(Self::Munlockall),
+            VHANGUP => Some(Self::Vhangup),
+            MODIFY_LDT => Some(Self::ModifyLdt),
+            PIVOT_ROOT => Some(Self::PivotRoot),
+            _SYSCTL => Some(Self::Sysctl),
+            PRCTL => Some(Self::Prctl),
+            ARCH_PRCTL => Some(Self::ArchPrctl),
+            ADJTIMEX => Some(Self::Adjtimex),
+            SETRLIMIT => Some(Self::Setrlimit),
+            CHROOT => Some(Self::Chroot),
+            SYNC => Some(Self::Sync),
+            ACCT => Some(Self::Acct),
+            SETTIMEOFDAY => Some(Self::Settimeofday),
+            MOUNT => Some(Self::Mount),
+            UMOUNT2 => Some(Self::Umount2),
+            SWAPON => Some(Self::Swapon),
+            SWAPOFF => Some(Self::Swapoff),
+            REBOOT => Some(Self::Reboot),
+            SETHOSTNAME => Some(Self::Sethostname),
+            SETDOMAINNAME => Some(Self::Setdomainname),
+            IOPL => Some(Self::Iopl),
+            IOPERM => Some(Self::Ioperm),
+            CREATE_MODULE => Some(Self::CreateModule),
+            INIT_MODULE => Some(Self::InitModule),
+            DELETE_MODULE => Some(Self::DeleteModule),
+            GET_KERNEL_SYMS => Some(Self::GetKernelSyms),
+            QUERY_MODULE => Some(Self::QueryModule),
+            QUOTACTL => Some(Self::Quotactl),
+            NFSSERVCTL => Some(Self::Nfsservctl),
+            GETPMSG => Some(Self::Getpmsg),
+            PUTPMSG => Some(Self::Putpmsg),
+            AFS_SYSCALL => Some(Self::AfsSyscall),
+            TUXCALL => Some(Self::Tuxcall),
+            SECURITY => Some(Self::Security),
+            GETTID => Some(Self::Gettid),
+            READAHEAD => Some(Self::Readahead),
+            SETXATTR => Some(Self::Setxattr),
+            LSETXATTR => Some(Self::Lsetxattr),
+            FSETXATTR => Some(Self::Fsetxattr),
+            GETXATTR => Some(Self::Getxattr),
+            LGETXATTR => Some(Self::Lgetxattr),
+            FGETXATTR => Some(Self::Fgetxattr),
+            LISTXATTR => Some(Self::Listxattr),
+            LLISTXATTR => Some(Self::Llistxattr),
+            FLISTXATTR => Some(Self::Flistxattr),
+            REMOVEXATTR => Some(Self::Removexattr),
+            LREMOVEXATTR => Some(Self::Lremovexattr),
+            FREMOVEXATTR => Some(Self::Fremovexattr),
+            TKILL => Some(Self::Tkill),
+            TIME => Some(Self::Time),
+            FUTEX => Some(Self::Futex),
+            SCHED_SETAFFINITY => Some(Self::SchedSetaffinity),
+            SCHED_GETAFFINITY => Some(Self::SchedGetaffinity),
+            SET_THREAD_AREA => Some(Self::SetThreadArea),
+            IO_SETUP => Some(Self::IoSetup),
+            IO_DESTROY => Some(Self::IoDestroy),
+            IO_GETEVENTS => Some(Self::IoGetevents),
+            IO_SUBMIT => Some(Self::IoSubmit),
+            IO_CANCEL => Some(Self::IoCancel),
+            GET_THREAD_AREA => Some(Self::GetThreadArea),
+            LOOKUP_DCOOKIE => Some(Self::LookupDcookie),
+            EPOLL_CREATE => Some(Self::EpollCreate),
+            EPOLL_CTL_OLD => Some(Self::EpollCtlOld),
+            EPOLL_WAIT_OLD => Some(Self::EpollWaitOld),
+            REMAP_FILE_PAGES => Some(Self::RemapFilePages),
+            GETDENTS64 => Some(Self::Getdents64),
+            SET_TID_ADDRESS => Some(Self::SetTidAddress),
+            RESTART_SYSCALL => Some(Self::RestartSyscall),
+            SEMTIMEDOP => Some(Self::Semtimedop),
+            FADVISE64 => Some(Self::Fadvise64),
+            TIMER_CREATE => Some(Self::TimerCreate),
+            TIMER_SETTIME => Some(Self::TimerSettime),
+            TIMER_GETTIME => Some(Self::TimerGettime),
+            TIMER_GETOVERRUN => Some(Self::TimerGetoverrun),
+            TIMER_DELETE => Some(Self::TimerDelete),
+            CLOCK_SETTIME => Some(Self::ClockSettime),
+            CLOCK_GETTIME => Some(Self::ClockGettime),
+            CLOCK_GETRES => Some(Self::ClockGetres),
+            CLOCK_NANOSLEEP => Some(Self::ClockNanosleep),
+            EXIT_GROUP => Some(Self::ExitGroup),
+            EPOLL_WAIT => Some(Self::EpollWait),
+            EPOLL_CTL => Some(Self::EpollCtl),
+            TGKILL => Some(Self::Tgkill),
+            UTIMES => Some(Self::Utimes),
+            VSERVER => Some(Self::Vserver),
+            MBIND => Some(Self::Mbind),
+            SET_MEMPOLICY => Some(Self::SetMempolicy),
+            GET_MEMPOLICY => Some(Self::GetMempolicy),
+            MQ_OPEN => Some(Self::MqOpen),
+            MQ_UNLINK => Some(Self::MqUnlink),
+            MQ_TIMEDSEND => Some(Self::MqTimedsend),
+            MQ_TIMEDRECEIVE => Some(Self::MqTimedreceive),
+            MQ_NOTIFY => Some(Self::MqNotify),
+            MQ_GETSETATTR => Some(Self::MqGetsetattr),
+            KEXEC_LOAD => Some(Self::KexecLoad),
+            WAITID => Some(Self::Waitid),
+            ADD_KEY => Some(Self::AddKey),
+            REQUEST_KEY => Some(Self::RequestKey),
+            KEYCTL => Some(Self::Keyctl),
+            IOPRIO_SET => Some(Self::IoprioSet),
+            IOPRIO_GET => Some(Self::IoprioGet),
+            INOTIFY_INIT => Some(Self::InotifyInit),
+            INOTIFY_ADD_WATCH => Some(Self::InotifyAddWatch),
+            INOTIFY_RM_WATCH => Some(Self::InotifyRmWatch),
+            MIGRATE_PAGES => Some(Self::MigratePages),
+            OPENAT => Some(Self::Openat),
+            MKDIRAT => Some(Self::Mkdirat),
+            MKNODAT => Some(Self::Mknodat),
+            FCHOWNAT => Some(Self::Fchownat),
+            FUTIMESAT => Some(Self::Futimesat),
+            NEWFSTATAT => Some(Self::Newfstatat),
+            UNLINKAT => Some(Self::Unlinkat),
+            RENAMEAT => Some(Self::Renameat),
+            LINKAT => Some(Self::Linkat),
+            SYMLINKAT => Some(Self::Symlinkat),
+            READLINKAT => Some(Self::Readlinkat),
+            FCHMODAT => Some(Self::Fchmodat),
+            FACCESSAT => Some(Self::Faccessat),
+            PSELECT6 => Some(Self::Pselect6),
+            PPOLL => Some(Self::Ppoll),
+            UNSHARE => Some(Self::Unshare),
+            SET_ROBUST_LIST => Some(Self::SetRobustList),
+            GET_ROBUST_LIST => Some(Self::GetRobustList),
+            SPLICE => Some(Self::Splice),
+            TEE => Some(Self::Tee),
+            SYNC_FILE_RANGE => Some(Self::SyncFileRange),
+            VMSPLICE => Some(Self::Vmsplice),
+            MOVE_PAGES => Some(Self::MovePages),
+            UTIMENSAT => Some(Self::Utimensat),
+            EPOLL_PWAIT => Some(Self::EpollPwait),
+            SIGNALFD => Some(Self::Signalfd),
+            TIMERFD_CREATE => Some(Self::TimerfdCreate),
+            EVENTFD => Some(Self::Eventfd),
+            FALLOCATE => Some(Self::Fallocate),
+            TIMERFD_SETTIME => Some(Self::TimerfdSettime),
+            TIMERFD_GETTIME => Some(Self::TimerfdGettime),
+            ACCEPT4 => Some(Self::Accept4),
+            SIGNALFD4 => Some(Self::Signalfd4),
+            EVENTFD2 => Some(Self::Eventfd2),
+            EPOLL_CREATE1 => Some(Self::EpollCreate1),
+            DUP3 => Some(Self::Dup3),
+            PIPE2 => Some(Self::Pipe2),
+            INOTIFY_INIT1 => Some(Self::InotifyInit1),
+            PREADV => Some(Self::Preadv),
+            PWRITEV => Some(Self::Pwritev),
+            RT_TGSIGQUEUEINFO => Some(Self::RtTgsigqueueinfo),
+            PERF_EVENT_OPEN => Some(Self::PerfEventOpen),
+            RECVMMSG => Some(Self::Recvmmsg),
+            FANOTIFY_INIT => Some(Self::FanotifyInit),
+            FANOTIFY_MARK => Some(Self::FanotifyMark),
+            PRLIMIT64 => Some(Self::Prlimit64),
+            NAME_TO_HANDLE_AT => Some(Self::NameToHandleAt),
+            OPEN_BY_HANDLE_AT => Some(Self::OpenByHandleAt),
+            CLOCK_ADJTIME => Some(Self::ClockAdjtime),
+            SYNCFS => Some(Self::Syncfs),
+            SENDMMSG => Some(Self::Sendmmsg),
+            SETNS => Some(Self::Setns),
+            GETCPU => Some(Self::Getcpu),
+            PROCESS_VM_READV => Some(Self::ProcessVmReadv),
+            PROCESS_VM_WRITEV => Some(Self::ProcessVmWritev),
+            KCMP => Some(Self::Kcmp),
+            FINIT_MODULE => Some(Self::FinitModule),
+            SCHED_SETATTR => Some(Self::SchedSetattr),
+            SCHED_GETATTR => Some(Self::SchedGetattr),
+            RENAMEAT2 => Some(Self::Renameat2),
+            SECCOMP => Some(Self::Seccomp),
+            GETRANDOM => Some(Self::Getrandom),
+            MEMFD_CREATE => Some(Self::MemfdCreate),
+            KEXEC_FILE_LOAD => Some(Self::KexecFileLoad),
+            BPF => Some(Self::Bpf),
+            EXECVEAT => Some(Self::Execveat),
+            USERFAULTFD => Some(Self::Userfaultfd),
+            MEMBARRIER => Some(Self::Membarrier),
+            MLOCK2 => Some(Self::Mlock2),
+            COPY_FILE_RANGE => Some(Self::CopyFileRange),
+            PREADV2 => Some(Self::Preadv2),
+            PWRITEV2 => Some(Self::Pwritev2),
+            PKEY_MPROTECT => Some(Self::PkeyMprotect),
+            PKEY_ALLOC => Some(Self::PkeyAlloc),
+            PKEY_FREE => Some(Self::PkeyFree),
+            STATX => Some(Self::Statx),
+            IO_PGETEVENTS => Some(Self::IoPgetevents),
+            RSEQ => Some(Self::Rseq),
+            PIDFD_SEND_SIGNAL => Some(Self::PidfdSendSignal),
+            IO_URING_SETUP => Some(Self::IoUringSetup),
+            IO_URING_ENTER => Some(Self::IoUringEnter),
+            IO_URING_REGISTER => Some(Self::IoUringRegister),
+            OPEN_TREE => Some(Self::OpenTree),
+            MOVE_MOUNT => Some(Self::MoveMount),
+            FSOPEN => Some(Self::Fsopen),
+            FSCONFIG => Some(Self::Fsconfig),
+            FSMOUNT => Some(Self::Fsmount),
+            FSPICK => Some(Self::Fspick),
+            PIDFD_OPEN => Some(Self::PidfdOpen),
+            CLONE3 => Some(Self::Clone3),
+            CLOSE_RANGE => Some(Self::CloseRange),
+            OPENAT2 => Some(Self::Openat2),
+            PIDFD_GETFD => Some(Self::PidfdGetfd),
+            FACCESSAT2 => Some(Self::Faccessat2),
+            PROCESS_MADVISE => Some(Self::ProcessMadvise),
+            EPOLL_PWAIT2 => Some(Self::EpollPwait2),
+            MOUNT_SETATTR => Some(Self::MountSetattr),
+            QUOTACTL_FD => Some(Self::QuotactlFd),
+            LANDLOCK_CREATE_RULESET => Some(Self::LandlockCreateRuleset),
+            LANDLOCK_ADD_RULE => Some(Self::LandlockAddRule),
+            LANDLOCK_RESTRICT_SELF => Some(Self::LandlockRestrictSelf),
+            MEMFD_SECRET => Some(Self::MemfdSecret),
+            PROCESS_MRELEASE => Some(Self::ProcessMrelease),
+            FUTEX_WAITV => Some(Self::FutexWaitv),
+            SET_MEMPOLICY_HOME_NODE => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the system call with the given name, or `None` if it is not
+    /// known on this platform.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "read" => Some(Self::Read),
+            "write" => Some(Self::Write),
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "stat" => Some(Self::Stat),
+            "fstat" => Some(Self::Fstat),
+            "lstat" => Some(Self::Lstat),
+            "poll" => Some(Self::Poll),
+            "lseek" => Some(Self::Lseek),
+            "mmap" => Some(Self::Mmap),
+            "mprotect" => Some(Self::Mprotect),
+            "munmap" => Some(Self::Munmap),
+            "brk" => Some(Self::Brk),
+            "rt_sigaction" => Some(Self::RtSigaction),
+            "rt_sigprocmask" => Some(Self::RtSigprocmask),
+            "rt_sigreturn" => Some(Self::RtSigreturn),
+            "ioctl" => Some(Self::Ioctl),
+            "pread64" => Some(Self::Pread64),
+            "pwrite64" => Some(Self::Pwrite64),
+            "readv" => Some(Self::Readv),
+            "writev" => Some(Self::Writev),
+            "access" => Some(Self::Access),
+            "pipe" => Some(Self::Pipe),
+            "select" => Some(Self::Select),
+            "sched_yield" => Some(Self::SchedYield),
+            "mremap" => Some(Self::Mremap),
+            "msync" => Some(Self::Msync),
+            "mincore" => Some(Self::Mincore),
+            "madvise" => Some(Self::Madvise),
+            "shmget" => Some(Self::Shmget),
+            "shmat" => Some(Self::Shmat),
+            "shmctl" => Some(Self::Shmctl),
+            "dup" => Some(Self::Dup),
+            "dup2" => Some(Self::Dup2),
+            "pause" => Some(Self::Pause),
+            "nanosleep" => Some(Self::Nanosleep),
+            "getitimer" => Some(Self::Getitimer),
+            "alarm" => Some(Self::Alarm),
+            "setitimer" => Some(Self::Setitimer),
+            "getpid" => Some(Self::Getpid),
+            "sendfile" => Some(Self::Sendfile),
+            "socket" => Some(Self::Socket),
+            "connect" => Some(Self::Connect),
+            "accept" => Some(Self::Accept),
+            "sendto" => Some(Self::Sendto),
+            "recvfrom" => Some(Self::Recvfrom),
+            "sendmsg" => Some(Self::Sendmsg),
+            "recvmsg" => Some(Self::Recvmsg),
+            "shutdown" => Some(Self::Shutdown),
+            "bind" => Some(Self::Bind),
+            "listen" => Some(Self::Listen),
+            "getsockname" => Some(Self::Getsockname),
+            "getpeername" => Some(Self::Getpeername),
+            "socketpair" => Some(Self::Socketpair),
+            "setsockopt" => Some(Self::Setsockopt),
+            "getsockopt" => Some(Self::Getsockopt),
+            "clone" => Some(Self::Clone),
+            "fork" => Some(Self::Fork),
+            "vfork" => Some(Self::Vfork),
+            "execve" => Some(Self::Execve),
+            "exit" => Some(Self::Exit),
+            "wait4" => Some(Self::Wait4),
+            "kill" => Some(Self::Kill),
+            "uname" => Some(Self::Uname),
+            "semget" => Some(Self::Semget),
+            "semop" => Some(Self::Semop),
+            "semctl" => Some(Self::Semctl),
+            "shmdt" => Some(Self::Shmdt),
+            "msgget" => Some(Self::Msgget),
+            "msgsnd" => Some(Self::Msgsnd),
+            "msgrcv" => Some(Self::Msgrcv),
+            "msgctl" => Some(Self::Msgctl),
+            "fcntl" => Some(Self::Fcntl),
+            "flock" => Some(Self::Flock),
+            "fsync" => Some(Self::Fsync),
+            "fdatasync" => Some(Self::Fdatasync),
+            "truncate" => Some(Self::Truncate),
+            "ftruncate" => Some(Self::Ftruncate),
+            "getdents" => Some(Self::Getdents),
+            "getcwd" => Some(Self::Getcwd),
+            "chdir" => Some(Self::Chdir),
+            "fchdir" => Some(Self::Fchdir),
+            "rename" => Some(Self::Rename),
+            "mkdir" => Some(Self::Mkdir),
+            "rmdir" => Some(Self::Rmdir),
+            "creat" => Some(Self::Creat),
+            "link" => Some(Self::Link),
+            "unlink" => Some(Self::Unlink),
+            "symlink" => Some(Self::Symlink),
+            "readlink" => Some(Self::Readlink),
+            "chmod" => Some(Self::Chmod),
+            "fchmod" => Some(Self::Fchmod),
+            "chown" => Some(Self::Chown),
+            "fchown" => Some(Self::Fchown),
+            "lchown" => Some(Self::Lchown),
+            "umask" => Some(Self::Umask),
+            "gettimeofday" => Some(Self::Gettimeofday),
+            "getrlimit" => Some(Self::Getrlimit),
+            "getrusage" => Some(Self::Getrusage),
+            "sysinfo" => Some(Self::Sysinfo),
+            "times" => Some(Self::Times),
+            "ptrace" => Some(Self::Ptrace),
+            "getuid" => Some(Self::Getuid),
+            "syslog" => Some(Self::Syslog),
+            "getgid" => Some(Self::Getgid),
+            "setuid" => Some(Self::Setuid),
+            "setgid" => Some(Self::Setgid),
+            "geteuid" => Some(Self::Geteuid),
+            "getegid" => Some(Self::Getegid),
+            "setpgid" => Some(Self::Setpgid),
+            "getppid" => Some(Self::Getppid),
+            "getpgrp" => Some(Self::Getpgrp),
+            "setsid" => Some(Self::Setsid),
+            "setreuid" => Some(Self::Setreuid),
+            "setregid" => Some(Self::Setregid),
+            "getgroups" => Some(Self::Getgroups),
+            "setgroups" => Some(Self::Setgroups),
+            "setresuid" => Some(Self::Setresuid),
+            "getresuid" => Some(Self::Getresuid),
+            "setresgid" => Some(Self::Setresgid),
+            "getresgid" => Some(Self::Getresgid),
+            "getpgid" => Some(Self::Getpgid),
+            "setfsuid" => Some(Self::Setfsuid),
+            "setfsgid" => Some(Self::Setfsgid),
+            "getsid" => Some(Self::Getsid),
+            "capget" => Some(Self::Capget),
+            "capset" => Some(Self::Capset),
+            "rt_sigpending" => Some(Self::RtSigpending),
+            "rt_sigtimedwait" => Some(Self::RtSigtimedwait),
+            "rt_sigqueueinfo" => Some(Self::RtSigqueueinfo),
+            "rt_sigsuspend" => Some(Self::RtSigsuspend),
+            "sigaltstack" => Some(Self::Sigaltstack),
+            "utime" => Some(Self::Utime),
+            "mknod" => Some(Self::Mknod),
+            "uselib" => Some(Self::Uselib),
+            "personality" => Some(Self::Personality),
+            "ustat" => Some(Self::Ustat),
+            "statfs" => Some(Self::Statfs),
+            "fstatfs" => Some(Self::Fstatfs),
+            "sysfs" => Some(Self::Sysfs),
+            "getpriority" => Some(Self::Getpriority),
+            "setpriority" => Some(Self::Setpriority),
+            "sched_setparam" => Some(Self::SchedSetparam),
+            "sched_getparam" => Some(Self::SchedGetparam),
+            "sched_setscheduler" => Some(Self::SchedSetscheduler),
+            "sched_getscheduler" => Some(Self::SchedGetscheduler),
+            "sched_get_priority_max" => Some(Self::SchedGetPriorityMax),
+            "sched_get_priority_min" => Some(Self::SchedGetPriorityMin),
+            "sched_rr_get_interval" => Some(Self::SchedRrGetInterval),
+            "mlock" => Some(Self::Mlock),
+            "munlock" => Some(Self::Munlock),
+            "mlockall" => Some(Self::Mlockall),
+            "munlockall" => Some(Self::Munlockall),
+            "vhangup" => Some(Self::Vhangup),
+            "modify_ldt" => Some(Self::ModifyLdt),
+            "pivot_root" => Some(Self::PivotRoot),
+            "_sysctl" => Some(Self::Sysctl),
+            "prctl" => Some(Self::Prctl),
+            "arch_prctl" => Some(Self::ArchPrctl),
+            "adjtimex" => Some(Self::Adjtimex),
+            "setrlimit" => Some(Self::Setrlimit),
+            "chroot" => Some(Self::Chroot),
+            "sync" => Some(Self::Sync),
+            "acct" => Some(Self::Acct),
+            "settimeofday" => Some(Self::Settimeofday),
+            "mount" => Some(Self::Mount),
+            "umount2" => Some(Self::Umount2),
+            "swapon" => Some(Self::Swapon),
+            "swapoff" => Some(Self::Swapoff),
+            "reboot" => Some(Self::Reboot),
+            "sethostname" => Some(Self::Sethostname),
+            "setdomainname" => Some(Self::Setdomainname),
+            "iopl" => Some(Self::Iopl),
+            "ioperm" => Some(Self::Ioperm),
+            "create_module" => Some(Self::CreateModule),
+            "init_module" => Some(Self::InitModule),
+            "delete_module" => Some(Self::DeleteModule),
+            "get_kernel_syms" => Some(Self::GetKernelSyms),
+            "query_module" => Some(Self::QueryModule),
+            "quotactl" => Some(Self::Quotactl),
+            "nfsservctl" => Some(Self::Nfsservctl),
+            "getpmsg" => Some(Self::Getpmsg),
+            "putpmsg" => Some(Self::Putpmsg),
+            "afs_syscall" => Some(Self::AfsSyscall),
+            "tuxcall" => Some(Self::Tuxcall),
+            "security" => Some(Self::Security),
+            "gettid" => Some(Self::Gettid),
+            "readahead" => Some(Self::Readahead),
+            "setxattr" => Some(Self::Setxattr),
+            "lsetxattr" => Some(Self::Lsetxattr),
+            "fsetxattr" => Some(Self::Fsetxattr),
+            "getxattr" => Some(Self::Getxattr),
+            "lgetxattr" => Some(Self::Lgetxattr),
+            "fgetxattr" => Some(Self::Fgetxattr),
+            "listxattr" => Some(Self::Listxattr),
+            "llistxattr" => Some(Self::Llistxattr),
+            "flistxattr" => Some(Self::Flistxattr),
+            "removexattr" => Some(Self::Removexattr),
+            "lremovexattr" => Some(Self::Lremovexattr),
+            "fremovexattr" => Some(Self::Fremovexattr),
+            "tkill" => Some(Self::Tkill),
+            "time" => Some(Self::Time),
+            "futex" => Some(Self::Futex),
+            "sched_setaffinity" => Some(Self::SchedSetaffinity),
+            "sched_getaffinity" => Some(Self::SchedGetaffinity),
+            "set_thread_area" => Some(Self::SetThreadArea),
+            "io_setup" => Some(Self::IoSetup),
+            "io_destroy" => Some(Self::IoDestroy),
+            "io_getevents" => Some(Self::IoGetevents),
+            "io_submit" => Some(Self::IoSubmit),
+            "io_cancel" => Some(Self::IoCancel),
+            "get_thread_area" => Some(Self::GetThreadArea),
+            "lookup_dcookie" => Some(Self::LookupDcookie),
+            "epoll_create" => Some(Self::EpollCreate),
+            "epoll_ctl_old" => Some(Self::EpollCtlOld),
+            "epoll_wait_old" => Some(Self::EpollWaitOld),
+            "remap_file_pages" => Some(Self::RemapFilePages),
+            "getdents64" => Some(Self::Getdents64),
+            "set_tid_address" => Some(Self::SetTidAddress),
+            "restart_syscall" => Some(Self::RestartSyscall),
+            "semtimedop" => Some(Self::Semtimedop),
+            "fadvise64" => Some(Self::Fadvise64),
+            "timer_create" => Some(Self::TimerCreate),
+            "timer_settime" => Some(Self::TimerSettime),
+            "timer_gettime" => Some(Self::TimerGettime),
+            "timer_getoverrun" => Some(Self::TimerGetoverrun),
+            "timer_delete" => Some(Self::TimerDelete),
+            "clock_settime" => Some(Self::ClockSettime),
+            "clock_gettime" => Some(Self::ClockGettime),
+            "clock_getres" => Some(Self::ClockGetres),
+            "clock_nanosleep" => Some(Self::ClockNanosleep),
+            "exit_group" => Some(Self::ExitGroup),
+            "epoll_wait" => Some(Self::EpollWait),
+            "epoll_ctl" => Some(Self::EpollCtl),
+            "tgkill" => Some(Self::Tgkill),
+            "utimes" => Some(Self::Utimes),
+            "vserver" => Some(Self::Vserver),
+            "mbind" => Some(Self::Mbind),
+            "set_mempolicy" => Some(Self::SetMempolicy),
+            "get_mempolicy" => Some(Self::GetMempolicy),
+            "mq_open" => Some(Self::MqOpen),
+            "mq_unlink" => Some(Self::MqUnlink),
+            "mq_timedsend" => Some(Self::MqTimedsend),
+            "mq_timedreceive" => Some(Self::MqTimedreceive),
+            "mq_notify" => Some(Self::MqNotify),
+            "mq_getsetattr" => Some(Self::MqGetsetattr),
+            "kexec_load" => Some(Self::KexecLoad),
+            "waitid" => Some(Self::Waitid),
+            "add_key" => Some(Self::AddKey),
+            "request_key" => Some(Self::RequestKey),
+            "keyctl" => Some(Self::Keyctl),
+            "ioprio_set" => Some(Self::IoprioSet),
+            "ioprio_get" => Some(Self::IoprioGet),
+            "inotify_init" => Some(Self::InotifyInit),
+            "inotify_add_watch" => Some(Self::InotifyAddWatch),
+            "inotify_rm_watch" => Some(Self::InotifyRmWatch),
+            "migrate_pages" => Some(Self::MigratePages),
+            "openat" => Some(Self::Openat),
+            "mkdirat" => Some(Self::Mkdirat),
+            "mknodat" => Some(Self::Mknodat),
+            "fchownat" => Some(Self::Fchownat),
+            "futimesat" => Some(Self::Futimesat),
+            "newfstatat" => Some(Self::Newfstatat),
+            "unlinkat" => Some(Self::Unlinkat),
+            "renameat" => Some(Self::Renameat),
+            "linkat" => Some(Self::Linkat),
+            "symlinkat" => Some(Self::Symlinkat),
+            "readlinkat" => Some(Self::Readlinkat),
+            "fchmodat" => Some(Self::Fchmodat),
+            "faccessat" => Some(Self::Faccessat),
+            "pselect6" => Some(Self::Pselect6),
+            "ppoll" => Some(Self::Ppoll),
+            "unshare" => Some(Self::Unshare),
+            "set_robust_list" => Some(Self::SetRobustList),
+            "get_robust_list" => Some(Self::GetRobustList),
+            "splice" => Some(Self::Splice),
+            "tee" => Some(Self::Tee),
+            "sync_file_range" => Some(Self::SyncFileRange),
+            "vmsplice" => Some(Self::Vmsplice),
+            "move_pages" => Some(Self::MovePages),
+            "utimensat" => Some(Self::Utimensat),
+            "epoll_pwait" => Some(Self::EpollPwait),
+            "signalfd" => Some(Self::Signalfd),
+            "timerfd_create" => Some(Self::TimerfdCreate),
+            "eventfd" => Some(Self::Eventfd),
+            "fallocate" => Some(Self::Fallocate),
+            "timerfd_settime" => Some(Self::TimerfdSettime),
+            "timerfd_gettime" => Some(Self::TimerfdGettime),
+            "accept4" => Some(Self::Accept4),
+            "signalfd4" => Some(Self::Signalfd4),
+            "eventfd2" => Some(Self::Eventfd2),
+            "epoll_create1" => Some(Self::EpollCreate1),
+            "dup3" => Some(Self::Dup3),
+            "pipe2" => Some(Self::Pipe2),
+            "inotify_init1" => Some(Self::InotifyInit1),
+            "preadv" => Some(Self::Preadv),
+            "pwritev" => Some(Self::Pwritev),
+            "rt_tgsigqueueinfo" => Some(Self::RtTgsigqueueinfo),
+            "perf_event_open" => Some(Self::PerfEventOpen),
+            "recvmmsg" => Some(Self::Recvmmsg),
+            "fanotify_init" => Some(Self::FanotifyInit),
+            "fanotify_mark" => Some(Self::FanotifyMark),
+            "prlimit64" => Some(Self::Prlimit64),
+            "name_to_handle_at" => Some(Self::NameToHandleAt),
+            "open_by_handle_at" => Some(Self::OpenByHandleAt),
+            "clock_adjtime" => Some(Self::ClockAdjtime),
+            "syncfs" => Some(Self::Syncfs),
+            "sendmmsg" => Some(Self::Sendmmsg),
+            "setns" => Some(Self::Setns),
+            "getcpu" => Some(Self::Getcpu),
+            "process_vm_readv" => Some(Self::ProcessVmReadv),
+            "process_vm_writev" => Some(Self::ProcessVmWritev),
+            "kcmp" => Some(Self::Kcmp),
+            "finit_module" => Some(Self::FinitModule),
+            "sched_setattr" => Some(Self::SchedSetattr),
+            "sched_getattr" => Some(Self::SchedGetattr),
+            "renameat2" => Some(Self::Renameat2),
+            "seccomp" => Some(Self::Seccomp),
+            "getrandom" => Some(Self::Getrandom),
+            "memfd_create" => Some(Self::MemfdCreate),
+            "kexec_file_load" => Some(Self::KexecFileLoad),
+            "bpf" => Some(Self::Bpf),
+            "execveat" => Some(Self::Execveat),
+            "userfaultfd" => Some(Self::Userfaultfd),
+            "membarrier" => Some(Self::Membarrier),
+            "mlock2" => Some(Self::Mlock2),
+            "copy_file_range" => Some(Self::CopyFileRange),
+            "preadv2" => Some(Self::Preadv2),
+            "pwritev2" => Some(Self::Pwritev2),
+            "pkey_mprotect" => Some(Self::PkeyMprotect),
+            "pkey_alloc" => Some(Self::PkeyAlloc),
+            "pkey_free" => Some(Self::PkeyFree),
+            "statx" => Some(Self::Statx),
+            "io_pgetevents" => Some(Self::IoPgetevents),
+            "rseq" => Some(Self::Rseq),
+            "pidfd_send_signal" => Some(Self::PidfdSendSignal),
+            "io_uring_setup" => Some(Self::IoUringSetup),
+            "io_uring_enter" => Some(Self::IoUringEnter),
+            "io_uring_register" => Some(Self::IoUringRegister),
+            "open_tree" => Some(Self::OpenTree),
+            "move_mount" => Some(Self::MoveMount),
+            "fsopen" => Some(Self::Fsopen),
+            "fsconfig" => Some(Self::Fsconfig),
+            "fsmount" => Some(Self::Fsmount),
+            "fspick" => Some(Self::Fspick),
+            "pidfd_open" => Some(Self::PidfdOpen),
+            "clone3" => Some(Self::Clone3),
+            "close_range" => Some(Self::CloseRange),
+            "openat2" => Some(Self::Openat2),
+            "pidfd_getfd" => Some(Self::PidfdGetfd),
+            "faccessat2" => Some(Self::Faccessat2),
+            "process_madvise" => Some(Self::ProcessMadvise),
+            "epoll_pwait2" => Some(Self::EpollPwait2),
+            "mount_setattr" => Some(Self::MountSetattr),
+            "quotactl_fd" => Some(Self::QuotactlFd),
+            "landlock_create_ruleset" => Some(Self::LandlockCreateRuleset),
+            "landlock_add_rule" => Some(Self::LandlockAddRule),
+            "landlock_restrict_self" => Some(Self::LandlockRestrictSelf),
+            "memfd_secret" => Some(Self::MemfdSecret),
+            "process_mrelease" => Some(Self::ProcessMrelease),
+            "futex_waitv" => Some(Self::FutexWaitv),
+            "set_mempolicy_home_node" => Some(Self::SetMempolicyHomeNode),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the system call.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn raw(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the kernel's name for the system call, such as `"read"`.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Stat => "stat",
+            Self::Fstat => "fstat",
+            Self::Lstat => "lstat",
+            Self::Poll => "poll",
+            Self::Lseek => "lseek",
+            Self::Mmap => "mmap",
+            Self::Mprotect => "mprotect",
+            Self::Munmap => "munmap",
+            Self::Brk => "brk",
+            Self::RtSigaction => "rt_sigaction",
+            Self::RtSigprocmask => "rt_sigprocmask",
+            Self::RtSigreturn => "rt_sigreturn",
+            Self::Ioctl => "ioctl",
+            Self::Pread64 => "pread64",
+            Self::Pwrite64 => "pwrite64",
+            Self::Readv => "readv",
+            Self::Writev => "writev",
+            Self::Access => "access",
+            Self::Pipe => "pipe",
+            Self::Select => "select",
+            Self::SchedYield => "sched_yield",
+            Self::Mremap => "mremap",
+            Self::Msync => "msync",
+            Self::Mincore => "mincore",
+            Self::Madvise => "madvise",
+            Self::Shmget => "shmget",
+            Self::Shmat => "shmat",
+            Self::Shmctl => "shmctl",
+            Self::Dup => "dup",
+            Self::Dup2 => "dup2",
+            Self::Pause => "pause",
+            Self::Nanosleep => "nanosleep",
+            Self::Getitimer => "getitimer",
+            Self::Alarm => "alarm",
+            Self::Setitimer => "setitimer",
+            Self::Getpid => "getpid",
+            Self::Sendfile => "sendfile",
+            Self::Socket => "socket",
+            Self::Connect => "connect",
+            Self::Accept => "accept",
+            Self::Sendto => "sendto",
+            Self::Recvfrom => "recvfrom",
+            Self::Sendmsg => "sendmsg",
+            Self::Recvmsg => "recvmsg",
+            Self::Shutdown => "shutdown",
+            Self::Bind => "bind",
+            Self::Listen => "listen",
+            Self::Getsockname => "getsockname",
+            Self::Getpeername => "getpeername",
+            Self::Socketpair => "socketpair",
+            Self::Setsockopt => "setsockopt",
+            Self::Getsockopt => "getsockopt",
+            Self::Clone => "clone",
+            Self::Fork => "fork",
+            Self::Vfork => "vfork",
+            Self::Execve => "execve",
+            Self::Exit => "exit",
+            Self::Wait4 => "wait4",
+            Self::Kill => "kill",
+            Self::Uname => "uname",
+            Self::Semget => "semget",
+            Self::Semop => "semop",
+            Self::Semctl => "semctl",
+            Self::Shmdt => "shmdt",
+            Self::Msgget => "msgget",
+            Self::Msgsnd => "msgsnd",
+            Self::Msgrcv => "msgrcv",
+            Self::Msgctl => "msgctl",
+            Self::Fcntl => "fcntl",
+            Self::Flock => "flock",
+            Self::Fsync => "fsync",
+            Self::Fdatasync => "fdatasync",
+            Self::Truncate => "truncate",
+            Self::Ftruncate => "ftruncate",
+            Self::Getdents => "getdents",
+            Self::Getcwd => "getcwd",
+            Self::Chdir => "chdir",
+            Self::Fchdir => "fchdir",
+            Self::Rename => "rename",
+            Self::Mkdir => "mkdir",
+            Self::Rmdir => "rmdir",
+            Self::Creat => "creat",
+            Self::Link => "link",
+            Self::Unlink => "unlink",
+            Self::Symlink => "symlink",
+            Self::Readlink => "readlink",
+            Self::Chmod => "chmod",
+            Self::Fchmod => "fchmod",
+            Self::Chown => "chown",
+            Self::Fchown => "fchown",
+            Self::Lchown => "lchown",
+            Self::Umask => "umask",
+            Self::Gettimeofday => "gettimeofday",
+            Self::Getrlimit => "getrlimit",
+            Self::Getrusage => "getrusage",
+            Self::Sysinfo => "sysinfo",
+            Self::Times => "times",
+            Self::Ptrace => "ptrace",
+            Self::Getuid => "getuid",
+            Self::Syslog => "syslog",
+            Self::Getgid => "getgid",
+            Self::Setuid => "setuid",
+            Self::Setgid => "setgid",
+            Self::Geteuid => "geteuid",
+            Self::Getegid => "getegid",
+            Self::Setpgid => "setpgid",
+            Self::Getppid => "getppid",
+            Self::Getpgrp => "getpgrp",
+            Self::Setsid => "setsid",
+            Self::Setreuid => "setreuid",
+            Self::Setregid => "setregid",
+            Self::Getgroups => "getgroups",
+            Self::Setgroups => "setgroups",
+            Self::Setresuid => "setresuid",
+            Self::Getresuid => "getresuid",
+            Self::Setresgid => "setresgid",
+            Self::Getresgid => "getresgid",
+            Self::Getpgid => "getpgid",
+            Self::Setfsuid => "setfsuid",
+            Self::Setfsgid => "setfsgid",
+            Self::Getsid => "getsid",
+            Self::Capget => "capget",
+            Self::Capset => "capset",
+            Self::RtSigpending => "rt_sigpending",
+            Self::RtSigtimedwait => "rt_sigtimedwait",
+            Self::RtSigqueueinfo => "rt_sigqueueinfo",
+            Self::RtSigsuspend => "rt_sigsuspend",
+            Self::Sigaltstack => "sigaltstack",
+            Self::Utime => "utime",
+            Self::Mknod => "mknod",
+            Self::Uselib => "uselib",
+            Self::Personality => "personality",
+            Self::Ustat => "ustat",
+            Self::Statfs => "statfs",
+            Self::Fstatfs => "fstatfs",
+            Self::Sysfs => "sysfs",
+            Self::Getpriority => "getpriority",
+            Self::Setpriority => "setpriority",
+            Self::SchedSetparam => "sched_setparam",
+            Self::SchedGetparam => "sched_getparam",
+            Self::SchedSetscheduler => "sched_setscheduler",
+            Self::SchedGetscheduler => "sched_getscheduler",
+            Self::SchedGetPriorityMax => "sched_get_priority_max",
+            Self::SchedGetPriorityMin => "sched_get_priority_min",
+            Self::SchedRrGetInterval => "sched_rr_get_interval",
+            Self::Mlock => "mlock",
+            Self::Munlock => "munlock",
+            Self::Mlockall => "mlockall",
+            Self::Munlockall => "munlockall",
+            Self::Vhangup => "vhangup",
+            Self::ModifyLdt => "modify_ldt",
+            Self::PivotRoot => "pivot_root",
+            Self::Sysctl => "_sysctl",
+            Self::Prctl => "prctl",
+            Self::ArchPrctl => "arch_prctl",
+            Self::Adjtimex => "adjtimex",
+            Self::Setrlimit => "setrlimit",
+            Self::Chroot => "chroot",
+            Self::Sync => "sync",
+            Self::Acct => "acct",
+            Self::Settimeofday => "settimeofday",
+            Self::Mount => "mount",
+            Self::Umount2 => "umount2",
+            Self::Swapon => "swapon",
+            Self::Swapoff => "swapoff",
+            Self::Reboot => "reboot",
+            Self::Sethostname => "sethostname",
+            Self::Setdomainname => "setdomainname",
+            Self::Iopl => "iopl",
+            Self::Ioperm => "ioperm",
+            Self::CreateModule => "create_module",
+            Self::InitModule => "init_module",
+            Self::DeleteModule => "delete_module",
+            Self::GetKernelSyms => "get_kernel_syms",
+            Self::QueryModule => "query_module",
+            Self::Quotactl => "quotactl",
+            Self::Nfsservctl => "nfsservctl",
+            Self::Getpmsg => "getpmsg",
+            Self::Putpmsg => "putpmsg",
+            Self::AfsSyscall => "afs_syscall",
+            Self::Tuxcall => "tuxcall",
+            Self::Security => "security",
+            Self::Gettid => "gettid",
+            Self::Readahead => "readahead",
+            Self::Setxattr => "setxattr",
+            Self::Lsetxattr => "lsetxattr",
+            Self::Fsetxattr => "fsetxattr",
+            Self::Getxattr => "getxattr",
+            Self::Lgetxattr => "lgetxattr",
+            Self::Fgetxattr => "fgetxattr",
+            Self::Listxattr => "listxattr",
+            Self::Llistxattr => "llistxattr",
+            Self::Flistxattr => "flistxattr",
+            Self::Removexattr => "removexattr",
+            Self::Lremovexattr => "lremovexattr",
+            Self::Fremovexattr => "fremovexattr",
+            Self::Tkill => "tkill",
+            Self::Time => "time",
+            Self::Futex => "futex",
+            Self::SchedSetaffinity => "sched_setaffinity",
+            Self::SchedGetaffinity => "sched_getaffinity",
+            Self::SetThreadArea => "set_thread_area",
+            Self::IoSetup => "io_setup",
+            Self::IoDestroy => "io_destroy",
+            Self::IoGetevents => "io_getevents",
+            Self::IoSubmit => "io_submit",
+            Self::IoCancel => "io_cancel",
+            Self::GetThreadArea => "get_thread_area",
+            Self::LookupDcookie => "lookup_dcookie",
+            Self::EpollCreate => "epoll_create",
+            Self::EpollCtlOld => "epoll_ctl_old",
+            Self::EpollWaitOld => "epoll_wait_old",
+            Self::RemapFilePages => "remap_file_pages",
+            Self::Getdents64 => "getdents64",
+            Self::SetTidAddress => "set_tid_address",
+            Self::RestartSyscall => "restart_syscall",
+            Self::Semtimedop => "semtimedop",
+            Self::Fadvise64 => "fadvise64",
+            Self::TimerCreate => "timer_create",
+            Self::TimerSettime => "timer_settime",
+            Self::TimerGettime => "timer_gettime",
+            Self::TimerGetoverrun => "timer_getoverrun",
+            Self::TimerDelete => "timer_delete",
+            Self::ClockSettime => "clock_settime",
+            Self::ClockGettime => "clock_gettime",
+            Self::ClockGetres => "clock_getres",
+            Self::ClockNanosleep => "clock_nanosleep",
+            Self::ExitGroup => "exit_group",
+            Self::EpollWait => "epoll_wait",
+            Self::EpollCtl => "epoll_ctl",
+            Self::Tgkill => "tgkill",
+            Self::Utimes => "utimes",
+            Self::Vserver => "vserver",
+            Self::Mbind => "mbind",
+            Self::SetMempolicy => "set_mempolicy",
+            Self::GetMempolicy => "get_mempolicy",
+            Self::MqOpen => "mq_open",
+            Self::MqUnlink => "mq_unlink",
+            Self::MqTimedsend => "mq_timedsend",
+            Self::MqTimedreceive => "mq_timedreceive",
+            Self::MqNotify => "mq_notify",
+            Self::MqGetsetattr => "mq_getsetattr",
+            Self::KexecLoad => "kexec_load",
+            Self::Waitid => "waitid",
+            Self::AddKey => "add_key",
+            Self::RequestKey => "request_key",
+            Self::Keyctl => "keyctl",
+            Self::IoprioSet => "ioprio_set",
+            Self::IoprioGet => "ioprio_get",
+            Self::InotifyInit => "inotify_init",
+            Self::InotifyAddWatch => "inotify_add_watch",
+            Self::InotifyRmWatch => "inotify_rm_watch",
+            Self::MigratePages => "migrate_pages",
+            Self::Openat => "openat",
+            Self::Mkdirat => "mkdirat",
+            Self::Mknodat => "mknodat",
+            Self::Fchownat => "fchownat",
+            Self::Futimesat => "futimesat",
+            Self::Newfstatat => "newfstatat",
+            Self::Unlinkat => "unlinkat",
+            Self::Renameat => "renameat",
+            Self::Linkat => "linkat",
+            Self::Symlinkat => "symlinkat",
+            Self::Readlinkat => "readlinkat",
+            Self::Fchmodat => "fchmodat",
+            Self::Faccessat => "faccessat",
+            Self::Pselect6 => "pselect6",
+            Self::Ppoll => "ppoll",
+            Self::Unshare => "unshare",
+            Self::SetRobustList => "set_robust_list",
+            Self::GetRobustList => "get_robust_list",
+            Self::Splice => "splice",
+            Self::Tee => "tee",
+            Self::SyncFileRange => "sync_file_range",
+            Self::Vmsplice => "vmsplice",
+            Self::MovePages => "move_pages",
+            Self::Utimensat => "utimensat",
+            Self::EpollPwait => "epoll_pwait",
+            Self::Signalfd => "signalfd",
+            Self::TimerfdCreate => "timerfd_create",
+            Self::Eventfd => "eventfd",
+            Self::Fallocate => "fallocate",
+            Self::TimerfdSettime => "timerfd_settime",
+            Self::TimerfdGettime => "timerfd_gettime",
+            Self::Accept4 => "accept4",
+            Self::Signalfd4 => "signalfd4",
+            Self::Eventfd2 => "eventfd2",
+            Self::EpollCreate1 => "epoll_create1",
+            Self::Dup3 => "dup3",
+            Self::Pipe2 => "pipe2",
+            Self::InotifyInit1 => "inotify_init1",
+            Self::Preadv => "preadv",
+            Self::Pwritev => "pwritev",
+            Self::RtTgsigqueueinfo => "rt_tgsigqueueinfo",
+            Self::PerfEventOpen => "perf_event_open",
+            Self::Recvmmsg => "recvmmsg",
+            Self::FanotifyInit => "fanotify_init",
+            Self::FanotifyMark => "fanotify_mark",
+            Self::Prlimit64 => "prlimit64",
+            Self::NameToHandleAt => "name_to_handle_at",
+            Self::OpenByHandleAt => "open_by_handle_at",
+            Self::ClockAdjtime => "clock_adjtime",
+            Self::Syncfs => "syncfs",
+            Self::Sendmmsg => "sendmmsg",
+            Self::Setns => "setns",
+            Self::Getcpu => "getcpu",
+            Self::ProcessVmReadv => "process_vm_readv",
+            Self::ProcessVmWritev => "process_vm_writev",
+            Self::Kcmp => "kcmp",
+            Self::FinitModule => "finit_module",
+            Self::SchedSetattr => "sched_setattr",
+            Self::SchedGetattr => "sched_getattr",
+            Self::Renameat2 => "renameat2",
+            Self::Seccomp => "seccomp",
+            Self::Getrandom => "getrandom",
+            Self::MemfdCreate => "memfd_create",
+            Self::KexecFileLoad => "kexec_file_load",
+            Self::Bpf => "bpf",
+            Self::Execveat => "execveat",
+            Self::Userfaultfd => "userfaultfd",
+            Self::Membarrier => "membarrier",
+            Self::Mlock2 => "mlock2",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Preadv2 => "preadv2",
+            Self::Pwritev2 => "pwritev2",
+            Self::PkeyMprotect => "pkey_mprotect",
+            Self::PkeyAlloc => "pkey_alloc",
+            Self::PkeyFree => "pkey_free",
+            Self::Statx => "statx",
+            Self::IoPgetevents => "io_pgetevents",
+            Self::Rseq => "rseq",
+            Self::PidfdSendSignal => "pidfd_send_signal",
+            Self::IoUringSetup => "io_uring_setup",
+            Self::IoUringEnter => "io_uring_enter",
+            Self::IoUringRegister => "io_uring_register",
+            Self::OpenTree => "open_tree",
+            Self::MoveMount => "move_mount",
+            Self::Fsopen => "fsopen",
+            Self::Fsconfig => "fsconfig",
+            Self::Fsmount => "fsmount",
+            Self::Fspick => "fspick",
+            Self::PidfdOpen => "pidfd_open",
+            Self::Clone3 => "clone3",
+            Self::CloseRange => "close_range",
+            Self::Openat2 => "openat2",
+            Self::PidfdGetfd => "pidfd_getfd",
+            Self::Faccessat2 => "faccessat2",
+            Self::ProcessMadvise => "process_madvise",
+            Self::EpollPwait2 => "epoll_pwait2",
+            Self::MountSetattr => "mount_setattr",
+            Self::QuotactlFd => "quotactl_fd",
+            Self::LandlockCreateRuleset => "landlock_create_ruleset",
+            Self::LandlockAddRule => "landlock_add_rule",
+            Self::LandlockRestrictSelf => "landlock_restrict_self",
+            Self::MemfdSecret => "memfd_secret",
+            Self::ProcessMrelease => "process_mrelease",
+            Self::FutexWaitv => "futex_waitv",
+            Self::SetMempolicyHomeNode => "set_mempolicy_home_node",
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use crate::Errno;
+use ::core::{
+    arch::asm,
+    hint::unreachable_unchecked,
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+};
+
+/// Summary overflow bit of `cr0` as read by `mfcr`, set by the kernel when a
+/// system call fails.
+///
+const CR0_SO: usize = 0x1000_0000;
+
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
+#[inline(always)]
+pub unsafe fn syscall(r0: usize, a: &[usize]) -> Result<usize, Errno> {
+    match a.len() {
+        0 => syscall_0(r0),
+        1 => syscall_1(r0, a[0]),
+        2 => syscall_2(r0, a[0], a[1]),
+        3 => syscall_3(r0, a[0], a[1], a[2]),
+        4 => syscall_4(r0, a[0], a[1], a[2], a[3]),
+        5 => syscall_5(r0, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6(r0, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_nr(r0: usize, a: &[usize]) -> ! {
+    match a.len() {
+        0 => syscall_0_nr(r0),
+        1 => syscall_1_nr(r0, a[0]),
+        2 => syscall_2_nr(r0, a[0], a[1]),
+        3 => syscall_3_nr(r0, a[0], a[1], a[2]),
+        4 => syscall_4_nr(r0, a[0], a[1], a[2], a[3]),
+        5 => syscall_5_nr(r0, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6_nr(r0, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call with no arguments and returns the result.
+///
+/// The argument specifies the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_0(r0: usize) -> Result<usize, Errno> {
+    let r3: usize;
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        lateout("r3") r3,
+        lateout("r4") _,
+        lateout("r5") _,
+        lateout("r6") _,
+        lateout("r7") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with no arguments which never returns.
+///
+/// The argument specifies the system call.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_nr(r0: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with one argument and returns the result.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_1(r0: usize, mut r3: usize) -> Result<usize, Errno> {
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        inlateout("r3") r3,
+        lateout("r4") _,
+        lateout("r5") _,
+        lateout("r6") _,
+        lateout("r7") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with one argument and never returns.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_nr(r0: usize, r3: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        in("r3") r3,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with two arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_2(r0: usize, mut r3: usize, r4: usize) -> Result<usize, Errno> {
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        inlateout("r3") r3,
+        inlateout("r4") r4 => _,
+        lateout("r5") _,
+        lateout("r6") _,
+        lateout("r7") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with two arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_nr(r0: usize, r3: usize, r4: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        in("r3") r3,
+        in("r4") r4,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with three arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_3(r0: usize, mut r3: usize, r4: usize, r5: usize) -> Result<usize, Errno> {
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        inlateout("r3") r3,
+        inlateout("r4") r4 => _,
+        inlateout("r5") r5 => _,
+        lateout("r6") _,
+        lateout("r7") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with three arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_nr(r0: usize, r3: usize, r4: usize, r5: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with four arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_4(r0: usize, mut r3: usize, r4: usize, r5: usize, r6: usize) -> Result<usize, Errno> {
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        inlateout("r3") r3,
+        inlateout("r4") r4 => _,
+        inlateout("r5") r5 => _,
+        inlateout("r6") r6 => _,
+        lateout("r7") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with four arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_nr(r0: usize, r3: usize, r4: usize, r5: usize, r6: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        in("r6") r6,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with five arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_5(r0: usize, mut r3: usize, r4: usize, r5: usize, r6: usize, r7: usize) -> Result<usize, Errno> {
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        inlateout("r3") r3,
+        inlateout("r4") r4 => _,
+        inlateout("r5") r5 => _,
+        inlateout("r6") r6 => _,
+        inlateout("r7") r7 => _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with five arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_nr(r0: usize, r3: usize, r4: usize, r5: usize, r6: usize, r7: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        in("r6") r6,
+        in("r7") r7,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with six arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_6(
+    r0: usize,
+    mut r3: usize,
+    r4: usize,
+    r5: usize,
+    r6: usize,
+    r7: usize,
+    r8: usize,
+) -> Result<usize, Errno>
+{
+    let cr: usize;
+    asm!(
+        "sc",
+        "mfcr {cr}",
+        cr = lateout(reg) cr,
+        inlateout("r0") r0 => _,
+        inlateout("r3") r3,
+        inlateout("r4") r4 => _,
+        inlateout("r5") r5 => _,
+        inlateout("r6") r6 => _,
+        inlateout("r7") r7 => _,
+        inlateout("r8") r8 => _,
+        lateout("r9") _,
+        lateout("r10") _,
+        lateout("r11") _,
+        lateout("r12") _,
+        lateout("cr0") _,
+        lateout("ctr") _,
+        lateout("xer") _,
+        options(nostack),
+    );
+    if cr & CR0_SO == 0 {
+        Ok(r3)
+    } else {
+        Err(Errno::new(r3))
+    }
+}
+
+/// Performs a system call with six arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_nr(r0: usize, r3: usize, r4: usize, r5: usize, r6: usize, r7: usize, r8: usize) -> ! {
+    asm!(
+        "sc",
+        in("r0") r0,
+        in("r3") r3,
+        in("r4") r4,
+        in("r5") r5,
+        in("r6") r6,
+        in("r7") r7,
+        in("r8") r8,
+        options(noreturn, nostack),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(r0);
+    syscall_1_as = syscall_1(r0, r3);
+    syscall_2_as = syscall_2(r0, r3, r4);
+    syscall_3_as = syscall_3(r0, r3, r4, r5);
+    syscall_4_as = syscall_4(r0, r3, r4, r5, r6);
+    syscall_5_as = syscall_5(r0, r3, r4, r5, r6, r7);
+    syscall_6_as = syscall_6(r0, r3, r4, r5, r6, r7, r8);
+}
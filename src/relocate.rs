@@ -0,0 +1,535 @@
+//! Self-relocation of static position independent executables.
+//!
+//! A static PIE has no interpreter to apply its relocations, so `_start` does
+//! it before running any other code. The program headers are found through
+//! `AT_PHDR`, and their `PT_DYNAMIC` entry gives the link-time address of the
+//! dynamic section, whose relative relocations are applied. The load offset
+//! is the difference between `AT_PHDR` and the address of the `PT_PHDR` entry,
+//! or if the linker didn't emit one, between the run-time address of
+//! `_DYNAMIC`, found relative to the program counter, and that of the dynamic
+//! section. GNU ld, gold and lld define `_DYNAMIC` in every image with a
+//! dynamic section, and the fallback relies on this.
+//!
+//! Nothing is done if `AT_BASE` is non-zero, since the interpreter it refers
+//! to has already relocated the program, or if the program has no dynamic
+//! section, as in a static non-PIE build.
+//!
+//! Until the relocations are applied, neither data holding addresses nor the
+//! global offset table may be used. Unoptimized builds call generic functions
+//! of `core` through the global offset table, so this module only uses integer
+//! arithmetic and plain dereferences.
+//!
+
+use crate::{
+    auxv::{
+        AT_BASE,
+        AT_NULL,
+        AT_PHDR,
+        AT_PHNUM,
+    },
+    elf::{
+        Dyn,
+        Phdr,
+        DT_NULL,
+        PT_DYNAMIC,
+    },
+};
+use ::core::{
+    arch::asm,
+    mem,
+    option::Option::{
+        None,
+        Some,
+    },
+};
+
+const PT_PHDR: u32 = 6;
+
+const DT_RELA: isize = 7;
+const DT_RELASZ: isize = 8;
+const DT_REL: isize = 17;
+const DT_RELSZ: isize = 18;
+const DT_RELRSZ: isize = 35;
+const DT_RELR: isize = 36;
+
+/// The relocation type adding the load offset to the addend.
+///
+#[cfg(target_arch = "aarch64")]
+const R_RELATIVE: usize = 1027;
+
+/// The relocation type adding the load offset to the addend.
+///
+#[cfg(target_arch = "arm")]
+const R_RELATIVE: usize = 23;
+
+/// The relocation type adding the load offset to the addend.
+///
+#[cfg(target_arch = "x86_64")]
+const R_RELATIVE: usize = 8;
+
+/// A relocation with an implicit addend, read from the relocated location.
+///
+#[repr(C)]
+struct Rel {
+    r_offset: usize,
+    r_info: usize,
+}
+
+/// A relocation with an explicit addend.
+///
+#[repr(C)]
+#[allow(clippy::struct_field_names)]
+struct Rela {
+    r_offset: usize,
+    r_info: usize,
+    r_addend: isize,
+}
+
+/// Returns the relocation type held in `r_info`.
+///
+#[cfg(target_pointer_width = "32")]
+#[inline(always)]
+fn r_type(info: usize) -> usize {
+    info & 0xff
+}
+
+/// Returns the relocation type held in `r_info`.
+///
+#[cfg(target_pointer_width = "64")]
+#[inline(always)]
+fn r_type(info: usize) -> usize {
+    info & 0xffff_ffff
+}
+
+const WORD: usize = mem::size_of::<usize>();
+const PHDR: usize = mem::size_of::<Phdr>();
+const DYN: usize = mem::size_of::<Dyn>();
+const REL: usize = mem::size_of::<Rel>();
+const RELA: usize = mem::size_of::<Rela>();
+
+/// Applies the relative relocations of the running executable, given the
+/// initial stack pointer, which points to the argument count.
+///
+/// This is kept out of line so that no access to relocated data in the caller
+/// can be scheduled before it.
+///
+#[inline(never)]
+pub unsafe fn relocate(sp: *const usize) {
+    let mut auxv = sp as usize + (*sp + 2) * WORD;
+    while *(auxv as *const usize) != 0 {
+        auxv += WORD;
+    }
+    auxv += WORD;
+
+    let mut phdrs = 0;
+    let mut phnum = 0;
+    loop {
+        let key = *(auxv as *const usize);
+        let value = *((auxv + WORD) as *const usize);
+        match key {
+            AT_NULL => break,
+            AT_BASE if value != 0 => return,
+            AT_PHDR => phdrs = value,
+            AT_PHNUM => phnum = value,
+            _ => {}
+        }
+        auxv += 2 * WORD;
+    }
+
+    let mut phdr_offset = None;
+    let mut dynamic = None;
+    let mut i = 0;
+    while i < phnum {
+        let phdr = &*((phdrs + i * PHDR) as *const Phdr);
+        if phdr.p_type == PT_PHDR {
+            phdr_offset = Some(phdrs.wrapping_sub(phdr.p_vaddr));
+        } else if phdr.p_type == PT_DYNAMIC {
+            dynamic = Some(phdr.p_vaddr);
+        }
+        i += 1;
+    }
+    let dynamic = match dynamic {
+        Some(dynamic) => dynamic,
+        None => return,
+    };
+    let load_offset = match phdr_offset {
+        Some(load_offset) => load_offset,
+        None => dynamic_address().wrapping_sub(dynamic),
+    };
+    apply(load_offset, load_offset.wrapping_add(dynamic));
+}
+
+/// Applies the relative relocations listed in the dynamic section at the given
+/// run-time address, given the load offset.
+///
+#[inline(always)]
+#[allow(clippy::cast_sign_loss)]
+unsafe fn apply(load_offset: usize, dynamic: usize) {
+    let (mut rel, mut relsz) = (0, 0);
+    let (mut rela, mut relasz) = (0, 0);
+    let (mut relr, mut relrsz) = (0, 0);
+    let mut entry = dynamic;
+    loop {
+        let dyn_ = &*(entry as *const Dyn);
+        match dyn_.d_tag {
+            DT_NULL => break,
+            DT_REL => rel = dyn_.d_val,
+            DT_RELSZ => relsz = dyn_.d_val,
+            DT_RELA => rela = dyn_.d_val,
+            DT_RELASZ => relasz = dyn_.d_val,
+            DT_RELR => relr = dyn_.d_val,
+            DT_RELRSZ => relrsz = dyn_.d_val,
+            _ => {}
+        }
+        entry += DYN;
+    }
+
+    let mut i = 0;
+    while i < relsz {
+        let rel = &*(load_offset.wrapping_add(rel + i) as *const Rel);
+        if r_type(rel.r_info) == R_RELATIVE {
+            let place = load_offset.wrapping_add(rel.r_offset) as *mut usize;
+            *place = (*place).wrapping_add(load_offset);
+        }
+        i += REL;
+    }
+
+    let mut i = 0;
+    while i < relasz {
+        let rela = &*(load_offset.wrapping_add(rela + i) as *const Rela);
+        if r_type(rela.r_info) == R_RELATIVE {
+            let place = load_offset.wrapping_add(rela.r_offset) as *mut usize;
+            *place = load_offset.wrapping_add(rela.r_addend as usize);
+        }
+        i += RELA;
+    }
+
+    // An entry with the low bit clear is the address of a place to relocate,
+    // and one with it set is a bitmap of which of the following places to
+    // relocate, above the bit marking it as a bitmap.
+    let mut place = 0;
+    let mut i = 0;
+    while i < relrsz {
+        let entry = *(load_offset.wrapping_add(relr + i) as *const usize);
+        if entry & 1 == 0 {
+            place = load_offset.wrapping_add(entry);
+            *(place as *mut usize) = (*(place as *const usize)).wrapping_add(load_offset);
+            place += WORD;
+        } else {
+            let mut bitmap = entry >> 1;
+            let mut next = place;
+            while bitmap != 0 {
+                if bitmap & 1 != 0 {
+                    *(next as *mut usize) = (*(next as *const usize)).wrapping_add(load_offset);
+                }
+                bitmap >>= 1;
+                next += WORD;
+            }
+            place += (WORD * 8 - 1) * WORD;
+        }
+        i += WORD;
+    }
+}
+
+/// Returns the run-time address of `_DYNAMIC`, which the linker defines as the
+/// start of the dynamic section.
+///
+/// The address is computed relative to the program counter, as the global
+/// offset table can't be used yet. The symbol is weak so that images without
+/// a dynamic section still link, but this must only be called for images with
+/// one: an undefined weak symbol referenced this way yields the load offset
+/// rather than zero, so its absence can't be detected at run time.
+///
+#[inline(always)]
+fn dynamic_address() -> usize {
+    let address: usize;
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+    unsafe {
+        asm!(
+            ".weak _DYNAMIC",
+            ".hidden _DYNAMIC",
+            "lea {}, [rip + _DYNAMIC]",
+            out(reg) address,
+            options(nomem, nostack, pure),
+        );
+    }
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    unsafe {
+        asm!(
+            ".weak _DYNAMIC",
+            ".hidden _DYNAMIC",
+            "lea {:e}, [rip + _DYNAMIC]",
+            out(reg) address,
+            options(nomem, nostack, pure),
+        );
+    }
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!(
+            ".weak _DYNAMIC",
+            ".hidden _DYNAMIC",
+            "adrp {0}, _DYNAMIC",
+            "add {0}, {0}, :lo12:_DYNAMIC",
+            out(reg) address,
+            options(nomem, nostack, pure),
+        );
+    }
+    // The program counter reads as the address of the instruction plus 8 in
+    // ARM state and plus 4 in Thumb state.
+    #[cfg(all(target_arch = "arm", not(thumb_mode)))]
+    unsafe {
+        asm!(
+            ".weak _DYNAMIC",
+            ".hidden _DYNAMIC",
+            "ldr {0}, 3f",
+            "2:",
+            "add {0}, pc, {0}",
+            "b 4f",
+            ".p2align 2",
+            "3:",
+            ".word _DYNAMIC - (2b + 8)",
+            "4:",
+            out(reg) address,
+            options(nomem, nostack, pure),
+        );
+    }
+    #[cfg(all(target_arch = "arm", thumb_mode))]
+    unsafe {
+        asm!(
+            ".weak _DYNAMIC",
+            ".hidden _DYNAMIC",
+            "ldr {0}, 3f",
+            "2:",
+            "add {0}, pc",
+            "b 4f",
+            ".p2align 2",
+            "3:",
+            ".word _DYNAMIC - (2b + 4)",
+            "4:",
+            out(reg) address,
+            options(nomem, nostack, pure),
+        );
+    }
+    address
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        dynamic_address,
+        relocate,
+        Rel,
+        Rela,
+        DT_REL,
+        DT_RELA,
+        DT_RELASZ,
+        DT_RELR,
+        DT_RELRSZ,
+        DT_RELSZ,
+        PT_PHDR,
+        R_RELATIVE,
+        WORD,
+    };
+    use crate::{
+        auxv::{
+            self,
+            AT_BASE,
+            AT_NULL,
+            AT_PHDR,
+            AT_PHNUM,
+        },
+        elf::{
+            Dyn,
+            Phdr,
+            DT_NULL,
+            PT_DYNAMIC,
+            PT_LOAD,
+        },
+    };
+    use ::core::{
+        assert_eq,
+        iter::Iterator,
+        mem,
+        option::Option::Some,
+        ptr,
+        result::Result::Ok,
+        slice,
+    };
+
+    const DATA: usize = 80;
+
+    /// An image linked at address zero, with everything `relocate` reads.
+    ///
+    #[repr(C)]
+    struct Image {
+        phdrs: [Phdr; 2],
+        dynamic: [Dyn; 7],
+        rel: [Rel; 2],
+        rela: [Rela; 1],
+        relr: [usize; 3],
+        data: [usize; DATA],
+    }
+
+    impl Image {
+        fn new() -> Self {
+            let mut image = Image {
+                phdrs: [phdr(PT_PHDR, 0), phdr(PT_LOAD, 0)],
+                dynamic: [
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                    Dyn { d_tag: DT_NULL, d_val: 0 },
+                ],
+                rel: [Rel { r_offset: 0, r_info: 0 }, Rel { r_offset: 0, r_info: 0 }],
+                rela: [Rela {
+                    r_offset: 0,
+                    r_info: 0,
+                    r_addend: 0,
+                }],
+                relr: [0; 3],
+                data: [0; DATA],
+            };
+            for (i, word) in image.data.iter_mut().enumerate() {
+                *word = i * WORD;
+            }
+            let data = image.offset(ptr::addr_of!(image.data).cast());
+            image.phdrs[1] = phdr(PT_DYNAMIC, image.offset(ptr::addr_of!(image.dynamic).cast()));
+            image.dynamic = [
+                Dyn {
+                    d_tag: DT_REL,
+                    d_val: image.offset(ptr::addr_of!(image.rel).cast()),
+                },
+                Dyn {
+                    d_tag: DT_RELSZ,
+                    d_val: mem::size_of_val(&image.rel),
+                },
+                Dyn {
+                    d_tag: DT_RELA,
+                    d_val: image.offset(ptr::addr_of!(image.rela).cast()),
+                },
+                Dyn {
+                    d_tag: DT_RELASZ,
+                    d_val: mem::size_of_val(&image.rela),
+                },
+                Dyn {
+                    d_tag: DT_RELR,
+                    d_val: image.offset(ptr::addr_of!(image.relr).cast()),
+                },
+                Dyn {
+                    d_tag: DT_RELRSZ,
+                    d_val: mem::size_of_val(&image.relr),
+                },
+                Dyn { d_tag: DT_NULL, d_val: 0 },
+            ];
+            // An implicit addend, and a relocation which isn't relative.
+            image.rel = [
+                Rel {
+                    r_offset: data,
+                    r_info: R_RELATIVE,
+                },
+                Rel {
+                    r_offset: data + WORD,
+                    r_info: R_RELATIVE + 1,
+                },
+            ];
+            // An explicit addend, replacing what is in place.
+            image.rela = [Rela {
+                r_offset: data + 2 * WORD,
+                r_info: R_RELATIVE,
+                r_addend: 0x100,
+            }];
+            // An address, then a bitmap of the first and third words past it,
+            // then a bitmap of the first word past those the previous covers.
+            image.relr = [data + 3 * WORD, 0b1011, 0b11];
+            image
+        }
+
+        fn base(&self) -> usize {
+            (self as *const Self) as usize
+        }
+
+        fn offset(&self, field: *const u8) -> usize {
+            field as usize - self.base()
+        }
+    }
+
+    fn phdr(p_type: u32, p_vaddr: usize) -> Phdr {
+        Phdr {
+            p_type,
+            p_flags: 0,
+            p_offset: 0,
+            p_vaddr,
+            p_paddr: 0,
+            p_filesz: 0,
+            p_memsz: 0,
+            p_align: 0,
+        }
+    }
+
+    /// Returns an initial stack with no arguments or environment.
+    ///
+    fn stack(image: &Image, at_base: usize) -> [usize; 11] {
+        [
+            0,
+            0,
+            0,
+            AT_BASE,
+            at_base,
+            AT_PHDR,
+            image.base(),
+            AT_PHNUM,
+            image.phdrs.len(),
+            AT_NULL,
+            0,
+        ]
+    }
+
+    #[test]
+    fn relative() {
+        let image = Image::new();
+        let base = image.base();
+        let stack = stack(&image, 0);
+        unsafe { relocate(stack.as_ptr()) };
+        let mut expected = [0; DATA];
+        for (i, word) in expected.iter_mut().enumerate() {
+            *word = i * WORD;
+        }
+        for &i in &[0, 3, 4, 6, 4 + WORD * 8 - 1] {
+            expected[i] += base;
+        }
+        expected[2] = base + 0x100;
+        assert_eq!(image.data[..], expected[..]);
+    }
+
+    #[test]
+    fn interpreter() {
+        let image = Image::new();
+        let stack = stack(&image, 0x1000);
+        unsafe { relocate(stack.as_ptr()) };
+        for (i, &word) in image.data.iter().enumerate() {
+            assert_eq!(word, i * WORD);
+        }
+    }
+
+    #[test]
+    fn dynamic() {
+        let mut buf = [0; 128];
+        let auxv = unsafe { auxv::read_proc(&mut buf) };
+        let auxv = match auxv {
+            Ok(auxv) => auxv,
+            _ => return,
+        };
+        let (phdrs, phnum) = match (auxv.get(AT_PHDR), auxv.get(AT_PHNUM)) {
+            (Some(phdrs), Some(phnum)) => (phdrs, phnum),
+            _ => return,
+        };
+        let phdrs = unsafe { slice::from_raw_parts(phdrs as *const Phdr, phnum) };
+        let vaddr = |p_type| phdrs.iter().find(|phdr| phdr.p_type == p_type).map(|phdr| phdr.p_vaddr);
+        if let (Some(phdr), Some(dynamic)) = (vaddr(PT_PHDR), vaddr(PT_DYNAMIC)) {
+            assert_eq!(dynamic_address(), phdrs.as_ptr() as usize - phdr + dynamic);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use crate::Errno;
+use ::core::{
+    arch::asm,
+    hint::unreachable_unchecked,
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+};
+
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
+#[inline(always)]
+pub unsafe fn syscall(a7: usize, a: &[usize]) -> Result<usize, Errno> {
+    match a.len() {
+        0 => syscall_0(a7),
+        1 => syscall_1(a7, a[0]),
+        2 => syscall_2(a7, a[0], a[1]),
+        3 => syscall_3(a7, a[0], a[1], a[2]),
+        4 => syscall_4(a7, a[0], a[1], a[2], a[3]),
+        5 => syscall_5(a7, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6(a7, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_nr(a7: usize, a: &[usize]) -> ! {
+    match a.len() {
+        0 => syscall_0_nr(a7),
+        1 => syscall_1_nr(a7, a[0]),
+        2 => syscall_2_nr(a7, a[0], a[1]),
+        3 => syscall_3_nr(a7, a[0], a[1], a[2]),
+        4 => syscall_4_nr(a7, a[0], a[1], a[2], a[3]),
+        5 => syscall_5_nr(a7, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6_nr(a7, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call with no arguments and returns the result.
+///
+/// The argument specifies the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_0(a7: usize) -> Result<usize, Errno> {
+    let a0: usize;
+    asm!(
+        "ecall",
+        lateout("a0") a0,
+        in("a7") a7,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with no arguments which never returns.
+///
+/// The argument specifies the system call.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_nr(a7: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with one argument and returns the result.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_1(a7: usize, mut a0: usize) -> Result<usize, Errno> {
+    asm!(
+        "ecall",
+        inlateout("a0") a0,
+        in("a7") a7,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with one argument and never returns.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_nr(a7: usize, a0: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        in("a0") a0,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with two arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_2(a7: usize, mut a0: usize, a1: usize) -> Result<usize, Errno> {
+    asm!(
+        "ecall",
+        inlateout("a0") a0,
+        in("a7") a7,
+        in("a1") a1,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with two arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_nr(a7: usize, a0: usize, a1: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        in("a0") a0,
+        in("a1") a1,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with three arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_3(a7: usize, mut a0: usize, a1: usize, a2: usize) -> Result<usize, Errno> {
+    asm!(
+        "ecall",
+        inlateout("a0") a0,
+        in("a7") a7,
+        in("a1") a1,
+        in("a2") a2,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with three arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_nr(a7: usize, a0: usize, a1: usize, a2: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        in("a0") a0,
+        in("a1") a1,
+        in("a2") a2,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with four arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_4(a7: usize, mut a0: usize, a1: usize, a2: usize, a3: usize) -> Result<usize, Errno> {
+    asm!(
+        "ecall",
+        inlateout("a0") a0,
+        in("a7") a7,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with four arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_nr(a7: usize, a0: usize, a1: usize, a2: usize, a3: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        in("a0") a0,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with five arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_5(a7: usize, mut a0: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> Result<usize, Errno> {
+    asm!(
+        "ecall",
+        inlateout("a0") a0,
+        in("a7") a7,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        in("a4") a4,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with five arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_nr(a7: usize, a0: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        in("a0") a0,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        in("a4") a4,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with six arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+pub unsafe fn syscall_6(
+    a7: usize,
+    mut a0: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> Result<usize, Errno>
+{
+    asm!(
+        "ecall",
+        inlateout("a0") a0,
+        in("a7") a7,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        in("a4") a4,
+        in("a5") a5,
+        options(nostack, preserves_flags),
+    );
+    if a0 < 0xffff_ffff_ffff_f000 {
+        Ok(a0)
+    } else {
+        Err(Errno::new(a0.wrapping_neg()))
+    }
+}
+
+/// Performs a system call with six arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_nr(a7: usize, a0: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") a7,
+        in("a0") a0,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        in("a4") a4,
+        in("a5") a5,
+        options(noreturn, nostack),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(a7);
+    syscall_1_as = syscall_1(a7, a0);
+    syscall_2_as = syscall_2(a7, a0, a1);
+    syscall_3_as = syscall_3(a7, a0, a1, a2);
+    syscall_4_as = syscall_4(a7, a0, a1, a2, a3);
+    syscall_5_as = syscall_5(a7, a0, a1, a2, a3, a4);
+    syscall_6_as = syscall_6(a7, a0, a1, a2, a3, a4, a5);
+}
@@ -0,0 +1,396 @@
+//! A `_start` entry point for executables which don't link a C runtime.
+//!
+//! This is enabled by the `start` feature. The entry point finds the argument
+//! count, argument and environment pointers and the auxiliary vector on the
+//! initial process stack, calls the function registered with `entry!`, and
+//! exits the process with its return value. The executable must be built with
+//! `#![no_main]` and linked without the C startup files, for example with
+//! `-C link-arg=-nostartfiles`. The module is left out of this crate's own
+//! test build, whose harness has its own entry point.
+//!
+//! On `powerpc64-linux` the entry point follows the `ELFv1` ABI on big-endian
+//! targets and the `ELFv2` ABI on little-endian ones.
+//!
+
+#[cfg(target_os = "linux")]
+use crate::{
+    auxv::Auxv,
+    nr,
+};
+#[cfg(all(
+    feature = "relocate",
+    target_os = "linux",
+    any(target_arch = "aarch64", target_arch = "arm", target_arch = "x86_64")
+))]
+use crate::relocate;
+use crate::{
+    syscall_1_nr,
+    IntoSyscallArg,
+};
+use ::core::{
+    arch::global_asm,
+    clone::Clone,
+    fmt::Debug,
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{
+        self,
+        None,
+        Some,
+    },
+    slice,
+};
+
+#[cfg(target_arch = "aarch64")]
+global_asm!(
+    ".globl _start",
+    ".type _start, %function",
+    "_start:",
+    "mov x29, #0",
+    "mov x30, #0",
+    "mov x0, sp",
+    "bl __raw_syscall_base_start",
+);
+
+#[cfg(target_arch = "arm")]
+global_asm!(
+    ".globl _start",
+    ".type _start, %function",
+    "_start:",
+    "mov r11, #0",
+    "mov lr, #0",
+    "mov r0, sp",
+    "bic r1, r0, #15",
+    "mov sp, r1",
+    "bl __raw_syscall_base_start",
+);
+
+#[cfg(target_arch = "loongarch64")]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    "move $fp, $zero",
+    "move $ra, $zero",
+    "move $a0, $sp",
+    "bstrins.d $sp, $zero, 3, 0",
+    "bl __raw_syscall_base_start",
+);
+
+#[cfg(target_arch = "mips")]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    ".set push",
+    ".set noreorder",
+    "_start:",
+    "move $fp, $zero",
+    "move $a0, $sp",
+    "addiu $t0, $zero, -8",
+    "and $sp, $sp, $t0",
+    "addiu $sp, $sp, -16",
+    "lui $t9, %hi(__raw_syscall_base_start)",
+    "addiu $t9, $t9, %lo(__raw_syscall_base_start)",
+    "jalr $t9",
+    "nop",
+    ".set pop",
+);
+
+#[cfg(target_arch = "mips64")]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    ".set push",
+    ".set noreorder",
+    "_start:",
+    "move $fp, $zero",
+    "move $a0, $sp",
+    "daddiu $t0, $zero, -16",
+    "and $sp, $sp, $t0",
+    "lui $t9, %highest(__raw_syscall_base_start)",
+    "daddiu $t9, $t9, %higher(__raw_syscall_base_start)",
+    "dsll $t9, $t9, 16",
+    "daddiu $t9, $t9, %hi(__raw_syscall_base_start)",
+    "dsll $t9, $t9, 16",
+    "daddiu $t9, $t9, %lo(__raw_syscall_base_start)",
+    "jalr $t9",
+    "nop",
+    ".set pop",
+);
+
+#[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+global_asm!(
+    ".section .opd, \"aw\"",
+    ".p2align 3",
+    ".globl _start",
+    "_start:",
+    ".quad .L_start, .TOC.@tocbase, 0",
+    ".text",
+    ".L_start:",
+    "mr 3, 1",
+    "clrrdi 1, 1, 4",
+    "li 0, 0",
+    "stdu 0, -128(1)",
+    "bl __raw_syscall_base_start",
+    "nop",
+);
+
+#[cfg(all(target_arch = "powerpc64", target_endian = "little"))]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    "addis 2, 12, .TOC.-_start@ha",
+    "addi 2, 2, .TOC.-_start@l",
+    ".localentry _start, .-_start",
+    "mr 3, 1",
+    "clrrdi 1, 1, 4",
+    "li 0, 0",
+    "stdu 0, -32(1)",
+    "bl __raw_syscall_base_start",
+    "nop",
+);
+
+#[cfg(target_arch = "riscv64")]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    ".option push",
+    ".option norelax",
+    "lla gp, __global_pointer$",
+    ".option pop",
+    "li fp, 0",
+    "li ra, 0",
+    "mv a0, sp",
+    "andi sp, sp, -16",
+    "call __raw_syscall_base_start",
+);
+
+#[cfg(target_arch = "s390x")]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    "lgr %r2, %r15",
+    "aghi %r15, -160",
+    "lghi %r0, 0",
+    "stg %r0, 0(%r15)",
+    "brasl %r14, __raw_syscall_base_start",
+);
+
+#[cfg(target_arch = "x86")]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    "xor ebp, ebp",
+    "mov eax, esp",
+    "and esp, -16",
+    "sub esp, 12",
+    "push eax",
+    "call __raw_syscall_base_start",
+);
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    "xor ebp, ebp",
+    "mov rdi, rsp",
+    "and rsp, -16",
+    "call __raw_syscall_base_start",
+);
+
+// FreeBSD passes the address of the argument count in `rdi`, and the stack
+// pointer isn't guaranteed to point to it.
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+global_asm!(
+    ".globl _start",
+    ".type _start, @function",
+    "_start:",
+    "xor ebp, ebp",
+    "and rsp, -16",
+    "call __raw_syscall_base_start",
+);
+
+#[cfg(target_os = "freebsd")]
+const SYS_EXIT: usize = 1;
+
+extern "Rust" {
+    fn __raw_syscall_base_main(info: StartupInfo) -> i32;
+}
+
+/// Called by `_start` with the initial stack pointer, which points to the
+/// argument count.
+///
+/// Static PIE builds are relocated first, unless the `relocate` feature is
+/// disabled, so nothing may be called before that.
+///
+#[no_mangle]
+unsafe extern "C" fn __raw_syscall_base_start(sp: *const usize) -> ! {
+    #[cfg(all(
+        feature = "relocate",
+        target_os = "linux",
+        any(target_arch = "aarch64", target_arch = "arm", target_arch = "x86_64")
+    ))]
+    relocate::relocate(sp);
+    let argc = *sp;
+    let argv = sp.add(1).cast::<*const u8>();
+    let envp = argv.add(argc + 1);
+    let mut end = envp;
+    while !(*end).is_null() {
+        end = end.add(1);
+    }
+    let auxv = end.add(1).cast::<usize>();
+    let info = StartupInfo {
+        argc,
+        argv,
+        envp,
+        #[cfg(target_os = "linux")]
+        auxv: Auxv::from_ptr(auxv),
+        #[cfg(not(target_os = "linux"))]
+        auxv,
+    };
+    let status = __raw_syscall_base_main(info).into_syscall_arg();
+    #[cfg(target_os = "linux")]
+    syscall_1_nr(nr::EXIT_GROUP, status);
+    #[cfg(target_os = "freebsd")]
+    syscall_1_nr(SYS_EXIT, status);
+}
+
+/// Registers the function called by `_start`.
+///
+/// The function takes a `StartupInfo` and returns the exit status of the
+/// process. This must be used exactly once in the executable.
+///
+/// ```ignore
+/// #![no_std]
+/// #![no_main]
+///
+/// use raw_syscall_base::{entry, StartupInfo};
+///
+/// entry!(main);
+///
+/// fn main(info: StartupInfo) -> i32 {
+///     info.argc() as i32
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! entry {
+    ($main:path) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub fn __raw_syscall_base_main(info: $crate::StartupInfo) -> i32 {
+            let main: fn($crate::StartupInfo) -> i32 = $main;
+            main(info)
+        }
+    };
+}
+
+/// The arguments, environment and auxiliary vector passed to the process,
+/// as found on the initial stack.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct StartupInfo {
+    argc: usize,
+    argv: *const *const u8,
+    envp: *const *const u8,
+    #[cfg(target_os = "linux")]
+    auxv: Auxv<'static>,
+    #[cfg(not(target_os = "linux"))]
+    auxv: *const usize,
+}
+
+impl StartupInfo {
+    /// Returns the number of arguments, including the program name.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn argc(&self) -> usize {
+        self.argc
+    }
+
+    /// Returns the null-terminated array of argument pointers.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn argv(&self) -> *const *const u8 {
+        self.argv
+    }
+
+    /// Returns the null-terminated array of environment pointers.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn envp(&self) -> *const *const u8 {
+        self.envp
+    }
+
+    /// Returns the auxiliary vector.
+    ///
+    #[cfg(target_os = "linux")]
+    #[inline(always)]
+    #[must_use]
+    pub fn auxv(&self) -> Auxv<'static> {
+        self.auxv
+    }
+
+    /// Returns the address of the auxiliary vector, a sequence of key/value
+    /// pairs terminated by a zero key.
+    ///
+    #[cfg(not(target_os = "linux"))]
+    #[inline(always)]
+    #[must_use]
+    pub fn auxv(&self) -> *const usize {
+        self.auxv
+    }
+
+    /// Returns an iterator over the arguments, without their terminating nul.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn args(&self) -> Strings {
+        Strings { next: self.argv }
+    }
+
+    /// Returns an iterator over the environment variables, each of the form
+    /// `NAME=value`, without their terminating nul.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn vars(&self) -> Strings {
+        Strings { next: self.envp }
+    }
+}
+
+/// An iterator over the strings of a null-terminated array of pointers on the
+/// initial stack.
+///
+#[derive(Clone, Debug)]
+pub struct Strings {
+    next: *const *const u8,
+}
+
+impl Iterator for Strings {
+    type Item = &'static [u8];
+
+    fn next(&mut self) -> Option<&'static [u8]> {
+        // The arrays and strings on the initial stack live as long as the
+        // process, and `StartupInfo` can only be created by `_start`.
+        unsafe {
+            let start = *self.next;
+            if start.is_null() {
+                return None;
+            }
+            self.next = self.next.add(1);
+            let mut len = 0;
+            while *start.add(len) != 0 {
+                len += 1;
+            }
+            Some(slice::from_raw_parts(start, len))
+        }
+    }
+}
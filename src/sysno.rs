@@ -0,0 +1,210 @@
+use crate::{
+    translate::{
+        aarch64_linux,
+        arm_linux,
+        i686_linux,
+        loongarch64_linux,
+        mips64_linux,
+        mips_linux,
+        powerpc64_linux,
+        riscv64_linux,
+        s390x_linux,
+        x32_linux,
+        x86_64_freebsd,
+        x86_64_linux,
+    },
+    IntoSyscallArg,
+    SyscallArg,
+};
+use ::core::{
+    clone::Clone,
+    cmp::{
+        Eq,
+        PartialEq,
+    },
+    convert::From,
+    fmt,
+    iter::{
+        Copied,
+        Iterator,
+    },
+    marker::Copy,
+    option::Option::{
+        None,
+        Some,
+    },
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+    slice,
+    str::FromStr,
+};
+
+/// An error returned when parsing the name of a system call which is not known
+/// on the platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseSysnoError;
+
+impl fmt::Display for ParseSysnoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown system call")
+    }
+}
+
+/// Implements the conversions shared by the `Sysno` of every platform.
+///
+macro_rules! sysno_impls {
+    ($($sysno:ty),*) => {
+        $(
+            impl $sysno {
+                /// Returns an iterator over every system call known on this
+                /// platform, in order of number.
+                ///
+                #[inline(always)]
+                pub fn iter() -> Copied<slice::Iter<'static, Self>> {
+                    Self::ALL.iter().copied()
+                }
+            }
+
+            impl From<$sysno> for usize {
+                #[inline(always)]
+                fn from(sysno: $sysno) -> Self {
+                    sysno.raw()
+                }
+            }
+
+            impl IntoSyscallArg for $sysno {
+                #[inline(always)]
+                fn into_syscall_arg(self) -> SyscallArg {
+                    self.raw().into_syscall_arg()
+                }
+            }
+
+            impl fmt::Display for $sysno {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            impl FromStr for $sysno {
+                type Err = ParseSysnoError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match Self::from_name(s) {
+                        Some(sysno) => Ok(sysno),
+                        None => Err(ParseSysnoError),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+sysno_impls!(
+    aarch64_linux::Sysno,
+    arm_linux::Sysno,
+    i686_linux::Sysno,
+    loongarch64_linux::Sysno,
+    mips_linux::Sysno,
+    mips64_linux::Sysno,
+    powerpc64_linux::Sysno,
+    riscv64_linux::Sysno,
+    s390x_linux::Sysno,
+    x32_linux::Sysno,
+    x86_64_freebsd::Sysno,
+    x86_64_linux::Sysno
+);
+
+#[cfg(test)]
+mod tests {
+    use super::ParseSysnoError;
+    use crate::translate::{
+        aarch64_linux,
+        arm_linux,
+        i686_linux,
+        loongarch64_linux,
+        mips64_linux,
+        mips_linux,
+        powerpc64_linux,
+        riscv64_linux,
+        s390x_linux,
+        x32_linux,
+        x86_64_freebsd,
+        x86_64_linux,
+    };
+    use ::core::{
+        assert,
+        assert_eq,
+        convert::From,
+        option::Option::{
+            None,
+            Some,
+        },
+        result::Result::{
+            Err,
+            Ok,
+        },
+    };
+
+    /// Checks that every system call of each platform converts back from its
+    /// number and name, and that they are in order of number.
+    ///
+    macro_rules! round_trip {
+        ($($platform:ident),*) => {
+            $(
+                let mut last = None;
+                for sysno in $platform::Sysno::iter() {
+                    assert_eq!($platform::Sysno::from_raw(sysno.raw()), Some(sysno));
+                    assert_eq!($platform::Sysno::from_name(sysno.name()), Some(sysno));
+                    assert_eq!(sysno.name().parse(), Ok(sysno));
+                    assert_eq!(usize::from(sysno), sysno.raw());
+                    assert!(last < Some(sysno.raw()));
+                    last = Some(sysno.raw());
+                }
+                assert_eq!($platform::Sysno::from_name(""), None);
+                assert_eq!("no_such_call".parse::<$platform::Sysno>(), Err(ParseSysnoError));
+            )*
+        };
+    }
+
+    #[test]
+    fn every_platform() {
+        round_trip!(
+            aarch64_linux,
+            arm_linux,
+            i686_linux,
+            loongarch64_linux,
+            mips_linux,
+            mips64_linux,
+            powerpc64_linux,
+            riscv64_linux,
+            s390x_linux,
+            x32_linux,
+            x86_64_freebsd,
+            x86_64_linux
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(x86_64_linux::Sysno::Getpid.raw(), 39);
+        assert_eq!(x86_64_linux::Sysno::from_raw(39), Some(x86_64_linux::Sysno::Getpid));
+        assert_eq!(x86_64_linux::Sysno::from_raw(100_000), None);
+        assert_eq!(x32_linux::Sysno::from_raw(39), None);
+        assert_eq!(x32_linux::Sysno::Getpid.raw(), 0x4000_0000 + 39);
+        assert_eq!(aarch64_linux::Sysno::from_name("openat").map(aarch64_linux::Sysno::raw), Some(56));
+        assert_eq!(aarch64_linux::Sysno::from_name("open"), None);
+        assert_eq!(arm_linux::Sysno::from_raw(0x0f_0005), Some(arm_linux::Sysno::ArmSetTls));
+        assert_eq!(arm_linux::Sysno::ArmSetTls.name(), "arm_set_tls");
+        assert_eq!(mips_linux::Sysno::Getpid.raw(), 4000 + 20);
+        assert_eq!(mips64_linux::Sysno::Getpid.raw(), 5000 + 38);
+        assert_eq!(x86_64_freebsd::Sysno::Getpid.raw(), 20);
+        assert_eq!(x86_64_freebsd::Sysno::from_raw(198), Some(x86_64_freebsd::Sysno::QuadSyscall));
+        assert_eq!(x86_64_freebsd::Sysno::QuadSyscall.name(), "__syscall");
+        assert_eq!(x86_64_freebsd::Sysno::from_name("exit").map(x86_64_freebsd::Sysno::raw), Some(1));
+        assert_eq!(x86_64_freebsd::Sysno::from_raw(188), None);
+    }
+}
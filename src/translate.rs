@@ -0,0 +1,444 @@
+//! Translation of system call numbers between platforms.
+//!
+//! The tables of every supported platform are available here regardless of
+//! the target, so numbers captured on one platform can be interpreted on
+//! another. Calls are matched by name, and when the target has no call of the
+//! same name a substitute is chosen which provides the same functionality,
+//! possibly with different arguments:
+//!
+//! * Calls taking paths, such as `open` or `mkdir`, are replaced by their
+//!   `*at` variants, which take a directory descriptor (`AT_FDCWD`) first.
+//! * Calls replaced by a more general one, such as `dup2` by `dup3` or `fork`
+//!   by `clone`, take the extra arguments of the replacement.
+//! * Calls which only differ in the width of their arguments, such as
+//!   `fcntl64`, `getuid32` or `clock_gettime64`, are replaced by the call with
+//!   the suffix removed, and the reverse where listed.
+//!
+//! A call keeps its name when the target has it, even where the target also
+//! has a wider variant, such as `getuid32` next to `getuid` on `arm-linux`.
+//! The exception is a call whose name some platforms use for an older form,
+//! such as `mmap` on `i686-linux` and `s390x-linux`, which takes a pointer to
+//! a structure holding its arguments. It is only translated exactly between
+//! platforms using the same form, and otherwise by a substitute such as
+//! `mmap2`, or by the call of the same name as a substitute if there is none.
+//!
+//! Between Linux and FreeBSD only the names are shared: a call translated
+//! exactly, such as `fcntl` or `ioctl`, may still take other commands, flags
+//! or structures on the target. Calls FreeBSD names differently, such as
+//! `__getcwd` for `getcwd` or `sigaction` for `rt_sigaction`, are listed as
+//! substitutes both ways.
+//!
+
+#[path = "nr/aarch64-linux.rs"]
+pub mod aarch64_linux;
+
+#[path = "nr/arm-linux.rs"]
+pub mod arm_linux;
+
+#[path = "nr/i686-linux.rs"]
+pub mod i686_linux;
+
+#[path = "nr/loongarch64-linux.rs"]
+pub mod loongarch64_linux;
+
+#[path = "nr/mips-linux.rs"]
+pub mod mips_linux;
+
+#[path = "nr/mips64-linux.rs"]
+pub mod mips64_linux;
+
+#[path = "nr/powerpc64-linux.rs"]
+pub mod powerpc64_linux;
+
+#[path = "nr/riscv64-linux.rs"]
+pub mod riscv64_linux;
+
+#[path = "nr/s390x-linux.rs"]
+pub mod s390x_linux;
+
+#[path = "nr/x32-linux.rs"]
+pub mod x32_linux;
+
+#[path = "nr/x86_64-linux.rs"]
+pub mod x86_64_linux;
+
+#[path = "nr/x86_64-freebsd.rs"]
+pub mod x86_64_freebsd;
+
+use ::core::{
+    clone::Clone,
+    cmp::{
+        Eq,
+        PartialEq,
+    },
+    fmt::Debug,
+    hash::Hash,
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{
+        self,
+        None,
+        Some,
+    },
+};
+
+/// A platform with its own system call numbers.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Arch {
+    Aarch64,
+    Arm,
+    I686,
+    Loongarch64,
+    Mips,
+    Mips64,
+    Powerpc64,
+    Riscv64,
+    S390x,
+    /// The x32 ABI of `x86_64`, whose numbers include `X32_SYSCALL_BIT`.
+    ///
+    X32,
+    X86_64,
+    /// FreeBSD on `x86_64`, which shares its numbers with the other FreeBSD
+    /// architectures.
+    ///
+    X86_64Freebsd,
+}
+
+/// The system call to use on the target platform.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Translation {
+    /// The number of the call with the same name.
+    ///
+    Exact(usize),
+    /// The number and name of a call providing the same functionality, which
+    /// may take different arguments.
+    ///
+    Substitute(usize, &'static str),
+}
+
+/// Calls to try in order when the target lacks a call of the same name, before
+/// falling back to removing a width suffix.
+///
+const SUBSTITUTES: &[(&str, &[&str])] = &[
+    ("__getcwd", &["getcwd"]),
+    ("_llseek", &["lseek"]),
+    ("_newselect", &["select", "pselect6"]),
+    ("access", &["faccessat"]),
+    ("alarm", &["setitimer"]),
+    ("chmod", &["fchmodat"]),
+    ("chown", &["fchownat"]),
+    ("creat", &["openat"]),
+    ("dup2", &["dup3"]),
+    ("epoll_create", &["epoll_create1"]),
+    ("epoll_wait", &["epoll_pwait"]),
+    ("eventfd", &["eventfd2"]),
+    ("fcntl", &["fcntl64"]),
+    ("fork", &["clone"]),
+    ("fstat", &["fstat64", "newfstatat", "fstatat64", "fstatat", "statx"]),
+    ("fstat64", &["fstat", "newfstatat", "statx"]),
+    ("fstatat", &["newfstatat", "fstatat64", "statx"]),
+    ("fstatat64", &["newfstatat", "fstatat", "statx"]),
+    ("fstatfs", &["fstatfs64"]),
+    ("futimesat", &["utimensat"]),
+    ("getcwd", &["__getcwd"]),
+    ("getdents", &["getdents64"]),
+    ("getpgrp", &["getpgid"]),
+    ("getrlimit", &["ugetrlimit", "prlimit64"]),
+    ("inotify_init", &["inotify_init1"]),
+    ("lchown", &["fchownat"]),
+    ("link", &["linkat"]),
+    ("lseek", &["_llseek"]),
+    ("lstat", &["lstat64", "newfstatat", "fstatat64", "fstatat", "statx"]),
+    ("lstat64", &["lstat", "newfstatat", "statx"]),
+    ("mkdir", &["mkdirat"]),
+    ("mknod", &["mknodat"]),
+    ("mmap", &["mmap2"]),
+    ("mmap2", &["mmap"]),
+    ("newfstatat", &["fstatat64", "fstatat", "statx"]),
+    ("nice", &["setpriority"]),
+    ("open", &["openat"]),
+    ("pipe", &["pipe2"]),
+    ("poll", &["ppoll"]),
+    ("pselect", &["pselect6"]),
+    ("pselect6", &["pselect"]),
+    ("readlink", &["readlinkat"]),
+    ("rename", &["renameat", "renameat2"]),
+    ("renameat", &["renameat2"]),
+    ("rmdir", &["unlinkat"]),
+    ("rt_sigaction", &["sigaction"]),
+    ("rt_sigpending", &["sigpending"]),
+    ("rt_sigprocmask", &["sigprocmask"]),
+    ("rt_sigsuspend", &["sigsuspend"]),
+    ("rt_sigtimedwait", &["sigtimedwait"]),
+    ("select", &["_newselect", "pselect6"]),
+    ("sendfile", &["sendfile64"]),
+    ("setrlimit", &["prlimit64"]),
+    ("sigaction", &["rt_sigaction"]),
+    ("signalfd", &["signalfd4"]),
+    ("sigpending", &["rt_sigpending"]),
+    ("sigprocmask", &["rt_sigprocmask"]),
+    ("sigsuspend", &["rt_sigsuspend"]),
+    ("sigtimedwait", &["rt_sigtimedwait"]),
+    ("stat", &["stat64", "newfstatat", "fstatat64", "fstatat", "statx"]),
+    ("stat64", &["stat", "newfstatat", "statx"]),
+    ("statfs", &["statfs64"]),
+    ("symlink", &["symlinkat"]),
+    ("truncate", &["truncate64"]),
+    ("ftruncate", &["ftruncate64"]),
+    ("ugetrlimit", &["getrlimit", "prlimit64"]),
+    ("umount", &["umount2"]),
+    ("unlink", &["unlinkat"]),
+    ("utime", &["utimensat"]),
+    ("utimes", &["utimensat"]),
+    ("vfork", &["clone"]),
+    ("waitpid", &["wait4"]),
+];
+
+/// Calls which take their arguments in another form on the given platform than
+/// elsewhere, with the kernel's entry point implementing them. `select` is only
+/// implemented for 32-bit programs on `powerpc64-linux`.
+///
+const OLD_FORMS: &[(Arch, &str, &str)] = &[
+    (Arch::I686, "mmap", "sys_old_mmap"),
+    (Arch::I686, "select", "sys_old_select"),
+    (Arch::Powerpc64, "select", "sys_ni_syscall"),
+    (Arch::S390x, "mmap", "sys_old_mmap"),
+];
+
+/// Suffixes marking calls which only differ in the width of their arguments.
+///
+const WIDTH_SUFFIXES: &[&str] = &["_time64", "64", "32"];
+
+#[cfg(all(target_arch = "aarch64", target_os = "linux"))]
+const HOST: Arch = Arch::Aarch64;
+
+#[cfg(all(target_arch = "arm", target_os = "linux"))]
+const HOST: Arch = Arch::Arm;
+
+#[cfg(all(target_arch = "loongarch64", target_os = "linux"))]
+const HOST: Arch = Arch::Loongarch64;
+
+#[cfg(all(target_arch = "mips", target_os = "linux"))]
+const HOST: Arch = Arch::Mips;
+
+#[cfg(all(target_arch = "mips64", target_os = "linux"))]
+const HOST: Arch = Arch::Mips64;
+
+#[cfg(all(target_arch = "powerpc64", target_os = "linux"))]
+const HOST: Arch = Arch::Powerpc64;
+
+#[cfg(all(target_arch = "riscv64", target_os = "linux"))]
+const HOST: Arch = Arch::Riscv64;
+
+#[cfg(all(target_arch = "s390x", target_os = "linux"))]
+const HOST: Arch = Arch::S390x;
+
+#[cfg(all(target_arch = "x86", target_os = "linux"))]
+const HOST: Arch = Arch::I686;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "64"))]
+const HOST: Arch = Arch::X86_64;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_pointer_width = "32"))]
+const HOST: Arch = Arch::X32;
+
+#[cfg(all(target_arch = "x86_64", target_os = "freebsd"))]
+const HOST: Arch = Arch::X86_64Freebsd;
+
+impl Arch {
+    /// Every platform, in the order of the variants.
+    ///
+    pub const ALL: &'static [Self] = &[
+        Self::Aarch64,
+        Self::Arm,
+        Self::I686,
+        Self::Loongarch64,
+        Self::Mips,
+        Self::Mips64,
+        Self::Powerpc64,
+        Self::Riscv64,
+        Self::S390x,
+        Self::X32,
+        Self::X86_64,
+        Self::X86_64Freebsd,
+    ];
+
+    /// Returns the platform this crate was built for.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn host() -> Self {
+        HOST
+    }
+
+    /// Returns the name of the system call with the given number, or `None` if
+    /// it is not known on this platform.
+    ///
+    #[must_use]
+    pub fn name(self, number: usize) -> Option<&'static str> {
+        match self {
+            Self::Aarch64 => aarch64_linux::Sysno::from_raw(number).map(aarch64_linux::Sysno::name),
+            Self::Arm => arm_linux::Sysno::from_raw(number).map(arm_linux::Sysno::name),
+            Self::I686 => i686_linux::Sysno::from_raw(number).map(i686_linux::Sysno::name),
+            Self::Loongarch64 => loongarch64_linux::Sysno::from_raw(number).map(loongarch64_linux::Sysno::name),
+            Self::Mips => mips_linux::Sysno::from_raw(number).map(mips_linux::Sysno::name),
+            Self::Mips64 => mips64_linux::Sysno::from_raw(number).map(mips64_linux::Sysno::name),
+            Self::Powerpc64 => powerpc64_linux::Sysno::from_raw(number).map(powerpc64_linux::Sysno::name),
+            Self::Riscv64 => riscv64_linux::Sysno::from_raw(number).map(riscv64_linux::Sysno::name),
+            Self::S390x => s390x_linux::Sysno::from_raw(number).map(s390x_linux::Sysno::name),
+            Self::X32 => x32_linux::Sysno::from_raw(number).map(x32_linux::Sysno::name),
+            Self::X86_64 => x86_64_linux::Sysno::from_raw(number).map(x86_64_linux::Sysno::name),
+            Self::X86_64Freebsd => x86_64_freebsd::Sysno::from_raw(number).map(x86_64_freebsd::Sysno::name),
+        }
+    }
+
+    /// Returns the number of the system call with the given name, or `None` if
+    /// it is not known on this platform.
+    ///
+    #[must_use]
+    pub fn number(self, name: &str) -> Option<usize> {
+        match self {
+            Self::Aarch64 => aarch64_linux::Sysno::from_name(name).map(aarch64_linux::Sysno::raw),
+            Self::Arm => arm_linux::Sysno::from_name(name).map(arm_linux::Sysno::raw),
+            Self::I686 => i686_linux::Sysno::from_name(name).map(i686_linux::Sysno::raw),
+            Self::Loongarch64 => loongarch64_linux::Sysno::from_name(name).map(loongarch64_linux::Sysno::raw),
+            Self::Mips => mips_linux::Sysno::from_name(name).map(mips_linux::Sysno::raw),
+            Self::Mips64 => mips64_linux::Sysno::from_name(name).map(mips64_linux::Sysno::raw),
+            Self::Powerpc64 => powerpc64_linux::Sysno::from_name(name).map(powerpc64_linux::Sysno::raw),
+            Self::Riscv64 => riscv64_linux::Sysno::from_name(name).map(riscv64_linux::Sysno::raw),
+            Self::S390x => s390x_linux::Sysno::from_name(name).map(s390x_linux::Sysno::raw),
+            Self::X32 => x32_linux::Sysno::from_name(name).map(x32_linux::Sysno::raw),
+            Self::X86_64 => x86_64_linux::Sysno::from_name(name).map(x86_64_linux::Sysno::raw),
+            Self::X86_64Freebsd => x86_64_freebsd::Sysno::from_name(name).map(x86_64_freebsd::Sysno::raw),
+        }
+    }
+}
+
+/// Translates a system call number from one platform to another.
+///
+/// Returns the number of the call with the same name on the target, or a
+/// substitute as described in the module documentation, or `None` if the
+/// number isn't known on the source or has no equivalent on the target.
+///
+#[must_use]
+pub fn translate(from: Arch, number: usize, to: Arch) -> Option<Translation> {
+    let name = from.name(number)?;
+    let same = to.number(name);
+    if let Some(number) = same {
+        if old_form(from, name) == old_form(to, name) {
+            return Some(Translation::Exact(number));
+        }
+    }
+    let listed = SUBSTITUTES
+        .iter()
+        .find(|&&(call, _)| call == name)
+        .map_or(&[][..], |&(_, substitutes)| substitutes);
+    for &substitute in listed {
+        if let Some(number) = to.number(substitute) {
+            return Some(Translation::Substitute(number, substitute));
+        }
+    }
+    if let Some(number) = same {
+        return Some(Translation::Substitute(number, name));
+    }
+    for suffix in WIDTH_SUFFIXES {
+        if let Some(stem) = name.strip_suffix(suffix) {
+            if let Some(number) = to.number(stem) {
+                return Some(Translation::Substitute(number, to.name(number)?));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the kernel's entry point for the call if it takes its arguments in
+/// another form on the platform than elsewhere.
+///
+fn old_form(arch: Arch, name: &str) -> Option<&'static str> {
+    OLD_FORMS
+        .iter()
+        .find(|&&(old_arch, old_name, _)| old_arch == arch && old_name == name)
+        .map(|&(_, _, entry)| entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        translate,
+        Arch,
+        Translation::{
+            Exact,
+            Substitute,
+        },
+    };
+    use ::core::{
+        assert_eq,
+        iter::Iterator,
+        option::Option::{
+            None,
+            Some,
+        },
+    };
+
+    #[test]
+    fn same_name() {
+        assert_eq!(translate(Arch::X86_64, 0, Arch::Aarch64), Some(Exact(63)));
+        assert_eq!(translate(Arch::X86_64, 0, Arch::X32), Some(Exact(0x4000_0000)));
+        assert_eq!(translate(Arch::X86_64, 9, Arch::Aarch64), Some(Exact(222)));
+        assert_eq!(translate(Arch::X86_64, 72, Arch::I686), Some(Exact(55)));
+        assert_eq!(translate(Arch::I686, 90, Arch::S390x), Some(Exact(90)));
+    }
+
+    #[test]
+    fn same_platform() {
+        for &arch in Arch::ALL {
+            let base = if arch == Arch::X32 { 0x4000_0000 } else { 0 };
+            for number in (base..base + 6000).chain(0x000f_0000..0x000f_0010) {
+                if arch.name(number).is_some() {
+                    assert_eq!(translate(arch, number, arch), Some(Exact(number)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn substitutes() {
+        assert_eq!(translate(Arch::X86_64, 2, Arch::Aarch64), Some(Substitute(56, "openat")));
+        assert_eq!(translate(Arch::I686, 221, Arch::X86_64), Some(Substitute(72, "fcntl")));
+        assert_eq!(translate(Arch::Arm, 403, Arch::X86_64), Some(Substitute(228, "clock_gettime")));
+    }
+
+    #[test]
+    fn freebsd() {
+        assert_eq!(translate(Arch::X86_64, 0, Arch::X86_64Freebsd), Some(Exact(3)));
+        assert_eq!(translate(Arch::X86_64Freebsd, 20, Arch::Aarch64), Some(Exact(172)));
+        assert_eq!(translate(Arch::X86_64Freebsd, 5, Arch::Aarch64), Some(Substitute(56, "openat")));
+        assert_eq!(translate(Arch::X86_64, 4, Arch::X86_64Freebsd), Some(Substitute(552, "fstatat")));
+        assert_eq!(translate(Arch::X86_64, 79, Arch::X86_64Freebsd), Some(Substitute(326, "__getcwd")));
+        assert_eq!(translate(Arch::X86_64Freebsd, 326, Arch::X86_64), Some(Substitute(79, "getcwd")));
+        assert_eq!(translate(Arch::X86_64, 13, Arch::X86_64Freebsd), Some(Substitute(416, "sigaction")));
+        assert_eq!(translate(Arch::X86_64Freebsd, 416, Arch::Aarch64), Some(Substitute(134, "rt_sigaction")));
+        assert_eq!(translate(Arch::X86_64Freebsd, 454, Arch::X86_64), None);
+        assert_eq!(translate(Arch::X86_64, 202, Arch::X86_64Freebsd), None);
+    }
+
+    #[test]
+    fn old_forms() {
+        assert_eq!(translate(Arch::X86_64, 9, Arch::I686), Some(Substitute(192, "mmap2")));
+        assert_eq!(translate(Arch::X86_64, 9, Arch::S390x), Some(Substitute(90, "mmap")));
+        assert_eq!(translate(Arch::I686, 90, Arch::X86_64), Some(Substitute(9, "mmap")));
+        assert_eq!(translate(Arch::X86_64, 23, Arch::I686), Some(Substitute(142, "_newselect")));
+        assert_eq!(translate(Arch::X86_64, 23, Arch::Powerpc64), Some(Substitute(142, "_newselect")));
+        assert_eq!(translate(Arch::X86_64, 23, Arch::Aarch64), Some(Substitute(72, "pselect6")));
+        assert_eq!(translate(Arch::I686, 142, Arch::X86_64), Some(Substitute(23, "select")));
+        assert_eq!(translate(Arch::I686, 82, Arch::Powerpc64), Some(Substitute(142, "_newselect")));
+    }
+
+    #[test]
+    fn no_equivalent() {
+        assert_eq!(translate(Arch::X86_64, 158, Arch::Aarch64), None);
+        assert_eq!(translate(Arch::X86_64, 100_000, Arch::Aarch64), None);
+    }
+}
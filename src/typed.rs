@@ -0,0 +1,343 @@
+use crate::{
+    Errno,
+    IntoSyscallArg,
+};
+use ::core::result::Result;
+
+/// Performs a system call with no arguments and returns the result.
+///
+/// The first argument specifies the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_typed(number: usize) -> Result<usize, Errno> {
+    crate::syscall_0(number)
+}
+
+/// Performs a system call with one argument and returns the result.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_typed<A0: IntoSyscallArg>(number: usize, a0: A0) -> Result<usize, Errno> {
+    crate::syscall_1(number, a0.into_syscall_arg())
+}
+
+/// Performs a system call with two arguments and returns the result.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_typed<A0: IntoSyscallArg, A1: IntoSyscallArg>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+) -> Result<usize, Errno> {
+    crate::syscall_2(number, a0.into_syscall_arg(), a1.into_syscall_arg())
+}
+
+/// Performs a system call with three arguments and returns the result.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_typed<A0: IntoSyscallArg, A1: IntoSyscallArg, A2: IntoSyscallArg>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+) -> Result<usize, Errno> {
+    crate::syscall_3(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with four arguments and returns the result.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_typed<
+    A0: IntoSyscallArg,
+    A1: IntoSyscallArg,
+    A2: IntoSyscallArg,
+    A3: IntoSyscallArg,
+>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+    a3: A3,
+) -> Result<usize, Errno> {
+    crate::syscall_4(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+        a3.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with five arguments and returns the result.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_typed<
+    A0: IntoSyscallArg,
+    A1: IntoSyscallArg,
+    A2: IntoSyscallArg,
+    A3: IntoSyscallArg,
+    A4: IntoSyscallArg,
+>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+    a3: A3,
+    a4: A4,
+) -> Result<usize, Errno> {
+    crate::syscall_5(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+        a3.into_syscall_arg(),
+        a4.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with six arguments and returns the result.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_typed<
+    A0: IntoSyscallArg,
+    A1: IntoSyscallArg,
+    A2: IntoSyscallArg,
+    A3: IntoSyscallArg,
+    A4: IntoSyscallArg,
+    A5: IntoSyscallArg,
+>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+    a3: A3,
+    a4: A4,
+    a5: A5,
+) -> Result<usize, Errno> {
+    crate::syscall_6(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+        a3.into_syscall_arg(),
+        a4.into_syscall_arg(),
+        a5.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with no arguments which never returns.
+///
+/// The first argument specifies the system call.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_typed_nr(number: usize) -> ! {
+    crate::syscall_0_nr(number)
+}
+
+/// Performs a system call with one argument which never returns.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_typed_nr<A0: IntoSyscallArg>(number: usize, a0: A0) -> ! {
+    crate::syscall_1_nr(number, a0.into_syscall_arg())
+}
+
+/// Performs a system call with two arguments which never returns.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_typed_nr<A0: IntoSyscallArg, A1: IntoSyscallArg>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+) -> ! {
+    crate::syscall_2_nr(number, a0.into_syscall_arg(), a1.into_syscall_arg())
+}
+
+/// Performs a system call with three arguments which never returns.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_typed_nr<A0: IntoSyscallArg, A1: IntoSyscallArg, A2: IntoSyscallArg>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+) -> ! {
+    crate::syscall_3_nr(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with four arguments which never returns.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_typed_nr<
+    A0: IntoSyscallArg,
+    A1: IntoSyscallArg,
+    A2: IntoSyscallArg,
+    A3: IntoSyscallArg,
+>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+    a3: A3,
+) -> ! {
+    crate::syscall_4_nr(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+        a3.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with five arguments which never returns.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_typed_nr<
+    A0: IntoSyscallArg,
+    A1: IntoSyscallArg,
+    A2: IntoSyscallArg,
+    A3: IntoSyscallArg,
+    A4: IntoSyscallArg,
+>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+    a3: A3,
+    a4: A4,
+) -> ! {
+    crate::syscall_5_nr(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+        a3.into_syscall_arg(),
+        a4.into_syscall_arg(),
+    )
+}
+
+/// Performs a system call with six arguments which never returns.
+///
+/// The first argument specifies the system call, and each remaining argument
+/// is converted with `IntoSyscallArg`.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_typed_nr<
+    A0: IntoSyscallArg,
+    A1: IntoSyscallArg,
+    A2: IntoSyscallArg,
+    A3: IntoSyscallArg,
+    A4: IntoSyscallArg,
+    A5: IntoSyscallArg,
+>(
+    number: usize,
+    a0: A0,
+    a1: A1,
+    a2: A2,
+    a3: A3,
+    a4: A4,
+    a5: A5,
+) -> ! {
+    crate::syscall_6_nr(
+        number,
+        a0.into_syscall_arg(),
+        a1.into_syscall_arg(),
+        a2.into_syscall_arg(),
+        a3.into_syscall_arg(),
+        a4.into_syscall_arg(),
+        a5.into_syscall_arg(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        syscall_1_typed,
+        syscall_4_typed,
+    };
+    use crate::{
+        errno::{
+            EBADF,
+            EFAULT,
+        },
+        nr,
+    };
+    use ::core::{
+        assert_eq,
+        convert::TryFrom,
+        option::Option::None,
+        result::Result::{
+            Err,
+            Ok,
+        },
+    };
+
+    const AT_FDCWD: i32 = -100;
+    const O_RDONLY: u32 = 0;
+
+    #[test]
+    fn open_close() {
+        unsafe {
+            let fd = syscall_4_typed(nr::OPENAT, AT_FDCWD, b"/\0", O_RDONLY, ()).unwrap();
+            let fd = i32::try_from(fd).unwrap();
+            assert_eq!(syscall_1_typed(nr::CLOSE, fd), Ok(0));
+            assert_eq!(syscall_1_typed(nr::CLOSE, fd), Err(EBADF));
+            assert_eq!(syscall_4_typed(nr::OPENAT, AT_FDCWD, None::<&u8>, O_RDONLY, ()), Err(EFAULT));
+        }
+    }
+}
@@ -0,0 +1,698 @@
+//! Calls through the vDSO, the shared object the kernel maps into every
+//! process to perform some system calls without entering the kernel.
+//!
+//! `init` locates the vDSO and resolves its entry points, after which the
+//! functions in this module call them. Until then, or when the vDSO lacks a
+//! symbol, they fall back to the system call. `Vdso` can also be used directly
+//! to look up other symbols.
+//!
+//! The vDSO isn't used on `powerpc64-linux`, where its entry points report
+//! errors through the condition register instead of the C return value.
+//!
+
+use crate::{
+    auxv::Auxv,
+    elf::{
+        Dyn,
+        Ehdr,
+        HashWord,
+        Phdr,
+        Sym,
+        Verdaux,
+        Verdef,
+        CLASS,
+        DT_GNU_HASH,
+        DT_HASH,
+        DT_NULL,
+        DT_STRTAB,
+        DT_SYMTAB,
+        DT_VERDEF,
+        DT_VERSYM,
+        EI_CLASS,
+        MAGIC,
+        PT_DYNAMIC,
+        PT_LOAD,
+        SHN_UNDEF,
+        STB_GLOBAL,
+        STB_WEAK,
+        STT_FUNC,
+        VER_FLG_BASE,
+    },
+    nr,
+    Errno,
+};
+use ::core::{
+    clone::Clone,
+    cmp::{
+        Eq,
+        PartialEq,
+    },
+    convert::From,
+    default::Default,
+    fmt::Debug,
+    iter::Iterator,
+    marker::Copy,
+    mem,
+    option::Option::{
+        self,
+        None,
+        Some,
+    },
+    ptr,
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+pub use crate::auxv::AT_SYSINFO_EHDR;
+
+/// The kernel's `long`, which is 64 bits on `x32-linux`.
+///
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+pub type KernelLong = i64;
+
+/// The kernel's `long`.
+///
+#[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+pub type KernelLong = isize;
+
+/// The kernel's `__kernel_timespec`, with a 64-bit time on every platform.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+/// The kernel's `timeval`, with a time the size of `KernelLong`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Timeval {
+    pub tv_sec: KernelLong,
+    pub tv_usec: KernelLong,
+}
+
+/// The kernel's `timezone`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Timezone {
+    pub tz_minuteswest: i32,
+    pub tz_dsttime: i32,
+}
+
+/// The vDSO's `clock_gettime`, or `clock_gettime64` on 32-bit platforms.
+///
+pub type ClockGettimeFn = unsafe extern "C" fn(clock: i32, ts: *mut Timespec) -> i32;
+
+/// The vDSO's `gettimeofday`.
+///
+pub type GettimeofdayFn = unsafe extern "C" fn(tv: *mut Timeval, tz: *mut Timezone) -> i32;
+
+/// The vDSO's `time`.
+///
+pub type TimeFn = unsafe extern "C" fn(t: *mut KernelLong) -> KernelLong;
+
+/// The vDSO's `getcpu`.
+///
+pub type GetcpuFn = unsafe extern "C" fn(cpu: *mut u32, node: *mut u32, cache: *mut u8) -> i32;
+
+/// The names of the vDSO's entry points on a platform, and the version they
+/// are defined with.
+///
+struct Symbols {
+    version: &'static str,
+    clock_gettime: Option<&'static str>,
+    gettimeofday: Option<&'static str>,
+    time: Option<&'static str>,
+    getcpu: Option<&'static str>,
+}
+
+#[cfg(target_arch = "aarch64")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6.39",
+    clock_gettime: Some("__kernel_clock_gettime"),
+    gettimeofday: Some("__kernel_gettimeofday"),
+    time: None,
+    getcpu: None,
+};
+
+#[cfg(any(target_arch = "arm", target_arch = "mips"))]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6",
+    clock_gettime: Some("__vdso_clock_gettime64"),
+    gettimeofday: Some("__vdso_gettimeofday"),
+    time: None,
+    getcpu: None,
+};
+
+#[cfg(target_arch = "loongarch64")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_5.10",
+    clock_gettime: Some("__vdso_clock_gettime"),
+    gettimeofday: Some("__vdso_gettimeofday"),
+    time: None,
+    getcpu: Some("__vdso_getcpu"),
+};
+
+#[cfg(target_arch = "mips64")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6",
+    clock_gettime: Some("__vdso_clock_gettime"),
+    gettimeofday: Some("__vdso_gettimeofday"),
+    time: None,
+    getcpu: None,
+};
+
+#[cfg(target_arch = "powerpc64")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6.15",
+    clock_gettime: None,
+    gettimeofday: None,
+    time: None,
+    getcpu: None,
+};
+
+#[cfg(target_arch = "riscv64")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_4.15",
+    clock_gettime: Some("__vdso_clock_gettime"),
+    gettimeofday: Some("__vdso_gettimeofday"),
+    time: None,
+    getcpu: Some("__vdso_getcpu"),
+};
+
+#[cfg(target_arch = "s390x")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6.29",
+    clock_gettime: Some("__kernel_clock_gettime"),
+    gettimeofday: Some("__kernel_gettimeofday"),
+    time: None,
+    getcpu: Some("__kernel_getcpu"),
+};
+
+#[cfg(target_arch = "x86")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6",
+    clock_gettime: Some("__vdso_clock_gettime64"),
+    gettimeofday: Some("__vdso_gettimeofday"),
+    time: Some("__vdso_time"),
+    getcpu: Some("__vdso_getcpu"),
+};
+
+#[cfg(target_arch = "x86_64")]
+const SYMBOLS: Symbols = Symbols {
+    version: "LINUX_2.6",
+    clock_gettime: Some("__vdso_clock_gettime"),
+    gettimeofday: Some("__vdso_gettimeofday"),
+    time: Some("__vdso_time"),
+    getcpu: Some("__vdso_getcpu"),
+};
+
+/// The system call `clock_gettime` falls back to, which takes a 64-bit time
+/// on every platform.
+///
+#[cfg(any(target_arch = "arm", target_arch = "mips", target_arch = "x86"))]
+const CLOCK_GETTIME_NR: usize = nr::CLOCK_GETTIME64;
+
+/// The system call `clock_gettime` falls back to.
+///
+#[cfg(not(any(target_arch = "arm", target_arch = "mips", target_arch = "x86")))]
+const CLOCK_GETTIME_NR: usize = nr::CLOCK_GETTIME;
+
+/// The clock `time` reads when falling back to `clock_gettime`.
+///
+const CLOCK_REALTIME: i32 = 0;
+
+static CLOCK_GETTIME: AtomicUsize = AtomicUsize::new(0);
+static GETTIMEOFDAY: AtomicUsize = AtomicUsize::new(0);
+static TIME: AtomicUsize = AtomicUsize::new(0);
+static GETCPU: AtomicUsize = AtomicUsize::new(0);
+
+/// A vDSO image mapped in memory, whose symbols can be looked up.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Vdso {
+    load_offset: usize,
+    symtab: *const Sym,
+    strtab: *const u8,
+    symbols: usize,
+    versym: *const u16,
+    verdef: *const Verdef,
+}
+
+impl Vdso {
+    /// Parses the vDSO whose address is found in the auxiliary vector.
+    ///
+    /// The argument points to the auxiliary vector, a sequence of key/value
+    /// pairs terminated by a zero key, as found on the initial process stack
+    /// past the environment pointers.
+    ///
+    /// Returns `None` if the auxiliary vector holds no `AT_SYSINFO_EHDR` or the
+    /// image can't be parsed.
+    ///
+    #[must_use]
+    pub unsafe fn from_auxv(auxv: *const usize) -> Option<Self> {
+        Self::from_base(Auxv::from_ptr(auxv).get(AT_SYSINFO_EHDR)? as *const u8)
+    }
+
+    /// Parses the vDSO whose ELF header is at the given address.
+    ///
+    /// Returns `None` if the address is null, the image isn't an ELF object of
+    /// the target's class, or it lacks a dynamic symbol table.
+    ///
+    #[must_use]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub unsafe fn from_base(base: *const u8) -> Option<Self> {
+        if base.is_null() {
+            return None;
+        }
+        let ehdr = &*base.cast::<Ehdr>();
+        if ehdr.e_ident[..4] != MAGIC || ehdr.e_ident[EI_CLASS] != CLASS {
+            return None;
+        }
+
+        let phdrs = base.add(ehdr.e_phoff).cast::<Phdr>();
+        let mut load_offset = None;
+        let mut dynamic = None;
+        for i in 0..usize::from(ehdr.e_phnum) {
+            let phdr = &*phdrs.add(i);
+            if phdr.p_type == PT_LOAD && load_offset.is_none() {
+                load_offset = Some((base as usize + phdr.p_offset).wrapping_sub(phdr.p_vaddr));
+            } else if phdr.p_type == PT_DYNAMIC {
+                dynamic = Some(phdr.p_vaddr);
+            }
+        }
+        let load_offset = load_offset?;
+        let mut entry = load_offset.wrapping_add(dynamic?) as *const Dyn;
+
+        let mut vdso = Self {
+            load_offset,
+            symtab: ptr::null(),
+            strtab: ptr::null(),
+            symbols: 0,
+            versym: ptr::null(),
+            verdef: ptr::null(),
+        };
+        let mut hash: *const HashWord = ptr::null();
+        let mut gnu_hash: *const u32 = ptr::null();
+        while (*entry).d_tag != DT_NULL {
+            let address = load_offset.wrapping_add((*entry).d_val);
+            match (*entry).d_tag {
+                DT_HASH => hash = address as *const HashWord,
+                DT_GNU_HASH => gnu_hash = address as *const u32,
+                DT_STRTAB => vdso.strtab = address as *const u8,
+                DT_SYMTAB => vdso.symtab = address as *const Sym,
+                DT_VERSYM => vdso.versym = address as *const u16,
+                DT_VERDEF => vdso.verdef = address as *const Verdef,
+                _ => {},
+            }
+            entry = entry.add(1);
+        }
+        if vdso.strtab.is_null() || vdso.symtab.is_null() {
+            return None;
+        }
+        if vdso.verdef.is_null() {
+            vdso.versym = ptr::null();
+        }
+
+        vdso.symbols = if !gnu_hash.is_null() {
+            gnu_hash_symbols(gnu_hash)
+        } else if !hash.is_null() {
+            #[allow(clippy::cast_possible_truncation)]
+            let symbols = *hash.add(1) as usize;
+            symbols
+        } else {
+            return None;
+        };
+        Some(vdso)
+    }
+
+    /// Returns the address of a function with the given name and version, or
+    /// `None` if the vDSO doesn't define it.
+    ///
+    /// The version is ignored if the vDSO has no version information.
+    ///
+    #[must_use]
+    pub fn lookup(&self, name: &str, version: &str) -> Option<usize> {
+        unsafe {
+            for i in 0..self.symbols {
+                let sym = &*self.symtab.add(i);
+                let kind = sym.st_info & 0xf;
+                let binding = sym.st_info >> 4;
+                if kind != STT_FUNC
+                    || (binding != STB_GLOBAL && binding != STB_WEAK)
+                    || sym.st_shndx == SHN_UNDEF
+                    || !self.name_is(sym.st_name, name)
+                    || !self.version_is(i, version)
+                {
+                    continue;
+                }
+                return Some(self.load_offset.wrapping_add(sym.st_value));
+            }
+        }
+        None
+    }
+
+    /// Returns the vDSO's `clock_gettime`, or `clock_gettime64` on 32-bit
+    /// platforms.
+    ///
+    #[must_use]
+    pub fn clock_gettime(&self) -> Option<ClockGettimeFn> {
+        let address = self.lookup(SYMBOLS.clock_gettime?, SYMBOLS.version)?;
+        Some(unsafe { mem::transmute::<usize, ClockGettimeFn>(address) })
+    }
+
+    /// Returns the vDSO's `gettimeofday`.
+    ///
+    #[must_use]
+    pub fn gettimeofday(&self) -> Option<GettimeofdayFn> {
+        let address = self.lookup(SYMBOLS.gettimeofday?, SYMBOLS.version)?;
+        Some(unsafe { mem::transmute::<usize, GettimeofdayFn>(address) })
+    }
+
+    /// Returns the vDSO's `time`.
+    ///
+    #[must_use]
+    pub fn time(&self) -> Option<TimeFn> {
+        let address = self.lookup(SYMBOLS.time?, SYMBOLS.version)?;
+        Some(unsafe { mem::transmute::<usize, TimeFn>(address) })
+    }
+
+    /// Returns the vDSO's `getcpu`.
+    ///
+    #[must_use]
+    pub fn getcpu(&self) -> Option<GetcpuFn> {
+        let address = self.lookup(SYMBOLS.getcpu?, SYMBOLS.version)?;
+        Some(unsafe { mem::transmute::<usize, GetcpuFn>(address) })
+    }
+
+    /// Returns whether the string at the given offset of the string table is
+    /// the given name.
+    ///
+    unsafe fn name_is(&self, offset: u32, name: &str) -> bool {
+        let string = self.strtab.add(offset as usize);
+        for (i, &byte) in name.as_bytes().iter().enumerate() {
+            if *string.add(i) != byte {
+                return false;
+            }
+        }
+        *string.add(name.len()) == 0
+    }
+
+    /// Returns whether the symbol with the given index is defined with the
+    /// given version.
+    ///
+    #[allow(clippy::cast_ptr_alignment)]
+    unsafe fn version_is(&self, symbol: usize, version: &str) -> bool {
+        if self.versym.is_null() {
+            return true;
+        }
+        let index = *self.versym.add(symbol) & 0x7fff;
+        let mut def = self.verdef;
+        loop {
+            if (*def).vd_flags & VER_FLG_BASE == 0 && (*def).vd_ndx & 0x7fff == index {
+                break;
+            }
+            if (*def).vd_next == 0 {
+                return false;
+            }
+            def = def.cast::<u8>().add((*def).vd_next as usize).cast::<Verdef>();
+        }
+        let aux = &*def.cast::<u8>().add((*def).vd_aux as usize).cast::<Verdaux>();
+        self.name_is(aux.vda_name, version)
+    }
+}
+
+/// Returns the number of symbols covered by a `DT_GNU_HASH` table, which is one
+/// past the last symbol of the longest chain.
+///
+unsafe fn gnu_hash_symbols(table: *const u32) -> usize {
+    let buckets = *table as usize;
+    let offset = *table.add(1) as usize;
+    let bloom = *table.add(2) as usize;
+    let bucket = table.add(4 + bloom * (mem::size_of::<usize>() / 4));
+    let chain = bucket.add(buckets);
+    let last = (0..buckets).map(|i| *bucket.add(i) as usize).max().unwrap_or(0);
+    if last < offset {
+        return offset;
+    }
+    let mut symbol = last;
+    while *chain.add(symbol - offset) & 1 == 0 {
+        symbol += 1;
+    }
+    symbol + 1
+}
+
+/// Locates the vDSO through the auxiliary vector and resolves the entry points
+/// used by the functions in this module.
+///
+/// The argument points to the auxiliary vector, a sequence of key/value pairs
+/// terminated by a zero key, as found on the initial process stack past the
+/// environment pointers.
+///
+/// Returns `true` if the vDSO was found. Entry points it doesn't define keep
+/// using the system call.
+///
+pub unsafe fn init(auxv: *const usize) -> bool {
+    let vdso = match Vdso::from_auxv(auxv) {
+        Some(vdso) => vdso,
+        None => return false,
+    };
+    let address = |f: Option<usize>| f.unwrap_or(0);
+    CLOCK_GETTIME.store(address(vdso.clock_gettime().map(|f| f as usize)), Ordering::Relaxed);
+    GETTIMEOFDAY.store(address(vdso.gettimeofday().map(|f| f as usize)), Ordering::Relaxed);
+    TIME.store(address(vdso.time().map(|f| f as usize)), Ordering::Relaxed);
+    GETCPU.store(address(vdso.getcpu().map(|f| f as usize)), Ordering::Relaxed);
+    true
+}
+
+/// Converts the C return value of a vDSO entry point, which is a negated error
+/// number on failure.
+///
+#[inline(always)]
+#[allow(clippy::cast_sign_loss)]
+fn decode(ret: isize) -> Result<usize, Errno> {
+    if (-4095..0).contains(&ret) {
+        Err(Errno::new(ret.wrapping_neg() as usize))
+    } else {
+        Ok(ret as usize)
+    }
+}
+
+/// Reads the time of a clock, through the vDSO if possible.
+///
+/// This uses `clock_gettime64` on 32-bit platforms, so the time is 64 bits
+/// everywhere.
+///
+#[inline(always)]
+pub unsafe fn clock_gettime(clock: i32, ts: *mut Timespec) -> Result<usize, Errno> {
+    match CLOCK_GETTIME.load(Ordering::Relaxed) {
+        0 => crate::syscall!(CLOCK_GETTIME_NR, clock, ts),
+        f => decode(mem::transmute::<usize, ClockGettimeFn>(f)(clock, ts) as isize),
+    }
+}
+
+/// Reads the time of day and the timezone, through the vDSO if possible.
+///
+/// Either pointer may be null.
+///
+#[inline(always)]
+pub unsafe fn gettimeofday(tv: *mut Timeval, tz: *mut Timezone) -> Result<usize, Errno> {
+    match GETTIMEOFDAY.load(Ordering::Relaxed) {
+        0 => crate::syscall!(nr::GETTIMEOFDAY, tv, tz),
+        f => decode(mem::transmute::<usize, GettimeofdayFn>(f)(tv, tz) as isize),
+    }
+}
+
+/// Returns the time in seconds since the epoch and stores it through the
+/// pointer unless it is null, through the vDSO if possible.
+///
+/// Where the vDSO doesn't provide `time`, this reads `CLOCK_REALTIME` with
+/// `clock_gettime` instead, as not every platform has the system call.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn time(t: *mut KernelLong) -> Result<KernelLong, Errno> {
+    let now = match TIME.load(Ordering::Relaxed) {
+        0 => {
+            let mut ts = Timespec::default();
+            clock_gettime(CLOCK_REALTIME, &mut ts)?;
+            ts.tv_sec as KernelLong
+        },
+        f => mem::transmute::<usize, TimeFn>(f)(ptr::null_mut()),
+    };
+    if !t.is_null() {
+        *t = now;
+    }
+    Ok(now)
+}
+
+/// Reads the CPU and NUMA node the calling thread is running on, through the
+/// vDSO if possible.
+///
+/// Either pointer may be null.
+///
+#[inline(always)]
+pub unsafe fn getcpu(cpu: *mut u32, node: *mut u32) -> Result<usize, Errno> {
+    match GETCPU.load(Ordering::Relaxed) {
+        0 => crate::syscall!(nr::GETCPU, cpu, node, 0),
+        f => decode(mem::transmute::<usize, GetcpuFn>(f)(cpu, node, ptr::null_mut()) as isize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        clock_gettime,
+        decode,
+        gnu_hash_symbols,
+        init,
+        Timespec,
+        Vdso,
+        AT_SYSINFO_EHDR,
+        CLOCK_GETTIME_NR,
+        SYMBOLS,
+    };
+    use crate::{
+        auxv,
+        elf::{
+            Dyn,
+            Ehdr,
+            HashWord,
+            Phdr,
+            DT_GNU_HASH,
+            DT_HASH,
+            DT_NULL,
+            PT_DYNAMIC,
+        },
+        errno,
+    };
+    use ::core::{
+        assert,
+        assert_eq,
+        convert::From,
+        default::Default,
+        option::Option::{
+            self,
+            None,
+            Some,
+        },
+        ptr,
+        result::Result::{
+            Err,
+            Ok,
+        },
+    };
+
+    /// Returns the address of the vDSO of the test process, if it has one.
+    ///
+    fn base() -> Option<usize> {
+        let mut buf = [0; 128];
+        unsafe { auxv::read_proc(&mut buf) }.ok()?.get(AT_SYSINFO_EHDR)
+    }
+
+    #[test]
+    fn parse() {
+        assert!(unsafe { Vdso::from_base(ptr::null()) }.is_none());
+        assert!(unsafe { Vdso::from_base(b"\x7fELF\0\0\0\0\0\0\0\0\0\0\0\0".as_ptr()) }.is_none());
+        let base = match base() {
+            Some(base) => base,
+            None => return,
+        };
+        let vdso = unsafe { Vdso::from_base(base as *const u8) }.unwrap();
+        assert!(vdso.symbols > 0);
+        assert!(vdso.load_offset >= base);
+    }
+
+    #[test]
+    fn lookup() {
+        let vdso = match base() {
+            Some(base) => unsafe { Vdso::from_base(base as *const u8) }.unwrap(),
+            None => return,
+        };
+        let name = match SYMBOLS.clock_gettime {
+            Some(name) => name,
+            None => return,
+        };
+        let address = vdso.lookup(name, SYMBOLS.version).unwrap();
+        assert_eq!(vdso.clock_gettime().map(|f| f as usize), Some(address));
+        assert_eq!(vdso.lookup(name, "LINUX_0"), None);
+        assert_eq!(vdso.lookup(&name[..name.len() - 1], SYMBOLS.version), None);
+        assert_eq!(vdso.lookup("__vdso_none", SYMBOLS.version), None);
+        assert_eq!(vdso.lookup(SYMBOLS.version, SYMBOLS.version), None);
+    }
+
+    /// Checks that the symbol count found through `DT_GNU_HASH` matches the
+    /// chain count of `DT_HASH` when the vDSO has both.
+    ///
+    #[test]
+    #[allow(clippy::cast_ptr_alignment)]
+    fn hash() {
+        let base = match base() {
+            Some(base) => base as *const u8,
+            None => return,
+        };
+        unsafe {
+            let vdso = Vdso::from_base(base).unwrap();
+            let ehdr = &*base.cast::<Ehdr>();
+            let phdrs = base.add(ehdr.e_phoff).cast::<Phdr>();
+            let mut entry = ptr::null::<Dyn>();
+            for i in 0..usize::from(ehdr.e_phnum) {
+                let phdr = &*phdrs.add(i);
+                if phdr.p_type == PT_DYNAMIC {
+                    entry = vdso.load_offset.wrapping_add(phdr.p_vaddr) as *const Dyn;
+                }
+            }
+            let (mut hash, mut gnu_hash) = (None, None);
+            while (*entry).d_tag != DT_NULL {
+                let address = vdso.load_offset.wrapping_add((*entry).d_val);
+                match (*entry).d_tag {
+                    DT_HASH => hash = Some(address as *const HashWord),
+                    DT_GNU_HASH => gnu_hash = Some(address as *const u32),
+                    _ => {},
+                }
+                entry = entry.add(1);
+            }
+            if let (Some(hash), Some(gnu_hash)) = (hash, gnu_hash) {
+                #[allow(clippy::cast_possible_truncation)]
+                let chains = *hash.add(1) as usize;
+                assert_eq!(gnu_hash_symbols(gnu_hash), chains);
+                assert_eq!(vdso.symbols, chains);
+            }
+        }
+    }
+
+    #[test]
+    fn call() {
+        let mut buf = [0; 128];
+        let auxv = match unsafe { auxv::read_proc(&mut buf) } {
+            Ok(_) => buf.as_ptr(),
+            Err(_) => return,
+        };
+        let mut before = Timespec::default();
+        let mut now = Timespec::default();
+        unsafe {
+            assert_eq!(crate::syscall!(CLOCK_GETTIME_NR, 0, &mut before), Ok(0));
+            assert_eq!(init(auxv), base().is_some());
+            assert_eq!(clock_gettime(0, &mut now), Ok(0));
+        }
+        assert!(now.tv_sec >= before.tv_sec);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(decode(0), Ok(0));
+        assert_eq!(decode(7), Ok(7));
+        assert_eq!(decode(-22), Err(errno::EINVAL));
+        assert_eq!(decode(-4096), Ok(usize::MAX - 4095));
+    }
+}
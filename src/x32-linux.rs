@@ -0,0 +1,442 @@
+use crate::Errno;
+use ::core::{
+    arch::asm,
+    hint::unreachable_unchecked,
+    result::Result::{
+        self,
+        Err,
+        Ok,
+    },
+};
+
+/// Bit set in the system call number to select the x32 system call table.
+///
+/// This is applied automatically by every function in this module, which
+/// takes each argument as a full 64-bit register and checks the full 64-bit
+/// result for errors before truncating it.
+///
+pub const X32_SYSCALL_BIT: usize = 0x4000_0000;
+
+/// Performs a system call and returns the result.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
+#[inline(always)]
+pub unsafe fn syscall(rax: usize, a: &[u64]) -> Result<usize, Errno> {
+    match a.len() {
+        0 => syscall_0(rax),
+        1 => syscall_1(rax, a[0]),
+        2 => syscall_2(rax, a[0], a[1]),
+        3 => syscall_3(rax, a[0], a[1], a[2]),
+        4 => syscall_4(rax, a[0], a[1], a[2], a[3]),
+        5 => syscall_5(rax, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6(rax, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call which never returns.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
+/// This should only be used for calls like `exit` or `exit_group` which are
+/// guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_nr(rax: usize, a: &[u64]) -> ! {
+    match a.len() {
+        0 => syscall_0_nr(rax),
+        1 => syscall_1_nr(rax, a[0]),
+        2 => syscall_2_nr(rax, a[0], a[1]),
+        3 => syscall_3_nr(rax, a[0], a[1], a[2]),
+        4 => syscall_4_nr(rax, a[0], a[1], a[2], a[3]),
+        5 => syscall_5_nr(rax, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6_nr(rax, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => unreachable_unchecked(),
+    }
+}
+
+/// Performs a system call with no arguments and returns the result.
+///
+/// The argument specifies the system call.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_0(rax: usize) -> Result<usize, Errno> {
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with no arguments which never returns.
+///
+/// The argument specifies the system call.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_nr(rax: usize) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with one argument and returns the result.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_1(rax: usize, rdi: u64) -> Result<usize, Errno> {
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with one argument and never returns.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_nr(rax: usize, rdi: u64) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        in("rdi") rdi,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with two arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_2(rax: usize, rdi: u64, rsi: u64) -> Result<usize, Errno> {
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with two arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_nr(rax: usize, rdi: u64, rsi: u64) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with three arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_3(rax: usize, rdi: u64, rsi: u64, rdx: u64) -> Result<usize, Errno> {
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with three arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_nr(rax: usize, rdi: u64, rsi: u64, rdx: u64) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with four arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_4(rax: usize, rdi: u64, rsi: u64, rdx: u64, r10: u64) -> Result<usize, Errno> {
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with four arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_nr(rax: usize, rdi: u64, rsi: u64, rdx: u64, r10: u64) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with five arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_5(
+    rax: usize,
+    rdi: u64,
+    rsi: u64,
+    rdx: u64,
+    r10: u64,
+    r8: u64,
+) -> Result<usize, Errno>
+{
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with five arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_nr(rax: usize, rdi: u64, rsi: u64, rdx: u64, r10: u64, r8: u64) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with six arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn syscall_6(
+    rax: usize,
+    rdi: u64,
+    rsi: u64,
+    rdx: u64,
+    r10: u64,
+    r8: u64,
+    r9: u64,
+) -> Result<usize, Errno>
+{
+    let mut rax = (rax | X32_SYSCALL_BIT) as u64;
+    asm!(
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
+    );
+    if rax < 0xffff_ffff_ffff_f000 {
+        Ok(rax as usize)
+    } else {
+        Err(Errno::new(rax.wrapping_neg() as usize))
+    }
+}
+
+/// Performs a system call with six arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_nr(rax: usize, rdi: u64, rsi: u64, rdx: u64, r10: u64, r8: u64, r9: u64) -> ! {
+    asm!(
+        "syscall",
+        in("rax") (rax | X32_SYSCALL_BIT) as u64,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        options(noreturn, nostack),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(rax);
+    syscall_1_as = syscall_1(rax, rdi);
+    syscall_2_as = syscall_2(rax, rdi, rsi);
+    syscall_3_as = syscall_3(rax, rdi, rsi, rdx);
+    syscall_4_as = syscall_4(rax, rdi, rsi, rdx, r10);
+    syscall_5_as = syscall_5(rax, rdi, rsi, rdx, r10, r8);
+    syscall_6_as = syscall_6(rax, rdi, rsi, rdx, r10, r8, r9);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        syscall_1,
+        syscall_3,
+        syscall_4,
+    };
+    use ::core::{
+        assert,
+        assert_eq,
+        result::Result::Ok,
+    };
+
+    const CLOSE: usize = 3;
+    const LSEEK: usize = 8;
+    const OPENAT: usize = 257;
+    const AT_FDCWD: u64 = u64::MAX - 99;
+    const SEEK_SET: u64 = 0;
+    const SEEK_CUR: u64 = 1;
+
+    /// Seeks with negative and 64-bit offsets, which only reach the kernel
+    /// intact if they are passed in full registers.
+    ///
+    #[test]
+    fn offsets() {
+        unsafe {
+            let path = b"/proc/self/exe\0".as_ptr() as usize as u64;
+            let fd = syscall_4(OPENAT, AT_FDCWD, path, 0, 0).unwrap() as u64;
+            assert_eq!(syscall_3(LSEEK, fd, 16, SEEK_SET), Ok(16));
+            assert_eq!(syscall_3(LSEEK, fd, u64::MAX, SEEK_CUR), Ok(15));
+            assert!(syscall_3(LSEEK, fd, 0x1_0000_000f, SEEK_SET).is_ok());
+            assert_eq!(syscall_3(LSEEK, fd, 0xffff_ffff_0000_0000, SEEK_CUR), Ok(15));
+            assert_eq!(syscall_1(CLOSE, fd), Ok(0));
+        }
+    }
+}
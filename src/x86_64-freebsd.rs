@@ -1,4 +1,9 @@
+use crate::{
+    errno::E2BIG,
+    Errno,
+};
 use ::core::{
+    arch::asm,
     hint::unreachable_unchecked,
     result::Result::{
         self,
@@ -7,13 +12,26 @@ use ::core::{
     },
 };
 
+/// Indirect system call taking the number of another call as its first
+/// argument.
+///
+pub const SYS_SYSCALL: usize = 0;
+
+/// Indirect system call taking the number of another call as its first
+/// argument, padded to 64 bits so that 64-bit arguments keep their alignment.
+///
+pub const SYS___SYSCALL: usize = 198;
+
 /// Performs a system call and returns the result.
 ///
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
 #[inline(always)]
-pub unsafe fn syscall(rax: usize, a: &[usize]) -> Result<usize, usize> {
+pub unsafe fn syscall(rax: usize, a: &[usize]) -> Result<usize, Errno> {
     match a.len() {
         0 => syscall_0(rax),
         1 => syscall_1(rax, a[0]),
@@ -31,6 +49,9 @@ pub unsafe fn syscall(rax: usize, a: &[usize]) -> Result<usize, usize> {
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
 /// This should only be used for calls like `exit` or `exit_group` which are
 /// guaranteed to never return.
 ///
@@ -48,31 +69,78 @@ pub unsafe fn syscall_nr(rax: usize, a: &[usize]) -> ! {
     }
 }
 
+/// Performs a system call through the indirect `__syscall` entry and returns
+/// the result.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it. Since the system call itself takes the first
+/// register, up to seven arguments are accepted with any past the fifth
+/// spilled onto the stack.
+///
+/// A slice of more than seven arguments is rejected with `E2BIG` without
+/// performing the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_indirect(rdi: usize, a: &[usize]) -> Result<usize, Errno> {
+    match a.len() {
+        0 => syscall_1(SYS___SYSCALL, rdi),
+        1 => syscall_2(SYS___SYSCALL, rdi, a[0]),
+        2 => syscall_3(SYS___SYSCALL, rdi, a[0], a[1]),
+        3 => syscall_4(SYS___SYSCALL, rdi, a[0], a[1], a[2]),
+        4 => syscall_5(SYS___SYSCALL, rdi, a[0], a[1], a[2], a[3]),
+        5 => syscall_6(SYS___SYSCALL, rdi, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_7(SYS___SYSCALL, rdi, a[0], a[1], a[2], a[3], a[4], a[5]),
+        7 => syscall_8(SYS___SYSCALL, rdi, a[0], a[1], a[2], a[3], a[4], a[5], a[6]),
+        _ => Err(E2BIG),
+    }
+}
+
+/// Performs a system call and returns both result registers.
+///
+/// The first argument specifies the system call, and the second is a slice of
+/// arguments to pass it.
+///
+/// A slice of more than six arguments is rejected with `E2BIG` without
+/// performing the system call.
+///
+#[inline(always)]
+pub unsafe fn syscall_pair(rax: usize, a: &[usize]) -> Result<(usize, usize), Errno> {
+    match a.len() {
+        0 => syscall_0_pair(rax),
+        1 => syscall_1_pair(rax, a[0]),
+        2 => syscall_2_pair(rax, a[0], a[1]),
+        3 => syscall_3_pair(rax, a[0], a[1], a[2]),
+        4 => syscall_4_pair(rax, a[0], a[1], a[2], a[3]),
+        5 => syscall_5_pair(rax, a[0], a[1], a[2], a[3], a[4]),
+        6 => syscall_6_pair(rax, a[0], a[1], a[2], a[3], a[4], a[5]),
+        _ => Err(E2BIG),
+    }
+}
+
 /// Performs a system call with no arguments and returns the result.
 ///
 /// The argument specifies the system call.
 ///
 #[inline(always)]
-pub unsafe fn syscall_0(mut rax: usize) -> Result<usize, usize> {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        :
-        : "rcx", "r11", "memory"
-        : "volatile"
+pub unsafe fn syscall_0(mut rax: usize) -> Result<usize, Errno> {
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        lateout("rdx") _,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -84,15 +152,11 @@ pub unsafe fn syscall_0(mut rax: usize) -> Result<usize, usize> {
 ///
 #[inline(always)]
 pub unsafe fn syscall_0_nr(rax: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+    asm!(
+        "syscall",
+        in("rax") rax,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with one argument and returns the result.
@@ -101,26 +165,26 @@ pub unsafe fn syscall_0_nr(rax: usize) -> ! {
 /// argument to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_1(mut rax: usize, rdi: usize) -> Result<usize, usize> {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        : "{rdi}"(rdi)
-        : "rcx", "r11", "memory"
-        : "volatile"
+pub unsafe fn syscall_1(mut rax: usize, rdi: usize) -> Result<usize, Errno> {
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        lateout("rdx") _,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -133,15 +197,12 @@ pub unsafe fn syscall_1(mut rax: usize, rdi: usize) -> Result<usize, usize> {
 ///
 #[inline(always)]
 pub unsafe fn syscall_1_nr(rax: usize, rdi: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax), "{rdi}"(rdi)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+    asm!(
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with two arguments and returns the result.
@@ -150,26 +211,27 @@ pub unsafe fn syscall_1_nr(rax: usize, rdi: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_2(mut rax: usize, rdi: usize, rsi: usize) -> Result<usize, usize> {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        : "{rdi}"(rdi), "{rsi}"(rsi)
-        : "rcx", "r11", "memory"
-        : "volatile"
+pub unsafe fn syscall_2(mut rax: usize, rdi: usize, rsi: usize) -> Result<usize, Errno> {
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        lateout("rdx") _,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -182,15 +244,13 @@ pub unsafe fn syscall_2(mut rax: usize, rdi: usize, rsi: usize) -> Result<usize,
 ///
 #[inline(always)]
 pub unsafe fn syscall_2_nr(rax: usize, rdi: usize, rsi: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+    asm!(
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with three arguments and returns the result.
@@ -199,26 +259,27 @@ pub unsafe fn syscall_2_nr(rax: usize, rdi: usize, rsi: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_3(mut rax: usize, rdi: usize, rsi: usize, rdx: usize) -> Result<usize, usize> {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx)
-        : "rcx", "r10", "r11", "memory"
-        : "volatile"
+pub unsafe fn syscall_3(mut rax: usize, rdi: usize, rsi: usize, rdx: usize) -> Result<usize, Errno> {
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx => _,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -231,15 +292,14 @@ pub unsafe fn syscall_3(mut rax: usize, rdi: usize, rsi: usize, rdx: usize) -> R
 ///
 #[inline(always)]
 pub unsafe fn syscall_3_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+    asm!(
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with four arguments and returns the result.
@@ -248,26 +308,27 @@ pub unsafe fn syscall_3_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize) -> !
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_4(mut rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize) -> Result<usize, usize> {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10)
-        : "rcx", "r11", "memory"
-        : "volatile"
+pub unsafe fn syscall_4(mut rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize) -> Result<usize, Errno> {
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx => _,
+        inlateout("r10") r10 => _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -280,15 +341,15 @@ pub unsafe fn syscall_4(mut rax: usize, rdi: usize, rsi: usize, rdx: usize, r10:
 ///
 #[inline(always)]
 pub unsafe fn syscall_4_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+    asm!(
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with five arguments and returns the result.
@@ -304,27 +365,28 @@ pub unsafe fn syscall_5(
     rdx: usize,
     r10: usize,
     r8: usize,
-) -> Result<usize, usize>
+) -> Result<usize, Errno>
 {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8)
-        : "rcx", "r11", "memory"
-        : "volatile"
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx => _,
+        inlateout("r10") r10 => _,
+        inlateout("r8") r8 => _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -337,15 +399,16 @@ pub unsafe fn syscall_5(
 ///
 #[inline(always)]
 pub unsafe fn syscall_5_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize, r8: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+    asm!(
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with six arguments and returns the result.
@@ -362,27 +425,28 @@ pub unsafe fn syscall_6(
     r10: usize,
     r8: usize,
     r9: usize,
-) -> Result<usize, usize>
+) -> Result<usize, Errno>
 {
-    let carry: usize;
-    asm!("
-        syscall
-        jnc 1f;
-        movq $$1, $1
-        jmp 2f
-        1:
-        movq $$0, $1
-        2:
-        "
-        : "+{rax}"(rax), "=r"(carry)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8), "{r9}"(r9)
-        : "rcx", "r11", "memory"
-        : "volatile"
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx => _,
+        inlateout("r10") r10 => _,
+        inlateout("r8") r8 => _,
+        inlateout("r9") r9 => _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
     if carry == 0 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax))
     }
 }
 
@@ -395,13 +459,544 @@ pub unsafe fn syscall_6(
 ///
 #[inline(always)]
 pub unsafe fn syscall_6_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize, r8: usize, r9: usize) -> ! {
-    asm!("
-        syscall
-        "
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8), "{r9}"(r9)
-        : "rcx", "r11"
-        : "volatile"
+    asm!(
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        options(noreturn, nostack),
+    )
+}
+
+/// Performs a system call with seven arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The seventh argument is passed on the stack just above the slot the kernel
+/// expects to hold a return address.
+///
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn syscall_7(
+    mut rax: usize,
+    rdi: usize,
+    rsi: usize,
+    rdx: usize,
+    r10: usize,
+    r8: usize,
+    r9: usize,
+    sp8: usize,
+) -> Result<usize, Errno>
+{
+    let carry: u8;
+    asm!(
+        "sub rsp, 16",
+        "mov [rsp + 8], {sp8}",
+        "syscall",
+        "setc {carry}",
+        "add rsp, 16",
+        sp8 = in(reg) sp8,
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx => _,
+        inlateout("r10") r10 => _,
+        inlateout("r8") r8 => _,
+        inlateout("r9") r9 => _,
+        lateout("rcx") _,
+        lateout("r11") _,
+    );
+    if carry == 0 {
+        Ok(rax)
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with seven arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The seventh argument is passed on the stack just above the slot the kernel
+/// expects to hold a return address.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn syscall_7_nr(
+    rax: usize,
+    rdi: usize,
+    rsi: usize,
+    rdx: usize,
+    r10: usize,
+    r8: usize,
+    r9: usize,
+    sp8: usize,
+) -> ! {
+    asm!(
+        "sub rsp, 16",
+        "mov [rsp + 8], {sp8}",
+        "syscall",
+        sp8 = in(reg) sp8,
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        options(noreturn),
+    )
+}
+
+/// Performs a system call with eight arguments and returns the result.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The seventh and eighth arguments are passed on the stack just above the
+/// slot the kernel expects to hold a return address.
+///
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn syscall_8(
+    mut rax: usize,
+    rdi: usize,
+    rsi: usize,
+    rdx: usize,
+    r10: usize,
+    r8: usize,
+    r9: usize,
+    sp8: usize,
+    sp16: usize,
+) -> Result<usize, Errno>
+{
+    let carry: u8;
+    asm!(
+        "sub rsp, 24",
+        "mov [rsp + 8], {sp8}",
+        "mov [rsp + 16], {sp16}",
+        "syscall",
+        "setc {carry}",
+        "add rsp, 24",
+        sp8 = in(reg) sp8,
+        sp16 = in(reg) sp16,
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx => _,
+        inlateout("r10") r10 => _,
+        inlateout("r8") r8 => _,
+        inlateout("r9") r9 => _,
+        lateout("rcx") _,
+        lateout("r11") _,
+    );
+    if carry == 0 {
+        Ok(rax)
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with eight arguments which never returns.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// The seventh and eighth arguments are passed on the stack just above the
+/// slot the kernel expects to hold a return address.
+///
+/// This function should only be used for calls guaranteed to never return.
+///
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn syscall_8_nr(
+    rax: usize,
+    rdi: usize,
+    rsi: usize,
+    rdx: usize,
+    r10: usize,
+    r8: usize,
+    r9: usize,
+    sp8: usize,
+    sp16: usize,
+) -> ! {
+    asm!(
+        "sub rsp, 24",
+        "mov [rsp + 8], {sp8}",
+        "mov [rsp + 16], {sp16}",
+        "syscall",
+        sp8 = in(reg) sp8,
+        sp16 = in(reg) sp16,
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        options(noreturn),
+    )
+}
+
+/// Performs a system call with no arguments and returns both result
+/// registers.
+///
+/// The argument specifies the system call.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_0_pair(mut rax: usize) -> Result<(usize, usize), Errno> {
+    let rdx: usize;
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        lateout("rdx") rdx,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
+    );
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with one argument and returns both result
+/// registers.
+///
+/// The first argument specifies the system call, and the second is the
+/// argument to pass it.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_1_pair(mut rax: usize, rdi: usize) -> Result<(usize, usize), Errno> {
+    let rdx: usize;
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        lateout("rdx") rdx,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
+    );
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with two arguments and returns both result
+/// registers.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_2_pair(mut rax: usize, rdi: usize, rsi: usize) -> Result<(usize, usize), Errno> {
+    let rdx: usize;
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        lateout("rdx") rdx,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
+    );
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with three arguments and returns both result
+/// registers.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_3_pair(mut rax: usize, rdi: usize, rsi: usize, mut rdx: usize) -> Result<(usize, usize), Errno> {
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx,
+        lateout("r10") _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
+    );
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with four arguments and returns both result
+/// registers.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_4_pair(
+    mut rax: usize,
+    rdi: usize,
+    rsi: usize,
+    mut rdx: usize,
+    r10: usize,
+) -> Result<(usize, usize), Errno>
+{
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx,
+        inlateout("r10") r10 => _,
+        lateout("r8") _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
+    );
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with five arguments and returns both result
+/// registers.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_5_pair(
+    mut rax: usize,
+    rdi: usize,
+    rsi: usize,
+    mut rdx: usize,
+    r10: usize,
+    r8: usize,
+) -> Result<(usize, usize), Errno>
+{
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx,
+        inlateout("r10") r10 => _,
+        inlateout("r8") r8 => _,
+        lateout("r9") _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
     );
-    unreachable_unchecked()
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+/// Performs a system call with six arguments and returns both result
+/// registers.
+///
+/// The first argument specifies the system call, and the remaining arguments
+/// are the arguments to pass it.
+///
+/// On success the values of `rax` and `rdx` are returned, for calls such as
+/// `pipe` or `fork` which return a second value.
+///
+#[inline(always)]
+pub unsafe fn syscall_6_pair(
+    mut rax: usize,
+    rdi: usize,
+    rsi: usize,
+    mut rdx: usize,
+    r10: usize,
+    r8: usize,
+    r9: usize,
+) -> Result<(usize, usize), Errno>
+{
+    let carry: u8;
+    asm!(
+        "syscall",
+        "setc {carry}",
+        carry = lateout(reg_byte) carry,
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        inlateout("rdx") rdx,
+        inlateout("r10") r10 => _,
+        inlateout("r8") r8 => _,
+        inlateout("r9") r9 => _,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack),
+    );
+    if carry == 0 {
+        Ok((rax, rdx))
+    } else {
+        Err(Errno::new(rax))
+    }
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(rax);
+    syscall_1_as = syscall_1(rax, rdi);
+    syscall_2_as = syscall_2(rax, rdi, rsi);
+    syscall_3_as = syscall_3(rax, rdi, rsi, rdx);
+    syscall_4_as = syscall_4(rax, rdi, rsi, rdx, r10);
+    syscall_5_as = syscall_5(rax, rdi, rsi, rdx, r10, r8);
+    syscall_6_as = syscall_6(rax, rdi, rsi, rdx, r10, r8, r9);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        syscall_0,
+        syscall_0_pair,
+        syscall_2,
+        syscall_7,
+        syscall_8,
+        syscall_indirect,
+        syscall_pair,
+        SYS___SYSCALL,
+    };
+    use crate::{
+        errno::{
+            E2BIG,
+            EINVAL,
+        },
+        nr,
+    };
+    use ::core::{
+        assert,
+        assert_eq,
+        result::Result::{
+            Err,
+            Ok,
+        },
+    };
+
+    const PROT_READ_WRITE: usize = 3;
+    const MAP_PRIVATE_ANON: usize = 0x1002;
+
+    /// Unmaps a page mapped by `mmap` and checks that the mapping succeeded.
+    ///
+    unsafe fn unmap(page: usize) {
+        assert!(page != 0);
+        assert_eq!(syscall_2(nr::MUNMAP, page, 4096), Ok(0));
+    }
+
+    #[test]
+    fn pair() {
+        unsafe {
+            let pid = syscall_0(nr::GETPID);
+            assert_eq!(syscall_0_pair(nr::GETPID).map(|(rax, _)| rax), pid);
+            assert_eq!(syscall_pair(nr::GETPID, &[]).map(|(rax, _)| rax), pid);
+            assert_eq!(syscall_pair(nr::GETPID, &[0; 7]), Err(E2BIG));
+        }
+    }
+
+    /// Passes the offset of `mmap` through `__syscall`, which puts it on the
+    /// stack. Anonymous mappings fail with `EINVAL` unless it is zero.
+    ///
+    #[test]
+    fn stack_argument() {
+        unsafe {
+            let mmap = |offset| {
+                syscall_7(SYS___SYSCALL, nr::MMAP, 0, 4096, PROT_READ_WRITE, MAP_PRIVATE_ANON, usize::MAX, offset)
+            };
+            unmap(mmap(0).unwrap());
+            assert_eq!(mmap(4096), Err(EINVAL));
+
+            let mmap = |offset| {
+                syscall_8(SYS___SYSCALL, nr::MMAP, 0, 4096, PROT_READ_WRITE, MAP_PRIVATE_ANON, usize::MAX, offset, 4096)
+            };
+            unmap(mmap(0).unwrap());
+            assert_eq!(mmap(4096), Err(EINVAL));
+        }
+    }
+
+    #[test]
+    fn indirect() {
+        unsafe {
+            assert_eq!(syscall_indirect(nr::GETPID, &[]), syscall_0(nr::GETPID));
+            let mmap = |offset| {
+                syscall_indirect(nr::MMAP, &[0, 4096, PROT_READ_WRITE, MAP_PRIVATE_ANON, usize::MAX, offset])
+            };
+            unmap(mmap(0).unwrap());
+            assert_eq!(mmap(4096), Err(EINVAL));
+            assert_eq!(syscall_indirect(nr::GETPID, &[0; 8]), Err(E2BIG));
+        }
+    }
 }
@@ -1,4 +1,6 @@
+use crate::Errno;
 use ::core::{
+    arch::asm,
     hint::unreachable_unchecked,
     result::Result::{
         self,
@@ -12,8 +14,11 @@ use ::core::{
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall` where the length isn't known to be valid.
+///
 #[inline(always)]
-pub unsafe fn syscall(rax: usize, a: &[usize]) -> Result<usize, usize> {
+pub unsafe fn syscall(rax: usize, a: &[usize]) -> Result<usize, Errno> {
     match a.len() {
         0 => syscall_0(rax),
         1 => syscall_1(rax, a[0]),
@@ -31,6 +36,9 @@ pub unsafe fn syscall(rax: usize, a: &[usize]) -> Result<usize, usize> {
 /// The first argument specifies the system call, and the second is a slice of
 /// arguments to pass it.
 ///
+/// The slice must not hold more than six arguments, otherwise the behavior is
+/// undefined. Use `try_syscall_nr` where the length isn't known to be valid.
+///
 /// This should only be used for calls like `exit` or `exit_group` which are
 /// guaranteed to never return.
 ///
@@ -53,18 +61,18 @@ pub unsafe fn syscall_nr(rax: usize, a: &[usize]) -> ! {
 /// The argument specifies the system call.
 ///
 #[inline(always)]
-pub unsafe fn syscall_0(mut rax: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_0(mut rax: usize) -> Result<usize, Errno> {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        :
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -77,13 +85,10 @@ pub unsafe fn syscall_0(mut rax: usize) -> Result<usize, usize> {
 #[inline(always)]
 pub unsafe fn syscall_0_nr(rax: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with one argument and returns the result.
@@ -92,18 +97,19 @@ pub unsafe fn syscall_0_nr(rax: usize) -> ! {
 /// argument to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_1(mut rax: usize, rdi: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_1(mut rax: usize, rdi: usize) -> Result<usize, Errno> {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        : "{rdi}"(rdi)
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -117,13 +123,11 @@ pub unsafe fn syscall_1(mut rax: usize, rdi: usize) -> Result<usize, usize> {
 #[inline(always)]
 pub unsafe fn syscall_1_nr(rax: usize, rdi: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax), "{rdi}"(rdi)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with two arguments and returns the result.
@@ -132,18 +136,20 @@ pub unsafe fn syscall_1_nr(rax: usize, rdi: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_2(mut rax: usize, rdi: usize, rsi: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_2(mut rax: usize, rdi: usize, rsi: usize) -> Result<usize, Errno> {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        : "{rdi}"(rdi), "{rsi}"(rsi)
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -157,13 +163,12 @@ pub unsafe fn syscall_2(mut rax: usize, rdi: usize, rsi: usize) -> Result<usize,
 #[inline(always)]
 pub unsafe fn syscall_2_nr(rax: usize, rdi: usize, rsi: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with three arguments and returns the result.
@@ -172,18 +177,21 @@ pub unsafe fn syscall_2_nr(rax: usize, rdi: usize, rsi: usize) -> ! {
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_3(mut rax: usize, rdi: usize, rsi: usize, rdx: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_3(mut rax: usize, rdi: usize, rsi: usize, rdx: usize) -> Result<usize, Errno> {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx)
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -197,13 +205,13 @@ pub unsafe fn syscall_3(mut rax: usize, rdi: usize, rsi: usize, rdx: usize) -> R
 #[inline(always)]
 pub unsafe fn syscall_3_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with four arguments and returns the result.
@@ -212,18 +220,22 @@ pub unsafe fn syscall_3_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize) -> !
 /// are the arguments to pass it.
 ///
 #[inline(always)]
-pub unsafe fn syscall_4(mut rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize) -> Result<usize, usize> {
+pub unsafe fn syscall_4(mut rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize) -> Result<usize, Errno> {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10)
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -237,13 +249,14 @@ pub unsafe fn syscall_4(mut rax: usize, rdi: usize, rsi: usize, rdx: usize, r10:
 #[inline(always)]
 pub unsafe fn syscall_4_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with five arguments and returns the result.
@@ -259,19 +272,24 @@ pub unsafe fn syscall_5(
     rdx: usize,
     r10: usize,
     r8: usize,
-) -> Result<usize, usize>
+) -> Result<usize, Errno>
 {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8)
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -285,13 +303,15 @@ pub unsafe fn syscall_5(
 #[inline(always)]
 pub unsafe fn syscall_5_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize, r8: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        options(noreturn, nostack),
+    )
 }
 
 /// Performs a system call with six arguments and returns the result.
@@ -308,19 +328,25 @@ pub unsafe fn syscall_6(
     r10: usize,
     r8: usize,
     r9: usize,
-) -> Result<usize, usize>
+) -> Result<usize, Errno>
 {
     asm!(
-        "syscall"
-        : "+{rax}"(rax)
-        : "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8), "{r9}"(r9)
-        : "rcx", "r11", "memory"
-        : "volatile"
+        "syscall",
+        inlateout("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack, preserves_flags),
     );
     if rax < 0xffff_ffff_ffff_f000 {
         Ok(rax)
     } else {
-        Err(rax)
+        Err(Errno::new(rax.wrapping_neg()))
     }
 }
 
@@ -334,11 +360,24 @@ pub unsafe fn syscall_6(
 #[inline(always)]
 pub unsafe fn syscall_6_nr(rax: usize, rdi: usize, rsi: usize, rdx: usize, r10: usize, r8: usize, r9: usize) -> ! {
     asm!(
-        "syscall"
-        :
-        : "{rax}"(rax), "{rdi}"(rdi), "{rsi}"(rsi), "{rdx}"(rdx), "{r10}"(r10), "{r8}"(r8), "{r9}"(r9)
-        : "rcx", "r11"
-        : "volatile"
-    );
-    unreachable_unchecked()
+        "syscall",
+        in("rax") rax,
+        in("rdi") rdi,
+        in("rsi") rsi,
+        in("rdx") rdx,
+        in("r10") r10,
+        in("r8") r8,
+        in("r9") r9,
+        options(noreturn, nostack),
+    )
+}
+
+syscall_as! {
+    syscall_0_as = syscall_0(rax);
+    syscall_1_as = syscall_1(rax, rdi);
+    syscall_2_as = syscall_2(rax, rdi, rsi);
+    syscall_3_as = syscall_3(rax, rdi, rsi, rdx);
+    syscall_4_as = syscall_4(rax, rdi, rsi, rdx, r10);
+    syscall_5_as = syscall_5(rax, rdi, rsi, rdx, r10, r8);
+    syscall_6_as = syscall_6(rax, rdi, rsi, rdx, r10, r8, r9);
 }
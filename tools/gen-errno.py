@@ -0,0 +1,88 @@
+#!/usr/bin/env python3
+"""Generates the Linux error number tables in src/errno from syscalls/.
+
+Run from anywhere without arguments. Only the vendored errno.h headers are
+read, so the output is reproducible offline. Each #define of a number becomes
+an entry of the table, described by its trailing comment, and each #define of
+another name becomes an alias. #include and #undef are followed, which is how
+the architecture headers build on the generic ones.
+
+The FreeBSD table is not generated, as no copy of sys/errno.h is vendored.
+"""
+
+import os
+import re
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+SOURCES = os.path.join(ROOT, "syscalls")
+OUTPUT = os.path.join(ROOT, "src", "errno")
+
+# Each platform matches a module selected in src/lib.rs, and is described in
+# the first line of its documentation.
+PLATFORMS = {
+    "linux": ("asm-generic/errno.h", "Linux platforms using the generic error table"),
+    "linux-mips": ("mips/errno.h", "Linux on MIPS"),
+    "linux-powerpc": ("powerpc/errno.h", "Linux on PowerPC"),
+}
+
+
+def defines(path, macros):
+    """Adds the macros a header defines, in order, as (value, comment)."""
+    with open(os.path.join(SOURCES, path)) as f:
+        text = f.read()
+    # Drop block comments which don't trail a definition, such as the one
+    # explaining ENOSYS, so they can't be mistaken for a description.
+    text = re.sub(r"^\s*/\*.*?\*/", "", text, flags=re.S | re.M)
+    for line in text.splitlines():
+        m = re.match(r"\s*#\s*(\w+)\s*(.*?)\s*$", line)
+        if not m:
+            continue
+        directive, rest = m.groups()
+        if directive == "include":
+            defines(re.match(r"<(.*)>", rest).group(1), macros)
+        elif directive == "undef":
+            macros.pop(rest, None)
+        elif directive == "define":
+            d = re.match(r"(E\w+)\s+(\w+)\s*(?:/\*\s*(.*?)\s*\*/)?$", rest)
+            if d:
+                macros.pop(d.group(1), None)
+                macros[d.group(1)] = (d.group(2), d.group(3))
+    return macros
+
+
+def render(platform, path, title, macros):
+    errors = sorted((int(value), name, comment) for name, (value, comment) in macros.items() if value.isdigit())
+    aliases = [(name, value) for name, (value, _) in macros.items() if not value.isdigit()]
+    out = [
+        "//! Error numbers for %s.\n" % title,
+        "//!\n",
+        "//! Every constant is documented with its description, and the `name` and\n",
+        "//! `description` lookups return static strings so they work without\n",
+        "//! allocation.\n",
+        "//!\n",
+        "//! Generated by `tools/gen-errno.py` from `syscalls/%s`, do not edit.\n" % path,
+        "\n",
+        "use crate::Errno;\n",
+        "\n",
+        "errno_table! {\n",
+    ]
+    for number, name, comment in errors:
+        if comment is None:
+            raise ValueError("%s has no description in %s" % (name, platform))
+        out.append('    %s = %d => "%s",\n' % (name, number, comment))
+    out.append("}\n\n")
+    for name, value in aliases:
+        out.append("/// Alias of `%s`.\n///\npub const %s: Errno = %s;\n" % (value, name, value))
+    return "".join(out)
+
+
+def main():
+    for platform, (path, title) in PLATFORMS.items():
+        macros = defines(path, {})
+        with open(os.path.join(OUTPUT, platform + ".rs"), "w") as f:
+            f.write(render(platform, path, title, macros))
+        print("%s: %d names" % (platform, len(macros)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,326 @@
+#!/usr/bin/env python3
+"""Generates the system call number tables in src/nr from syscalls/.
+
+Run from anywhere without arguments. Only the vendored files are read, so the
+output is reproducible offline:
+
+* Kernel uapi headers (asm-generic/unistd.h and x86/unistd_*.h) are run through
+  a small preprocessor with the macros the architecture's own asm/unistd.h
+  defines before including them.
+* syscall.tbl files are filtered by abi and offset by the platform's base.
+* FreeBSD's syscalls.master is filtered by type, leaving out compatibility,
+  obsolete and unimplemented entries.
+"""
+
+import os
+import re
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+SOURCES = os.path.join(ROOT, "syscalls")
+OUTPUT = os.path.join(ROOT, "src", "nr")
+
+# Names defined with a __NR_ prefix which aren't system calls.
+NOT_SYSCALLS = {"arch_specific_syscall", "syscalls"}
+
+
+def header(path, defines=None, extra=None):
+    return {"kind": "header", "path": path, "defines": defines or {}, "extra": extra or {}}
+
+
+def table(path, abis, base=0, extra=None):
+    return {"kind": "table", "path": path, "abis": abis, "base": base, "extra": extra or {}}
+
+
+def master(path, variants=None):
+    return {"kind": "master", "path": path, "variants": variants or {}}
+
+
+# Types of syscalls.master entries which FreeBSD's sys/syscall.h defines under
+# their own name.
+MASTER_TYPES = {"STD", "NOSTD", "NOPROTO", "SYSMUX"}
+
+
+# Each platform matches a module selected in src/lib.rs. The defines mirror
+# arch/<arch>/include/uapi/asm/unistd.h for Linux 6.1, as do the extra calls,
+# which that header defines outside the numbering of the header or table.
+PLATFORMS = {
+    "aarch64-linux": header(
+        "asm-generic/unistd.h",
+        {
+            "__BITS_PER_LONG": 64,
+            "__ARCH_WANT_RENAMEAT": 1,
+            "__ARCH_WANT_NEW_STAT": 1,
+            "__ARCH_WANT_SET_GET_RLIMIT": 1,
+            "__ARCH_WANT_TIME32_SYSCALLS": 1,
+            "__ARCH_WANT_SYS_CLONE3": 1,
+            "__ARCH_WANT_MEMFD_SECRET": 1,
+        },
+    ),
+    "arm-linux": table(
+        "arm/syscall.tbl",
+        {"common", "eabi"},
+        extra={
+            "arm_breakpoint": 0x0F0001,
+            "arm_cacheflush": 0x0F0002,
+            "arm_usr26": 0x0F0003,
+            "arm_usr32": 0x0F0004,
+            "arm_set_tls": 0x0F0005,
+            "arm_get_tls": 0x0F0006,
+        },
+    ),
+    "i686-linux": header("x86/unistd_32.h"),
+    "loongarch64-linux": header(
+        "asm-generic/unistd.h",
+        {
+            "__BITS_PER_LONG": 64,
+            "__ARCH_WANT_SYS_CLONE": 1,
+            "__ARCH_WANT_SYS_CLONE3": 1,
+        },
+    ),
+    "mips-linux": table("mips/syscall_o32.tbl", {"o32"}, 4000),
+    "mips64-linux": table("mips/syscall_n64.tbl", {"n64"}, 5000),
+    "powerpc64-linux": table("powerpc/syscall.tbl", {"common", "64", "nospu"}),
+    "riscv64-linux": header(
+        "asm-generic/unistd.h",
+        {
+            "__BITS_PER_LONG": 64,
+            "__ARCH_WANT_NEW_STAT": 1,
+            "__ARCH_WANT_SET_GET_RLIMIT": 1,
+            "__ARCH_WANT_SYS_CLONE3": 1,
+            "__ARCH_WANT_MEMFD_SECRET": 1,
+        },
+        {"riscv_flush_icache": "__NR_arch_specific_syscall + 15"},
+    ),
+    "s390x-linux": table("s390/syscall.tbl", {"common", "64"}),
+    "x32-linux": header("x86/unistd_x32.h", {"__X32_SYSCALL_BIT": 0x40000000}),
+    "x86_64-linux": header("x86/unistd_64.h"),
+    # __syscall takes the number as a quad, and would otherwise share the
+    # variant of syscall.
+    "x86_64-freebsd": master("freebsd/syscalls.master", {"__syscall": "QuadSyscall"}),
+}
+
+
+def logical_lines(text):
+    text = re.sub(r"/\*.*?\*/", " ", text, flags=re.S)
+    return text.replace("\\\n", " ").splitlines()
+
+
+def evaluate(expr, macros, depth=0):
+    if depth > 32:
+        raise ValueError("recursive macro: " + expr)
+    expr = re.sub(
+        r"defined\s*(?:\(\s*(\w+)\s*\)|(\w+))",
+        lambda m: "1" if (m.group(1) or m.group(2)) in macros else "0",
+        expr,
+    )
+    expr = expr.replace("&&", " and ").replace("||", " or ")
+    expr = re.sub(r"!(?!=)", " not ", expr)
+
+    def identifier(m):
+        name = m.group(0)
+        if name in ("and", "or", "not"):
+            return name
+        if name in macros:
+            return "(%d)" % evaluate(str(macros[name]), macros, depth + 1)
+        return "0"
+
+    expr = re.sub(r"\b[A-Za-z_]\w*\b", identifier, expr)
+    return int(eval(expr, {"__builtins__": {}}))
+
+
+def preprocess(path, defines):
+    """Returns the __NR_ macros defined by a header, evaluated to numbers."""
+    macros = dict(defines)
+    # Each entry is (enclosing block active, this branch active, branch taken).
+    stack = []
+    active = True
+    with open(path) as f:
+        lines = logical_lines(f.read())
+    for line in lines:
+        m = re.match(r"\s*#\s*(\w+)\s*(.*?)\s*$", line)
+        if not m:
+            continue
+        directive, rest = m.groups()
+        if directive in ("if", "ifdef", "ifndef"):
+            if directive == "ifdef":
+                cond = rest in macros
+            elif directive == "ifndef":
+                cond = rest not in macros
+            else:
+                cond = active and bool(evaluate(rest, macros))
+            stack.append((active, active and cond, active and cond))
+            active = active and cond
+        elif directive == "elif":
+            outer, _, taken = stack.pop()
+            cond = outer and not taken and bool(evaluate(rest, macros))
+            stack.append((outer, cond, taken or cond))
+            active = cond
+        elif directive == "else":
+            outer, _, taken = stack.pop()
+            stack.append((outer, outer and not taken, True))
+            active = outer and not taken
+        elif directive == "endif":
+            active = stack.pop()[0]
+        elif not active:
+            continue
+        elif directive == "define":
+            d = re.match(r"(\w+)(\([^)]*\))?\s*(.*)", rest)
+            if not d.group(2):
+                macros[d.group(1)] = d.group(3) or "1"
+        elif directive == "undef":
+            macros.pop(rest, None)
+    if stack:
+        raise ValueError("unterminated conditional in " + path)
+    return macros
+
+
+def from_header(spec):
+    macros = preprocess(os.path.join(SOURCES, spec["path"]), spec["defines"])
+    for name, value in spec["extra"].items():
+        macros["__NR_" + name] = value
+    calls = {}
+    for name in macros:
+        if name.startswith("__NR_") and name[5:] not in NOT_SYSCALLS:
+            calls[name[5:]] = evaluate(str(macros[name]), macros)
+    return calls
+
+
+def from_table(spec):
+    calls = {}
+    with open(os.path.join(SOURCES, spec["path"])) as f:
+        for line in f:
+            fields = line.split("#", 1)[0].split()
+            if len(fields) >= 3 and fields[1] in spec["abis"]:
+                calls[fields[2]] = spec["base"] + int(fields[0])
+    calls.update(spec["extra"])
+    return calls
+
+
+def from_master(spec):
+    """Returns the entries of a syscalls.master, named as in sys/syscall.h."""
+    with open(os.path.join(SOURCES, spec["path"])) as f:
+        lines = [line for line in f if not line.startswith((";", "#"))]
+    entry = re.compile(
+        r"^(\d+)\s+\w+\s+([\w|]+)\s*(?:\{(.*?)\}[ \t]*(\w*)|\S*)",
+        re.M | re.S,
+    )
+    calls = {}
+    for m in entry.finditer("".join(lines)):
+        number, kind, prototype, alias = m.groups()
+        if kind.split("|")[0] not in MASTER_TYPES:
+            continue
+        name = alias or re.search(r"(\w+)\s*\(", prototype).group(1)
+        calls[name] = int(number)
+    return calls
+
+
+def variant(name, spec):
+    if name in spec.get("variants", {}):
+        return spec["variants"][name]
+    return "".join(part[:1].upper() + part[1:] for part in name.split("_"))
+
+
+def render(platform, spec, calls):
+    calls = sorted(calls.items(), key=lambda c: (c[1], c[0]))
+    out = [
+        "//! System call numbers for `%s`.\n" % platform,
+        "//!\n",
+        "//! Generated by `tools/gen-nr.py` from `syscalls/%s`, do not edit.\n" % spec["path"],
+        "//!\n",
+        "\n",
+        "use ::core::option::Option::{\n",
+        "    self,\n",
+        "    None,\n",
+        "    Some,\n",
+        "};\n",
+        "\n",
+    ]
+    for name, number in calls:
+        literal = "{:_}".format(number) if number > 99999 else str(number)
+        out.append("pub const %s: usize = %s;\n" % (name.upper(), literal))
+
+    out.append(
+        "\n"
+        "/// A system call known on this platform.\n"
+        "///\n"
+        "#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n"
+        "#[non_exhaustive]\n"
+        "#[repr(usize)]\n"
+        "pub enum Sysno {\n"
+    )
+    for name, _ in calls:
+        out.append("    %s = %s,\n" % (variant(name, spec), name.upper()))
+    out.append("}\n\nimpl Sysno {\n")
+
+    out.append(
+        "    /// Every system call known on this platform, in order of number.\n"
+        "    ///\n"
+        "    pub const ALL: &'static [Self] = &[\n"
+    )
+    for name, _ in calls:
+        out.append("        Self::%s,\n" % variant(name, spec))
+    out.append("    ];\n\n")
+
+    out.append(
+        "    /// Returns the system call with the given number, or `None` if it is not\n"
+        "    /// known on this platform.\n"
+        "    ///\n"
+        "    #[must_use]\n"
+        "    #[allow(clippy::too_many_lines)]\n"
+        "    pub fn from_raw(raw: usize) -> Option<Self> {\n"
+        "        match raw {\n"
+    )
+    for name, _ in calls:
+        out.append("            %s => Some(Self::%s),\n" % (name.upper(), variant(name, spec)))
+    out.append("            _ => None,\n        }\n    }\n\n")
+
+    out.append(
+        "    /// Returns the system call with the given name, or `None` if it is not\n"
+        "    /// known on this platform.\n"
+        "    ///\n"
+        "    #[must_use]\n"
+        "    #[allow(clippy::too_many_lines)]\n"
+        "    pub fn from_name(name: &str) -> Option<Self> {\n"
+        "        match name {\n"
+    )
+    for name, _ in calls:
+        out.append("            \"%s\" => Some(Self::%s),\n" % (name, variant(name, spec)))
+    out.append("            _ => None,\n        }\n    }\n\n")
+
+    out.append(
+        "    /// Returns the number of the system call.\n"
+        "    ///\n"
+        "    #[inline(always)]\n"
+        "    #[must_use]\n"
+        "    pub const fn raw(self) -> usize {\n"
+        "        self as usize\n"
+        "    }\n\n"
+    )
+
+    out.append(
+        "    /// Returns the kernel's name for the system call, such as `\"read\"`.\n"
+        "    ///\n"
+        "    #[must_use]\n"
+        "    #[allow(clippy::too_many_lines)]\n"
+        "    pub fn name(self) -> &'static str {\n"
+        "        match self {\n"
+    )
+    for name, _ in calls:
+        out.append("            Self::%s => \"%s\",\n" % (variant(name, spec), name))
+    out.append("        }\n    }\n}\n")
+    return "".join(out)
+
+
+def main():
+    os.makedirs(OUTPUT, exist_ok=True)
+    for platform, spec in PLATFORMS.items():
+        calls = {"header": from_header, "table": from_table, "master": from_master}[spec["kind"]](spec)
+        if len({variant(name, spec).upper() for name in calls}) != len(calls):
+            raise ValueError("names collide in " + platform)
+        with open(os.path.join(OUTPUT, platform + ".rs"), "w") as f:
+            f.write(render(platform, spec, calls))
+        print("%s: %d system calls" % (platform, len(calls)))
+
+
+if __name__ == "__main__":
+    main()